@@ -0,0 +1,10 @@
+// lesson: string
+// section: 7
+// expect: E0277
+// Strings are UTF-8 bytes, so `s[0]` is not allowed: a byte is not a character.
+
+fn main() {
+    let s = String::from("Здравствуйте");
+    let c = s[0];
+    println!("{}", c);
+}
@@ -0,0 +1,15 @@
+// lesson: references_borrowing_slices
+// section: 4
+// expect: E0106
+// A function can't return a reference to its own local data: there is
+// nothing for the returned reference to borrow from.
+
+fn dangle() -> &String {
+    let s = String::from("hello");
+    &s
+}
+
+fn main() {
+    let reference = dangle();
+    println!("{}", reference);
+}
@@ -0,0 +1,13 @@
+// lesson: references_borrowing_slices
+// section: 3
+// expect: E0502
+// Mutable and immutable references can't be mixed while the immutable ones
+// are still used.
+
+fn main() {
+    let mut s = String::from("hello");
+    let r1 = &s;
+    let r2 = &mut s;
+    r2.push_str(" world");
+    println!("{}", r1);
+}
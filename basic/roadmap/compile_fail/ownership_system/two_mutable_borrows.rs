@@ -0,0 +1,12 @@
+// lesson: references_borrowing_slices
+// section: 3
+// expect: E0499
+// Only one mutable reference may be alive at a time.
+
+fn main() {
+    let mut s = String::from("hello");
+    let r1 = &mut s;
+    let r2 = &mut s;
+    r1.push_str("!");
+    r2.push_str("?");
+}
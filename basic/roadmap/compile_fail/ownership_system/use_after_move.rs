@@ -0,0 +1,11 @@
+// lesson: ownership_rules_and_memory_safety
+// section: 3
+// expect: E0382
+// After `let s2 = s1;` the String belongs to s2, s1 can't be used anymore.
+
+fn main() {
+    let s1 = String::from("hello");
+    let s2 = s1;
+    println!("s2 = {}", s2);
+    println!("s1 = {}", s1);
+}
@@ -0,0 +1,14 @@
+// lesson: ownership_rules_and_memory_safety
+// section: 6
+// expect: E0382
+// Passing a String to a function moves it, just like assignment does.
+
+fn takes_ownership(some_string: String) {
+    println!("{}", some_string);
+}
+
+fn main() {
+    let s = String::from("hello");
+    takes_ownership(s);
+    println!("After function: s = {}", s);
+}
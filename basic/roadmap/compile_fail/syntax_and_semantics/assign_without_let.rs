@@ -0,0 +1,12 @@
+// lesson: shadowing
+// section: 3
+// expect: E0384
+// Forgetting `let` turns shadowing into reassignment, which an immutable
+// variable does not allow.
+
+fn main() {
+    let x = 5;
+    let x = x + 1;
+    x = x + 1;
+    println!("x = {}", x);
+}
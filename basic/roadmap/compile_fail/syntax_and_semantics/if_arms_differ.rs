@@ -0,0 +1,10 @@
+// lesson: control_flow
+// section: 3
+// expect: E0308
+// Both arms of an `if` used as a value must have the same type.
+
+fn main() {
+    let condition = true;
+    let number = if condition { 5 } else { "six" };
+    println!("number = {}", number);
+}
@@ -0,0 +1,11 @@
+// lesson: control_flow
+// section: 1
+// expect: E0308
+// Conditions must be bool, integers are not converted automatically.
+
+fn main() {
+    let number = 3;
+    if number {
+        println!("number was three");
+    }
+}
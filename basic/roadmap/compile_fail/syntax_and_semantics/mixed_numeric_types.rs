@@ -0,0 +1,11 @@
+// lesson: numeric_operations
+// section: 6
+// expect: E0277
+// There is no implicit conversion between i32 and f64, not even in arithmetic.
+
+fn main() {
+    let int_val: i32 = 10;
+    let float_val: f64 = 2.5;
+    let mix = int_val + float_val;
+    println!("{}", mix);
+}
@@ -0,0 +1,13 @@
+// lesson: functions
+// section: 6
+// expect: E0308
+// With the semicolon `x + 1;` is a statement, so the body evaluates to ()
+// instead of the promised i32.
+
+fn plus_one(x: i32) -> i32 {
+    x + 1;
+}
+
+fn main() {
+    println!("{}", plus_one(5));
+}
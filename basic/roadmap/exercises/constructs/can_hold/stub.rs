@@ -0,0 +1,14 @@
+// lesson: impl_blocks
+// Give `Rectangle` a `can_hold` method: true when `other` fits strictly inside
+// `self`, i.e. `self` is both wider and taller than `other`.
+
+pub struct Rectangle {
+    pub width: u32,
+    pub height: u32,
+}
+
+impl Rectangle {
+    pub fn can_hold(&self, other: &Rectangle) -> bool {
+        todo!()
+    }
+}
@@ -0,0 +1,24 @@
+fn rect(width: u32, height: u32) -> Rectangle {
+    Rectangle { width, height }
+}
+
+#[test]
+fn larger_can_hold_smaller() {
+    assert!(rect(8, 7).can_hold(&rect(5, 1)));
+}
+
+#[test]
+fn smaller_cannot_hold_larger() {
+    assert!(!rect(5, 1).can_hold(&rect(8, 7)));
+}
+
+#[test]
+fn both_sides_must_be_larger() {
+    assert!(!rect(8, 7).can_hold(&rect(9, 1)));
+    assert!(!rect(8, 7).can_hold(&rect(1, 9)));
+}
+
+#[test]
+fn an_equal_rectangle_does_not_fit() {
+    assert!(!rect(8, 7).can_hold(&rect(8, 7)));
+}
@@ -0,0 +1,6 @@
+pub fn initials(name: &str) -> String {
+    name.split_whitespace()
+        .filter_map(|word| word.chars().next())
+        .flat_map(|first| first.to_uppercase())
+        .collect()
+}
@@ -0,0 +1,8 @@
+// lesson: string
+// Write `initials`, which returns the first character of every word in `name`,
+// upper-cased: "ada lovelace" gives "AL". Words are separated by whitespace,
+// and names may use any script, so work with chars rather than bytes.
+
+pub fn initials(name: &str) -> String {
+    todo!()
+}
@@ -0,0 +1,23 @@
+#[test]
+fn takes_the_first_letter_of_each_word() {
+    assert_eq!(initials("Ada Lovelace"), "AL");
+    assert_eq!(initials("Grace Brewster Murray Hopper"), "GBMH");
+}
+
+#[test]
+fn upper_cases_the_initials() {
+    assert_eq!(initials("ada lovelace"), "AL");
+}
+
+#[test]
+fn extra_whitespace_is_ignored() {
+    assert_eq!(initials("  ada \t lovelace  "), "AL");
+    assert_eq!(initials(""), "");
+    assert_eq!(initials("   "), "");
+}
+
+#[test]
+fn multi_byte_initials_are_kept_whole() {
+    assert_eq!(initials("élodie ågren"), "ÉÅ");
+    assert_eq!(initials("Здравствуй мир"), "ЗМ");
+}
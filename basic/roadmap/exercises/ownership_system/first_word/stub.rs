@@ -0,0 +1,8 @@
+// lesson: references_borrowing_slices
+// Write `first_word`, which returns the first word of `s`: everything before
+// the first space, or all of `s` when there is no space. Return a slice of
+// `s` rather than a new String.
+
+pub fn first_word(s: &str) -> &str {
+    todo!()
+}
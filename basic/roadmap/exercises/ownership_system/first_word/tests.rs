@@ -0,0 +1,26 @@
+#[test]
+fn stops_at_the_first_space() {
+    assert_eq!(first_word("hello world"), "hello");
+}
+
+#[test]
+fn a_single_word_is_returned_whole() {
+    assert_eq!(first_word("hello"), "hello");
+}
+
+#[test]
+fn empty_input_gives_an_empty_word() {
+    assert_eq!(first_word(""), "");
+}
+
+#[test]
+fn a_leading_space_gives_an_empty_word() {
+    assert_eq!(first_word(" hello"), "");
+}
+
+#[test]
+fn the_word_borrows_from_the_input() {
+    let s = String::from("borrowed slice");
+    let word = first_word(&s);
+    assert_eq!(word.as_ptr(), s.as_ptr());
+}
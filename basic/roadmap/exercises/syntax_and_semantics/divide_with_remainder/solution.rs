@@ -0,0 +1,3 @@
+pub fn divide_with_remainder(dividend: i32, divisor: i32) -> (i32, i32) {
+    (dividend / divisor, dividend % divisor)
+}
@@ -0,0 +1,7 @@
+// lesson: tuple
+// Write `divide_with_remainder`, which returns both the quotient and the
+// remainder of an integer division as a tuple: (17, 5) gives (3, 2).
+
+pub fn divide_with_remainder(dividend: i32, divisor: i32) -> (i32, i32) {
+    todo!()
+}
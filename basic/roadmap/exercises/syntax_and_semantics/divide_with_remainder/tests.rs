@@ -0,0 +1,20 @@
+#[test]
+fn splits_into_quotient_and_remainder() {
+    assert_eq!(divide_with_remainder(17, 5), (3, 2));
+}
+
+#[test]
+fn exact_division_has_no_remainder() {
+    assert_eq!(divide_with_remainder(20, 4), (5, 0));
+}
+
+#[test]
+fn truncates_toward_zero_for_negative_numbers() {
+    assert_eq!(divide_with_remainder(-7, 2), (-3, -1));
+}
+
+#[test]
+fn result_can_be_destructured() {
+    let (quotient, remainder) = divide_with_remainder(9, 4);
+    assert_eq!(quotient * 4 + remainder, 9);
+}
@@ -0,0 +1,3 @@
+pub fn plus_one(x: i32) -> i32 {
+    x + 1
+}
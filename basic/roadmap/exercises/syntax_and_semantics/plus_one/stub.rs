@@ -0,0 +1,7 @@
+// lesson: functions
+// Write `plus_one`, which returns its argument plus one. The body should be a
+// single expression: remember what a trailing semicolon does.
+
+pub fn plus_one(x: i32) -> i32 {
+    todo!()
+}
@@ -0,0 +1,15 @@
+#[test]
+fn adds_one() {
+    assert_eq!(plus_one(5), 6);
+}
+
+#[test]
+fn works_for_negative_numbers() {
+    assert_eq!(plus_one(-1), 0);
+    assert_eq!(plus_one(-10), -9);
+}
+
+#[test]
+fn works_at_zero() {
+    assert_eq!(plus_one(0), 1);
+}
@@ -0,0 +1,1126 @@
+// Command Line Interface
+// Hand-rolled argument parsing (no external crates) for the `roadmap` binary.
+//
+//   roadmap list                         all lessons, grouped by topic
+//   roadmap list <lesson>                the numbered sections of one lesson
+//   roadmap run <topic>/<lesson>         run a whole lesson
+//   roadmap run <lesson> --section 3     run only section 3
+//   roadmap run --all                    run every lesson in study order
+//   roadmap run <lesson> --format json   render as terminal text, json or markdown
+//   roadmap snapshot check|review        compare lesson output with snapshots/
+//   roadmap snapshot accept <lesson>     store the current output as the snapshot
+//   roadmap site [--out <dir>]           build the offline HTML course
+//   roadmap quiz [<topic>|<lesson>]      answer questions from quizzes/
+//   roadmap compile-fail [<lesson>]      check compile_fail/ snippets against rustc
+//   roadmap path                         every lesson in prerequisite order
+//   roadmap next [<lesson>...]           what to study after the given (or completed) lessons
+//   roadmap progress [--file <path>]     per-topic completion report
+//   roadmap exercise list|start|check|hint   write code against hidden tests
+//   roadmap search <term>...             which sections explain a concept
+//   roadmap layout [<type> [<type>]]     field offsets and padding, two side by side
+//   roadmap overflow <int> <a> <op> [<b>]   checked/wrapping/saturating/overflowing results
+//   roadmap float <value> [--f32]        IEEE-754 fields, exact value, ULP and neighbours
+//   roadmap utf8 <text>                  code points, encodings, char boundaries, graphemes
+//   roadmap literal <literal> [--as <type>]   type, radix and value, or rustc's error
+//   roadmap turtle <script> [--out <file>]    draw a Message script as SVG (or .ppm)
+
+use std::fmt;
+use std::fs;
+use std::io::{self, BufRead, Write};
+use std::path::PathBuf;
+
+use crate::compile_fail::{self, Outcome, Snippet, SnippetError};
+use crate::exercise::{self, ExerciseError};
+use crate::float;
+use crate::layout;
+use crate::learning_path::{self, PathError};
+use crate::output::{self, Format};
+use crate::overflow::{self, OverflowError};
+use crate::progress::{self, ProgressError, Record};
+use crate::quiz::{self, QuizError};
+use crate::registry::{self, Entry, LookupError, TOPICS};
+use crate::search;
+use crate::site;
+use crate::snapshot::{self, Report};
+use roadmap::literal::{self, LiteralError};
+use roadmap::turtle::render::RenderError;
+use roadmap::turtle::{self, Canvas, ScriptError};
+use roadmap::unicode;
+
+pub const USAGE: &str = "\
+Usage:
+  roadmap list [<lesson>]
+  roadmap run <topic>/<lesson> [--section <n>] [--format <format>]
+  roadmap run <lesson> [--section <n>] [--format <format>]
+  roadmap run --all [--format <format>]
+  roadmap snapshot check [<lesson>]
+  roadmap snapshot review [<lesson>]
+  roadmap snapshot accept <lesson>|--all
+  roadmap site [--out <dir>]
+  roadmap quiz [<topic>|<lesson>]
+  roadmap compile-fail [<lesson>]
+  roadmap path
+  roadmap next [<lesson>...]
+  roadmap progress [--file <path>]
+  roadmap exercise list
+  roadmap exercise start|check|hint <exercise>
+  roadmap search <term>...
+  roadmap layout [<type> [<type>]]
+  roadmap overflow <int> <a> <op> [<b>]
+  roadmap float <value> [--f32]
+  roadmap utf8 <text>
+  roadmap literal <literal> [--as <type>]
+  roadmap turtle <script> [--out <file>]
+  roadmap help
+
+A bare <lesson> id works when it is unique; otherwise prefix it with its topic
+(e.g. data_structures/arrays vs syntax_and_semantics/arrays).
+<format> is terminal (the default), json or markdown.
+<int> is i8..i128, u8..u128, isize or usize; <op> is + - * / % << pow neg abs
+(quote * for the shell), and MIN or MAX may stand for an operand.
+turtle prints SVG unless --out names a file; a .ppm file gets a P6 image.
+run and quiz record what you did in ~/.roadmap/progress.txt ($ROADMAP_PROGRESS);
+next without lessons suggests what follows the lessons completed there.";
+
+#[derive(Debug, PartialEq)]
+pub enum Command {
+    Help,
+    List {
+        lesson: Option<String>,
+    },
+    Run {
+        lesson: String,
+        section: Option<usize>,
+        format: Format,
+    },
+    RunAll {
+        format: Format,
+    },
+    Snapshot {
+        action: SnapshotAction,
+        lesson: Option<String>,
+    },
+    Site {
+        out: Option<PathBuf>,
+    },
+    Quiz {
+        query: Option<String>,
+    },
+    CompileFail {
+        lesson: Option<String>,
+    },
+    Path,
+    Next {
+        done: Vec<String>,
+    },
+    Progress {
+        file: Option<PathBuf>,
+    },
+    Exercise {
+        action: ExerciseAction,
+        name: Option<String>,
+    },
+    Search {
+        query: String,
+    },
+    Layout {
+        types: Vec<String>,
+    },
+    Overflow {
+        ty: String,
+        a: String,
+        op: String,
+        b: Option<String>,
+    },
+    Float {
+        value: String,
+        single: bool,
+    },
+    Utf8 {
+        text: String,
+    },
+    Literal {
+        text: String,
+        annotation: Option<String>,
+    },
+    Turtle {
+        script: PathBuf,
+        out: Option<PathBuf>,
+    },
+}
+
+#[derive(Debug, PartialEq)]
+pub enum ExerciseAction {
+    List,
+    Start,
+    Check,
+    Hint,
+}
+
+#[derive(Debug, PartialEq)]
+pub enum SnapshotAction {
+    Check,
+    Review,
+    Accept,
+}
+
+#[derive(Debug)]
+pub enum CliError {
+    Usage(String),
+    Lookup(LookupError),
+    Io(io::Error),
+    SnapshotMismatch(usize),
+    Quiz(QuizError),
+    Snippet(SnippetError),
+    CompileFailMismatch(usize),
+    Path(PathError),
+    Progress(ProgressError),
+    Exercise(ExerciseError),
+    TestsFailed { failed: usize, total: usize },
+    UnknownType(String),
+    Overflow(OverflowError),
+    Literal(LiteralError),
+    Script { file: PathBuf, err: ScriptError },
+    Render(RenderError),
+}
+
+impl fmt::Display for CliError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            CliError::Usage(message) => write!(f, "{}\n\n{}", message, USAGE),
+            CliError::Lookup(err) => write!(f, "{}", err),
+            CliError::Io(err) => write!(f, "{}", err),
+            CliError::SnapshotMismatch(count) => write!(
+                f,
+                "{} lesson(s) differ from their snapshot, run `roadmap snapshot review`",
+                count
+            ),
+            CliError::Quiz(err) => write!(f, "{}", err),
+            CliError::Snippet(err) => write!(f, "{}", err),
+            CliError::CompileFailMismatch(count) => write!(
+                f,
+                "{} snippet(s) did not fail with their expected error",
+                count
+            ),
+            CliError::Path(err) => write!(f, "{}", err),
+            CliError::Progress(err) => write!(f, "{}", err),
+            CliError::Exercise(err) => write!(f, "{}", err),
+            CliError::TestsFailed { failed, total } => {
+                write!(f, "{} of {} hidden tests failed", failed, total)
+            }
+            CliError::Overflow(err) => write!(f, "{}", err),
+            CliError::Literal(err) => write!(f, "{}", err),
+            CliError::Script { file, err } => write!(f, "{}: {}", file.display(), err),
+            CliError::Render(err) => write!(f, "{}", err),
+            CliError::UnknownType(name) => {
+                let known: Vec<&str> = layout::registered().iter().map(|l| l.name).collect();
+                write!(
+                    f,
+                    "no layout registered for '{}' (known: {})",
+                    name,
+                    known.join(", ")
+                )
+            }
+        }
+    }
+}
+
+impl From<LookupError> for CliError {
+    fn from(err: LookupError) -> Self {
+        CliError::Lookup(err)
+    }
+}
+
+impl From<io::Error> for CliError {
+    fn from(err: io::Error) -> Self {
+        CliError::Io(err)
+    }
+}
+
+impl From<QuizError> for CliError {
+    fn from(err: QuizError) -> Self {
+        CliError::Quiz(err)
+    }
+}
+
+impl From<PathError> for CliError {
+    fn from(err: PathError) -> Self {
+        CliError::Path(err)
+    }
+}
+
+impl From<ProgressError> for CliError {
+    fn from(err: ProgressError) -> Self {
+        CliError::Progress(err)
+    }
+}
+
+impl From<ExerciseError> for CliError {
+    fn from(err: ExerciseError) -> Self {
+        CliError::Exercise(err)
+    }
+}
+
+impl From<OverflowError> for CliError {
+    fn from(err: OverflowError) -> Self {
+        CliError::Overflow(err)
+    }
+}
+
+impl From<RenderError> for CliError {
+    fn from(err: RenderError) -> Self {
+        CliError::Render(err)
+    }
+}
+
+impl From<LiteralError> for CliError {
+    fn from(err: LiteralError) -> Self {
+        CliError::Literal(err)
+    }
+}
+
+impl From<SnippetError> for CliError {
+    fn from(err: SnippetError) -> Self {
+        CliError::Snippet(err)
+    }
+}
+
+pub fn parse<I>(args: I) -> Result<Command, CliError>
+where
+    I: IntoIterator<Item = String>,
+{
+    let mut args = args.into_iter();
+    let Some(command) = args.next() else {
+        return Ok(Command::Help);
+    };
+    let rest: Vec<String> = args.collect();
+
+    match command.as_str() {
+        "help" | "--help" | "-h" => Ok(Command::Help),
+        "list" => match rest.as_slice() {
+            [] => Ok(Command::List { lesson: None }),
+            [lesson] => Ok(Command::List {
+                lesson: Some(lesson.clone()),
+            }),
+            _ => Err(CliError::Usage("list takes at most one lesson".to_string())),
+        },
+        "run" => parse_run(&rest),
+        "snapshot" => parse_snapshot(&rest),
+        "exercise" => parse_exercise(&rest),
+        "search" if rest.is_empty() => Err(CliError::Usage("search needs a term".to_string())),
+        "search" => Ok(Command::Search {
+            query: rest.join(" "),
+        }),
+        "layout" if rest.len() > 2 => Err(CliError::Usage(
+            "layout compares at most two types".to_string(),
+        )),
+        "layout" => Ok(Command::Layout { types: rest }),
+        "overflow" => match rest.as_slice() {
+            [ty, a, op] => Ok(Command::Overflow {
+                ty: ty.clone(),
+                a: a.clone(),
+                op: op.clone(),
+                b: None,
+            }),
+            [ty, a, op, b] => Ok(Command::Overflow {
+                ty: ty.clone(),
+                a: a.clone(),
+                op: op.clone(),
+                b: Some(b.clone()),
+            }),
+            _ => Err(CliError::Usage(
+                "overflow takes <int> <a> <op> [<b>]".to_string(),
+            )),
+        },
+        "float" => match rest.as_slice() {
+            [value] => Ok(Command::Float {
+                value: value.clone(),
+                single: false,
+            }),
+            [value, flag] | [flag, value] if flag == "--f32" => Ok(Command::Float {
+                value: value.clone(),
+                single: true,
+            }),
+            _ => Err(CliError::Usage(
+                "float takes one value and optionally --f32".to_string(),
+            )),
+        },
+        "utf8" if rest.is_empty() => Err(CliError::Usage("utf8 needs some text".to_string())),
+        "utf8" => Ok(Command::Utf8 {
+            text: rest.join(" "),
+        }),
+        "literal" => match rest.as_slice() {
+            [text] => Ok(Command::Literal {
+                text: text.clone(),
+                annotation: None,
+            }),
+            [text, flag, ty] | [flag, ty, text] if flag == "--as" => Ok(Command::Literal {
+                text: text.clone(),
+                annotation: Some(ty.clone()),
+            }),
+            _ => Err(CliError::Usage(
+                "literal takes one literal and an optional --as <type>".to_string(),
+            )),
+        },
+        "turtle" => match rest.as_slice() {
+            [script] => Ok(Command::Turtle {
+                script: PathBuf::from(script),
+                out: None,
+            }),
+            [script, flag, file] | [flag, file, script] if flag == "--out" => Ok(Command::Turtle {
+                script: PathBuf::from(script),
+                out: Some(PathBuf::from(file)),
+            }),
+            _ => Err(CliError::Usage(
+                "turtle takes a script file and an optional --out <file>".to_string(),
+            )),
+        },
+        "site" => match rest.as_slice() {
+            [] => Ok(Command::Site { out: None }),
+            [flag, dir] if flag == "--out" => Ok(Command::Site {
+                out: Some(PathBuf::from(dir)),
+            }),
+            _ => Err(CliError::Usage("site takes only --out <dir>".to_string())),
+        },
+        "quiz" => match rest.as_slice() {
+            [] => Ok(Command::Quiz { query: None }),
+            [query] => Ok(Command::Quiz {
+                query: Some(query.clone()),
+            }),
+            _ => Err(CliError::Usage(
+                "quiz takes at most one topic or lesson".to_string(),
+            )),
+        },
+        "compile-fail" => match rest.as_slice() {
+            [] => Ok(Command::CompileFail { lesson: None }),
+            [lesson] => Ok(Command::CompileFail {
+                lesson: Some(lesson.clone()),
+            }),
+            _ => Err(CliError::Usage(
+                "compile-fail takes at most one lesson".to_string(),
+            )),
+        },
+        "path" => match rest.as_slice() {
+            [] => Ok(Command::Path),
+            _ => Err(CliError::Usage("path takes no arguments".to_string())),
+        },
+        "next" => Ok(Command::Next { done: rest }),
+        "progress" => match rest.as_slice() {
+            [] => Ok(Command::Progress { file: None }),
+            [flag, file] if flag == "--file" => Ok(Command::Progress {
+                file: Some(PathBuf::from(file)),
+            }),
+            _ => Err(CliError::Usage(
+                "progress takes only --file <path>".to_string(),
+            )),
+        },
+        other => Err(CliError::Usage(format!("unknown command '{}'", other))),
+    }
+}
+
+fn parse_run(args: &[String]) -> Result<Command, CliError> {
+    let mut lesson = None;
+    let mut section = None;
+    let mut all = false;
+    let mut format = Format::Terminal;
+
+    let mut args = args.iter();
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--all" => all = true,
+            "--section" => {
+                let Some(value) = args.next() else {
+                    return Err(CliError::Usage("--section needs a number".to_string()));
+                };
+                let Ok(number) = value.parse::<usize>() else {
+                    return Err(CliError::Usage(format!(
+                        "'{}' is not a section number",
+                        value
+                    )));
+                };
+                section = Some(number);
+            }
+            "--format" => {
+                let Some(value) = args.next() else {
+                    return Err(CliError::Usage("--format needs a format".to_string()));
+                };
+                let Some(parsed) = Format::parse(value) else {
+                    return Err(CliError::Usage(format!("unknown format '{}'", value)));
+                };
+                format = parsed;
+            }
+            flag if flag.starts_with("--") => {
+                return Err(CliError::Usage(format!("unknown flag '{}'", flag)));
+            }
+            _ if lesson.is_some() => {
+                return Err(CliError::Usage("run takes a single lesson".to_string()));
+            }
+            _ => lesson = Some(arg.clone()),
+        }
+    }
+
+    match (all, lesson, section) {
+        (true, None, None) => Ok(Command::RunAll { format }),
+        (true, _, _) => Err(CliError::Usage(
+            "--all cannot be combined with a lesson or --section".to_string(),
+        )),
+        (false, Some(lesson), section) => Ok(Command::Run {
+            lesson,
+            section,
+            format,
+        }),
+        (false, None, _) => Err(CliError::Usage("run needs a lesson or --all".to_string())),
+    }
+}
+
+fn parse_snapshot(args: &[String]) -> Result<Command, CliError> {
+    let (action, rest) = match args.split_first() {
+        Some((action, rest)) => (action.as_str(), rest),
+        None => ("check", args),
+    };
+    let action = match action {
+        "check" => SnapshotAction::Check,
+        "review" => SnapshotAction::Review,
+        "accept" => SnapshotAction::Accept,
+        other => {
+            return Err(CliError::Usage(format!(
+                "unknown snapshot action '{}'",
+                other
+            )));
+        }
+    };
+
+    let lesson = match rest {
+        [] if action == SnapshotAction::Accept => {
+            return Err(CliError::Usage(
+                "snapshot accept needs a lesson or --all".to_string(),
+            ));
+        }
+        [] => None,
+        [all] if all == "--all" => None,
+        [lesson] => Some(lesson.clone()),
+        _ => {
+            return Err(CliError::Usage(
+                "snapshot takes at most one lesson".to_string(),
+            ));
+        }
+    };
+    Ok(Command::Snapshot { action, lesson })
+}
+
+fn parse_exercise(args: &[String]) -> Result<Command, CliError> {
+    let action = match args.first().map(String::as_str) {
+        None | Some("list") => ExerciseAction::List,
+        Some("start") => ExerciseAction::Start,
+        Some("check") => ExerciseAction::Check,
+        Some("hint") => ExerciseAction::Hint,
+        Some(other) => {
+            return Err(CliError::Usage(format!(
+                "unknown exercise action '{}'",
+                other
+            )));
+        }
+    };
+    let rest = args.get(1..).unwrap_or_default();
+    match (&action, rest) {
+        (ExerciseAction::List, []) => Ok(Command::Exercise { action, name: None }),
+        (ExerciseAction::List, _) => Err(CliError::Usage(
+            "exercise list takes no arguments".to_string(),
+        )),
+        (_, [name]) => Ok(Command::Exercise {
+            action,
+            name: Some(name.clone()),
+        }),
+        _ => Err(CliError::Usage(
+            "exercise start|check|hint needs one exercise name".to_string(),
+        )),
+    }
+}
+
+pub fn execute(command: Command) -> Result<(), CliError> {
+    match command {
+        Command::Help => println!("{}", USAGE),
+        Command::List { lesson: None } => list_lessons(),
+        Command::List {
+            lesson: Some(lesson),
+        } => list_sections(&lesson)?,
+        Command::Run {
+            lesson,
+            section,
+            format,
+        } => {
+            let entry = registry::find(&lesson)?;
+            match section {
+                Some(number) => {
+                    // Fail before any output is rendered
+                    entry.lesson.section(number)?;
+                    output::render(format, || entry.run_section(number))?;
+                }
+                None => output::render(format, || entry.run()),
+            }
+            record(Record::Run {
+                at: progress::now(),
+                lesson: entry.path(),
+                section,
+            });
+        }
+        Command::RunAll { format } => {
+            output::render(format, || {
+                for entry in registry::entries() {
+                    entry.run();
+                }
+            });
+            for entry in registry::entries() {
+                record(Record::Run {
+                    at: progress::now(),
+                    lesson: entry.path(),
+                    section: None,
+                });
+            }
+        }
+        Command::Snapshot { action, lesson } => {
+            let entries = match lesson {
+                Some(lesson) => vec![registry::find(&lesson)?],
+                None => registry::entries().collect(),
+            };
+            match action {
+                SnapshotAction::Check => check_snapshots(entries)?,
+                SnapshotAction::Review => review_snapshots(entries)?,
+                SnapshotAction::Accept => {
+                    for entry in entries {
+                        snapshot::accept(&snapshot::check(entry))?;
+                        println!("accepted {}", entry.path());
+                    }
+                }
+            }
+        }
+        Command::Site { out } => {
+            let dir = out.unwrap_or_else(|| PathBuf::from(site::DEFAULT_OUT_DIR));
+            let pages = site::generate(&dir)?;
+            println!("wrote {} pages to {}", pages, dir.display());
+        }
+        Command::Quiz { query } => {
+            let questions = quiz::select(query.as_deref())?;
+            let score = quiz::run(&questions, &mut io::stdin().lock())?;
+            if score.asked > 0 {
+                record(Record::Quiz {
+                    at: progress::now(),
+                    scope: quiz_scope(query),
+                    correct: score.correct,
+                    asked: score.asked,
+                });
+            }
+        }
+        Command::CompileFail { lesson } => {
+            let mut snippets = compile_fail::snippets()?;
+            if let Some(lesson) = lesson {
+                let entry = registry::find(&lesson)?;
+                snippets.retain(|snippet| snippet.entry.path() == entry.path());
+            }
+            check_snippets(&snippets)?;
+        }
+        Command::Path => print_path()?,
+        Command::Next { done } if done.is_empty() => {
+            let progress = progress::load(&progress::default_path())?;
+            print_next(&progress.completed())?;
+        }
+        Command::Next { done } => {
+            let done = done
+                .iter()
+                .map(|lesson| registry::find(lesson))
+                .collect::<Result<Vec<Entry>, LookupError>>()?;
+            print_next(&done)?;
+        }
+        Command::Exercise { action, name } => run_exercise(action, name.as_deref())?,
+        Command::Search { query } => print_search(&query)?,
+        Command::Layout { types } => print_layout(&types)?,
+        Command::Float { value, single } => {
+            let lines = float::describe_text(&value, single)
+                .ok_or_else(|| CliError::Usage(format!("'{}' is not a number", value)))?;
+            for line in lines {
+                println!("{}", line);
+            }
+        }
+        Command::Utf8 { text } => {
+            for line in unicode::report(&text) {
+                println!("{}", line);
+            }
+        }
+        Command::Literal { text, annotation } => {
+            let literal = literal::parse(&text, annotation.as_deref())?;
+            for line in literal::describe(&literal) {
+                println!("{}", line);
+            }
+        }
+        Command::Turtle { script, out } => {
+            let source = fs::read_to_string(&script)?;
+            let messages = turtle::parse(&source).map_err(|err| CliError::Script {
+                file: script.clone(),
+                err,
+            })?;
+            let mut canvas = Canvas::new();
+            let executed = canvas.run(&messages);
+            match out {
+                None => print!("{}", turtle::render::svg(&canvas)),
+                Some(file) => {
+                    let ppm = file.extension().is_some_and(|ext| ext == "ppm");
+                    if ppm {
+                        fs::write(&file, turtle::render::ppm(&canvas)?)?;
+                    } else {
+                        fs::write(&file, turtle::render::svg(&canvas))?;
+                    }
+                    println!(
+                        "ran {} of {} messages: {} line(s), {} label(s), wrote {}",
+                        executed,
+                        messages.len(),
+                        canvas.segments.len(),
+                        canvas.labels.len(),
+                        file.display()
+                    );
+                }
+            }
+        }
+        Command::Overflow { ty, a, op, b } => {
+            for line in overflow::table_named(&ty, &a, &op, b.as_deref())? {
+                println!("{}", line);
+            }
+        }
+        Command::Progress { file } => {
+            let path = file.unwrap_or_else(progress::default_path);
+            progress::report(&progress::load(&path)?, &path)?;
+        }
+    }
+    Ok(())
+}
+
+fn list_lessons() {
+    for topic in TOPICS {
+        println!("{} ({})", topic.title, topic.id);
+        for lesson in topic.lessons {
+            let path = format!("{}/{}", topic.id, lesson.id);
+            let count = lesson.sections.len();
+            let noun = if count == 1 { "section" } else { "sections" };
+            println!("  {:<52} {} ({} {})", path, lesson.title, count, noun);
+        }
+        println!();
+    }
+}
+
+fn list_sections(query: &str) -> Result<(), CliError> {
+    let entry = registry::find(query)?;
+    let meta = &entry.lesson.meta;
+    println!("{} ({})", entry.lesson.title, entry.path());
+    println!(
+        "  {}, about {} min  {}",
+        meta.difficulty,
+        meta.minutes,
+        book_chapter(meta.book)
+    );
+    if !meta.prerequisites.is_empty() {
+        println!("  requires: {}", meta.prerequisites.join(", "));
+    }
+    if !meta.tags.is_empty() {
+        println!("  tags: {}", meta.tags.join(", "));
+    }
+    for (index, section) in entry.lesson.sections.iter().enumerate() {
+        println!("  {:>2}. {}", index + 1, section.title);
+    }
+    Ok(())
+}
+
+fn print_layout(types: &[String]) -> Result<(), CliError> {
+    let find =
+        |name: &String| layout::find(name).ok_or_else(|| CliError::UnknownType(name.clone()));
+    match types {
+        [] => {
+            for registered in layout::registered() {
+                println!("{}", registered.summary());
+            }
+        }
+        [name] => {
+            let inspected = find(name)?;
+            for line in inspected.report() {
+                println!("{}", line);
+            }
+            println!("{}", inspected.niche_summary());
+        }
+        [left, right] => {
+            for line in layout::side_by_side(&find(left)?, &find(right)?) {
+                println!("{}", line);
+            }
+        }
+        _ => unreachable!("parse allows at most two types"),
+    }
+    Ok(())
+}
+
+fn print_search(query: &str) -> Result<(), CliError> {
+    const SHOWN: usize = 10;
+    let documents = search::index()?;
+    let hits = search::search(&documents, query);
+    if hits.is_empty() {
+        println!("no section mentions '{}'", query);
+        return Ok(());
+    }
+
+    for hit in hits.iter().take(SHOWN) {
+        let document = hit.document;
+        println!(
+            "{}, section {}: {}",
+            document.entry.path(),
+            document.section,
+            document.title()
+        );
+        println!("    {}", hit.snippet);
+        println!("    $ {}", document.command());
+    }
+    if hits.len() > SHOWN {
+        println!("\n{} more sections match", hits.len() - SHOWN);
+    }
+    Ok(())
+}
+
+fn run_exercise(action: ExerciseAction, name: Option<&str>) -> Result<(), CliError> {
+    let Some(name) = name else {
+        for exercise in exercise::all()? {
+            println!(
+                "  {:<24} {:<52} {}",
+                exercise.name,
+                exercise.entry.path(),
+                exercise.description
+            );
+        }
+        return Ok(());
+    };
+    let exercise = exercise::find(name)?;
+
+    match action {
+        ExerciseAction::List => {}
+        ExerciseAction::Start => {
+            let (path, created) = exercise::start(&exercise)?;
+            println!("{}", exercise.description);
+            if created {
+                println!("\nwrote {}", path.display());
+            } else {
+                println!("\nyour attempt is already at {}", path.display());
+            }
+            println!(
+                "edit it, then run `roadmap exercise check {}`",
+                exercise.name
+            );
+        }
+        ExerciseAction::Check => {
+            let path = exercise.attempt_path();
+            let attempt = match fs::read_to_string(&path) {
+                Ok(attempt) => attempt,
+                Err(err) if err.kind() == io::ErrorKind::NotFound => {
+                    return Err(ExerciseError::NotStarted(path).into());
+                }
+                Err(err) => return Err(err.into()),
+            };
+            let results = exercise::check(&exercise, &attempt)?;
+            for result in &results {
+                let mark = if result.passed { "✓" } else { "✗" };
+                println!("{} {}", mark, result.name);
+                if let Some(message) = &result.message {
+                    for line in message.lines() {
+                        println!("    {}", line);
+                    }
+                }
+            }
+            let failed = results.iter().filter(|result| !result.passed).count();
+            if failed > 0 {
+                println!("\nstuck? `roadmap exercise hint {}`", exercise.name);
+                return Err(CliError::TestsFailed {
+                    failed,
+                    total: results.len(),
+                });
+            }
+            println!("\nall {} hidden tests pass", results.len());
+        }
+        ExerciseAction::Hint => match exercise.next_hint()? {
+            Some((number, total, hint)) => println!("Hint {}/{}: {}", number, total, hint),
+            None => println!(
+                "no more hints, compare with the lesson {}",
+                exercise.entry.path()
+            ),
+        },
+    }
+    Ok(())
+}
+
+// Lessons are stored as "topic/lesson" whichever way they were typed
+fn quiz_scope(query: Option<String>) -> String {
+    match query {
+        None => "all".to_string(),
+        Some(query) => registry::find(&query)
+            .map(|entry| entry.path())
+            .unwrap_or(query),
+    }
+}
+
+// Progress is a convenience: failing to save it must not fail the lesson
+fn record(record: Record) {
+    if let Err(err) = progress::append(&progress::default_path(), &record) {
+        eprintln!("warning: could not record progress: {}", err);
+    }
+}
+
+fn print_path() -> Result<(), CliError> {
+    let mut total = 0;
+    for (index, entry) in learning_path::order()?.iter().enumerate() {
+        let meta = &entry.lesson.meta;
+        total += meta.minutes;
+        println!(
+            "{:>3}. {:<52} {:<12} {:>3} min  {}",
+            index + 1,
+            entry.path(),
+            meta.difficulty,
+            meta.minutes,
+            book_chapter(meta.book)
+        );
+    }
+    println!("\nabout {}h{:02} in total", total / 60, total % 60);
+    Ok(())
+}
+
+fn print_next(done: &[Entry]) -> Result<(), CliError> {
+    let ready = learning_path::next(done)?;
+    if ready.is_empty() {
+        println!("nothing left, every lesson is done");
+        return Ok(());
+    }
+    println!("Ready to study:");
+    for entry in ready {
+        let meta = &entry.lesson.meta;
+        println!(
+            "  {:<52} {} ({}, {} min)",
+            entry.path(),
+            entry.lesson.title,
+            meta.difficulty,
+            meta.minutes
+        );
+    }
+    Ok(())
+}
+
+fn book_chapter(book: Option<&str>) -> String {
+    book.map(|chapter| format!("Book ch. {}", chapter))
+        .unwrap_or_default()
+}
+
+fn check_snapshots(entries: Vec<Entry>) -> Result<(), CliError> {
+    let mut mismatches = 0;
+    for entry in entries {
+        let report = snapshot::check(entry);
+        let label = match report.status {
+            snapshot::Status::Matches => "ok",
+            snapshot::Status::Missing => "MISSING",
+            snapshot::Status::Changed { .. } => "CHANGED",
+        };
+        println!("{:<8} {}", label, entry.path());
+        if !report.matches() {
+            mismatches += 1;
+        }
+    }
+    match mismatches {
+        0 => Ok(()),
+        count => Err(CliError::SnapshotMismatch(count)),
+    }
+}
+
+fn check_snippets(snippets: &[Snippet]) -> Result<(), CliError> {
+    let mut mismatches = 0;
+    for snippet in snippets {
+        let outcome = compile_fail::check(snippet)?;
+        let label = match &outcome {
+            Outcome::Rejected => "ok".to_string(),
+            Outcome::Compiled => "COMPILED".to_string(),
+            Outcome::WrongErrors(codes) => format!("GOT {}", codes.join(",")),
+        };
+        let section = snippet
+            .section
+            .map(|number| format!(" section {}", number))
+            .unwrap_or_default();
+        println!(
+            "{:<8} {} {:<52} {}{}",
+            label,
+            snippet.expected,
+            snippet.name(),
+            snippet.entry.path(),
+            section
+        );
+        if !matches!(outcome, Outcome::Rejected) {
+            mismatches += 1;
+        }
+    }
+    match mismatches {
+        0 => Ok(()),
+        count => Err(CliError::CompileFailMismatch(count)),
+    }
+}
+
+// Shows each differing lesson and asks whether to accept its new output
+fn review_snapshots(entries: Vec<Entry>) -> Result<(), CliError> {
+    let stdin = io::stdin();
+    let mut answers = stdin.lock().lines();
+
+    let reports: Vec<Report> = entries
+        .into_iter()
+        .map(snapshot::check)
+        .filter(|report| !report.matches())
+        .collect();
+    if reports.is_empty() {
+        println!("all snapshots match");
+        return Ok(());
+    }
+
+    for report in &reports {
+        println!("--- {}", snapshot::path(&report.entry).display());
+        println!("+++ {} (current output)", report.entry.path());
+        print!("{}", report.diff());
+        print!("accept new output for {}? [y/N/q] ", report.entry.path());
+        io::stdout().flush()?;
+
+        let answer = answers.next().transpose()?.unwrap_or_default();
+        match answer.trim() {
+            "y" | "Y" | "yes" => {
+                snapshot::accept(report)?;
+                println!("accepted\n");
+            }
+            "q" | "Q" => break,
+            _ => println!("skipped\n"),
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse_str(args: &str) -> Result<Command, CliError> {
+        parse(args.split_whitespace().map(String::from))
+    }
+
+    fn usage(args: &str) -> String {
+        match parse_str(args) {
+            Err(CliError::Usage(message)) => message,
+            other => panic!("expected a usage error for '{}', got {:?}", args, other),
+        }
+    }
+
+    #[test]
+    fn no_arguments_or_help_prints_usage() {
+        assert_eq!(parse_str("").unwrap(), Command::Help);
+        assert_eq!(parse_str("--help").unwrap(), Command::Help);
+        assert_eq!(usage("frobnicate"), "unknown command 'frobnicate'");
+    }
+
+    #[test]
+    fn parses_run() {
+        assert_eq!(
+            parse_str("run closures --section 2 --format json").unwrap(),
+            Command::Run {
+                lesson: "closures".to_string(),
+                section: Some(2),
+                format: Format::Json,
+            }
+        );
+        assert_eq!(
+            parse_str("run --all").unwrap(),
+            Command::RunAll {
+                format: Format::Terminal
+            }
+        );
+        assert_eq!(usage("run"), "run needs a lesson or --all");
+        assert_eq!(usage("run a b"), "run takes a single lesson");
+        assert_eq!(
+            usage("run a --section two"),
+            "'two' is not a section number"
+        );
+        assert_eq!(usage("run a --format pdf"), "unknown format 'pdf'");
+        assert_eq!(
+            usage("run a --all"),
+            "--all cannot be combined with a lesson or --section"
+        );
+    }
+
+    #[test]
+    fn flags_may_come_before_or_after_values() {
+        let literal = Command::Literal {
+            text: "255".to_string(),
+            annotation: Some("u8".to_string()),
+        };
+        assert_eq!(parse_str("literal 255 --as u8").unwrap(), literal);
+        assert_eq!(parse_str("literal --as u8 255").unwrap(), literal);
+        assert_eq!(
+            parse_str("float --f32 0.1").unwrap(),
+            Command::Float {
+                value: "0.1".to_string(),
+                single: true,
+            }
+        );
+        assert_eq!(
+            parse_str("overflow u8 MAX + 1").unwrap(),
+            Command::Overflow {
+                ty: "u8".to_string(),
+                a: "MAX".to_string(),
+                op: "+".to_string(),
+                b: Some("1".to_string()),
+            }
+        );
+    }
+
+    #[test]
+    fn parses_snapshot_and_exercise_actions() {
+        assert_eq!(
+            parse_str("snapshot").unwrap(),
+            Command::Snapshot {
+                action: SnapshotAction::Check,
+                lesson: None,
+            }
+        );
+        assert_eq!(
+            parse_str("snapshot accept constructs/structs").unwrap(),
+            Command::Snapshot {
+                action: SnapshotAction::Accept,
+                lesson: Some("constructs/structs".to_string()),
+            }
+        );
+        assert_eq!(
+            usage("snapshot accept"),
+            "snapshot accept needs a lesson or --all"
+        );
+        assert_eq!(
+            parse_str("exercise").unwrap(),
+            Command::Exercise {
+                action: ExerciseAction::List,
+                name: None,
+            }
+        );
+        assert_eq!(
+            parse_str("exercise hint first_word").unwrap(),
+            Command::Exercise {
+                action: ExerciseAction::Hint,
+                name: Some("first_word".to_string()),
+            }
+        );
+        assert_eq!(
+            usage("exercise check"),
+            "exercise start|check|hint needs one exercise name"
+        );
+    }
+
+    #[test]
+    fn multi_word_arguments_are_joined() {
+        assert_eq!(
+            parse_str("search borrow checker").unwrap(),
+            Command::Search {
+                query: "borrow checker".to_string()
+            }
+        );
+        assert_eq!(usage("utf8"), "utf8 needs some text");
+        assert_eq!(usage("layout A B C"), "layout compares at most two types");
+    }
+}
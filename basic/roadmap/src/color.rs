@@ -0,0 +1,530 @@
+// Color
+// The destructuring lesson's `Color` enum, as a working colour library.
+// `Rgb` (8 bits per channel, as stored in images and written in CSS) is the
+// hub every other space converts through:
+//
+//   let rgb = Rgb::parse("#7a1128")?;        // also "rgb(122, 17, 40)", "crimson"
+//   let Hsv { h, s, v } = Hsv::from(rgb);    // h in degrees, s and v in 0..=1
+//   Rgb::from(Hsv { h, s, v }) == rgb        // true
+//
+// `Hsv`, `Hsl` and `Cmyk` hold f64 components, so converting an `Rgb` into
+// any of them and back gives the same `Rgb`; only values that never came from
+// an `Rgb` get rounded to the nearest 8-bit channel. Contrast ratios follow
+// WCAG 2.x.
+
+use std::fmt;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct Rgb {
+    pub r: u8,
+    pub g: u8,
+    pub b: u8,
+}
+
+// Hue in degrees 0.0..360.0; saturation and value in 0.0..=1.0
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct Hsv {
+    pub h: f64,
+    pub s: f64,
+    pub v: f64,
+}
+
+// Hue in degrees 0.0..360.0; saturation and lightness in 0.0..=1.0
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct Hsl {
+    pub h: f64,
+    pub s: f64,
+    pub l: f64,
+}
+
+// Ink coverage, each in 0.0..=1.0
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct Cmyk {
+    pub c: f64,
+    pub m: f64,
+    pub y: f64,
+    pub k: f64,
+}
+
+#[derive(Debug, PartialEq)]
+pub enum ColorError {
+    Empty,
+    InvalidHex(String),
+    InvalidRgb(String),
+    OutOfRange(String),
+    UnknownName(String),
+}
+
+impl fmt::Display for ColorError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ColorError::Empty => write!(f, "no colour given"),
+            ColorError::InvalidHex(text) => {
+                write!(f, "'{}' is not a hex colour (#rgb or #rrggbb)", text)
+            }
+            ColorError::InvalidRgb(text) => {
+                write!(f, "'{}' is not rgb(<red>, <green>, <blue>)", text)
+            }
+            ColorError::OutOfRange(component) => {
+                write!(f, "colour component '{}' is not in 0..=255", component)
+            }
+            ColorError::UnknownName(name) => write!(f, "unknown colour name '{}'", name),
+        }
+    }
+}
+
+// The CSS Level 1 keywords plus a few common extended ones. CSS "green" is
+// only half bright; full green is "lime"
+const NAMES: &[(&str, Rgb)] = &[
+    ("black", Rgb::new(0, 0, 0)),
+    ("silver", Rgb::new(192, 192, 192)),
+    ("gray", Rgb::new(128, 128, 128)),
+    ("white", Rgb::new(255, 255, 255)),
+    ("maroon", Rgb::new(128, 0, 0)),
+    ("red", Rgb::new(255, 0, 0)),
+    ("purple", Rgb::new(128, 0, 128)),
+    ("fuchsia", Rgb::new(255, 0, 255)),
+    ("green", Rgb::new(0, 128, 0)),
+    ("lime", Rgb::new(0, 255, 0)),
+    ("olive", Rgb::new(128, 128, 0)),
+    ("yellow", Rgb::new(255, 255, 0)),
+    ("navy", Rgb::new(0, 0, 128)),
+    ("blue", Rgb::new(0, 0, 255)),
+    ("teal", Rgb::new(0, 128, 128)),
+    ("aqua", Rgb::new(0, 255, 255)),
+    ("orange", Rgb::new(255, 165, 0)),
+    ("brown", Rgb::new(165, 42, 42)),
+    ("crimson", Rgb::new(220, 20, 60)),
+    ("gold", Rgb::new(255, 215, 0)),
+    ("indigo", Rgb::new(75, 0, 130)),
+    ("pink", Rgb::new(255, 192, 203)),
+    ("rust", Rgb::new(183, 65, 14)),
+];
+
+fn hex_digit(c: u8) -> Option<u8> {
+    (c as char).to_digit(16).map(|digit| digit as u8)
+}
+
+// Red, green and blue as 0.0..=1.0
+fn unit(rgb: Rgb) -> (f64, f64, f64) {
+    (
+        rgb.r as f64 / 255.0,
+        rgb.g as f64 / 255.0,
+        rgb.b as f64 / 255.0,
+    )
+}
+
+fn from_unit(r: f64, g: f64, b: f64) -> Rgb {
+    let channel = |value: f64| (value.clamp(0.0, 1.0) * 255.0).round() as u8;
+    Rgb::new(channel(r), channel(g), channel(b))
+}
+
+// The hue shared by HSV and HSL: which sixth of the colour wheel the
+// largest channel puts us in, and how far along it
+fn hue(r: f64, g: f64, b: f64, max: f64, delta: f64) -> f64 {
+    if delta == 0.0 {
+        return 0.0;
+    }
+    let sixth = if max == r {
+        ((g - b) / delta).rem_euclid(6.0)
+    } else if max == g {
+        (b - r) / delta + 2.0
+    } else {
+        (r - g) / delta + 4.0
+    };
+    sixth * 60.0
+}
+
+// From hue, chroma and the amount added to every channel
+fn from_hue(h: f64, chroma: f64, m: f64) -> Rgb {
+    let sixth = h.rem_euclid(360.0) / 60.0;
+    let x = chroma * (1.0 - (sixth.rem_euclid(2.0) - 1.0).abs());
+    let (r, g, b) = match sixth as u32 {
+        0 => (chroma, x, 0.0),
+        1 => (x, chroma, 0.0),
+        2 => (0.0, chroma, x),
+        3 => (0.0, x, chroma),
+        4 => (x, 0.0, chroma),
+        _ => (chroma, 0.0, x),
+    };
+    from_unit(r + m, g + m, b + m)
+}
+
+// sRGB channel to linear light
+fn linear(channel: u8) -> f64 {
+    let value = channel as f64 / 255.0;
+    if value <= 0.04045 {
+        value / 12.92
+    } else {
+        ((value + 0.055) / 1.055).powf(2.4)
+    }
+}
+
+fn encode(value: f64) -> f64 {
+    if value <= 0.0031308 {
+        value * 12.92
+    } else {
+        1.055 * value.powf(1.0 / 2.4) - 0.055
+    }
+}
+
+impl Rgb {
+    pub const fn new(r: u8, g: u8, b: u8) -> Rgb {
+        Rgb { r, g, b }
+    }
+
+    // "#7a1128", "#f00", "rgb(122, 17, 40)" or a name like "crimson";
+    // case and surrounding spaces don't matter
+    pub fn parse(text: &str) -> Result<Rgb, ColorError> {
+        let text = text.trim();
+        let lower = text.to_ascii_lowercase();
+        if text.is_empty() {
+            Err(ColorError::Empty)
+        } else if let Some(digits) = lower.strip_prefix('#') {
+            let bad = || ColorError::InvalidHex(text.to_string());
+            let digits: Vec<u8> = digits
+                .bytes()
+                .map(hex_digit)
+                .collect::<Option<_>>()
+                .ok_or_else(bad)?;
+            match digits[..] {
+                [r, g, b] => Ok(Rgb::new(r * 17, g * 17, b * 17)),
+                [r1, r2, g1, g2, b1, b2] => Ok(Rgb::new(r1 * 16 + r2, g1 * 16 + g2, b1 * 16 + b2)),
+                _ => Err(bad()),
+            }
+        } else if let Some(inner) = lower
+            .strip_prefix("rgb(")
+            .and_then(|rest| rest.strip_suffix(')'))
+        {
+            let parts: Vec<&str> = inner.split(',').map(str::trim).collect();
+            let &[r, g, b] = parts.as_slice() else {
+                return Err(ColorError::InvalidRgb(text.to_string()));
+            };
+            let component = |part: &str| -> Result<u8, ColorError> {
+                if part.is_empty() || !part.bytes().all(|c| c.is_ascii_digit()) {
+                    return Err(ColorError::InvalidRgb(text.to_string()));
+                }
+                part.parse()
+                    .map_err(|_| ColorError::OutOfRange(part.to_string()))
+            };
+            Ok(Rgb::new(component(r)?, component(g)?, component(b)?))
+        } else {
+            NAMES
+                .iter()
+                .find(|&&(name, _)| name == lower)
+                .map(|&(_, rgb)| rgb)
+                .ok_or(ColorError::UnknownName(text.to_string()))
+        }
+    }
+
+    // The keyword for exactly this colour, if it has one
+    pub fn name(&self) -> Option<&'static str> {
+        NAMES
+            .iter()
+            .find(|&&(_, rgb)| rgb == *self)
+            .map(|&(name, _)| name)
+    }
+
+    pub fn to_hex(&self) -> String {
+        format!("#{:02x}{:02x}{:02x}", self.r, self.g, self.b)
+    }
+
+    // How bright the colour looks, 0.0 (black) to 1.0 (white), weighting
+    // linear-light channels the way the eye does
+    pub fn luminance(&self) -> f64 {
+        0.2126 * linear(self.r) + 0.7152 * linear(self.g) + 0.0722 * linear(self.b)
+    }
+
+    // 1.0 (identical) to 21.0 (black on white); WCAG asks for at least 4.5
+    // for body text and 3.0 for large text
+    pub fn contrast(&self, other: Rgb) -> f64 {
+        let (a, b) = (self.luminance(), other.luminance());
+        (a.max(b) + 0.05) / (a.min(b) + 0.05)
+    }
+
+    // `amount` of the way from self to `other`, channel by channel, as CSS
+    // and most image editors blend
+    pub fn mix(&self, other: Rgb, amount: f64) -> Rgb {
+        let t = amount.clamp(0.0, 1.0);
+        let blend = |a: u8, b: u8| (a as f64 + (b as f64 - a as f64) * t).round() as u8;
+        Rgb::new(
+            blend(self.r, other.r),
+            blend(self.g, other.g),
+            blend(self.b, other.b),
+        )
+    }
+
+    // The same blend done in linear light, as mixing real light would: red
+    // and green meet at a bright yellow instead of a muddy one
+    pub fn mix_linear(&self, other: Rgb, amount: f64) -> Rgb {
+        let t = amount.clamp(0.0, 1.0);
+        let blend = |a: u8, b: u8| encode(linear(a) + (linear(b) - linear(a)) * t);
+        from_unit(
+            blend(self.r, other.r),
+            blend(self.g, other.g),
+            blend(self.b, other.b),
+        )
+    }
+}
+
+impl From<Rgb> for Hsv {
+    fn from(rgb: Rgb) -> Hsv {
+        let (r, g, b) = unit(rgb);
+        let max = r.max(g).max(b);
+        let delta = max - r.min(g).min(b);
+        Hsv {
+            h: hue(r, g, b, max, delta),
+            s: if max == 0.0 { 0.0 } else { delta / max },
+            v: max,
+        }
+    }
+}
+
+impl From<Hsv> for Rgb {
+    fn from(hsv: Hsv) -> Rgb {
+        let chroma = hsv.v * hsv.s;
+        from_hue(hsv.h, chroma, hsv.v - chroma)
+    }
+}
+
+impl From<Rgb> for Hsl {
+    fn from(rgb: Rgb) -> Hsl {
+        let (r, g, b) = unit(rgb);
+        let (max, min) = (r.max(g).max(b), r.min(g).min(b));
+        let delta = max - min;
+        let l = (max + min) / 2.0;
+        Hsl {
+            h: hue(r, g, b, max, delta),
+            s: if delta == 0.0 {
+                0.0
+            } else {
+                delta / (1.0 - (2.0 * l - 1.0).abs())
+            },
+            l,
+        }
+    }
+}
+
+impl From<Hsl> for Rgb {
+    fn from(hsl: Hsl) -> Rgb {
+        let chroma = (1.0 - (2.0 * hsl.l - 1.0).abs()) * hsl.s;
+        from_hue(hsl.h, chroma, hsl.l - chroma / 2.0)
+    }
+}
+
+impl From<Rgb> for Cmyk {
+    fn from(rgb: Rgb) -> Cmyk {
+        let (r, g, b) = unit(rgb);
+        let k = 1.0 - r.max(g).max(b);
+        if k == 1.0 {
+            return Cmyk {
+                k,
+                ..Cmyk::default()
+            };
+        }
+        Cmyk {
+            c: (1.0 - r - k) / (1.0 - k),
+            m: (1.0 - g - k) / (1.0 - k),
+            y: (1.0 - b - k) / (1.0 - k),
+            k,
+        }
+    }
+}
+
+impl From<Cmyk> for Rgb {
+    fn from(cmyk: Cmyk) -> Rgb {
+        let white = 1.0 - cmyk.k;
+        from_unit(
+            (1.0 - cmyk.c) * white,
+            (1.0 - cmyk.m) * white,
+            (1.0 - cmyk.y) * white,
+        )
+    }
+}
+
+// HSV and HSL describe the same hue, so converting between them skips RGB
+// and loses nothing
+impl From<Hsv> for Hsl {
+    fn from(hsv: Hsv) -> Hsl {
+        let l = hsv.v * (1.0 - hsv.s / 2.0);
+        let s = if l == 0.0 || l == 1.0 {
+            0.0
+        } else {
+            (hsv.v - l) / l.min(1.0 - l)
+        };
+        Hsl { h: hsv.h, s, l }
+    }
+}
+
+impl From<Hsl> for Hsv {
+    fn from(hsl: Hsl) -> Hsv {
+        let v = hsl.l + hsl.s * hsl.l.min(1.0 - hsl.l);
+        let s = if v == 0.0 {
+            0.0
+        } else {
+            2.0 * (1.0 - hsl.l / v)
+        };
+        Hsv { h: hsl.h, s, v }
+    }
+}
+
+impl fmt::Display for Rgb {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.to_hex())
+    }
+}
+
+impl fmt::Display for Hsv {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "hsv({:.0}°, {:.0}%, {:.0}%)",
+            self.h,
+            self.s * 100.0,
+            self.v * 100.0
+        )
+    }
+}
+
+impl fmt::Display for Hsl {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "hsl({:.0}°, {:.0}%, {:.0}%)",
+            self.h,
+            self.s * 100.0,
+            self.l * 100.0
+        )
+    }
+}
+
+impl fmt::Display for Cmyk {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "cmyk({:.0}%, {:.0}%, {:.0}%, {:.0}%)",
+            self.c * 100.0,
+            self.m * 100.0,
+            self.y * 100.0,
+            self.k * 100.0
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // A fixed xorshift sequence, so the property tests are random-looking
+    // but the same on every run
+    fn samples(count: usize) -> Vec<Rgb> {
+        let mut state: u32 = 0x2545_f491;
+        let mut colors: Vec<Rgb> = NAMES.iter().map(|&(_, rgb)| rgb).collect();
+        for _ in 0..count {
+            state ^= state << 13;
+            state ^= state >> 17;
+            state ^= state << 5;
+            let [r, g, b, _] = state.to_le_bytes();
+            colors.push(Rgb::new(r, g, b));
+        }
+        colors
+    }
+
+    fn close(a: f64, b: f64) -> bool {
+        (a - b).abs() < 1e-9
+    }
+
+    #[test]
+    fn every_space_round_trips_through_rgb() {
+        for rgb in samples(20_000) {
+            assert_eq!(Rgb::from(Hsv::from(rgb)), rgb, "hsv {}", rgb);
+            assert_eq!(Rgb::from(Hsl::from(rgb)), rgb, "hsl {}", rgb);
+            assert_eq!(Rgb::from(Cmyk::from(rgb)), rgb, "cmyk {}", rgb);
+            assert_eq!(Rgb::parse(&rgb.to_hex()), Ok(rgb));
+            assert_eq!(Rgb::from(Hsl::from(Hsv::from(rgb))), rgb, "hsv→hsl {}", rgb);
+            assert_eq!(Rgb::from(Hsv::from(Hsl::from(rgb))), rgb, "hsl→hsv {}", rgb);
+        }
+    }
+
+    #[test]
+    fn converts_known_colours() {
+        let crimson = Rgb::parse("crimson").unwrap();
+        let hsv = Hsv::from(crimson);
+        assert!(close(hsv.h, 348.0) && close(hsv.s, 200.0 / 220.0));
+        assert_eq!(hsv.to_string(), "hsv(348°, 91%, 86%)");
+        assert_eq!(Hsl::from(crimson).to_string(), "hsl(348°, 83%, 47%)");
+        assert_eq!(Cmyk::from(crimson).to_string(), "cmyk(0%, 91%, 73%, 14%)");
+        assert_eq!(
+            Cmyk::from(Rgb::new(0, 0, 0)).to_string(),
+            "cmyk(0%, 0%, 0%, 100%)"
+        );
+        let gray = Hsl::from(Rgb::new(128, 128, 128));
+        assert_eq!((gray.h, gray.s), (0.0, 0.0));
+        // Hues past 360 or below 0 wrap around the wheel
+        let red = Hsv {
+            h: 360.0,
+            s: 1.0,
+            v: 1.0,
+        };
+        assert_eq!(Rgb::from(red), Rgb::new(255, 0, 0));
+        assert_eq!(Rgb::from(Hsv { h: -120.0, ..red }), Rgb::new(0, 0, 255));
+    }
+
+    #[test]
+    fn parses_hex_rgb_and_names() {
+        assert_eq!(Rgb::parse("#7a1128"), Ok(Rgb::new(122, 17, 40)));
+        assert_eq!(Rgb::parse(" #7A1128 "), Ok(Rgb::new(122, 17, 40)));
+        assert_eq!(Rgb::parse("#f80"), Ok(Rgb::new(255, 136, 0)));
+        assert_eq!(Rgb::parse("RGB(122,17, 40)"), Ok(Rgb::new(122, 17, 40)));
+        assert_eq!(Rgb::parse("Lime"), Ok(Rgb::new(0, 255, 0)));
+        assert_eq!(Rgb::new(0, 128, 0).name(), Some("green"));
+        assert_eq!(Rgb::new(1, 2, 3).name(), None);
+
+        assert_eq!(Rgb::parse(""), Err(ColorError::Empty));
+        assert_eq!(
+            Rgb::parse("#12345").unwrap_err().to_string(),
+            "'#12345' is not a hex colour (#rgb or #rrggbb)"
+        );
+        assert_eq!(
+            Rgb::parse("#ggg"),
+            Err(ColorError::InvalidHex("#ggg".to_string()))
+        );
+        assert_eq!(
+            Rgb::parse("rgb(1, 2)"),
+            Err(ColorError::InvalidRgb("rgb(1, 2)".to_string()))
+        );
+        assert_eq!(
+            Rgb::parse("rgb(1, -2, 3)"),
+            Err(ColorError::InvalidRgb("rgb(1, -2, 3)".to_string()))
+        );
+        assert_eq!(
+            Rgb::parse("rgb(256, 0, 0)").unwrap_err().to_string(),
+            "colour component '256' is not in 0..=255"
+        );
+        assert_eq!(
+            Rgb::parse("blurple"),
+            Err(ColorError::UnknownName("blurple".to_string()))
+        );
+    }
+
+    #[test]
+    fn contrast_and_blending() {
+        let (black, white) = (Rgb::new(0, 0, 0), Rgb::new(255, 255, 255));
+        assert!(close(black.contrast(white), 21.0));
+        assert!(close(white.contrast(black), 21.0));
+        assert!(close(white.contrast(white), 1.0));
+        // #767676 is the lightest gray that passes 4.5:1 on white
+        assert!(Rgb::new(0x76, 0x76, 0x76).contrast(white) >= 4.5);
+        assert!(Rgb::new(0x77, 0x77, 0x77).contrast(white) < 4.5);
+
+        let (red, lime) = (Rgb::new(255, 0, 0), Rgb::new(0, 255, 0));
+        assert_eq!(red.mix(lime, 0.5), Rgb::new(128, 128, 0));
+        assert_eq!(red.mix_linear(lime, 0.5), Rgb::new(188, 188, 0));
+        assert_eq!(red.mix(lime, 0.0), red);
+        assert_eq!(red.mix(lime, 2.0), lime);
+        for rgb in samples(1_000) {
+            assert_eq!(rgb.mix_linear(rgb, 0.3), rgb);
+            assert!(close(rgb.contrast(black), black.contrast(rgb)));
+        }
+    }
+}
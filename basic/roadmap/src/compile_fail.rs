@@ -0,0 +1,225 @@
+// Compile-Fail Examples
+// The lessons describe code the compiler rejects. Each such snippet lives as a
+// real file under compile_fail/<topic>/, with a header naming the lesson it
+// belongs to and the rustc error code it must fail with:
+//
+//     // lesson: references_borrowing_slices
+//     // section: 3
+//     // expect: E0499
+//
+// `roadmap compile-fail` (and `cargo test`) run each file through the local
+// rustc and check that it fails with exactly that error.
+
+use std::fmt;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+use crate::registry::{self, Entry, LookupError};
+use crate::sandbox;
+
+pub const COMPILE_FAIL_DIR: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/compile_fail");
+
+pub struct Snippet {
+    pub path: PathBuf,
+    pub entry: Entry,
+    pub section: Option<usize>,
+    pub expected: String,
+}
+
+pub enum Outcome {
+    // Failed with the expected error code and nothing else
+    Rejected,
+    Compiled,
+    WrongErrors(Vec<String>),
+}
+
+#[derive(Debug)]
+pub enum SnippetError {
+    Io(io::Error),
+    Header { file: String, message: String },
+    Lookup { file: String, err: LookupError },
+}
+
+impl fmt::Display for SnippetError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SnippetError::Io(err) => write!(f, "{}", err),
+            SnippetError::Header { file, message } => write!(f, "{}: {}", file, message),
+            SnippetError::Lookup { file, err } => write!(f, "{}: {}", file, err),
+        }
+    }
+}
+
+impl From<io::Error> for SnippetError {
+    fn from(err: io::Error) -> Self {
+        SnippetError::Io(err)
+    }
+}
+
+impl Snippet {
+    // Path relative to compile_fail/, e.g. "ownership_system/dangling_reference.rs"
+    pub fn name(&self) -> String {
+        let root = Path::new(COMPILE_FAIL_DIR);
+        let relative = self.path.strip_prefix(root).unwrap_or(&self.path);
+        relative.display().to_string()
+    }
+}
+
+// Every snippet, sorted by topic directory and file name
+pub fn snippets() -> Result<Vec<Snippet>, SnippetError> {
+    let mut paths = Vec::new();
+    for dir in fs::read_dir(COMPILE_FAIL_DIR)? {
+        let dir = dir?.path();
+        if !dir.is_dir() {
+            continue;
+        }
+        for file in fs::read_dir(&dir)? {
+            let file = file?.path();
+            if file.extension().is_some_and(|ext| ext == "rs") {
+                paths.push(file);
+            }
+        }
+    }
+    paths.sort();
+
+    paths
+        .into_iter()
+        .map(|path| {
+            let source = fs::read_to_string(&path)?;
+            parse(path, &source)
+        })
+        .collect()
+}
+
+fn parse(path: PathBuf, source: &str) -> Result<Snippet, SnippetError> {
+    let topic = path
+        .parent()
+        .and_then(Path::file_name)
+        .map(|name| name.to_string_lossy().into_owned())
+        .unwrap_or_default();
+    let file = format!(
+        "{}/{}",
+        topic,
+        path.file_name().unwrap_or_default().to_string_lossy()
+    );
+    let header = |message: &str| SnippetError::Header {
+        file: file.clone(),
+        message: message.to_string(),
+    };
+
+    let mut lesson = None;
+    let mut section = None;
+    let mut expected = None;
+    for line in source.lines() {
+        let Some(comment) = line.strip_prefix("//") else {
+            break;
+        };
+        match comment.trim().split_once(':') {
+            Some(("lesson", value)) => lesson = Some(value.trim()),
+            Some(("section", value)) => match value.trim().parse::<usize>() {
+                Ok(n) => section = Some(n),
+                Err(_) => return Err(header("section is not a number")),
+            },
+            Some(("expect", value)) => expected = Some(value.trim().to_string()),
+            _ => {}
+        }
+    }
+
+    let Some(lesson) = lesson else {
+        return Err(header("missing '// lesson:' header"));
+    };
+    let Some(expected) = expected else {
+        return Err(header("missing '// expect:' header"));
+    };
+    let lookup = |err| SnippetError::Lookup {
+        file: file.clone(),
+        err,
+    };
+    let entry = registry::find(&format!("{}/{}", topic, lesson)).map_err(lookup)?;
+    if let Some(number) = section {
+        entry.lesson.section(number).map_err(lookup)?;
+    }
+
+    Ok(Snippet {
+        path,
+        entry,
+        section,
+        expected,
+    })
+}
+
+// Compiles the snippet with `sandbox::rustc`, stopping after analysis so
+// nothing is linked
+pub fn check(snippet: &Snippet) -> io::Result<Outcome> {
+    let output = sandbox::in_scratch_dir(|dir| {
+        sandbox::rustc()
+            .args(["--error-format=short", "--emit=metadata"])
+            .arg("--out-dir")
+            .arg(dir)
+            .arg(&snippet.path)
+            .output()
+    })?;
+
+    if output.status.success() {
+        return Ok(Outcome::Compiled);
+    }
+    let codes = error_codes(&String::from_utf8_lossy(&output.stderr));
+    if !codes.is_empty() && codes.iter().all(|code| *code == snippet.expected) {
+        Ok(Outcome::Rejected)
+    } else {
+        Ok(Outcome::WrongErrors(codes))
+    }
+}
+
+// The distinct codes of "error[E0499]: ..." lines, in order of appearance
+fn error_codes(stderr: &str) -> Vec<String> {
+    let mut codes: Vec<String> = Vec::new();
+    for line in stderr.lines() {
+        let Some(start) = line.find("error[") else {
+            continue;
+        };
+        let rest = &line[start + "error[".len()..];
+        if let Some(end) = rest.find(']') {
+            let code = rest[..end].to_string();
+            if !codes.contains(&code) {
+                codes.push(code);
+            }
+        }
+    }
+    codes
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn error_codes_are_read_from_short_diagnostics() {
+        let stderr = "\
+a.rs:9:14: error[E0499]: cannot borrow `s` as mutable more than once at a time
+a.rs:10:5: error[E0499]: cannot borrow `s` as mutable more than once at a time
+a.rs:11:5: error[E0502]: cannot borrow `s` as immutable
+error: aborting due to 3 previous errors
+";
+        assert_eq!(error_codes(stderr), vec!["E0499", "E0502"]);
+    }
+
+    #[test]
+    fn every_snippet_fails_with_its_expected_error() {
+        let snippets = snippets().unwrap();
+        assert!(!snippets.is_empty());
+        for snippet in &snippets {
+            match check(snippet).unwrap() {
+                Outcome::Rejected => {}
+                Outcome::Compiled => panic!("{} compiled", snippet.name()),
+                Outcome::WrongErrors(codes) => panic!(
+                    "{} expected {}, rustc reported {:?}",
+                    snippet.name(),
+                    snippet.expected,
+                    codes
+                ),
+            }
+        }
+    }
+}
@@ -1,29 +1,38 @@
-fn main() {
-    println!("=== Enums in Rust ===\n");
-
-    // 1. Basic enum definition
-    demonstrate_basic_enum();
-
-    // 2. Enums with data
-    demonstrate_enum_with_data();
-
-    // 3. Different data types in variants
-    demonstrate_different_data_types();
-
-    // 4. Methods on enums
-    demonstrate_enum_methods();
-
-    // 5. The Option enum
-    demonstrate_option_enum();
-
-    // 6. Working with Option
-    demonstrate_working_with_option();
-}
+use crate::output;
+use crate::registry::{Difficulty, Lesson, Meta, Section};
+use roadmap::turtle::{self, Canvas, Message};
+
+// The script behind `roadmap turtle src/turtle/house.txt`
+const HOUSE: &str = include_str!("../turtle/house.txt");
+
+pub const LESSON: Lesson = Lesson {
+    id: "enums",
+    title: "Enums in Rust",
+    meta: Meta {
+        difficulty: Difficulty::Intermediate,
+        minutes: 20,
+        book: Some("6.1"),
+        prerequisites: &["structs", "syntax_and_semantics/match"],
+        tags: &["enums", "pattern-matching"],
+    },
+    sections: &[
+        Section::new("Basic Enum Definition", demonstrate_basic_enum),
+        Section::new("Enums with Data", demonstrate_enum_with_data),
+        Section::new(
+            "Different Data Types in Variants",
+            demonstrate_different_data_types,
+        ),
+        Section::new("Methods on Enums", demonstrate_enum_methods),
+        Section::new("The Option Enum", demonstrate_option_enum),
+        Section::new("Working with Option", demonstrate_working_with_option),
+        Section::new("Messages as a Drawing Script", demonstrate_message_script),
+    ],
+};
 
 // 1. Basic enum definition
 fn demonstrate_basic_enum() {
-    println!("1. Basic Enum Definition");
-    println!("   Enums let you define a type by enumerating its possible variants\n");
+    output::note("Enums let you define a type by enumerating its possible variants");
+    println!();
 
     enum IpAddrKind {
         V4,
@@ -32,25 +41,27 @@ fn demonstrate_basic_enum() {
 
     fn route(ip_kind: IpAddrKind) {
         match ip_kind {
-            IpAddrKind::V4 => println!("   Routing IPv4 packet"),
-            IpAddrKind::V6 => println!("   Routing IPv6 packet"),
+            IpAddrKind::V4 => output::result("Routing IPv4 packet"),
+            IpAddrKind::V6 => output::result("Routing IPv6 packet"),
         }
     }
 
     let four = IpAddrKind::V4;
     let six = IpAddrKind::V6;
 
-    println!("   Created two IP address kinds:");
+    output::note("Created two IP address kinds:");
     route(four);
     route(six);
 
-    println!("\n   Variants are namespaced under the enum identifier\n");
+    println!();
+    output::note("Variants are namespaced under the enum identifier");
+    println!();
 }
 
 // 2. Enums with data
 fn demonstrate_enum_with_data() {
-    println!("2. Enums with Data");
-    println!("   Attach data directly to enum variants\n");
+    output::note("Attach data directly to enum variants");
+    println!();
 
     // Old way: using struct
     #[allow(dead_code)]
@@ -65,8 +76,8 @@ fn demonstrate_enum_with_data() {
         address: String,
     }
 
-    println!("   Old way (struct + enum):");
-    println!("   struct IpAddr {{ kind: IpAddrKind, address: String }}");
+    output::note("Old way (struct + enum):");
+    output::code("struct IpAddr { kind: IpAddrKind, address: String }");
 
     // Better way: data in enum
     enum IpAddr {
@@ -77,27 +88,30 @@ fn demonstrate_enum_with_data() {
     let home = IpAddr::V4(String::from("127.0.0.1"));
     let loopback = IpAddr::V6(String::from("::1"));
 
-    println!("\n   Better way (data in enum):");
-    println!("   enum IpAddr {{ V4(String), V6(String) }}");
+    println!();
+    output::note("Better way (data in enum):");
+    output::code("enum IpAddr { V4(String), V6(String) }");
 
     match home {
-        IpAddr::V4(addr) => println!("   Home: IPv4 address {}", addr),
-        IpAddr::V6(addr) => println!("   Home: IPv6 address {}", addr),
+        IpAddr::V4(addr) => output::result(format!("Home: IPv4 address {}", addr)),
+        IpAddr::V6(addr) => output::result(format!("Home: IPv6 address {}", addr)),
     }
 
     match loopback {
-        IpAddr::V4(addr) => println!("   Loopback: IPv4 address {}", addr),
-        IpAddr::V6(addr) => println!("   Loopback: IPv6 address {}", addr),
+        IpAddr::V4(addr) => output::result(format!("Loopback: IPv4 address {}", addr)),
+        IpAddr::V6(addr) => output::result(format!("Loopback: IPv6 address {}", addr)),
     }
 
-    println!("\n   Enum variants become constructor functions\n");
+    println!();
+    output::note("Enum variants become constructor functions");
+    println!();
 }
 
 // 3. Different data types in variants
 #[allow(dead_code)]
 fn demonstrate_different_data_types() {
-    println!("3. Different Data Types in Variants");
-    println!("   Each variant can have different types and amounts of data\n");
+    output::note("Each variant can have different types and amounts of data");
+    println!();
 
     // IP address with different data types
     enum IpAddr {
@@ -108,23 +122,32 @@ fn demonstrate_different_data_types() {
     let home = IpAddr::V4(127, 0, 0, 1);
     let loopback = IpAddr::V6(String::from("::1"));
 
-    println!("   enum IpAddr {{");
-    println!("       V4(u8, u8, u8, u8),  // Four numbers");
-    println!("       V6(String),          // One string");
-    println!("   }}");
+    output::code(concat!(
+        "enum IpAddr {\n",
+        "    V4(u8, u8, u8, u8),  // Four numbers\n",
+        "    V6(String),          // One string\n",
+        "}",
+    ));
 
     match home {
-        IpAddr::V4(a, b, c, d) => println!("\n   Home: {}.{}.{}.{}", a, b, c, d),
-        IpAddr::V6(addr) => println!("\n   Home: {}", addr),
+        IpAddr::V4(a, b, c, d) => {
+            println!();
+            output::result(format!("Home: {}.{}.{}.{}", a, b, c, d));
+        }
+        IpAddr::V6(addr) => {
+            println!();
+            output::result(format!("Home: {}", addr));
+        }
     }
 
     match loopback {
-        IpAddr::V4(a, b, c, d) => println!("   Loopback: {}.{}.{}.{}", a, b, c, d),
-        IpAddr::V6(addr) => println!("   Loopback: {}", addr),
+        IpAddr::V4(a, b, c, d) => output::result(format!("Loopback: {}.{}.{}.{}", a, b, c, d)),
+        IpAddr::V6(addr) => output::result(format!("Loopback: {}", addr)),
     }
 
     // Message enum with various types
-    println!("\n   Message enum with different variant types:");
+    println!();
+    output::note("Message enum with different variant types:");
     #[allow(dead_code)]
     enum Message {
         Quit,                       // No data
@@ -142,10 +165,12 @@ fn demonstrate_different_data_types() {
 
     fn process_message(msg: Message) {
         match msg {
-            Message::Quit => println!("   Quit message"),
-            Message::Move { x, y } => println!("   Move to ({}, {})", x, y),
-            Message::Write(text) => println!("   Write: {}", text),
-            Message::ChangeColor(r, g, b) => println!("   Change color to RGB({}, {}, {})", r, g, b),
+            Message::Quit => output::result("Quit message"),
+            Message::Move { x, y } => output::result(format!("Move to ({}, {})", x, y)),
+            Message::Write(text) => output::result(format!("Write: {}", text)),
+            Message::ChangeColor(r, g, b) => {
+                output::result(format!("Change color to RGB({}, {}, {})", r, g, b))
+            }
         }
     }
 
@@ -157,8 +182,8 @@ fn demonstrate_different_data_types() {
 
 // 4. Methods on enums
 fn demonstrate_enum_methods() {
-    println!("4. Methods on Enums");
-    println!("   Define methods using impl, just like structs\n");
+    output::note("Define methods using impl, just like structs");
+    println!();
 
     #[allow(dead_code)]
     enum Message {
@@ -171,11 +196,11 @@ fn demonstrate_enum_methods() {
     impl Message {
         fn call(&self) {
             match self {
-                Message::Quit => println!("   Calling Quit"),
-                Message::Move { x, y } => println!("   Calling Move({}, {})", x, y),
-                Message::Write(text) => println!("   Calling Write(\"{}\")", text),
+                Message::Quit => output::result("Calling Quit"),
+                Message::Move { x, y } => output::result(format!("Calling Move({}, {})", x, y)),
+                Message::Write(text) => output::result(format!("Calling Write(\"{}\")", text)),
                 Message::ChangeColor(r, g, b) => {
-                    println!("   Calling ChangeColor({}, {}, {})", r, g, b)
+                    output::result(format!("Calling ChangeColor({}, {}, {})", r, g, b))
                 }
             }
         }
@@ -192,43 +217,54 @@ fn demonstrate_enum_methods() {
 
     let m = Message::Write(String::from("hello"));
     m.call();
-    println!("   Description: {}\n", m.describe());
+    output::result(format!("Description: {}", m.describe()));
+    println!();
 }
 
 // 5. The Option enum
 fn demonstrate_option_enum() {
-    println!("5. The Option Enum");
-    println!("   Rust doesn't have null - it has Option<T>\n");
+    output::note("Rust doesn't have null - it has Option<T>");
+    println!();
 
-    println!("   enum Option<T> {{");
-    println!("       Some(T),");
-    println!("       None,");
-    println!("   }}");
+    output::code(concat!(
+        "enum Option<T> {\n",
+        "    Some(T),\n",
+        "    None,\n",
+        "}",
+    ));
 
     // Creating Option values
     let some_number = Some(5);
     let some_char = Some('e');
     let absent_number: Option<i32> = None;
 
-    println!("\n   let some_number = Some(5);           // Option<i32>");
-    println!("   let some_char = Some('e');           // Option<char>");
-    println!("   let absent_number: Option<i32> = None;");
+    println!();
+    output::code(concat!(
+        "let some_number = Some(5);           // Option<i32>\n",
+        "let some_char = Some('e');           // Option<char>\n",
+        "let absent_number: Option<i32> = None;",
+    ));
 
-    println!("\n   some_number: {:?}", some_number);
-    println!("   some_char: {:?}", some_char);
-    println!("   absent_number: {:?}", absent_number);
+    println!();
+    output::result(format!("some_number: {:?}", some_number));
+    output::result(format!("some_char: {:?}", some_char));
+    output::result(format!("absent_number: {:?}", absent_number));
 
     // Option prevents null pointer errors
-    println!("\n   Why Option is better than null:");
-    println!("   - Compiler forces you to handle the None case");
-    println!("   - Can't use Option<T> as if it were T");
-    println!("   - Must explicitly convert Option<T> to T\n");
+    println!();
+    output::note("Why Option is better than null:");
+    output::note(
+        "- Compiler forces you to handle the None case\n\
+         - Can't use Option<T> as if it were T\n\
+         - Must explicitly convert Option<T> to T",
+    );
+    println!();
 }
 
 // 6. Working with Option
 fn demonstrate_working_with_option() {
-    println!("6. Working with Option");
-    println!("   Must handle both Some and None cases\n");
+    output::note("Must handle both Some and None cases");
+    println!();
 
     // Example 1: Using match
     fn plus_one(x: Option<i32>) -> Option<i32> {
@@ -242,34 +278,44 @@ fn demonstrate_working_with_option() {
     let six = plus_one(five);
     let none = plus_one(None);
 
-    println!("   Using match:");
-    println!("   plus_one(Some(5)) = {:?}", six);
-    println!("   plus_one(None) = {:?}", none);
+    output::note("Using match:");
+    output::result(format!("plus_one(Some(5)) = {:?}", six));
+    output::result(format!("plus_one(None) = {:?}", none));
 
     // Example 2: Using if let
-    println!("\n   Using if let:");
+    println!();
+    output::note("Using if let:");
     if let Some(value) = six {
-        println!("   Got value: {}", value);
+        output::result(format!("Got value: {}", value));
     } else {
-        println!("   Got None");
+        output::result("Got None");
     }
 
     // Example 3: Using unwrap_or
-    println!("\n   Using unwrap_or (provide default):");
+    println!();
+    output::note("Using unwrap_or (provide default):");
     let some_value = Some(10);
     let no_value: Option<i32> = None;
 
-    println!("   Some(10).unwrap_or(0) = {}", some_value.unwrap_or(0));
-    println!("   None.unwrap_or(0) = {}", no_value.unwrap_or(0));
+    output::result(format!(
+        "Some(10).unwrap_or(0) = {}",
+        some_value.unwrap_or(0)
+    ));
+    output::result(format!("None.unwrap_or(0) = {}", no_value.unwrap_or(0)));
 
     // Example 4: Using map
-    println!("\n   Using map (transform the value):");
+    println!();
+    output::note("Using map (transform the value):");
     let some_string = Some("hello");
     let string_length = some_string.map(|s| s.len());
-    println!("   Some(\"hello\").map(|s| s.len()) = {:?}", string_length);
+    output::result(format!(
+        "Some(\"hello\").map(|s| s.len()) = {:?}",
+        string_length
+    ));
 
     // Example 5: Real-world example
-    println!("\n   Real-world example - finding an item:");
+    println!();
+    output::note("Real-world example - finding an item:");
     fn find_item(items: &[&str], search: &str) -> Option<usize> {
         for (index, &item) in items.iter().enumerate() {
             if item == search {
@@ -281,14 +327,64 @@ fn demonstrate_working_with_option() {
 
     let items = ["apple", "banana", "cherry"];
     match find_item(&items, "banana") {
-        Some(index) => println!("   Found 'banana' at index {}", index),
-        None => println!("   'banana' not found"),
+        Some(index) => output::result(format!("Found 'banana' at index {}", index)),
+        None => output::result("'banana' not found"),
     }
 
     match find_item(&items, "orange") {
-        Some(index) => println!("   Found 'orange' at index {}", index),
-        None => println!("   'orange' not found"),
+        Some(index) => output::result(format!("Found 'orange' at index {}", index)),
+        None => output::result("'orange' not found"),
+    }
+
+    println!();
+}
+
+// 7. Messages as a drawing script
+fn demonstrate_message_script() {
+    output::note("The Message enum from section 3 can drive a turtle that draws");
+    println!();
+
+    // roadmap::turtle parses a text file of messages written as Rust values,
+    // then matches on each one to move a pen over a canvas
+    let messages = match turtle::parse(HOUSE) {
+        Ok(messages) => messages,
+        Err(err) => {
+            output::warning(err.to_string());
+            return;
+        }
+    };
+
+    let mut canvas = Canvas::new();
+    for message in &messages {
+        let action = match message {
+            Message::Quit => String::from("stop here"),
+            Message::Move { x, y } => format!("draw a line by ({}, {})", x, y),
+            Message::Write(text) => format!("label \"{}\"", text),
+            Message::ChangeColor(r, g, b) => format!("pen colour rgb({}, {}, {})", r, g, b),
+        };
+        // Once Quit has run, execute ignores everything after it
+        let skipped = if canvas.quit { "  (skipped)" } else { "" };
+        canvas.execute(message);
+        output::result(format!("{:<28} {}{}", message.to_string(), action, skipped));
     }
 
     println!();
-}
\ No newline at end of file
+    output::result(format!("Pen ends at {:?} in {}", canvas.pen, canvas.color));
+    output::result(format!(
+        "Canvas: {} lines, {} labels",
+        canvas.segments.len(),
+        canvas.labels.len()
+    ));
+    let svg = turtle::render::svg(&canvas);
+    output::result(svg.lines().next().unwrap_or_default());
+
+    // A malformed line is reported with its number, like a compiler error
+    if let Err(err) = turtle::parse("Quit\nMove { x: 10 }") {
+        println!();
+        output::result(format!("Bad script: {}", err));
+    }
+
+    println!();
+    output::note("Draw it yourself: roadmap turtle src/turtle/house.txt --out house.svg");
+    println!();
+}
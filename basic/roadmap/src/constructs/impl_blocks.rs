@@ -1,335 +1,248 @@
-fn main() {
-    println!("=== impl Keyword in Rust ===\n");
-
-    // 1. Basic methods with &self
-    demonstrate_basic_methods();
-
-    // 2. Mutable methods with &mut self
-    demonstrate_mutable_methods();
-
-    // 3. Methods with multiple parameters
-    demonstrate_methods_with_parameters();
-
-    // 4. Associated functions (no self)
-    demonstrate_associated_functions();
-
-    // 5. Method calls as syntactic sugar
-    demonstrate_method_syntax_sugar();
+use crate::output;
+use crate::registry::{Difficulty, Lesson, Meta, Section};
+use roadmap::geometry::{self, Circle, Point, Shape, Square};
+
+pub const LESSON: Lesson = Lesson {
+    id: "impl_blocks",
+    title: "impl Keyword in Rust",
+    meta: Meta {
+        difficulty: Difficulty::Intermediate,
+        minutes: 20,
+        book: Some("5.3"),
+        prerequisites: &["structs"],
+        tags: &["structs", "methods"],
+    },
+    sections: &[
+        Section::new("Basic Methods with &self", demonstrate_basic_methods),
+        Section::new(
+            "Mutable Methods with &mut self",
+            demonstrate_mutable_methods,
+        ),
+        Section::new(
+            "Methods with Multiple Parameters",
+            demonstrate_methods_with_parameters,
+        ),
+        Section::new("Associated Functions", demonstrate_associated_functions),
+        Section::new(
+            "Method Calls as Syntactic Sugar",
+            demonstrate_method_syntax_sugar,
+        ),
+        Section::new("Multiple impl Blocks", demonstrate_multiple_impl_blocks),
+        Section::new("Getters and Method Naming", demonstrate_getters),
+        Section::new("Methods that Consume self", demonstrate_consuming_methods),
+    ],
+};
+
+// The one Rectangle this lesson defines itself, to show impl syntax. A type can
+// have several impl blocks; these are split by what the methods do.
+#[derive(Debug)]
+struct Rectangle {
+    width: u32,
+    height: u32,
+}
 
-    // 6. Multiple impl blocks
-    demonstrate_multiple_impl_blocks();
+// Methods that borrow self immutably
+impl Rectangle {
+    fn area(&self) -> u32 {
+        self.width * self.height
+    }
 
-    // 7. Getters and method naming
-    demonstrate_getters();
+    fn perimeter(&self) -> u32 {
+        2 * (self.width + self.height)
+    }
 
-    // 8. Methods that consume self
-    demonstrate_consuming_methods();
+    fn is_square(&self) -> bool {
+        self.width == self.height
+    }
 }
 
-// 1. Basic methods with &self
-fn demonstrate_basic_methods() {
-    println!("1. Basic Methods with &self");
-    println!("   Methods that borrow the instance immutably\n");
-
-    #[derive(Debug)]
-    struct Rectangle {
-        width: u32,
-        height: u32,
+// Associated functions (no self), used as constructors
+impl Rectangle {
+    fn new(width: u32, height: u32) -> Self {
+        Self { width, height }
     }
 
-    impl Rectangle {
-        // Method that borrows self immutably
-        fn area(&self) -> u32 {
-            self.width * self.height
+    fn square(size: u32) -> Self {
+        Self {
+            width: size,
+            height: size,
         }
+    }
 
-        fn perimeter(&self) -> u32 {
-            2 * (self.width + self.height)
+    fn default() -> Self {
+        Self {
+            width: 1,
+            height: 1,
         }
+    }
+}
 
-        fn is_square(&self) -> bool {
-            self.width == self.height
-        }
+// Getters and methods that take ownership of self
+impl Rectangle {
+    // Getter method with same name as field
+    fn width(&self) -> u32 {
+        self.width
     }
 
+    // Method that checks a condition
+    fn has_width(&self) -> bool {
+        self.width > 0
+    }
+
+    // Consumes self: Rectangle is not Copy, so the caller's value is moved
+    fn into_geometry(self) -> geometry::Rectangle {
+        geometry::Rectangle::new(self.width as f64, self.height as f64)
+    }
+}
+
+// 1. Basic methods with &self
+fn demonstrate_basic_methods() {
+    output::note("Methods that borrow the instance immutably");
+    println!();
+
     let rect = Rectangle {
         width: 30,
         height: 50,
     };
 
-    println!("   Rectangle: {:?}", rect);
-    println!("   Area: {}", rect.area());
-    println!("   Perimeter: {}", rect.perimeter());
-    println!("   Is square? {}", rect.is_square());
+    output::result(format!("Rectangle: {:?}", rect));
+    output::result(format!("Area: {}", rect.area()));
+    output::result(format!("Perimeter: {}", rect.perimeter()));
+    output::result(format!("Is square? {}", rect.is_square()));
+
+    // The finished, unit-tested versions of these shapes live in
+    // src/geometry.rs, where area and perimeter come from the Shape trait
+    let library_rect = geometry::Rectangle::new(30.0, 50.0);
+    let shapes: [(&str, &dyn Shape); 3] = [
+        ("geometry::Rectangle::new(30.0, 50.0)", &library_rect),
+        ("Square::new(25.0)", &Square::new(25.0)),
+        (
+            "Circle::new(Point::new(0.0, 0.0), 5.0)",
+            &Circle::new(Point::new(0.0, 0.0), 5.0),
+        ),
+    ];
+    println!();
+    for (name, shape) in shapes {
+        output::result(format!(
+            "{}: area {:.1}, perimeter {:.1}",
+            name,
+            shape.area(),
+            shape.perimeter()
+        ));
+    }
     println!();
 }
 
 // 2. Mutable methods with &mut self
 fn demonstrate_mutable_methods() {
-    println!("2. Mutable Methods with &mut self");
-    println!("   Methods that borrow the instance mutably\n");
-
-    #[derive(Debug)]
-    struct Rectangle {
-        width: u32,
-        height: u32,
-    }
-
-    impl Rectangle {
-        fn area(&self) -> u32 {
-            self.width * self.height
-        }
-
-        // Method that modifies self
-        fn set_width(&mut self, width: u32) {
-            self.width = width;
-        }
-
-        #[allow(dead_code)]
-        fn set_height(&mut self, height: u32) {
-            self.height = height;
-        }
+    output::note("Methods that borrow the instance mutably");
+    println!();
 
-        fn scale(&mut self, factor: u32) {
-            self.width *= factor;
-            self.height *= factor;
-        }
-    }
+    // geometry::Rectangle has `fn set_width(&mut self, width: f64)` and
+    // `fn scale(&mut self, factor: f64)`
+    let mut rect = geometry::Rectangle::new(10.0, 20.0);
 
-    let mut rect = Rectangle {
-        width: 10,
-        height: 20,
-    };
+    output::result(format!("Original: {}, area: {}", rect, rect.area()));
 
-    println!("   Original: {:?}, area: {}", rect, rect.area());
+    rect.set_width(15.0);
+    output::result(format!(
+        "After set_width(15.0): {}, area: {}",
+        rect,
+        rect.area()
+    ));
 
-    rect.set_width(15);
-    println!("   After set_width(15): {:?}, area: {}", rect, rect.area());
-
-    rect.scale(2);
-    println!("   After scale(2): {:?}, area: {}", rect, rect.area());
+    rect.scale(2.0);
+    output::result(format!("After scale(2.0): {}, area: {}", rect, rect.area()));
     println!();
 }
 
 // 3. Methods with multiple parameters
 fn demonstrate_methods_with_parameters() {
-    println!("3. Methods with Multiple Parameters");
-    println!("   Methods can take additional parameters after self\n");
-
-    #[derive(Debug)]
-    struct Rectangle {
-        width: u32,
-        height: u32,
-    }
-
-    impl Rectangle {
-        #[allow(dead_code)]
-        fn area(&self) -> u32 {
-            self.width * self.height
-        }
-
-        // Method with another parameter
-        fn can_hold(&self, other: &Rectangle) -> bool {
-            self.width > other.width && self.height > other.height
-        }
+    output::note("Methods can take additional parameters after self");
+    println!();
 
-        // Method with multiple parameters
-        #[allow(dead_code)]
-        fn resize(&mut self, new_width: u32, new_height: u32) {
-            self.width = new_width;
-            self.height = new_height;
-        }
-    }
+    // `fn can_hold(&self, other: &Rectangle) -> bool` takes a second rectangle
+    let rect1 = geometry::Rectangle::new(30.0, 50.0);
+    let rect2 = geometry::Rectangle::new(10.0, 40.0);
+    let rect3 = geometry::Rectangle::new(60.0, 45.0);
 
-    let rect1 = Rectangle {
-        width: 30,
-        height: 50,
-    };
-    let rect2 = Rectangle {
-        width: 10,
-        height: 40,
-    };
-    let rect3 = Rectangle {
-        width: 60,
-        height: 45,
-    };
-
-    println!("   rect1: {:?}", rect1);
-    println!("   rect2: {:?}", rect2);
-    println!("   rect3: {:?}", rect3);
+    output::result(format!("rect1: {}", rect1));
+    output::result(format!("rect2: {}", rect2));
+    output::result(format!("rect3: {}", rect3));
     println!();
-    println!("   Can rect1 hold rect2? {}", rect1.can_hold(&rect2));
-    println!("   Can rect1 hold rect3? {}", rect1.can_hold(&rect3));
+    output::result(format!("Can rect1 hold rect2? {}", rect1.can_hold(&rect2)));
+    output::result(format!("Can rect1 hold rect3? {}", rect1.can_hold(&rect3)));
     println!();
 }
 
 // 4. Associated functions (no self)
 fn demonstrate_associated_functions() {
-    println!("4. Associated Functions");
-    println!("   Functions without self, often used as constructors\n");
-
-    #[allow(dead_code)]
-    #[derive(Debug)]
-    struct Rectangle {
-        width: u32,
-        height: u32,
-    }
-
-    impl Rectangle {
-        // Associated function - constructor
-        fn new(width: u32, height: u32) -> Self {
-            Self { width, height }
-        }
-
-        // Associated function - specialized constructor
-        fn square(size: u32) -> Self {
-            Self {
-                width: size,
-                height: size,
-            }
-        }
-
-        // Associated function - factory method
-        fn default() -> Self {
-            Self {
-                width: 1,
-                height: 1,
-            }
-        }
-    }
+    output::note("Functions without self, often used as constructors");
+    println!();
 
     // Call with :: syntax
     let rect1 = Rectangle::new(30, 50);
     let rect2 = Rectangle::square(25);
     let rect3 = Rectangle::default();
 
-    println!("   Rectangle::new(30, 50): {:?}", rect1);
-    println!("   Rectangle::square(25): {:?}", rect2);
-    println!("   Rectangle::default(): {:?}", rect3);
+    output::result(format!("Rectangle::new(30, 50): {:?}", rect1));
+    output::result(format!("Rectangle::square(25): {:?}", rect2));
+    output::result(format!("Rectangle::default(): {:?}", rect3));
     println!();
 }
 
 // 5. Method calls as syntactic sugar
 fn demonstrate_method_syntax_sugar() {
-    println!("5. Method Calls as Syntactic Sugar");
-    println!("   Method calls are sugar for function calls\n");
-
-    struct Rectangle {
-        width: u32,
-        height: u32,
-    }
-
-    impl Rectangle {
-        fn area(&self) -> u32 {
-            self.width * self.height
-        }
-
-        fn set_width(&mut self, width: u32) {
-            self.width = width;
-        }
-    }
+    output::note("Method calls are sugar for function calls");
+    println!();
 
-    let mut r = Rectangle {
-        width: 10,
-        height: 20,
-    };
+    let mut r = geometry::Rectangle::new(10.0, 20.0);
 
     // Method syntax
     let area1 = r.area();
 
-    // Equivalent function syntax
-    let area2 = Rectangle::area(&r);
+    // Equivalent function syntax; area comes from the Shape trait, which
+    // works the same way
+    let area2 = geometry::Rectangle::area(&r);
 
-    println!("   Method syntax: r.area() = {}", area1);
-    println!("   Function syntax: Rectangle::area(&r) = {}", area2);
-    println!("   Both are equivalent!");
+    output::result(format!("Method syntax: r.area() = {}", area1));
+    output::result(format!(
+        "Function syntax: geometry::Rectangle::area(&r) = {}",
+        area2
+    ));
+    output::note("Both are equivalent!");
 
-    println!("\n   Method syntax: r.set_width(15)");
-    r.set_width(15);
+    println!();
+    output::note("Method syntax: r.set_width(15.0)");
+    r.set_width(15.0);
 
-    println!("   Function syntax: Rectangle::set_width(&mut r, 20)");
-    Rectangle::set_width(&mut r, 20);
+    output::note("Function syntax: geometry::Rectangle::set_width(&mut r, 20.0)");
+    geometry::Rectangle::set_width(&mut r, 20.0);
 
-    println!("   Final width: {}", r.width);
+    output::result(format!("Final width: {}", r.width));
     println!();
 }
 
 // 6. Multiple impl blocks
 fn demonstrate_multiple_impl_blocks() {
-    println!("6. Multiple impl Blocks");
-    println!("   A type can have multiple impl blocks\n");
-
-    #[allow(dead_code)]
-    #[derive(Debug)]
-    struct Rectangle {
-        width: u32,
-        height: u32,
-    }
-
-    // First impl block - constructors
-    impl Rectangle {
-        fn new(width: u32, height: u32) -> Self {
-            Self { width, height }
-        }
-
-        #[allow(dead_code)]
-        fn square(size: u32) -> Self {
-            Self {
-                width: size,
-                height: size,
-            }
-        }
-    }
-
-    // Second impl block - measurements
-    impl Rectangle {
-        fn area(&self) -> u32 {
-            self.width * self.height
-        }
-
-        fn perimeter(&self) -> u32 {
-            2 * (self.width + self.height)
-        }
-    }
-
-    // Third impl block - comparisons
-    impl Rectangle {
-        #[allow(dead_code)]
-        fn can_hold(&self, other: &Rectangle) -> bool {
-            self.width > other.width && self.height > other.height
-        }
-    }
+    output::note("A type can have multiple impl blocks");
+    println!();
 
+    // Rectangle at the top of this file has three: one for &self methods, one
+    // for constructors and one for getters and consuming methods
     let rect = Rectangle::new(30, 50);
-    println!("   Rectangle: {:?}", rect);
-    println!("   Area: {}", rect.area());
-    println!("   Perimeter: {}", rect.perimeter());
-    println!("\n   Multiple impl blocks are valid but usually not necessary\n");
+    output::result(format!("Rectangle: {:?}", rect));
+    output::result(format!("Area: {}", rect.area()));
+    output::result(format!("Perimeter: {}", rect.perimeter()));
+    println!();
+    output::note("Multiple impl blocks are valid but usually not necessary");
+    println!();
 }
 
 // 7. Getters and method naming
 fn demonstrate_getters() {
-    println!("7. Getters and Method Naming");
-    println!("   Methods can have the same name as fields\n");
-
-    #[allow(dead_code)]
-    #[derive(Debug)]
-    struct Rectangle {
-        width: u32,
-        height: u32,
-    }
-
-    impl Rectangle {
-        // Getter method with same name as field
-        fn width(&self) -> u32 {
-            self.width
-        }
-
-        // Method that checks a condition
-        fn has_width(&self) -> bool {
-            self.width > 0
-        }
-    }
+    output::note("Methods can have the same name as fields");
+    println!();
 
     let rect = Rectangle {
         width: 30,
@@ -337,84 +250,53 @@ fn demonstrate_getters() {
     };
 
     // Field access (no parentheses)
-    println!("   Field access: rect.width = {}", rect.width);
+    output::result(format!("Field access: rect.width = {}", rect.width));
 
     // Method call (with parentheses)
-    println!("   Method call: rect.width() = {}", rect.width());
+    output::result(format!("Method call: rect.width() = {}", rect.width()));
 
     // Condition check
     if rect.has_width() {
-        println!("   Rectangle has a non-zero width");
+        output::result("Rectangle has a non-zero width");
     }
 
-    println!("\n   Rust distinguishes fields from methods by parentheses\n");
+    println!();
+    output::note("Rust distinguishes fields from methods by parentheses");
+    println!();
 }
 
 // 8. Methods that consume self
 fn demonstrate_consuming_methods() {
-    println!("8. Methods that Consume self");
-    println!("   Methods can take ownership of self\n");
-
-    #[derive(Debug)]
-    struct Rectangle {
-        width: u32,
-        height: u32,
-    }
-
-    impl Rectangle {
-        #[allow(dead_code)]
-        fn area(&self) -> u32 {
-            self.width * self.height
-        }
-
-        // Method that consumes self and returns a new value
-        fn max(self, other: Rectangle) -> Rectangle {
-            Rectangle {
-                width: self.width.max(other.width),
-                height: self.height.max(other.height),
-            }
-        }
-
-        // Method that transforms self into something else
-        fn into_square(self) -> Rectangle {
-            let size = self.width.max(self.height);
-            Rectangle {
-                width: size,
-                height: size,
-            }
-        }
-    }
+    output::note("Methods can take ownership of self");
+    println!();
 
-    let rect1 = Rectangle {
-        width: 30,
-        height: 50,
-    };
-    let rect2 = Rectangle {
-        width: 40,
-        height: 20,
-    };
+    // `fn max(self, other: Rectangle) -> Rectangle` takes both by value
+    let rect1 = geometry::Rectangle::new(30.0, 50.0);
+    let rect2 = geometry::Rectangle::new(40.0, 20.0);
 
-    println!("   rect1: {:?}", rect1);
-    println!("   rect2: {:?}", rect2);
+    output::result(format!("rect1: {}", rect1));
+    output::result(format!("rect2: {}", rect2));
 
-    // This consumes both rectangles
     let max_rect = rect1.max(rect2);
-    println!("   max_rect: {:?}", max_rect);
-
-    // rect1 and rect2 are no longer usable here
-    // println!("{:?}", rect1); // Error: value borrowed after move
+    output::result(format!("max_rect: {}", max_rect));
 
-    let rect3 = Rectangle {
-        width: 30,
-        height: 50,
-    };
-    println!("\n   rect3: {:?}", rect3);
+    // `fn into_square(self) -> Square` turns a rectangle into another type
+    let rect3 = geometry::Rectangle::new(30.0, 50.0);
+    println!();
+    output::result(format!("rect3: {}", rect3));
 
     let square = rect3.into_square();
-    println!("   Transformed into square: {:?}", square);
+    output::result(format!("Transformed into square: {}", square));
 
-    // rect3 is no longer usable
-    // println!("{:?}", rect3); // Error: value borrowed after move
+    // geometry::Rectangle is Copy, so rect1, rect2 and rect3 were copied into
+    // those calls and are still usable. The local Rectangle is not Copy, so
+    // consuming it moves it
+    let local = Rectangle::new(30, 50);
+    let converted = local.into_geometry();
+    output::result(format!("local.into_geometry(): {}", converted));
+    // output::result(format!("{:?}", local)); // Error: value borrowed after move
 
-    println!("\n   Consuming methods are useful for transformations\n");
-}
\ No newline at end of file
+    println!();
+    output::note("Consuming methods are useful for transformations");
+    println!();
+}
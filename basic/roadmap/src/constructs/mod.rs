@@ -0,0 +1,20 @@
+// Constructs
+// Language constructs: structs, impl blocks, enums and traits
+
+use crate::registry::Topic;
+
+pub mod enums;
+pub mod impl_blocks;
+pub mod structs;
+pub mod traits;
+
+pub const TOPIC: Topic = Topic {
+    id: "constructs",
+    title: "Constructs",
+    lessons: &[
+        structs::LESSON,
+        impl_blocks::LESSON,
+        enums::LESSON,
+        traits::LESSON,
+    ],
+};
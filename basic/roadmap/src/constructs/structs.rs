@@ -1,32 +1,35 @@
-fn main() {
-    println!("=== Structs in Rust ===\n");
-
-    // 1. Defining and instantiating structs
-    demonstrate_basic_structs();
-
-    // 2. Mutable structs
-    demonstrate_mutable_structs();
-
-    // 3. Field init shorthand
-    demonstrate_field_init_shorthand();
-
-    // 4. Struct update syntax
-    demonstrate_struct_update_syntax();
-
-    // 5. Tuple structs
-    demonstrate_tuple_structs();
-
-    // 6. Unit-like structs
-    demonstrate_unit_like_structs();
-
-    // 7. Struct methods
-    demonstrate_struct_methods();
-}
+use crate::output;
+use crate::registry::{Difficulty, Lesson, Meta, Section};
+use roadmap::geometry::{Rectangle, Shape};
+
+pub const LESSON: Lesson = Lesson {
+    id: "structs",
+    title: "Structs in Rust",
+    meta: Meta {
+        difficulty: Difficulty::Beginner,
+        minutes: 20,
+        book: Some("5.1"),
+        prerequisites: &["ownership_system/references_borrowing_slices"],
+        tags: &["structs", "types"],
+    },
+    sections: &[
+        Section::new(
+            "Defining and Instantiating Structs",
+            demonstrate_basic_structs,
+        ),
+        Section::new("Mutable Structs", demonstrate_mutable_structs),
+        Section::new("Field Init Shorthand", demonstrate_field_init_shorthand),
+        Section::new("Struct Update Syntax", demonstrate_struct_update_syntax),
+        Section::new("Tuple Structs", demonstrate_tuple_structs),
+        Section::new("Unit-Like Structs", demonstrate_unit_like_structs),
+        Section::new("Struct Methods", demonstrate_struct_methods),
+    ],
+};
 
 // 1. Defining and instantiating structs
 fn demonstrate_basic_structs() {
-    println!("1. Defining and Instantiating Structs");
-    println!("   Structs group related data with named fields\n");
+    output::note("Structs group related data with named fields");
+    println!();
 
     struct User {
         active: bool,
@@ -43,18 +46,18 @@ fn demonstrate_basic_structs() {
         sign_in_count: 1,
     };
 
-    println!("   User created:");
-    println!("   - Username: {}", user1.username);
-    println!("   - Email: {}", user1.email);
-    println!("   - Active: {}", user1.active);
-    println!("   - Sign-in count: {}", user1.sign_in_count);
+    output::note("User created:");
+    output::result(format!("Username: {}", user1.username));
+    output::result(format!("Email: {}", user1.email));
+    output::result(format!("Active: {}", user1.active));
+    output::result(format!("Sign-in count: {}", user1.sign_in_count));
     println!();
 }
 
 // 2. Mutable structs
 fn demonstrate_mutable_structs() {
-    println!("2. Mutable Structs");
-    println!("   Entire instance must be mutable to change fields\n");
+    output::note("Entire instance must be mutable to change fields");
+    println!();
 
     #[allow(dead_code)]
     struct User {
@@ -71,21 +74,23 @@ fn demonstrate_mutable_structs() {
         sign_in_count: 1,
     };
 
-    println!("   Original email: {}", user1.email);
+    output::result(format!("Original email: {}", user1.email));
 
     // Change a field value
     user1.email = String::from("anotheremail@example.com");
     user1.sign_in_count += 1;
 
-    println!("   Updated email: {}", user1.email);
-    println!("   Updated sign-in count: {}", user1.sign_in_count);
-    println!("\n   Note: Rust doesn't allow marking only certain fields as mutable\n");
+    output::result(format!("Updated email: {}", user1.email));
+    output::result(format!("Updated sign-in count: {}", user1.sign_in_count));
+    println!();
+    output::note("Note: Rust doesn't allow marking only certain fields as mutable");
+    println!();
 }
 
 // 3. Field init shorthand
 fn demonstrate_field_init_shorthand() {
-    println!("3. Field Init Shorthand");
-    println!("   Avoid repetition when parameter names match field names\n");
+    output::note("Avoid repetition when parameter names match field names");
+    println!();
 
     #[allow(dead_code)]
     struct User {
@@ -125,15 +130,15 @@ fn demonstrate_field_init_shorthand() {
         String::from("anotherusername"),
     );
 
-    println!("   User 1 (with shorthand): {}", user1.email);
-    println!("   User 2 (without shorthand): {}", user2.email);
+    output::result(format!("User 1 (with shorthand): {}", user1.email));
+    output::result(format!("User 2 (without shorthand): {}", user2.email));
     println!();
 }
 
 // 4. Struct update syntax
 fn demonstrate_struct_update_syntax() {
-    println!("4. Struct Update Syntax");
-    println!("   Create instances from other instances with ..\n");
+    output::note("Create instances from other instances with ..");
+    println!();
 
     struct User {
         active: bool,
@@ -152,7 +157,7 @@ fn demonstrate_struct_update_syntax() {
     // Without update syntax (verbose)
     let user2_verbose = User {
         active: user1.active,
-        username: String::from("user2name"), // Different
+        username: String::from("user2name"),        // Different
         email: String::from("another@example.com"), // Different
         sign_in_count: user1.sign_in_count,
     };
@@ -164,19 +169,21 @@ fn demonstrate_struct_update_syntax() {
         ..user2_verbose // Use remaining fields from user2_verbose
     };
 
-    println!("   User 3 email: {}", user3.email);
-    println!("   User 3 username: {}", user3.username);
-    println!("   User 3 active: {}", user3.active);
-    println!("   User 3 sign-in count: {}", user3.sign_in_count);
+    output::result(format!("User 3 email: {}", user3.email));
+    output::result(format!("User 3 username: {}", user3.username));
+    output::result(format!("User 3 active: {}", user3.active));
+    output::result(format!("User 3 sign-in count: {}", user3.sign_in_count));
 
-    println!("\n   Note: .. moves data, so user2_verbose.username is no longer usable");
-    println!("   But user2_verbose.active and sign_in_count are still usable (Copy trait)\n");
+    println!();
+    output::note("Note: .. moves data, so user2_verbose.username is no longer usable");
+    output::note("But user2_verbose.active and sign_in_count are still usable (Copy trait)");
+    println!();
 }
 
 // 5. Tuple structs
 fn demonstrate_tuple_structs() {
-    println!("5. Tuple Structs");
-    println!("   Structs without named fields, just types\n");
+    output::note("Structs without named fields, just types");
+    println!();
 
     struct Color(i32, i32, i32);
     struct Point(i32, i32, i32);
@@ -184,17 +191,27 @@ fn demonstrate_tuple_structs() {
     let black = Color(0, 0, 0);
     let origin = Point(0, 0, 0);
 
-    println!("   Color and Point are different types!");
-    println!("   Black color: ({}, {}, {})", black.0, black.1, black.2);
-    println!("   Origin point: ({}, {}, {})", origin.0, origin.1, origin.2);
+    output::note("Color and Point are different types!");
+    output::result(format!(
+        "Black color: ({}, {}, {})",
+        black.0, black.1, black.2
+    ));
+    output::result(format!(
+        "Origin point: ({}, {}, {})",
+        origin.0, origin.1, origin.2
+    ));
 
     // Destructuring tuple structs
     let Color(r, g, b) = black;
-    println!("\n   Destructured black: r={}, g={}, b={}", r, g, b);
+    println!();
+    output::result(format!("Destructured black: r={}, g={}, b={}", r, g, b));
 
     // Function that only accepts Color, not Point
     fn print_color(color: Color) {
-        println!("   Color RGB: ({}, {}, {})", color.0, color.1, color.2);
+        output::result(format!(
+            "Color RGB: ({}, {}, {})",
+            color.0, color.1, color.2
+        ));
     }
 
     let red = Color(255, 0, 0);
@@ -206,16 +223,17 @@ fn demonstrate_tuple_structs() {
 
 // 6. Unit-like structs
 fn demonstrate_unit_like_structs() {
-    println!("6. Unit-Like Structs");
-    println!("   Structs with no fields\n");
+    output::note("Structs with no fields");
+    println!();
 
     struct AlwaysEqual;
 
     let _subject = AlwaysEqual;
 
-    println!("   AlwaysEqual is a unit-like struct");
-    println!("   Useful for implementing traits without data");
-    println!("   Example: marker types, zero-sized types\n");
+    output::note("AlwaysEqual is a unit-like struct");
+    output::note("Useful for implementing traits without data");
+    output::note("Example: marker types, zero-sized types");
+    println!();
 
     // Another example
     struct Marker;
@@ -223,68 +241,43 @@ fn demonstrate_unit_like_structs() {
     let _marker1 = Marker;
     let _marker2 = Marker;
 
-    println!("   Created two Marker instances");
-    println!("   They take up zero bytes in memory!\n");
+    output::note("Created two Marker instances");
+    output::note("They take up zero bytes in memory!");
+    println!();
 }
 
 // 7. Struct methods
 fn demonstrate_struct_methods() {
-    println!("7. Struct Methods");
-    println!("   Define methods using impl blocks\n");
-
-    #[derive(Debug)]
-    struct Rectangle {
-        width: u32,
-        height: u32,
-    }
-
-    impl Rectangle {
-        // Method (takes &self)
-        fn area(&self) -> u32 {
-            self.width * self.height
-        }
-
-        // Method that takes mutable reference
-        fn double(&mut self) {
-            self.width *= 2;
-            self.height *= 2;
-        }
-
-        // Method that checks a condition
-        fn can_hold(&self, other: &Rectangle) -> bool {
-            self.width > other.width && self.height > other.height
-        }
-
-        // Associated function (no self) - like a constructor
-        fn square(size: u32) -> Self {
-            Self {
-                width: size,
-                height: size,
-            }
-        }
-    }
-
-    let mut rect1 = Rectangle {
-        width: 30,
-        height: 50,
-    };
-
-    let rect2 = Rectangle {
-        width: 10,
-        height: 40,
-    };
-
-    let rect3 = Rectangle::square(25);
-
-    println!("   rect1: {:?}", rect1);
-    println!("   rect1 area: {}", rect1.area());
-    println!("   Can rect1 hold rect2? {}", rect1.can_hold(&rect2));
-    println!("   Can rect1 hold rect3? {}", rect1.can_hold(&rect3));
+    output::note("Define methods using impl blocks");
+    println!();
 
-    rect1.double();
-    println!("\n   After doubling rect1: {:?}", rect1);
-    println!("   rect1 area: {}", rect1.area());
+    // A finished Rectangle with these methods lives in src/geometry.rs:
+    //
+    //   impl Rectangle {
+    //       // Method that checks a condition (takes &self)
+    //       fn can_hold(&self, other: &Rectangle) -> bool { ... }
+    //       // Method that takes a mutable reference
+    //       fn scale(&mut self, factor: f64) { ... }
+    //       // Associated function (no self) - like a constructor
+    //       fn square(size: f64) -> Self { ... }
+    //   }
+    //
+    // and area comes from its `impl Shape for Rectangle`
+    let mut rect1 = Rectangle::new(30.0, 50.0);
+    let rect2 = Rectangle::new(10.0, 40.0);
+    let rect3 = Rectangle::square(25.0);
+
+    output::result(format!("rect1: {}", rect1));
+    output::result(format!("rect1 area: {}", rect1.area()));
+    output::result(format!("Can rect1 hold rect2? {}", rect1.can_hold(&rect2)));
+    output::result(format!("Can rect1 hold rect3? {}", rect1.can_hold(&rect3)));
+
+    rect1.scale(2.0);
+    println!();
+    output::result(format!("After doubling rect1: {}", rect1));
+    output::result(format!("rect1 area: {}", rect1.area()));
 
-    println!("\n   Square (associated function): {:?}", rect3);
     println!();
-}
\ No newline at end of file
+    output::result(format!("Square (associated function): {}", rect3));
+    println!();
+}
@@ -1,37 +1,39 @@
+use crate::output;
+use crate::registry::{Difficulty, Lesson, Meta, Section};
 use std::fmt::{Debug, Display};
 
-fn main() {
-    println!("=== Traits in Rust ===\n");
-
-    // 1. Defining and implementing traits
-    demonstrate_basic_traits();
-
-    // 2. Default implementations
-    demonstrate_default_implementations();
-
-    // 3. Traits as parameters
-    demonstrate_traits_as_parameters();
-
-    // 4. Trait bounds
-    demonstrate_trait_bounds();
-
-    // 5. Multiple trait bounds
-    demonstrate_multiple_trait_bounds();
-
-    // 6. Where clauses
-    demonstrate_where_clauses();
-
-    // 7. Returning traits
-    demonstrate_returning_traits();
-
-    // 8. Conditional implementations
-    demonstrate_conditional_implementations();
-}
+pub const LESSON: Lesson = Lesson {
+    id: "traits",
+    title: "Traits in Rust",
+    meta: Meta {
+        difficulty: Difficulty::Advanced,
+        minutes: 25,
+        book: Some("10.2"),
+        prerequisites: &["impl_blocks", "enums"],
+        tags: &["traits", "generics"],
+    },
+    sections: &[
+        Section::new("Defining and Implementing Traits", demonstrate_basic_traits),
+        Section::new(
+            "Default Implementations",
+            demonstrate_default_implementations,
+        ),
+        Section::new("Traits as Parameters", demonstrate_traits_as_parameters),
+        Section::new("Trait Bounds", demonstrate_trait_bounds),
+        Section::new("Multiple Trait Bounds", demonstrate_multiple_trait_bounds),
+        Section::new("Where Clauses", demonstrate_where_clauses),
+        Section::new("Returning Traits", demonstrate_returning_traits),
+        Section::new(
+            "Conditional Implementations",
+            demonstrate_conditional_implementations,
+        ),
+    ],
+};
 
 // 1. Defining and implementing traits
 fn demonstrate_basic_traits() {
-    println!("1. Defining and Implementing Traits");
-    println!("   Traits define shared behavior\n");
+    output::note("Traits define shared behavior");
+    println!();
 
     // Define a trait
     pub trait Summary {
@@ -82,15 +84,15 @@ fn demonstrate_basic_traits() {
         retweet: false,
     };
 
-    println!("   Article: {}", article.summarize());
-    println!("   Tweet: {}", tweet.summarize());
+    output::result(format!("Article: {}", article.summarize()));
+    output::result(format!("Tweet: {}", tweet.summarize()));
     println!();
 }
 
 // 2. Default implementations
 fn demonstrate_default_implementations() {
-    println!("2. Default Implementations");
-    println!("   Traits can provide default method implementations\n");
+    output::note("Traits can provide default method implementations");
+    println!();
 
     pub trait Summary {
         fn summarize_author(&self) -> String;
@@ -119,15 +121,15 @@ fn demonstrate_default_implementations() {
         content: String::from("of course, as you probably already know, people"),
     };
 
-    println!("   Using default implementation:");
-    println!("   {}", tweet.summarize());
+    output::note("Using default implementation:");
+    output::result(tweet.summarize());
     println!();
 }
 
 // 3. Traits as parameters
 fn demonstrate_traits_as_parameters() {
-    println!("3. Traits as Parameters");
-    println!("   Use impl Trait syntax for function parameters\n");
+    output::note("Use impl Trait syntax for function parameters");
+    println!();
 
     pub trait Summary {
         fn summarize(&self) -> String;
@@ -146,7 +148,7 @@ fn demonstrate_traits_as_parameters() {
 
     // Function that accepts any type implementing Summary
     pub fn notify(item: &impl Summary) {
-        println!("   Breaking news! {}", item.summarize());
+        output::result(format!("Breaking news! {}", item.summarize()));
     }
 
     let article = NewsArticle {
@@ -160,8 +162,8 @@ fn demonstrate_traits_as_parameters() {
 
 // 4. Trait bounds
 fn demonstrate_trait_bounds() {
-    println!("4. Trait Bounds");
-    println!("   Full syntax for constraining generic types\n");
+    output::note("Full syntax for constraining generic types");
+    println!();
 
     pub trait Summary {
         fn summarize(&self) -> String;
@@ -179,17 +181,21 @@ fn demonstrate_trait_bounds() {
 
     // impl Trait syntax (sugar)
     fn notify_short(item: &impl Summary) {
-        println!("   Short syntax: {}", item.summarize());
+        output::result(format!("Short syntax: {}", item.summarize()));
     }
 
     // Trait bound syntax (full form)
     fn notify_long<T: Summary>(item: &T) {
-        println!("   Long syntax: {}", item.summarize());
+        output::result(format!("Long syntax: {}", item.summarize()));
     }
 
     // When you need same type for multiple parameters
     fn notify_same<T: Summary>(item1: &T, item2: &T) {
-        println!("   Same type: {} and {}", item1.summarize(), item2.summarize());
+        output::result(format!(
+            "Same type: {} and {}",
+            item1.summarize(),
+            item2.summarize()
+        ));
     }
 
     let article1 = Article {
@@ -207,8 +213,8 @@ fn demonstrate_trait_bounds() {
 
 // 5. Multiple trait bounds
 fn demonstrate_multiple_trait_bounds() {
-    println!("5. Multiple Trait Bounds");
-    println!("   Require multiple traits with + syntax\n");
+    output::note("Require multiple traits with + syntax");
+    println!();
 
     pub trait Summary {
         fn summarize(&self) -> String;
@@ -234,14 +240,14 @@ fn demonstrate_multiple_trait_bounds() {
 
     // Multiple trait bounds with impl Trait
     fn notify_display(item: &(impl Summary + Display)) {
-        println!("   Display: {}", item);
-        println!("   Summary: {}", item.summarize());
+        output::result(format!("Display: {}", item));
+        output::result(format!("Summary: {}", item.summarize()));
     }
 
     // Multiple trait bounds with generic syntax
     fn notify_generic<T: Summary + Display>(item: &T) {
-        println!("   Generic - Display: {}", item);
-        println!("   Generic - Summary: {}", item.summarize());
+        output::result(format!("Generic - Display: {}", item));
+        output::result(format!("Generic - Summary: {}", item.summarize()));
     }
 
     let article = Article {
@@ -257,8 +263,8 @@ fn demonstrate_multiple_trait_bounds() {
 
 // 6. Where clauses
 fn demonstrate_where_clauses() {
-    println!("6. Where Clauses");
-    println!("   Cleaner syntax for complex trait bounds\n");
+    output::note("Cleaner syntax for complex trait bounds");
+    println!();
 
     #[allow(dead_code)]
     pub trait Summary {
@@ -301,15 +307,21 @@ fn demonstrate_where_clauses() {
     };
     let number = 42;
 
-    println!("   Without where: {}", some_function_ugly(&article, &number));
-    println!("   With where: {}", some_function_clean(&article, &number));
+    output::result(format!(
+        "Without where: {}",
+        some_function_ugly(&article, &number)
+    ));
+    output::result(format!(
+        "With where: {}",
+        some_function_clean(&article, &number)
+    ));
     println!();
 }
 
 // 7. Returning traits
 fn demonstrate_returning_traits() {
-    println!("7. Returning Traits");
-    println!("   Return types that implement traits\n");
+    output::note("Return types that implement traits");
+    println!();
 
     pub trait Summary {
         fn summarize(&self) -> String;
@@ -336,16 +348,18 @@ fn demonstrate_returning_traits() {
     }
 
     let tweet = returns_summarizable();
-    println!("   Returned: {}", tweet.summarize());
+    output::result(format!("Returned: {}", tweet.summarize()));
 
-    println!("\n   Note: impl Trait can only return a single concrete type");
-    println!("   For multiple types, use trait objects (Box<dyn Trait>)\n");
+    println!();
+    output::note("Note: impl Trait can only return a single concrete type");
+    output::note("For multiple types, use trait objects (Box<dyn Trait>)");
+    println!();
 }
 
 // 8. Conditional implementations
 fn demonstrate_conditional_implementations() {
-    println!("8. Conditional Implementations");
-    println!("   Implement methods only for types with certain traits\n");
+    output::note("Implement methods only for types with certain traits");
+    println!();
 
     struct Pair<T> {
         x: T,
@@ -363,9 +377,9 @@ fn demonstrate_conditional_implementations() {
     impl<T: Display + PartialOrd> Pair<T> {
         fn cmp_display(&self) {
             if self.x >= self.y {
-                println!("   The largest member is x = {}", self.x);
+                output::result(format!("The largest member is x = {}", self.x));
             } else {
-                println!("   The largest member is y = {}", self.y);
+                output::result(format!("The largest member is y = {}", self.y));
             }
         }
     }
@@ -374,10 +388,11 @@ fn demonstrate_conditional_implementations() {
     pair.cmp_display();
 
     // Blanket implementation example
-    println!("\n   Blanket implementations:");
-    println!("   Any type implementing Display also gets ToString");
+    println!();
+    output::note("Blanket implementations:");
+    output::note("Any type implementing Display also gets ToString");
     let num = 42;
     let s = num.to_string(); // Works because i32 implements Display
-    println!("   42.to_string() = \"{}\"", s);
+    output::result(format!("42.to_string() = \"{}\"", s));
     println!();
-}
\ No newline at end of file
+}
@@ -1,302 +1,331 @@
-fn main() {
-    println!("=== Boolean Type in Rust ===\n");
-
-    // 1. Boolean basics
-    demonstrate_bool_basics();
-
-    // 2. Boolean operations
-    demonstrate_bool_operations();
-
-    // 3. Logical operators
-    demonstrate_logical_operators();
-
-    // 4. Comparison operators
-    demonstrate_comparison_operators();
-
-    // 5. Boolean in control flow
-    demonstrate_control_flow();
-
-    // 6. Boolean casting
-    demonstrate_bool_casting();
-
-    // 7. Short-circuit evaluation
-    demonstrate_short_circuit();
-
-    // 8. Practical examples
-    demonstrate_practical_examples();
-}
+use crate::output;
+use crate::registry::{Difficulty, Lesson, Meta, Section};
+use roadmap::password;
+use roadmap::validation::{Nested, Validate, contains, length, only, range};
+use roadmap::validator;
+
+pub const LESSON: Lesson = Lesson {
+    id: "boolean",
+    title: "Boolean Type in Rust",
+    meta: Meta {
+        difficulty: Difficulty::Beginner,
+        minutes: 10,
+        book: Some("3.2"),
+        prerequisites: &["syntax_and_semantics/data_types"],
+        tags: &["types", "booleans"],
+    },
+    sections: &[
+        Section::new("Boolean Basics", demonstrate_bool_basics),
+        Section::new("Boolean Operations", demonstrate_bool_operations),
+        Section::new("Logical Operators", demonstrate_logical_operators),
+        Section::new("Comparison Operators", demonstrate_comparison_operators),
+        Section::new("Boolean in Control Flow", demonstrate_control_flow),
+        Section::new("Boolean Casting", demonstrate_bool_casting),
+        Section::new("Short-Circuit Evaluation", demonstrate_short_circuit),
+        Section::new("Practical Examples", demonstrate_practical_examples),
+    ],
+};
 
 // 1. Boolean basics
 fn demonstrate_bool_basics() {
-    println!("1. Boolean Basics");
-    println!("   bool has only two values: true and false\n");
+    output::note("bool has only two values: true and false");
+    println!();
 
     let t = true;
     let f: bool = false; // with explicit type annotation
 
-    println!("   t = {}", t);
-    println!("   f = {}", f);
+    output::result(format!("t = {}", t));
+    output::result(format!("f = {}", f));
 
-    println!("\n   Size of bool: {} byte", std::mem::size_of::<bool>());
-    println!("   Booleans are one byte in size\n");
+    println!();
+    output::result(format!(
+        "Size of bool: {} byte",
+        std::mem::size_of::<bool>()
+    ));
+    output::note("Booleans are one byte in size");
+    println!();
 }
 
 // 2. Boolean operations
 fn demonstrate_bool_operations() {
-    println!("2. Boolean Operations");
-    println!("   Bitwise and logical operations on booleans\n");
+    output::note("Bitwise and logical operations on booleans");
+    println!();
 
     let a = true;
     let b = false;
 
     // Bitwise AND (&)
-    println!("   Bitwise AND (&):");
-    println!("   true & true = {}", true & true);
-    println!("   true & false = {}", true & false);
-    println!("   false & false = {}", false & false);
+    output::note("Bitwise AND (&):");
+    output::result(format!("true & true = {}", true & true));
+    output::result(format!("true & false = {}", true & false));
+    output::result(format!("false & false = {}", false & false));
 
     // Bitwise OR (|)
-    println!("\n   Bitwise OR (|):");
-    println!("   true | true = {}", true | true);
-    println!("   true | false = {}", true | false);
-    println!("   false | false = {}", false | false);
+    println!();
+    output::note("Bitwise OR (|):");
+    output::result(format!("true | true = {}", true | true));
+    output::result(format!("true | false = {}", true | false));
+    output::result(format!("false | false = {}", false | false));
 
     // Bitwise XOR (^)
-    println!("\n   Bitwise XOR (^):");
-    println!("   true ^ true = {}", true ^ true);
-    println!("   true ^ false = {}", true ^ false);
-    println!("   false ^ false = {}", false ^ false);
+    println!();
+    output::note("Bitwise XOR (^):");
+    output::result(format!("true ^ true = {}", true ^ true));
+    output::result(format!("true ^ false = {}", true ^ false));
+    output::result(format!("false ^ false = {}", false ^ false));
 
     // NOT (!)
-    println!("\n   NOT (!):");
-    println!("   !true = {}", !a);
-    println!("   !false = {}", !b);
+    println!();
+    output::note("NOT (!):");
+    output::result(format!("!true = {}", !a));
+    output::result(format!("!false = {}", !b));
 
     println!();
 }
 
 // 3. Logical operators
 fn demonstrate_logical_operators() {
-    println!("3. Logical Operators");
-    println!("   Short-circuiting logical operators\n");
+    output::note("Short-circuiting logical operators");
+    println!();
 
     let _a = true;
     let _b = false;
 
     // Logical AND (&&)
-    println!("   Logical AND (&&):");
-    println!("   true && true = {}", true && true);
-    println!("   true && false = {}", true && false);
-    println!("   false && true = {}", false && true);
-    println!("   false && false = {}", false && false);
+    output::note("Logical AND (&&):");
+    output::result(format!("true && true = {}", true && true));
+    output::result(format!("true && false = {}", true && false));
+    output::result(format!("false && true = {}", false && true));
+    output::result(format!("false && false = {}", false && false));
 
     // Logical OR (||)
-    println!("\n   Logical OR (||):");
-    println!("   true || true = {}", true || true);
-    println!("   true || false = {}", true || false);
-    println!("   false || true = {}", false || true);
-    println!("   false || false = {}", false || false);
+    println!();
+    output::note("Logical OR (||):");
+    output::result(format!("true || true = {}", true || true));
+    output::result(format!("true || false = {}", true || false));
+    output::result(format!("false || true = {}", false || true));
+    output::result(format!("false || false = {}", false || false));
 
     // Difference between & and &&, | and ||
-    println!("\n   Difference:");
-    println!("   & and | evaluate both operands");
-    println!("   && and || short-circuit (stop early if result is known)");
+    println!();
+    output::note("Difference:");
+    output::note("& and | evaluate both operands");
+    output::note("&& and || short-circuit (stop early if result is known)");
 
     println!();
 }
 
 // 4. Comparison operators
 fn demonstrate_comparison_operators() {
-    println!("4. Comparison Operators");
-    println!("   Comparisons return boolean values\n");
+    output::note("Comparisons return boolean values");
+    println!();
 
     let x = 5;
     let y = 10;
 
-    println!("   x = {}, y = {}", x, y);
-    println!("   x == y: {}", x == y);
-    println!("   x != y: {}", x != y);
-    println!("   x < y: {}", x < y);
-    println!("   x > y: {}", x > y);
-    println!("   x <= y: {}", x <= y);
-    println!("   x >= y: {}", x >= y);
+    output::result(format!("x = {}, y = {}", x, y));
+    output::result(format!("x == y: {}", x == y));
+    output::result(format!("x != y: {}", x != y));
+    output::result(format!("x < y: {}", x < y));
+    output::result(format!("x > y: {}", x > y));
+    output::result(format!("x <= y: {}", x <= y));
+    output::result(format!("x >= y: {}", x >= y));
 
     // Boolean comparisons
-    println!("\n   Boolean comparisons:");
-    println!("   true == true: {}", true == true);
-    println!("   true == false: {}", true == false);
-    println!("   true != false: {}", true != false);
+    println!();
+    output::note("Boolean comparisons:");
+    output::result(format!("true == true: {}", true == true));
+    output::result(format!("true == false: {}", true == false));
+    output::result(format!("true != false: {}", true != false));
 
     println!();
 }
 
 // 5. Boolean in control flow
 fn demonstrate_control_flow() {
-    println!("5. Boolean in Control Flow");
-    println!("   if expressions require boolean conditions\n");
+    output::note("if expressions require boolean conditions");
+    println!();
 
     let condition = true;
 
     // if expression
     if condition {
-        println!("   condition is true");
+        output::result("condition is true");
     } else {
-        println!("   condition is false");
+        output::result("condition is false");
     }
 
     // if-else chain
     let number = 6;
     if number % 4 == 0 {
-        println!("   {} is divisible by 4", number);
+        output::result(format!("{} is divisible by 4", number));
     } else if number % 3 == 0 {
-        println!("   {} is divisible by 3", number);
+        output::result(format!("{} is divisible by 3", number));
     } else if number % 2 == 0 {
-        println!("   {} is divisible by 2", number);
+        output::result(format!("{} is divisible by 2", number));
     } else {
-        println!("   {} is not divisible by 4, 3, or 2", number);
+        output::result(format!("{} is not divisible by 4, 3, or 2", number));
     }
 
     // if as an expression
     let result = if condition { "yes" } else { "no" };
-    println!("   Result: {}", result);
+    output::result(format!("Result: {}", result));
 
     // while loop with boolean
     let mut count = 0;
     while count < 3 {
         count += 1;
     }
-    println!("   Counted to {}", count);
+    output::result(format!("Counted to {}", count));
 
     println!();
 }
 
 // 6. Boolean casting
 fn demonstrate_bool_casting() {
-    println!("6. Boolean Casting");
-    println!("   Converting between bool and integers\n");
+    output::note("Converting between bool and integers");
+    println!();
 
     // bool to integer
     let t = true;
     let f = false;
 
-    println!("   true as i32 = {}", t as i32);
-    println!("   false as i32 = {}", f as i32);
-    println!("   true as u8 = {}", t as u8);
-    println!("   false as u8 = {}", f as u8);
+    output::result(format!("true as i32 = {}", t as i32));
+    output::result(format!("false as i32 = {}", f as i32));
+    output::result(format!("true as u8 = {}", t as u8));
+    output::result(format!("false as u8 = {}", f as u8));
 
     // Note: Cannot directly cast integer to bool
     // Must use comparison
     let num = 5;
     let is_nonzero = num != 0;
-    println!("\n   {} != 0 = {}", num, is_nonzero);
+    println!();
+    output::result(format!("{} != 0 = {}", num, is_nonzero));
 
     let zero = 0;
     let is_zero = zero == 0;
-    println!("   {} == 0 = {}", zero, is_zero);
+    output::result(format!("{} == 0 = {}", zero, is_zero));
 
     println!();
 }
 
 // 7. Short-circuit evaluation
 fn demonstrate_short_circuit() {
-    println!("7. Short-Circuit Evaluation");
-    println!("   && and || stop evaluating when result is known\n");
+    output::note("&& and || stop evaluating when result is known");
+    println!();
 
     // && short-circuits
-    println!("   Logical AND (&&) short-circuits:");
+    output::note("Logical AND (&&) short-circuits:");
     let result = false && {
-        println!("   This won't print!");
+        output::note("This won't print!");
         true
     };
-    println!("   Result: {}", result);
+    output::result(format!("Result: {}", result));
 
     let result2 = true && {
-        println!("   This will print!");
+        output::result("This will print!");
         true
     };
-    println!("   Result: {}", result2);
+    output::result(format!("Result: {}", result2));
 
     // || short-circuits
-    println!("\n   Logical OR (||) short-circuits:");
+    println!();
+    output::note("Logical OR (||) short-circuits:");
     let result3 = true || {
-        println!("   This won't print!");
+        output::note("This won't print!");
         false
     };
-    println!("   Result: {}", result3);
+    output::result(format!("Result: {}", result3));
 
     let result4 = false || {
-        println!("   This will print!");
+        output::result("This will print!");
         true
     };
-    println!("   Result: {}", result4);
+    output::result(format!("Result: {}", result4));
 
     println!();
 }
 
 // 8. Practical examples
 fn demonstrate_practical_examples() {
-    println!("8. Practical Examples\n");
-
     // Example 1: Validation
-    println!("   Example 1: Input validation");
+    output::note("Example 1: Input validation");
     fn is_valid_age(age: i32) -> bool {
         age >= 0 && age <= 150
     }
 
     let age = 25;
-    println!("   Is {} a valid age? {}", age, is_valid_age(age));
-    println!("   Is -5 a valid age? {}", is_valid_age(-5));
+    output::result(format!("Is {} a valid age? {}", age, is_valid_age(age)));
+    output::result(format!("Is -5 a valid age? {}", is_valid_age(-5)));
 
     // Example 2: Range checking
-    println!("\n   Example 2: Range checking");
+    println!();
+    output::note("Example 2: Range checking");
     fn is_in_range(value: i32, min: i32, max: i32) -> bool {
         value >= min && value <= max
     }
 
     let score = 85;
-    println!("   Is {} in range [0, 100]? {}", score, is_in_range(score, 0, 100));
+    output::result(format!(
+        "Is {} in range [0, 100]? {}",
+        score,
+        is_in_range(score, 0, 100)
+    ));
 
     // Example 3: Multiple conditions
-    println!("\n   Example 3: Multiple conditions");
+    println!();
+    output::note("Example 3: Multiple conditions");
     fn can_vote(age: i32, is_citizen: bool, is_registered: bool) -> bool {
         age >= 18 && is_citizen && is_registered
     }
 
-    println!(
-        "   Can vote (age=20, citizen=true, registered=true)? {}",
+    output::result(format!(
+        "Can vote (age=20, citizen=true, registered=true)? {}",
         can_vote(20, true, true)
-    );
-    println!(
-        "   Can vote (age=16, citizen=true, registered=true)? {}",
+    ));
+    output::result(format!(
+        "Can vote (age=16, citizen=true, registered=true)? {}",
         can_vote(16, true, true)
-    );
+    ));
 
     // Example 4: String validation
-    println!("\n   Example 4: String validation");
+    println!();
+    output::note("Example 4: String validation");
     fn is_valid_username(username: &str) -> bool {
         !username.is_empty() && username.len() >= 3 && username.len() <= 20
     }
 
-    println!("   Is 'john' valid? {}", is_valid_username("john"));
-    println!("   Is 'ab' valid? {}", is_valid_username("ab"));
-    println!("   Is '' valid? {}", is_valid_username(""));
+    output::result(format!("Is 'john' valid? {}", is_valid_username("john")));
+    output::result(format!("Is 'ab' valid? {}", is_valid_username("ab")));
+    output::result(format!("Is '' valid? {}", is_valid_username("")));
 
     // Example 5: Combining conditions
-    println!("\n   Example 5: Password strength");
-    fn is_strong_password(password: &str) -> bool {
-        let has_length = password.len() >= 8;
-        let has_uppercase = password.chars().any(|c| c.is_uppercase());
-        let has_lowercase = password.chars().any(|c| c.is_lowercase());
-        let has_digit = password.chars().any(|c| c.is_numeric());
-
-        has_length && has_uppercase && has_lowercase && has_digit
+    println!();
+    output::note("Example 5: Password strength");
+    // Four checks joined with && would call "Password123" strong. The
+    // estimator in roadmap::password counts chars (not bytes) and knows the
+    // words, sequences and keyboard walks attackers try first
+    for candidate in [
+        "Password123",
+        "weak",
+        "qwerty2024!",
+        "correct horse battery staple",
+    ] {
+        let strength = password::estimate(candidate);
+        output::result(format!(
+            "Is '{}' strong? {} - {}",
+            candidate,
+            strength.is_strong(),
+            strength
+        ));
+        for tip in &strength.feedback {
+            output::result(format!("tip: {}", tip));
+        }
     }
 
-    println!("   Is 'Password123' strong? {}", is_strong_password("Password123"));
-    println!("   Is 'weak' strong? {}", is_strong_password("weak"));
-
     // Example 6: Boolean flags
-    println!("\n   Example 6: Feature flags");
+    println!();
+    output::note("Example 6: Feature flags");
     #[allow(dead_code)]
     struct Config {
         debug_mode: bool,
@@ -311,12 +340,69 @@ fn demonstrate_practical_examples() {
     };
 
     if config.debug_mode {
-        println!("   Debug mode is enabled");
+        output::result("Debug mode is enabled");
     }
 
     if config.auto_save {
-        println!("   Auto-save is enabled");
+        output::result("Auto-save is enabled");
     }
 
+    // Example 7: The checks above as values (roadmap::validation).
+    // Rules combine with and/or/not like bools do, and a failed check says
+    // which field broke which rule instead of a bare false
     println!();
-}
\ No newline at end of file
+    output::note("Example 7: Composable validation");
+    struct Account {
+        username: String,
+        age: i32,
+        password: String,
+    }
+
+    struct Signup {
+        account: Account,
+        plan: String,
+    }
+
+    validator!(Account {
+        username: length(3..=20).and(only("[A-Za-z0-9_]")),
+        age: range(0..=150),
+        password: length(8..)
+            .and(contains("[A-Z]").describe("contain an uppercase letter"))
+            .and(contains("\\d").describe("contain a digit")),
+    });
+
+    validator!(Signup {
+        account: Nested,
+        plan: only("[a-z]").or(only("[A-Z]")),
+    });
+
+    let good = Signup {
+        account: Account {
+            username: "john".to_string(),
+            age: 25,
+            password: "Password123".to_string(),
+        },
+        plan: "free".to_string(),
+    };
+    let bad = Signup {
+        account: Account {
+            username: "ab!".to_string(),
+            age: -5,
+            password: "weak".to_string(),
+        },
+        plan: "Gold".to_string(),
+    };
+
+    output::result(format!(
+        "Is the first signup valid? {}",
+        good.validate().is_ok()
+    ));
+    output::note("The second one fails with:");
+    if let Err(failures) = bad.validate() {
+        for failure in failures {
+            output::result(failure.to_string());
+        }
+    }
+
+    println!();
+}
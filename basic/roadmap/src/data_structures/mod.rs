@@ -0,0 +1,26 @@
+// Data Structures
+// Primitive types (integers, floats, booleans, characters) and compound types
+
+use crate::registry::Topic;
+
+pub mod arrays;
+pub mod boolean;
+pub mod character;
+pub mod floats;
+pub mod integers;
+pub mod string;
+pub mod tuple;
+
+pub const TOPIC: Topic = Topic {
+    id: "data_structures",
+    title: "Data Structures",
+    lessons: &[
+        integers::LESSON,
+        floats::LESSON,
+        boolean::LESSON,
+        character::LESSON,
+        string::LESSON,
+        tuple::LESSON,
+        arrays::LESSON,
+    ],
+};
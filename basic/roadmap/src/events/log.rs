@@ -0,0 +1,418 @@
+// Event Log
+// Recorded events as text, one per line, after the number of events that
+// had been dispatched when each was emitted:
+//
+//   # roadmap web events
+//   0 page_load
+//   1 click 20 80
+//   1 key_press 'a'
+//   2 paste "two\nlines"
+//
+// Chars and strings are quoted and escaped the way Rust's Debug output
+// writes them. Knowing when each event arrived makes replay exact: `replay`
+// dispatches until the same count has been reached before emitting the next
+// event, so follow-ups queued by handlers interleave exactly as they did when
+// the log was recorded. Blank lines and `#` comments are ignored.
+
+use std::fmt;
+use std::fs;
+use std::io::{self, Write};
+use std::path::Path;
+
+use super::{Dispatcher, Kind, WebEvent};
+
+const HEADER: &str = "# roadmap web events";
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Entry {
+    // Events dispatched, since recording started, before this one was emitted
+    pub at: usize,
+    pub event: WebEvent,
+}
+
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct Log {
+    entries: Vec<Entry>,
+}
+
+#[derive(Debug)]
+pub enum LogError {
+    Io(io::Error),
+    Parse {
+        line: usize,
+        message: String,
+    },
+    OutOfOrder {
+        line: usize,
+        at: usize,
+        previous: usize,
+    },
+    // Replaying through different handlers never reached an entry's count
+    Diverged {
+        entry: usize,
+        at: usize,
+        dispatched: usize,
+    },
+}
+
+impl fmt::Display for LogError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            LogError::Io(err) => write!(f, "{}", err),
+            LogError::Parse { line, message } => write!(f, "line {}: {}", line, message),
+            LogError::OutOfOrder { line, at, previous } => write!(
+                f,
+                "line {}: event at {} comes after one at {}",
+                line, at, previous
+            ),
+            LogError::Diverged {
+                entry,
+                at,
+                dispatched,
+            } => write!(
+                f,
+                "entry {} was emitted after {} dispatched events, but replay ran out of events after {}",
+                entry, at, dispatched
+            ),
+        }
+    }
+}
+
+impl From<io::Error> for LogError {
+    fn from(err: io::Error) -> Self {
+        LogError::Io(err)
+    }
+}
+
+fn encode(entry: &Entry) -> String {
+    let kind = entry.event.kind();
+    match &entry.event {
+        WebEvent::PageLoad | WebEvent::PageUnload => format!("{} {}", entry.at, kind),
+        WebEvent::KeyPress(c) => format!("{} {} {:?}", entry.at, kind, c),
+        WebEvent::Paste(text) => format!("{} {} {:?}", entry.at, kind, text),
+        WebEvent::Click { x, y } => format!("{} {} {} {}", entry.at, kind, x, y),
+    }
+}
+
+// The contents of a quoted, escaped literal starting at `text`'s first
+// char, and what follows it
+fn unquote(text: &str) -> Result<(String, &str), String> {
+    let mut chars = text.char_indices();
+    let quote = match chars.next() {
+        Some((_, quote @ ('\'' | '"'))) => quote,
+        _ => return Err(format!("expected a quoted value, found '{}'", text)),
+    };
+    let mut value = String::new();
+    while let Some((index, c)) = chars.next() {
+        match c {
+            c if c == quote => return Ok((value, &text[index + 1..])),
+            '\\' => match chars.next().map(|(_, e)| e) {
+                Some('n') => value.push('\n'),
+                Some('r') => value.push('\r'),
+                Some('t') => value.push('\t'),
+                Some('0') => value.push('\0'),
+                Some(e @ ('\\' | '\'' | '"')) => value.push(e),
+                Some('u') => {
+                    let rest = &text[index + 2..];
+                    let hex = rest
+                        .strip_prefix('{')
+                        .and_then(|rest| rest.split_once('}'))
+                        .map(|(hex, _)| hex)
+                        .ok_or("expected \\u{...}")?;
+                    let escaped = u32::from_str_radix(hex, 16)
+                        .ok()
+                        .and_then(char::from_u32)
+                        .ok_or_else(|| format!("\\u{{{}}} is not a char", hex))?;
+                    value.push(escaped);
+                    // Skip past `{hex}`
+                    for _ in 0..hex.len() + 2 {
+                        chars.next();
+                    }
+                }
+                Some(e) => return Err(format!("unknown escape '\\{}'", e)),
+                None => break,
+            },
+            c => value.push(c),
+        }
+    }
+    Err("unterminated quote".to_string())
+}
+
+fn number<T: std::str::FromStr>(word: Option<&str>, what: &str) -> Result<T, String> {
+    let word = word.ok_or_else(|| format!("missing {}", what))?;
+    word.parse()
+        .map_err(|_| format!("'{}' is not a valid {}", word, what))
+}
+
+fn decode(line: &str) -> Result<Entry, String> {
+    let (at, rest) = line.split_once(' ').unwrap_or((line, ""));
+    let at = number(Some(at), "event count")?;
+    let (name, args) = rest.split_once(' ').unwrap_or((rest, ""));
+    let kind = Kind::from_name(name).ok_or_else(|| format!("unknown event '{}'", name))?;
+    let (event, trailing) = match kind {
+        Kind::PageLoad => (WebEvent::PageLoad, args),
+        Kind::PageUnload => (WebEvent::PageUnload, args),
+        Kind::KeyPress => {
+            let (text, rest) = unquote(args)?;
+            let mut chars = text.chars();
+            match (chars.next(), chars.next()) {
+                (Some(c), None) => (WebEvent::KeyPress(c), rest),
+                _ => return Err(format!("key_press needs one char, found '{}'", text)),
+            }
+        }
+        Kind::Paste => {
+            let (text, rest) = unquote(args)?;
+            (WebEvent::Paste(text), rest)
+        }
+        Kind::Click => {
+            let mut words = args.split(' ');
+            let x = number(words.next(), "x coordinate")?;
+            let y = number(words.next(), "y coordinate")?;
+            let rest = words.next().unwrap_or("");
+            (WebEvent::Click { x, y }, rest)
+        }
+    };
+    if !trailing.trim().is_empty() {
+        return Err(format!("unexpected '{}' after the event", trailing.trim()));
+    }
+    Ok(Entry { at, event })
+}
+
+impl Log {
+    pub fn new() -> Log {
+        Log::default()
+    }
+
+    // Counts must not go down: nothing can be emitted before an earlier event.
+    // Only the recording Dispatcher pushes, so a Log is either recorded or
+    // parsed, and `parse` reports out-of-order lines as errors
+    pub(crate) fn push(&mut self, at: usize, event: WebEvent) {
+        assert!(
+            self.entries.last().is_none_or(|last| last.at <= at),
+            "event at {} pushed after one at {}",
+            at,
+            self.entries.last().map_or(0, |last| last.at)
+        );
+        self.entries.push(Entry { at, event });
+    }
+
+    pub fn entries(&self) -> &[Entry] {
+        &self.entries
+    }
+
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    pub fn to_text(&self) -> String {
+        let mut text = format!("{}\n", HEADER);
+        for entry in &self.entries {
+            text.push_str(&encode(entry));
+            text.push('\n');
+        }
+        text
+    }
+
+    pub fn parse(text: &str) -> Result<Log, LogError> {
+        let mut log = Log::new();
+        for (index, line) in text.lines().enumerate() {
+            let line = line.trim_end_matches('\r');
+            if line.trim().is_empty() || line.starts_with('#') {
+                continue;
+            }
+            let entry = decode(line).map_err(|message| LogError::Parse {
+                line: index + 1,
+                message,
+            })?;
+            if let Some(previous) = log.entries.last()
+                && entry.at < previous.at
+            {
+                return Err(LogError::OutOfOrder {
+                    line: index + 1,
+                    at: entry.at,
+                    previous: previous.at,
+                });
+            }
+            log.entries.push(entry);
+        }
+        Ok(log)
+    }
+
+    pub fn write_to(&self, writer: &mut impl Write) -> io::Result<()> {
+        writer.write_all(self.to_text().as_bytes())
+    }
+
+    pub fn save(&self, path: &Path) -> io::Result<()> {
+        fs::write(path, self.to_text())
+    }
+
+    pub fn load(path: &Path) -> Result<Log, LogError> {
+        Log::parse(&fs::read_to_string(path)?)
+    }
+}
+
+// Feeds a log back through `dispatcher`'s handlers, dispatching between
+// entries exactly as much as happened while recording, then runs whatever is
+// left. Returns how many events were dispatched
+pub fn replay(log: &Log, dispatcher: &mut Dispatcher) -> Result<usize, LogError> {
+    let start = dispatcher.dispatched();
+    for (index, entry) in log.entries().iter().enumerate() {
+        while dispatcher.dispatched() - start < entry.at {
+            if dispatcher.dispatch_next().is_none() {
+                return Err(LogError::Diverged {
+                    entry: index + 1,
+                    at: entry.at,
+                    dispatched: dispatcher.dispatched() - start,
+                });
+            }
+        }
+        dispatcher.emit(entry.event.clone());
+    }
+    dispatcher.run();
+    Ok(dispatcher.dispatched() - start)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::events::Queue;
+    use std::cell::RefCell;
+    use std::rc::Rc;
+
+    // A text field: clicks focus it, keys type into it when focused, a paste
+    // is typed out key by key, and unloading the page clears it
+    fn text_field(events: &mut Dispatcher) -> Rc<RefCell<(bool, String)>> {
+        let state = Rc::new(RefCell::new((false, String::new())));
+        let focus = Rc::clone(&state);
+        events.on(Kind::Click, move |event, _| {
+            if let WebEvent::Click { x, y } = event {
+                focus.borrow_mut().0 = (0..100).contains(x) && (0..20).contains(y);
+            }
+        });
+        let typed = Rc::clone(&state);
+        events.on(Kind::KeyPress, move |event, _| {
+            let mut field = typed.borrow_mut();
+            if let (true, WebEvent::KeyPress(c)) = (field.0, event) {
+                field.1.push(*c);
+            }
+        });
+        events.on(Kind::Paste, |event, queue: &mut Queue| {
+            if let WebEvent::Paste(text) = event {
+                text.chars().for_each(|c| queue.push(WebEvent::KeyPress(c)));
+            }
+        });
+        let cleared = Rc::clone(&state);
+        events.on(Kind::PageUnload, move |_, _| cleared.borrow_mut().1.clear());
+        state
+    }
+
+    fn session(events: &mut Dispatcher) {
+        events.emit(WebEvent::PageLoad);
+        events.emit(WebEvent::KeyPress('x'));
+        events.emit(WebEvent::Click { x: 10, y: 5 });
+        events.emit(WebEvent::Paste("hi".to_string()));
+        // Typed before the paste's keys come through
+        events.emit(WebEvent::KeyPress('!'));
+        events.run();
+        events.emit(WebEvent::Paste(" \"ok\"\n\u{1F980}".to_string()));
+        events.dispatch_next();
+        events.emit(WebEvent::Click { x: -3, y: 500 });
+        events.run();
+        events.emit(WebEvent::KeyPress('\''));
+        events.run();
+    }
+
+    #[test]
+    fn replay_reproduces_the_recorded_session() {
+        let mut live = Dispatcher::new();
+        let live_state = text_field(&mut live);
+        live.record();
+        session(&mut live);
+        let log = live.take_log().unwrap();
+        assert_eq!(log.len(), 8);
+        assert_eq!(live_state.borrow().1, "!hi \"ok\"\n\u{1F980}");
+
+        let text = log.to_text();
+        assert!(text.starts_with("# roadmap web events\n0 page_load\n0 key_press 'x'\n"));
+        assert!(text.contains("\n7 paste \" \\\"ok\\\"\\n🦀\"\n8 click -3 500\n"));
+        let loaded = Log::parse(&text).unwrap();
+        assert_eq!(loaded, log);
+
+        let mut replayed = Dispatcher::new();
+        let replayed_state = text_field(&mut replayed);
+        assert_eq!(replay(&loaded, &mut replayed).unwrap(), live.dispatched());
+        assert_eq!(*replayed_state.borrow(), *live_state.borrow());
+    }
+
+    #[test]
+    fn save_and_load_round_trip_through_a_file() {
+        let mut log = Log::new();
+        log.push(0, WebEvent::PageLoad);
+        log.push(
+            1,
+            WebEvent::Paste("two\nlines \"quoted\" \u{1F980}".to_string()),
+        );
+        log.push(1, WebEvent::KeyPress('\\'));
+        log.push(4, WebEvent::Click { x: -1, y: 2 });
+        log.push(4, WebEvent::PageUnload);
+
+        let path = std::env::temp_dir().join(format!("roadmap-events-{}.log", std::process::id()));
+        log.save(&path).unwrap();
+        let loaded = Log::load(&path);
+        fs::remove_file(&path).unwrap();
+        assert_eq!(loaded.unwrap(), log);
+
+        let missing = Log::load(&path).unwrap_err();
+        assert!(matches!(missing, LogError::Io(_)), "{}", missing);
+    }
+
+    #[test]
+    #[should_panic(expected = "event at 1 pushed after one at 2")]
+    fn push_rejects_a_count_that_goes_down() {
+        let mut log = Log::new();
+        log.push(2, WebEvent::PageLoad);
+        log.push(1, WebEvent::PageUnload);
+    }
+
+    #[test]
+    fn reports_bad_lines() {
+        let error = |text: &str| Log::parse(text).unwrap_err().to_string();
+        assert_eq!(error("0 scroll 3"), "line 1: unknown event 'scroll'");
+        assert_eq!(error("# header\n0 click 1"), "line 2: missing y coordinate");
+        assert_eq!(
+            error("0 key_press 'ab'"),
+            "line 1: key_press needs one char, found 'ab'"
+        );
+        assert_eq!(error("0 paste \"open"), "line 1: unterminated quote");
+        assert_eq!(
+            error("x page_load"),
+            "line 1: 'x' is not a valid event count"
+        );
+        assert_eq!(
+            error("0 page_load extra"),
+            "line 1: unexpected 'extra' after the event"
+        );
+        assert_eq!(
+            error("3 page_load\n1 page_unload"),
+            "line 2: event at 1 comes after one at 3"
+        );
+        let escaped = Log::parse("0 key_press '\\u{e9}'").unwrap();
+        assert_eq!(escaped.entries()[0].event, WebEvent::KeyPress('é'));
+    }
+
+    #[test]
+    fn replay_through_other_handlers_can_diverge() {
+        // Recorded with the paste typed out, replayed without that handler
+        let log = Log::parse("0 paste \"ab\"\n3 page_unload").unwrap();
+        let mut events = Dispatcher::new();
+        let err = replay(&log, &mut events).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "entry 2 was emitted after 3 dispatched events, but replay ran out of events after 1"
+        );
+    }
+}
@@ -0,0 +1,264 @@
+// Events
+// The destructuring lesson's `WebEvent` enum driving a small event loop.
+// Handlers are registered per `Kind` of event; `emit` queues an event and
+// `run` dispatches the queue in order, handing every event to each handler
+// for its kind in the order they were registered:
+//
+//   let mut events = Dispatcher::new();
+//   events.on(Kind::Click, |event, _| println!("{:?}", event));
+//   events.emit(WebEvent::Click { x: 20, y: 80 });
+//   events.run();   // 1 event dispatched
+//
+// Handlers may queue follow-up events through the `Queue` they are given;
+// those run after everything already queued. While recording, every event
+// passed to `emit` is also appended to a `Log`, which `log::replay` feeds
+// back in at the same points. Follow-ups are not recorded: replaying the
+// input through the same handlers produces them again.
+
+pub mod log;
+
+use std::collections::{HashMap, VecDeque};
+use std::fmt;
+
+pub use log::{Log, LogError};
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum WebEvent {
+    PageLoad,
+    PageUnload,
+    KeyPress(char),
+    Paste(String),
+    Click { x: i64, y: i64 },
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Kind {
+    PageLoad,
+    PageUnload,
+    KeyPress,
+    Paste,
+    Click,
+}
+
+impl Kind {
+    pub const ALL: [Kind; 5] = [
+        Kind::PageLoad,
+        Kind::PageUnload,
+        Kind::KeyPress,
+        Kind::Paste,
+        Kind::Click,
+    ];
+
+    // As written in a log
+    pub fn name(self) -> &'static str {
+        match self {
+            Kind::PageLoad => "page_load",
+            Kind::PageUnload => "page_unload",
+            Kind::KeyPress => "key_press",
+            Kind::Paste => "paste",
+            Kind::Click => "click",
+        }
+    }
+
+    pub fn from_name(name: &str) -> Option<Kind> {
+        Kind::ALL.into_iter().find(|kind| kind.name() == name)
+    }
+}
+
+impl fmt::Display for Kind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.name())
+    }
+}
+
+impl WebEvent {
+    pub fn kind(&self) -> Kind {
+        match self {
+            WebEvent::PageLoad => Kind::PageLoad,
+            WebEvent::PageUnload => Kind::PageUnload,
+            WebEvent::KeyPress(_) => Kind::KeyPress,
+            WebEvent::Paste(_) => Kind::Paste,
+            WebEvent::Click { .. } => Kind::Click,
+        }
+    }
+}
+
+// Events waiting to be dispatched, first in first out
+#[derive(Debug, Default)]
+pub struct Queue {
+    events: VecDeque<WebEvent>,
+}
+
+impl Queue {
+    pub fn push(&mut self, event: WebEvent) {
+        self.events.push_back(event);
+    }
+
+    pub fn len(&self) -> usize {
+        self.events.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.events.is_empty()
+    }
+}
+
+pub type Handler = Box<dyn FnMut(&WebEvent, &mut Queue)>;
+
+#[derive(Default)]
+pub struct Dispatcher {
+    handlers: HashMap<Kind, Vec<Handler>>,
+    queue: Queue,
+    // The log and how many events had been dispatched when it was started
+    log: Option<(Log, usize)>,
+    dispatched: usize,
+}
+
+impl Dispatcher {
+    pub fn new() -> Dispatcher {
+        Dispatcher::default()
+    }
+
+    pub fn on(&mut self, kind: Kind, handler: impl FnMut(&WebEvent, &mut Queue) + 'static) {
+        self.handlers
+            .entry(kind)
+            .or_default()
+            .push(Box::new(handler));
+    }
+
+    pub fn handler_count(&self, kind: Kind) -> usize {
+        self.handlers.get(&kind).map_or(0, Vec::len)
+    }
+
+    // Queues an event from outside, recording it if a log is being kept
+    pub fn emit(&mut self, event: WebEvent) {
+        if let Some((log, start)) = &mut self.log {
+            log.push(self.dispatched - *start, event.clone());
+        }
+        self.queue.push(event);
+    }
+
+    pub fn pending(&self) -> usize {
+        self.queue.len()
+    }
+
+    // Dispatches the oldest queued event; None when the queue is empty. An
+    // event with no handlers is simply dropped
+    pub fn dispatch_next(&mut self) -> Option<WebEvent> {
+        let event = self.queue.events.pop_front()?;
+        if let Some(handlers) = self.handlers.get_mut(&event.kind()) {
+            for handler in handlers {
+                handler(&event, &mut self.queue);
+            }
+        }
+        self.dispatched += 1;
+        Some(event)
+    }
+
+    // Dispatches until the queue is empty, follow-ups included; returns how
+    // many events were dispatched
+    pub fn run(&mut self) -> usize {
+        let mut count = 0;
+        while self.dispatch_next().is_some() {
+            count += 1;
+        }
+        count
+    }
+
+    // Every event dispatched since the dispatcher was made
+    pub fn dispatched(&self) -> usize {
+        self.dispatched
+    }
+
+    // Starts a fresh log of emitted events, replacing any current one. Start
+    // with an empty queue: events already waiting are not in the log
+    pub fn record(&mut self) {
+        self.log = Some((Log::new(), self.dispatched));
+    }
+
+    pub fn is_recording(&self) -> bool {
+        self.log.is_some()
+    }
+
+    // Stops recording and hands over what was recorded
+    pub fn take_log(&mut self) -> Option<Log> {
+        self.log.take().map(|(log, _)| log)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::cell::RefCell;
+    use std::rc::Rc;
+
+    #[test]
+    fn dispatches_in_queue_and_registration_order() {
+        let seen = Rc::new(RefCell::new(Vec::new()));
+        let mut events = Dispatcher::new();
+        for name in ["first", "second"] {
+            let seen = Rc::clone(&seen);
+            events.on(Kind::Click, move |event, _| {
+                if let WebEvent::Click { x, y } = event {
+                    seen.borrow_mut().push(format!("{} ({}, {})", name, x, y));
+                }
+            });
+        }
+        let keys = Rc::clone(&seen);
+        events.on(Kind::KeyPress, move |event, _| {
+            if let WebEvent::KeyPress(c) = event {
+                keys.borrow_mut().push(format!("key {}", c));
+            }
+        });
+
+        events.emit(WebEvent::Click { x: 1, y: 2 });
+        events.emit(WebEvent::KeyPress('q'));
+        events.emit(WebEvent::PageLoad);
+        assert_eq!(events.pending(), 3);
+        assert_eq!(events.run(), 3);
+        assert_eq!(
+            *seen.borrow(),
+            vec!["first (1, 2)", "second (1, 2)", "key q"]
+        );
+        assert_eq!((events.pending(), events.dispatched()), (0, 3));
+        assert_eq!(events.handler_count(Kind::Click), 2);
+        assert_eq!(events.handler_count(Kind::Paste), 0);
+    }
+
+    #[test]
+    fn handlers_queue_follow_ups_behind_what_is_waiting() {
+        let order = Rc::new(RefCell::new(String::new()));
+        let mut events = Dispatcher::new();
+        // A paste is typed out one key at a time
+        events.on(Kind::Paste, |event, queue| {
+            if let WebEvent::Paste(text) = event {
+                for c in text.chars() {
+                    queue.push(WebEvent::KeyPress(c));
+                }
+            }
+        });
+        let typed = Rc::clone(&order);
+        events.on(Kind::KeyPress, move |event, _| {
+            if let WebEvent::KeyPress(c) = event {
+                typed.borrow_mut().push(*c);
+            }
+        });
+        let clicks = Rc::clone(&order);
+        events.on(Kind::Click, move |_, _| clicks.borrow_mut().push('*'));
+
+        events.emit(WebEvent::Paste("hi".to_string()));
+        events.emit(WebEvent::Click { x: 0, y: 0 });
+        assert_eq!(events.run(), 4);
+        assert_eq!(*order.borrow(), "*hi");
+        assert_eq!(events.dispatch_next(), None);
+    }
+
+    #[test]
+    fn kinds_have_log_names() {
+        for kind in Kind::ALL {
+            assert_eq!(Kind::from_name(kind.name()), Some(kind));
+        }
+        assert_eq!(WebEvent::Click { x: 0, y: 0 }.kind(), Kind::Click);
+        assert_eq!(Kind::from_name("scroll"), None);
+    }
+}
@@ -0,0 +1,251 @@
+// Exercises
+// Each exercise is a folder under exercises/<topic>/<name>/:
+//
+//   stub.rs      what the learner starts from; its header names the lesson
+//                and describes the task
+//   tests.rs     hidden tests, compiled together with the learner's attempt
+//   solution.rs  a reference answer, only used to test the tests
+//   hints.md     hints, one paragraph each, revealed one at a time
+//
+// `roadmap exercise start` copies the stub into the learner's workspace and
+// `roadmap exercise check` runs the hidden tests against it (see runner.rs).
+
+mod runner;
+
+use std::env;
+use std::fmt;
+use std::fs;
+use std::io;
+use std::path::PathBuf;
+
+use crate::progress;
+use crate::registry::{self, Entry, LookupError};
+use crate::sandbox::BuildError;
+
+pub use runner::check;
+
+pub const EXERCISE_DIR: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/exercises");
+
+pub struct Exercise {
+    pub name: String,
+    pub entry: Entry,
+    pub description: String,
+    dir: PathBuf,
+}
+
+#[derive(Debug)]
+pub enum ExerciseError {
+    Io(io::Error),
+    UnknownExercise(String),
+    BadHeader { exercise: String, message: String },
+    Lookup { exercise: String, err: LookupError },
+    NotStarted(PathBuf),
+    Compile(String),
+}
+
+impl fmt::Display for ExerciseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ExerciseError::Io(err) => write!(f, "{}", err),
+            ExerciseError::UnknownExercise(name) => write!(f, "unknown exercise '{}'", name),
+            ExerciseError::BadHeader { exercise, message } => {
+                write!(f, "exercise {}: {}", exercise, message)
+            }
+            ExerciseError::Lookup { exercise, err } => write!(f, "exercise {}: {}", exercise, err),
+            ExerciseError::NotStarted(path) => write!(
+                f,
+                "{} does not exist yet, run `roadmap exercise start` first",
+                path.display()
+            ),
+            ExerciseError::Compile(errors) => write!(f, "your code does not compile:\n{}", errors),
+        }
+    }
+}
+
+impl From<io::Error> for ExerciseError {
+    fn from(err: io::Error) -> Self {
+        ExerciseError::Io(err)
+    }
+}
+
+impl From<BuildError> for ExerciseError {
+    fn from(err: BuildError) -> Self {
+        match err {
+            BuildError::Io(err) => ExerciseError::Io(err),
+            BuildError::Compile(errors) => ExerciseError::Compile(errors),
+        }
+    }
+}
+
+impl Exercise {
+    pub fn stub(&self) -> io::Result<String> {
+        fs::read_to_string(self.dir.join("stub.rs"))
+    }
+
+    pub fn tests(&self) -> io::Result<String> {
+        fs::read_to_string(self.dir.join("tests.rs"))
+    }
+
+    #[cfg(test)]
+    pub fn solution(&self) -> io::Result<String> {
+        fs::read_to_string(self.dir.join("solution.rs"))
+    }
+
+    pub fn hints(&self) -> io::Result<Vec<String>> {
+        let text = fs::read_to_string(self.dir.join("hints.md"))?;
+        Ok(text
+            .split("\n\n")
+            .map(|hint| hint.trim().to_string())
+            .filter(|hint| !hint.is_empty())
+            .collect())
+    }
+
+    // Where the learner's attempt lives
+    pub fn attempt_path(&self) -> PathBuf {
+        workspace_dir().join(format!("{}.rs", self.name))
+    }
+
+    // How many hints have been shown so far is kept next to the attempt
+    fn hints_seen_path(&self) -> PathBuf {
+        workspace_dir().join(format!("{}.hints", self.name))
+    }
+
+    // The next unseen hint with its number, or None when all have been shown
+    pub fn next_hint(&self) -> io::Result<Option<(usize, usize, String)>> {
+        let hints = self.hints()?;
+        let path = self.hints_seen_path();
+        let seen: usize = fs::read_to_string(&path)
+            .ok()
+            .and_then(|count| count.trim().parse().ok())
+            .unwrap_or(0);
+        let Some(hint) = hints.get(seen) else {
+            return Ok(None);
+        };
+        fs::create_dir_all(workspace_dir())?;
+        fs::write(&path, (seen + 1).to_string())?;
+        Ok(Some((seen + 1, hints.len(), hint.clone())))
+    }
+}
+
+// $ROADMAP_EXERCISES, or an exercises/ folder next to the progress file
+pub fn workspace_dir() -> PathBuf {
+    if let Some(dir) = env::var_os("ROADMAP_EXERCISES") {
+        return PathBuf::from(dir);
+    }
+    let progress = progress::default_path();
+    progress
+        .parent()
+        .map(|dir| dir.join("exercises"))
+        .unwrap_or_else(|| PathBuf::from("exercises"))
+}
+
+// Every exercise, in registry order of the lessons they belong to
+pub fn all() -> Result<Vec<Exercise>, ExerciseError> {
+    let mut exercises = Vec::new();
+    for topic in fs::read_dir(EXERCISE_DIR)? {
+        let topic = topic?.path();
+        if !topic.is_dir() {
+            continue;
+        }
+        for dir in fs::read_dir(&topic)? {
+            let dir = dir?.path();
+            if dir.join("stub.rs").is_file() {
+                exercises.push(load(dir)?);
+            }
+        }
+    }
+
+    let position = |exercise: &Exercise| {
+        registry::entries()
+            .position(|entry| entry.path() == exercise.entry.path())
+            .unwrap_or(usize::MAX)
+    };
+    exercises.sort_by(|a, b| position(a).cmp(&position(b)).then(a.name.cmp(&b.name)));
+    Ok(exercises)
+}
+
+pub fn find(name: &str) -> Result<Exercise, ExerciseError> {
+    all()?
+        .into_iter()
+        .find(|exercise| exercise.name == name)
+        .ok_or_else(|| ExerciseError::UnknownExercise(name.to_string()))
+}
+
+// Copies the stub into the workspace, leaving an existing attempt untouched
+pub fn start(exercise: &Exercise) -> Result<(PathBuf, bool), ExerciseError> {
+    let path = exercise.attempt_path();
+    if path.exists() {
+        return Ok((path, false));
+    }
+    fs::create_dir_all(workspace_dir())?;
+    fs::write(&path, exercise.stub()?)?;
+    Ok((path, true))
+}
+
+fn load(dir: PathBuf) -> Result<Exercise, ExerciseError> {
+    let name = dir
+        .file_name()
+        .map(|name| name.to_string_lossy().into_owned())
+        .unwrap_or_default();
+    let topic = dir
+        .parent()
+        .and_then(|topic| topic.file_name())
+        .map(|topic| topic.to_string_lossy().into_owned())
+        .unwrap_or_default();
+    let stub = fs::read_to_string(dir.join("stub.rs"))?;
+
+    let mut lesson = None;
+    let mut description = Vec::new();
+    for line in stub.lines() {
+        let Some(comment) = line.strip_prefix("//") else {
+            break;
+        };
+        match comment.trim().strip_prefix("lesson:") {
+            Some(id) => lesson = Some(id.trim().to_string()),
+            None => description.push(comment.trim()),
+        }
+    }
+    let Some(lesson) = lesson else {
+        return Err(ExerciseError::BadHeader {
+            exercise: name,
+            message: "stub.rs has no '// lesson:' header".to_string(),
+        });
+    };
+    let entry =
+        registry::find(&format!("{}/{}", topic, lesson)).map_err(|err| ExerciseError::Lookup {
+            exercise: name.clone(),
+            err,
+        })?;
+
+    Ok(Exercise {
+        name,
+        entry,
+        description: description.join(" "),
+        dir,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn every_exercise_loads_with_its_hints() {
+        let exercises = all().unwrap();
+        let names: Vec<&str> = exercises.iter().map(|e| e.name.as_str()).collect();
+        assert_eq!(
+            names,
+            vec![
+                "divide_with_remainder",
+                "plus_one",
+                "initials",
+                "first_word",
+                "can_hold"
+            ]
+        );
+        for exercise in &exercises {
+            assert!(!exercise.description.is_empty(), "{}", exercise.name);
+            assert!(exercise.hints().unwrap().len() >= 2, "{}", exercise.name);
+        }
+    }
+}
@@ -0,0 +1,225 @@
+// Hidden Test Runner
+// Appends the exercise's tests.rs to the learner's code as a `hidden_tests`
+// module, builds it with `rustc --test` in a sandbox scratch directory and
+// runs the resulting binary. A test binary that runs for too long (an
+// accidental infinite loop) is killed.
+
+use std::io::Read;
+use std::process::{Command, Stdio};
+use std::thread;
+use std::time::{Duration, Instant};
+
+use super::{Exercise, ExerciseError};
+use crate::sandbox;
+
+const TIME_LIMIT: Duration = Duration::from_secs(10);
+
+#[derive(Debug, PartialEq)]
+pub struct TestResult {
+    pub name: String,
+    pub passed: bool,
+    // The panic message of a failing test
+    pub message: Option<String>,
+}
+
+pub fn check(exercise: &Exercise, attempt: &str) -> Result<Vec<TestResult>, ExerciseError> {
+    let source = format!(
+        "{}\n#[cfg(test)]\nmod hidden_tests {{\n    use super::*;\n\n{}}}\n",
+        attempt,
+        exercise.tests()?
+    );
+    sandbox::in_scratch_dir(|dir| {
+        let flags = [
+            "--test",
+            "--error-format=short",
+            "-A",
+            "dead_code",
+            "-A",
+            "unused_variables",
+        ];
+        let binary = sandbox::compile(dir, &exercise.name, &source, &flags)?;
+        run_tests(&mut Command::new(binary))
+    })
+}
+
+fn run_tests(command: &mut Command) -> Result<Vec<TestResult>, ExerciseError> {
+    let mut child = command
+        .arg("--test-threads=1")
+        .env("RUST_BACKTRACE", "0")
+        .stdout(Stdio::piped())
+        .stderr(Stdio::null())
+        .spawn()?;
+    // Read while the tests run: a full pipe would block the binary and look
+    // like a loop that never ends
+    let mut stdout = child.stdout.take().expect("stdout is piped");
+    let reader = thread::spawn(move || {
+        let mut output = Vec::new();
+        stdout.read_to_end(&mut output).map(|_| output)
+    });
+
+    let started = Instant::now();
+    let mut timed_out = false;
+    while child.try_wait()?.is_none() {
+        if started.elapsed() > TIME_LIMIT {
+            child.kill()?;
+            timed_out = true;
+            break;
+        }
+        thread::sleep(Duration::from_millis(20));
+    }
+    child.wait()?;
+    let output = reader.join().expect("stdout reader panicked")?;
+    let mut results = parse_results(&String::from_utf8_lossy(&output));
+    if timed_out {
+        // The test that was running when the binary was killed never reported
+        results.push(TestResult {
+            name: "(time limit)".to_string(),
+            passed: false,
+            message: Some(format!(
+                "stopped after {}s, is there a loop that never ends?",
+                TIME_LIMIT.as_secs()
+            )),
+        });
+    }
+    Ok(results)
+}
+
+// Reads libtest's default output:
+//
+//   test hidden_tests::adds_one ... FAILED
+//   ...
+//   ---- hidden_tests::adds_one stdout ----
+//   thread 'hidden_tests::adds_one' panicked at plus_one.rs:5:5:
+//   not yet implemented
+fn parse_results(stdout: &str) -> Vec<TestResult> {
+    let mut results: Vec<TestResult> = stdout
+        .lines()
+        .filter_map(|line| {
+            let rest = line.strip_prefix("test ")?;
+            let (name, status) = rest.split_once(" ... ")?;
+            Some(TestResult {
+                name: short_name(name).to_string(),
+                passed: status == "ok",
+                message: None,
+            })
+        })
+        .collect();
+
+    let mut lines = stdout.lines().peekable();
+    while let Some(line) = lines.next() {
+        let Some(name) = line
+            .strip_prefix("---- ")
+            .and_then(|rest| rest.strip_suffix(" stdout ----"))
+        else {
+            continue;
+        };
+        let mut message = Vec::new();
+        while let Some(next) = lines.peek() {
+            if next.starts_with("---- ") || *next == "failures:" {
+                break;
+            }
+            let skip =
+                next.trim().is_empty() || next.starts_with("thread '") || next.starts_with("note:");
+            if !skip {
+                message.push(next.trim());
+            }
+            lines.next();
+        }
+        if let Some(result) = results.iter_mut().find(|r| r.name == short_name(name)) {
+            result.message = Some(message.join("\n"));
+        }
+    }
+    results
+}
+
+fn short_name(name: &str) -> &str {
+    name.strip_prefix("hidden_tests::").unwrap_or(name)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::all;
+    use super::*;
+
+    #[test]
+    fn parses_passing_and_failing_tests() {
+        let stdout = "\
+running 2 tests
+test hidden_tests::adds_one ... FAILED
+test hidden_tests::works_at_zero ... ok
+
+failures:
+
+---- hidden_tests::adds_one stdout ----
+
+thread 'hidden_tests::adds_one' panicked at plus_one.rs:8:5:
+assertion `left == right` failed
+  left: 4
+ right: 6
+note: run with `RUST_BACKTRACE=1` environment variable to display a backtrace
+
+
+failures:
+    hidden_tests::adds_one
+";
+        assert_eq!(
+            parse_results(stdout),
+            vec![
+                TestResult {
+                    name: "adds_one".to_string(),
+                    passed: false,
+                    message: Some(
+                        "assertion `left == right` failed\nleft: 4\nright: 6".to_string()
+                    ),
+                },
+                TestResult {
+                    name: "works_at_zero".to_string(),
+                    passed: true,
+                    message: None,
+                },
+            ]
+        );
+    }
+
+    // More output than a pipe holds must not stall the binary until the time
+    // limit
+    #[test]
+    fn reads_large_output_while_the_tests_run() {
+        let source = "#[test]\nfn noisy() {\n    for line in 0..100_000 {\n        println!(\"line {}\", line);\n    }\n    panic!(\"done\");\n}\n";
+        let started = Instant::now();
+        let results = sandbox::in_scratch_dir(|dir| {
+            let binary = sandbox::compile(dir, "noisy", source, &["--test"])?;
+            run_tests(&mut Command::new(binary))
+        })
+        .unwrap();
+        assert!(started.elapsed() < TIME_LIMIT);
+        assert_eq!(results.len(), 1);
+        assert_eq!(results[0].name, "noisy");
+        assert!(!results[0].passed);
+        assert!(results[0].message.as_ref().unwrap().ends_with("done"));
+    }
+
+    // The reference solutions pass every hidden test and the untouched stubs
+    // compile but pass none
+    #[test]
+    fn solutions_pass_and_stubs_fail() {
+        for exercise in all().unwrap() {
+            let solved = check(&exercise, &exercise.solution().unwrap()).unwrap();
+            assert!(!solved.is_empty(), "{}", exercise.name);
+            assert!(
+                solved.iter().all(|test| test.passed),
+                "{}: {:?}",
+                exercise.name,
+                solved
+            );
+
+            let stub = check(&exercise, &exercise.stub().unwrap()).unwrap();
+            assert!(
+                stub.iter().all(|test| !test.passed),
+                "{}: {:?}",
+                exercise.name,
+                stub
+            );
+        }
+    }
+}
@@ -0,0 +1,430 @@
+// Float Inspector
+// Takes an f32 or f64 apart into its IEEE-754 fields (sign, biased exponent,
+// mantissa), classifies it, prints the exact decimal value the bits stand for,
+// and measures distances in ULPs (units in the last place: how many
+// representable floats lie between two values). Comparisons come in three
+// modes, because a fixed absolute epsilon is only right near 1.0:
+//
+//   approx_eq(a, b, Tolerance::Absolute(1e-9))   |a - b| <= 1e-9
+//   approx_eq(a, b, Tolerance::Relative(1e-9))   scaled by the larger magnitude
+//   approx_eq(a, b, Tolerance::Ulps(4))          at most 4 floats apart
+//
+// `roadmap float <value>` prints the whole breakdown.
+
+use std::fmt;
+use std::str::FromStr;
+
+pub trait Float: Copy + PartialEq + fmt::Debug + fmt::Display + FromStr {
+    const NAME: &'static str;
+    const EXPONENT_BITS: u32;
+    const MANTISSA_BITS: u32;
+
+    // The raw bits, zero-extended to 64
+    fn to_raw(self) -> u64;
+    fn from_raw(raw: u64) -> Self;
+    fn to_f64(self) -> f64;
+}
+
+macro_rules! float_impl {
+    ($($ty:ident: $uint:ident, $exponent:expr, $mantissa:expr);*) => {$(
+        impl Float for $ty {
+            const NAME: &'static str = stringify!($ty);
+            const EXPONENT_BITS: u32 = $exponent;
+            const MANTISSA_BITS: u32 = $mantissa;
+
+            fn to_raw(self) -> u64 {
+                self.to_bits() as u64
+            }
+
+            fn from_raw(raw: u64) -> $ty {
+                $ty::from_bits(raw as $uint)
+            }
+
+            fn to_f64(self) -> f64 {
+                self as f64
+            }
+        }
+    )*};
+}
+
+float_impl!(f32: u32, 8, 23; f64: u64, 11, 52);
+
+#[derive(Debug, PartialEq)]
+pub enum Class {
+    Zero,
+    // Below the smallest normal number: no implicit leading 1, less precision
+    Subnormal,
+    Normal,
+    Infinite,
+    // The top mantissa bit says quiet or signalling, the rest is a payload
+    Nan { quiet: bool, payload: u64 },
+}
+
+impl fmt::Display for Class {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Class::Zero => write!(f, "zero"),
+            Class::Subnormal => write!(f, "subnormal"),
+            Class::Normal => write!(f, "normal"),
+            Class::Infinite => write!(f, "infinite"),
+            Class::Nan { quiet, payload } => write!(
+                f,
+                "{} NaN, payload {:#x}",
+                if *quiet { "quiet" } else { "signalling" },
+                payload
+            ),
+        }
+    }
+}
+
+#[derive(Debug, PartialEq)]
+pub struct Parts {
+    pub negative: bool,
+    // As stored, with the bias added
+    pub biased_exponent: u64,
+    pub mantissa: u64,
+    pub class: Class,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Tolerance {
+    Absolute(f64),
+    Relative(f64),
+    Ulps(u64),
+}
+
+fn bias<F: Float>() -> i64 {
+    (1 << (F::EXPONENT_BITS - 1)) - 1
+}
+
+fn sign_bit<F: Float>() -> u64 {
+    1 << (F::EXPONENT_BITS + F::MANTISSA_BITS)
+}
+
+pub fn decompose<F: Float>(x: F) -> Parts {
+    let raw = x.to_raw();
+    let mantissa = raw & ((1 << F::MANTISSA_BITS) - 1);
+    let biased_exponent = (raw >> F::MANTISSA_BITS) & ((1 << F::EXPONENT_BITS) - 1);
+    let all_ones = (1 << F::EXPONENT_BITS) - 1;
+    let quiet_bit = 1 << (F::MANTISSA_BITS - 1);
+    let class = match (biased_exponent, mantissa) {
+        (0, 0) => Class::Zero,
+        (0, _) => Class::Subnormal,
+        (e, 0) if e == all_ones => Class::Infinite,
+        (e, m) if e == all_ones => Class::Nan {
+            quiet: m & quiet_bit != 0,
+            payload: m & !quiet_bit,
+        },
+        _ => Class::Normal,
+    };
+    Parts {
+        negative: raw & sign_bit::<F>() != 0,
+        biased_exponent,
+        mantissa,
+        class,
+    }
+}
+
+// The power of two the mantissa is scaled by; None for infinities and NaN
+pub fn exponent<F: Float>(x: F) -> Option<i64> {
+    let parts = decompose(x);
+    match parts.class {
+        Class::Normal => Some(parts.biased_exponent as i64 - bias::<F>()),
+        // Subnormals (and zero) use the smallest exponent without the implicit 1
+        Class::Zero | Class::Subnormal => Some(1 - bias::<F>()),
+        Class::Infinite | Class::Nan { .. } => None,
+    }
+}
+
+// "0 01111111011 1001100110011001100110011001100110011001100110011010"
+pub fn bit_fields<F: Float>(x: F) -> String {
+    let parts = decompose(x);
+    format!(
+        "{} {:0e$b} {:0m$b}",
+        parts.negative as u8,
+        parts.biased_exponent,
+        parts.mantissa,
+        e = F::EXPONENT_BITS as usize,
+        m = F::MANTISSA_BITS as usize
+    )
+}
+
+// Every digit of the value the bits stand for: a binary fraction always has a
+// finite decimal expansion, and Rust prints it exactly when asked for enough
+// digits
+pub fn exact_decimal<F: Float>(x: F) -> String {
+    let Some(exponent) = exponent(x) else {
+        return x.to_string();
+    };
+    let digits = (F::MANTISSA_BITS as i64 - exponent).max(0) as usize;
+    let text = format!("{:.*}", digits, x.to_f64());
+    if text.contains('.') {
+        text.trim_end_matches('0').trim_end_matches('.').to_string()
+    } else {
+        text
+    }
+}
+
+// Maps floats onto integers in the same order, so neighbours differ by one
+// and +0.0 and -0.0 both map to 0
+fn ordered<F: Float>(x: F) -> i128 {
+    let raw = x.to_raw();
+    let magnitude = (raw & !sign_bit::<F>()) as i128;
+    if raw & sign_bit::<F>() != 0 {
+        -magnitude
+    } else {
+        magnitude
+    }
+}
+
+fn from_ordered<F: Float>(n: i128) -> F {
+    if n < 0 {
+        F::from_raw(n.unsigned_abs() as u64 | sign_bit::<F>())
+    } else {
+        F::from_raw(n as u64)
+    }
+}
+
+fn is_nan<F: Float>(x: F) -> bool {
+    matches!(decompose(x).class, Class::Nan { .. })
+}
+
+// How many representable floats apart two values are; None with a NaN
+pub fn ulp_distance<F: Float>(a: F, b: F) -> Option<u64> {
+    if is_nan(a) || is_nan(b) {
+        return None;
+    }
+    Some((ordered(a) - ordered(b)).unsigned_abs() as u64)
+}
+
+// The smallest float greater than x
+pub fn next_up<F: Float>(x: F) -> F {
+    let infinity = F::from_raw(((1 << F::EXPONENT_BITS) - 1) << F::MANTISSA_BITS);
+    if is_nan(x) || x == infinity {
+        return x;
+    }
+    // Stepping up from the smallest negative subnormal keeps the sign: -0.0
+    if ordered(x) == -1 {
+        return F::from_raw(sign_bit::<F>());
+    }
+    from_ordered(ordered(x) + 1)
+}
+
+// The largest float less than x
+pub fn next_down<F: Float>(x: F) -> F {
+    let negative_infinity =
+        F::from_raw((((1 << F::EXPONENT_BITS) - 1) << F::MANTISSA_BITS) | sign_bit::<F>());
+    if is_nan(x) || x == negative_infinity {
+        return x;
+    }
+    from_ordered(ordered(x) - 1)
+}
+
+// The gap between x and the next float away from zero. The largest finite
+// value has no finite float above it, so its gap is measured to the float
+// below instead (2^971 for f64). Infinities and NaN have no gap: NaN.
+pub fn ulp<F: Float>(x: F) -> f64 {
+    let magnitude = F::from_raw(x.to_raw() & !sign_bit::<F>());
+    if exponent(magnitude).is_none() {
+        return f64::NAN;
+    }
+    let above = next_up(magnitude);
+    if exponent(above).is_none() {
+        return magnitude.to_f64() - next_down(magnitude).to_f64();
+    }
+    above.to_f64() - magnitude.to_f64()
+}
+
+pub fn approx_eq<F: Float>(a: F, b: F, tolerance: Tolerance) -> bool {
+    if is_nan(a) || is_nan(b) {
+        return false;
+    }
+    // Also covers equal infinities, whose difference is NaN
+    if a == b {
+        return true;
+    }
+    // An infinity is never close to a finite value, however loose the tolerance
+    if exponent(a).is_none() || exponent(b).is_none() {
+        return false;
+    }
+    let (a64, b64) = (a.to_f64(), b.to_f64());
+    let difference = (a64 - b64).abs();
+    if !difference.is_finite() {
+        return false;
+    }
+    match tolerance {
+        Tolerance::Absolute(epsilon) => difference <= epsilon,
+        Tolerance::Relative(epsilon) => difference <= epsilon * a64.abs().max(b64.abs()),
+        Tolerance::Ulps(max) => ulp_distance(a, b).is_some_and(|distance| distance <= max),
+    }
+}
+
+// Everything the inspector knows about one value
+pub fn describe<F: Float>(x: F) -> Vec<String> {
+    let parts = decompose(x);
+    let mut lines = vec![
+        format!("{:?} ({})", x, F::NAME),
+        format!("  bits      {}", bit_fields(x)),
+        format!(
+            "  sign      {}",
+            if parts.negative {
+                "1 (negative)"
+            } else {
+                "0 (positive)"
+            }
+        ),
+    ];
+    match exponent(x) {
+        Some(exponent) if parts.class == Class::Normal => lines.push(format!(
+            "  exponent  {} - {} = {}",
+            parts.biased_exponent,
+            bias::<F>(),
+            exponent
+        )),
+        Some(exponent) => lines.push(format!(
+            "  exponent  0 (fixed at {}, no implicit leading 1)",
+            exponent
+        )),
+        None => lines.push(format!(
+            "  exponent  {} (all ones: infinity or NaN)",
+            parts.biased_exponent
+        )),
+    }
+    if let Some(exponent) = exponent(x) {
+        let leading = if parts.class == Class::Normal {
+            1.0
+        } else {
+            0.0
+        };
+        let significand = leading + parts.mantissa as f64 / (1u64 << F::MANTISSA_BITS) as f64;
+        lines.push(format!(
+            "  value     {}{} x 2^{}",
+            if parts.negative { "-" } else { "" },
+            significand,
+            exponent
+        ));
+        lines.push(format!("  exact     {}", exact_decimal(x)));
+        lines.push(format!("  ulp       {:e}", ulp(x)));
+        lines.push(format!(
+            "  next      {:?} below, {:?} above",
+            next_down(x),
+            next_up(x)
+        ));
+    }
+    lines.push(format!("  class     {}", parts.class));
+    lines
+}
+
+// `roadmap float <value> [--f32]`
+pub fn describe_text(text: &str, single: bool) -> Option<Vec<String>> {
+    if single {
+        text.parse::<f32>().ok().map(describe)
+    } else {
+        text.parse::<f64>().ok().map(describe)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn decomposes_normal_subnormal_and_special_values() {
+        assert_eq!(
+            bit_fields(0.1f64),
+            "0 01111111011 1001100110011001100110011001100110011001100110011010"
+        );
+        assert_eq!(exponent(0.1f64), Some(-4));
+        assert_eq!(bit_fields(-2.0f32), "1 10000000 00000000000000000000000");
+        assert_eq!(decompose(f64::MIN_POSITIVE / 2.0).class, Class::Subnormal);
+        assert_eq!(decompose(-0.0f32).class, Class::Zero);
+        assert!(decompose(-0.0f32).negative);
+        assert_eq!(decompose(f32::INFINITY).class, Class::Infinite);
+
+        let payload_nan = f64::from_bits(0x7ff8_0000_0000_002a);
+        assert_eq!(
+            decompose(payload_nan).class,
+            Class::Nan {
+                quiet: true,
+                payload: 0x2a
+            }
+        );
+        let signalling = f32::from_bits(0x7f80_0001);
+        assert_eq!(
+            decompose(signalling).class.to_string(),
+            "signalling NaN, payload 0x1"
+        );
+    }
+
+    #[test]
+    fn exact_decimals_show_what_is_stored() {
+        assert_eq!(
+            exact_decimal(0.1f64),
+            "0.1000000000000000055511151231257827021181583404541015625"
+        );
+        assert_eq!(exact_decimal(0.1f32), "0.100000001490116119384765625");
+        assert_eq!(exact_decimal(0.5f64), "0.5");
+        assert_eq!(exact_decimal(1e3f64), "1000");
+        assert_eq!(exact_decimal(f64::NAN), "NaN");
+    }
+
+    #[test]
+    fn next_up_down_and_ulps_match_std() {
+        for x in [0.0, -0.0, 1.0, -1.0, 0.1, 1e300, -5e-324, f64::MAX] {
+            assert_eq!(next_up(x).to_bits(), x.next_up().to_bits(), "{}", x);
+            assert_eq!(next_down(x).to_bits(), x.next_down().to_bits(), "{}", x);
+        }
+        assert_eq!(next_up(1.0f32), 1.0f32.next_up());
+        assert_eq!(next_up(f64::INFINITY), f64::INFINITY);
+        assert_eq!(ulp(1.0f64), f64::EPSILON);
+        assert_eq!(ulp_distance(0.1 + 0.2, 0.3f64), Some(1));
+        assert_eq!(ulp_distance(-0.0, 0.0f64), Some(0));
+        assert_eq!(ulp(0.0f64), 5e-324);
+        assert_eq!(ulp(f64::MAX), 2f64.powi(971));
+        assert_eq!(ulp(-f64::MAX), 2f64.powi(971));
+        assert_eq!(ulp(f32::MAX), 2f64.powi(104));
+        assert_eq!(ulp(f32::from_bits(1)), 2f64.powi(-149));
+        assert!(ulp(f64::INFINITY).is_nan());
+        assert!(ulp(f32::NEG_INFINITY).is_nan());
+        assert_eq!(ulp_distance(-5e-324, 5e-324f64), Some(2));
+        assert_eq!(ulp_distance(f64::NAN, 1.0), None);
+    }
+
+    #[test]
+    fn comparison_modes_differ_at_large_and_small_magnitudes() {
+        let sum = 0.1 + 0.2;
+        assert!(approx_eq(sum, 0.3, Tolerance::Absolute(1e-10)));
+        assert!(approx_eq(sum, 0.3, Tolerance::Ulps(1)));
+
+        // Neighbouring floats near 1e20 are 16384 apart
+        let big = 1e20f64;
+        assert!(!approx_eq(big, next_up(big), Tolerance::Absolute(1e-10)));
+        assert!(approx_eq(big, next_up(big), Tolerance::Relative(1e-12)));
+
+        // 1e-20 and 2e-20 differ by a factor of two yet are "close" in absolute terms
+        assert!(approx_eq(1e-20, 2e-20f64, Tolerance::Absolute(1e-10)));
+        assert!(!approx_eq(1e-20, 2e-20f64, Tolerance::Relative(1e-12)));
+        assert!(!approx_eq(1e-20, 2e-20f64, Tolerance::Ulps(4)));
+
+        assert!(approx_eq(f64::INFINITY, f64::INFINITY, Tolerance::Ulps(0)));
+        assert!(!approx_eq(f64::NAN, f64::NAN, Tolerance::Absolute(1.0)));
+    }
+
+    #[test]
+    fn infinity_is_never_close_to_a_finite_value() {
+        assert!(!approx_eq(f64::INFINITY, 1.0, Tolerance::Relative(1e-9)));
+        assert!(!approx_eq(1.0, f64::NEG_INFINITY, Tolerance::Relative(1.0)));
+        assert!(!approx_eq(f64::MAX, f64::INFINITY, Tolerance::Ulps(1)));
+        assert!(!approx_eq(
+            f32::INFINITY,
+            f32::MAX,
+            Tolerance::Absolute(f64::MAX)
+        ));
+        assert!(!approx_eq(f64::MAX, -f64::MAX, Tolerance::Relative(2.0)));
+        assert!(approx_eq(
+            f64::MAX,
+            next_down(f64::MAX),
+            Tolerance::Relative(1e-15)
+        ));
+    }
+}
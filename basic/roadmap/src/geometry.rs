@@ -0,0 +1,426 @@
+// Geometry
+// The shapes the struct and impl lessons build step by step, as one tested
+// library: `Point`, `Rectangle`, `Square` and `Circle`, all implementing
+// `Shape`. Coordinates are f64 and grow to the right and upwards; a
+// rectangle's `origin` is its bottom-left corner.
+//
+//   use roadmap::geometry::{Circle, Point, Rectangle, Shape};
+//
+//   let rect = Rectangle::new(30.0, 50.0);
+//   let circle = Circle::new(Point::new(30.0, 50.0), 10.0);
+//   rect.intersects(&circle);             // true
+//   rect.bounding_box_overlap(&circle);   // Some(10×10 at (20, 40))
+
+use std::f64::consts::PI;
+use std::fmt;
+
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct Point {
+    pub x: f64,
+    pub y: f64,
+}
+
+impl Point {
+    pub fn new(x: f64, y: f64) -> Point {
+        Point { x, y }
+    }
+
+    pub fn distance(&self, other: Point) -> f64 {
+        (self.x - other.x).hypot(self.y - other.y)
+    }
+}
+
+impl fmt::Display for Point {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "({}, {})", self.x, self.y)
+    }
+}
+
+// Every shape here is either an axis-aligned box or a disc, which is all
+// `Shape::intersects` needs to know to compare any two of them exactly
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Outline {
+    Box(Rectangle),
+    Disc(Circle),
+}
+
+pub trait Shape {
+    fn area(&self) -> f64;
+    fn perimeter(&self) -> f64;
+    // The smallest axis-aligned rectangle around the shape
+    fn bounding_box(&self) -> Rectangle;
+    // Points on the edge count as inside
+    fn contains(&self, point: Point) -> bool;
+    fn outline(&self) -> Outline;
+
+    // Do the two shapes share at least one point? Touching counts
+    fn intersects(&self, other: &dyn Shape) -> bool {
+        match (self.outline(), other.outline()) {
+            (Outline::Box(a), Outline::Box(b)) => {
+                a.origin.x <= b.right()
+                    && b.origin.x <= a.right()
+                    && a.origin.y <= b.top()
+                    && b.origin.y <= a.top()
+            }
+            (Outline::Box(rect), Outline::Disc(circle))
+            | (Outline::Disc(circle), Outline::Box(rect)) => {
+                circle.contains(rect.closest_point(circle.center))
+            }
+            (Outline::Disc(a), Outline::Disc(b)) => {
+                a.center.distance(b.center) <= a.radius + b.radius
+            }
+        }
+    }
+
+    // Where the two bounding boxes overlap, or None if they only touch or
+    // are apart. This is the overlap of the shapes themselves only for
+    // rectangles and squares: two circles can have overlapping boxes without
+    // touching (use `intersects` for the exact answer)
+    fn bounding_box_overlap(&self, other: &dyn Shape) -> Option<Rectangle> {
+        let a = self.bounding_box();
+        let b = other.bounding_box();
+        let left = a.origin.x.max(b.origin.x);
+        let bottom = a.origin.y.max(b.origin.y);
+        let right = a.right().min(b.right());
+        let top = a.top().min(b.top());
+        if right > left && top > bottom {
+            Some(Rectangle::at(
+                Point::new(left, bottom),
+                right - left,
+                top - bottom,
+            ))
+        } else {
+            None
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct Rectangle {
+    pub origin: Point,
+    pub width: f64,
+    pub height: f64,
+}
+
+impl Rectangle {
+    // A rectangle with its corner at (0, 0)
+    pub fn new(width: f64, height: f64) -> Rectangle {
+        Rectangle::at(Point::default(), width, height)
+    }
+
+    pub fn at(origin: Point, width: f64, height: f64) -> Rectangle {
+        Rectangle {
+            origin,
+            width,
+            height,
+        }
+    }
+
+    pub fn square(size: f64) -> Rectangle {
+        Rectangle::new(size, size)
+    }
+
+    pub fn right(&self) -> f64 {
+        self.origin.x + self.width
+    }
+
+    pub fn top(&self) -> f64 {
+        self.origin.y + self.height
+    }
+
+    // The point of the rectangle (edge included) nearest to `point`
+    pub fn closest_point(&self, point: Point) -> Point {
+        Point::new(
+            point.x.clamp(self.origin.x, self.right()),
+            point.y.clamp(self.origin.y, self.top()),
+        )
+    }
+
+    pub fn is_square(&self) -> bool {
+        self.width == self.height
+    }
+
+    // Strictly bigger in both directions, so `other` fits inside with room
+    pub fn can_hold(&self, other: &Rectangle) -> bool {
+        self.width > other.width && self.height > other.height
+    }
+
+    pub fn set_width(&mut self, width: f64) {
+        self.width = width;
+    }
+
+    pub fn set_height(&mut self, height: f64) {
+        self.height = height;
+    }
+
+    // Grows (or shrinks) away from the origin
+    pub fn scale(&mut self, factor: f64) {
+        self.width *= factor;
+        self.height *= factor;
+    }
+
+    // The larger width and the larger height of the two
+    pub fn max(self, other: Rectangle) -> Rectangle {
+        Rectangle::at(
+            self.origin,
+            self.width.max(other.width),
+            self.height.max(other.height),
+        )
+    }
+
+    // The smallest square this rectangle fits in
+    pub fn into_square(self) -> Square {
+        Square::at(self.origin, self.width.max(self.height))
+    }
+}
+
+impl Shape for Rectangle {
+    fn area(&self) -> f64 {
+        self.width * self.height
+    }
+
+    fn perimeter(&self) -> f64 {
+        2.0 * (self.width + self.height)
+    }
+
+    fn bounding_box(&self) -> Rectangle {
+        *self
+    }
+
+    fn contains(&self, point: Point) -> bool {
+        (self.origin.x..=self.right()).contains(&point.x)
+            && (self.origin.y..=self.top()).contains(&point.y)
+    }
+
+    fn outline(&self) -> Outline {
+        Outline::Box(*self)
+    }
+}
+
+impl fmt::Display for Rectangle {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}×{} at {}", self.width, self.height, self.origin)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct Square {
+    pub origin: Point,
+    pub side: f64,
+}
+
+impl Square {
+    pub fn new(side: f64) -> Square {
+        Square::at(Point::default(), side)
+    }
+
+    pub fn at(origin: Point, side: f64) -> Square {
+        Square { origin, side }
+    }
+}
+
+impl From<Square> for Rectangle {
+    fn from(square: Square) -> Rectangle {
+        Rectangle::at(square.origin, square.side, square.side)
+    }
+}
+
+impl Shape for Square {
+    fn area(&self) -> f64 {
+        self.side * self.side
+    }
+
+    fn perimeter(&self) -> f64 {
+        4.0 * self.side
+    }
+
+    fn bounding_box(&self) -> Rectangle {
+        Rectangle::from(*self)
+    }
+
+    fn contains(&self, point: Point) -> bool {
+        self.bounding_box().contains(point)
+    }
+
+    fn outline(&self) -> Outline {
+        Outline::Box(self.bounding_box())
+    }
+}
+
+impl fmt::Display for Square {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "square of side {} at {}", self.side, self.origin)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct Circle {
+    pub center: Point,
+    pub radius: f64,
+}
+
+impl Circle {
+    pub fn new(center: Point, radius: f64) -> Circle {
+        Circle { center, radius }
+    }
+}
+
+impl Shape for Circle {
+    fn area(&self) -> f64 {
+        PI * self.radius * self.radius
+    }
+
+    fn perimeter(&self) -> f64 {
+        2.0 * PI * self.radius
+    }
+
+    fn bounding_box(&self) -> Rectangle {
+        Rectangle::at(
+            Point::new(self.center.x - self.radius, self.center.y - self.radius),
+            2.0 * self.radius,
+            2.0 * self.radius,
+        )
+    }
+
+    fn contains(&self, point: Point) -> bool {
+        self.center.distance(point) <= self.radius
+    }
+
+    fn outline(&self) -> Outline {
+        Outline::Disc(*self)
+    }
+}
+
+impl fmt::Display for Circle {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "circle of radius {} at {}", self.radius, self.center)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn close(a: f64, b: f64) -> bool {
+        (a - b).abs() < 1e-9
+    }
+
+    #[test]
+    fn measures_every_shape() {
+        let rect = Rectangle::new(30.0, 50.0);
+        assert_eq!((rect.area(), rect.perimeter()), (1500.0, 160.0));
+        let square = Square::new(4.0);
+        assert_eq!((square.area(), square.perimeter()), (16.0, 16.0));
+        let circle = Circle::new(Point::new(1.0, 1.0), 2.0);
+        assert!(close(circle.area(), 4.0 * PI));
+        assert!(close(circle.perimeter(), 4.0 * PI));
+        assert_eq!(
+            circle.bounding_box(),
+            Rectangle::at(Point::new(-1.0, -1.0), 4.0, 4.0)
+        );
+        assert_eq!(Point::new(0.0, 0.0).distance(Point::new(3.0, 4.0)), 5.0);
+    }
+
+    #[test]
+    fn contains_includes_the_edge() {
+        let rect = Rectangle::at(Point::new(1.0, 1.0), 2.0, 3.0);
+        assert!(rect.contains(Point::new(1.0, 4.0)));
+        assert!(rect.contains(Point::new(2.0, 2.0)));
+        assert!(!rect.contains(Point::new(0.9, 2.0)));
+        assert!(!rect.contains(Point::new(2.0, 4.1)));
+
+        let circle = Circle::new(Point::default(), 5.0);
+        assert!(circle.contains(Point::new(3.0, 4.0)));
+        assert!(!circle.contains(Point::new(4.0, 4.0)));
+        assert!(Square::new(2.0).contains(Point::new(2.0, 0.0)));
+    }
+
+    #[test]
+    fn bounding_box_overlap_is_exact_only_for_rectangles() {
+        let a = Rectangle::new(4.0, 4.0);
+        let b = Rectangle::at(Point::new(2.0, 1.0), 5.0, 2.0);
+        assert_eq!(
+            a.bounding_box_overlap(&b),
+            Some(Rectangle::at(Point::new(2.0, 1.0), 2.0, 2.0))
+        );
+        assert_eq!(a.bounding_box_overlap(&b), b.bounding_box_overlap(&a));
+        // Sharing an edge is not an overlap
+        let beside = Rectangle::at(Point::new(4.0, 0.0), 1.0, 1.0);
+        assert_eq!(a.bounding_box_overlap(&beside), None);
+
+        let circle = Circle::new(Point::new(30.0, 50.0), 10.0);
+        assert_eq!(
+            Rectangle::new(30.0, 50.0).bounding_box_overlap(&circle),
+            Some(Rectangle::at(Point::new(20.0, 40.0), 10.0, 10.0))
+        );
+        assert_eq!(
+            Square::new(4.0).bounding_box_overlap(&a),
+            Some(Rectangle::new(4.0, 4.0))
+        );
+
+        // Boxes overlap in [3, 5]², but the circles are 11.3 apart
+        let left = Circle::new(Point::new(0.0, 0.0), 5.0);
+        let right = Circle::new(Point::new(8.0, 8.0), 5.0);
+        let overlap = left.bounding_box_overlap(&right).unwrap();
+        assert_eq!(overlap, Rectangle::at(Point::new(3.0, 3.0), 2.0, 2.0));
+        assert!(!left.intersects(&right));
+        assert!(!left.contains(Point::new(4.0, 4.0)) && !right.contains(Point::new(4.0, 4.0)));
+    }
+
+    #[test]
+    fn intersects_is_exact_for_every_pair() {
+        let rect = Rectangle::new(4.0, 4.0);
+        let square = Square::at(Point::new(4.0, 4.0), 2.0);
+        let circle = Circle::new(Point::new(0.0, 0.0), 5.0);
+        let shapes: [&dyn Shape; 3] = [&rect, &square, &circle];
+
+        // Rectangle and square share only the corner (4, 4)
+        assert!(rect.intersects(&square));
+        assert!(!rect.intersects(&Square::at(Point::new(4.1, 0.0), 1.0)));
+        // Circle meets the rectangle and reaches past (3, 4) but not to (4, 4)
+        assert!(circle.intersects(&rect));
+        assert!(!circle.intersects(&square));
+        for a in shapes {
+            assert!(a.intersects(a));
+            for b in shapes {
+                assert_eq!(a.intersects(b), b.intersects(a));
+            }
+        }
+
+        // A rectangle near the circle whose box corner is inside the circle's
+        // bounding box, yet the shapes are apart
+        let corner = Rectangle::at(Point::new(4.0, 4.0), 1.0, 1.0);
+        assert!(circle.bounding_box_overlap(&corner).is_some());
+        assert!(!circle.intersects(&corner));
+        // A thin rectangle crossing the circle with no corner inside it
+        let bar = Rectangle::at(Point::new(-10.0, -1.0), 20.0, 2.0);
+        assert!(circle.intersects(&bar) && bar.intersects(&circle));
+        // A circle entirely inside a rectangle
+        let small = Circle::new(Point::new(2.0, 2.0), 1.0);
+        assert!(small.intersects(&rect));
+
+        let touching = Circle::new(Point::new(6.0, 8.0), 5.0);
+        assert!(circle.intersects(&touching));
+        let right = Circle::new(Point::new(8.0, 8.0), 5.0);
+        assert!(!circle.intersects(&right));
+    }
+
+    #[test]
+    fn impl_block_methods_from_the_lessons() {
+        let mut rect = Rectangle::new(10.0, 20.0);
+        rect.set_width(15.0);
+        rect.scale(2.0);
+        assert_eq!(rect, Rectangle::new(30.0, 40.0));
+        rect.set_height(50.0);
+        assert!(rect.can_hold(&Rectangle::new(10.0, 40.0)));
+        assert!(!rect.can_hold(&Rectangle::new(60.0, 45.0)));
+        assert!(!rect.can_hold(&rect));
+        assert!(Rectangle::square(25.0).is_square());
+
+        let max = Rectangle::new(30.0, 50.0).max(Rectangle::new(40.0, 20.0));
+        assert_eq!(max, Rectangle::new(40.0, 50.0));
+        assert_eq!(Rectangle::new(30.0, 50.0).into_square(), Square::new(50.0));
+        assert_eq!(Rectangle::from(Square::new(3.0)), Rectangle::square(3.0));
+        assert_eq!(max.to_string(), "40×50 at (0, 0)");
+    }
+}
@@ -0,0 +1,313 @@
+// Memory Layout Inspector
+// Shows why a type is as big as it is: its size and alignment, where each
+// field sits, which bytes are padding, and a byte-by-byte diagram. Types are
+// described with `type_layout!`, which reads the real offsets with
+// `mem::offset_of!` instead of guessing them:
+//
+//   type_layout!(Point { x, y })
+//
+// `roadmap layout <type>` prints one of the registered types, and two types
+// are printed side by side (e.g. a struct and its `#[repr(C)]` twin).
+
+use std::any;
+use std::mem;
+
+use crate::ownership_system::box_stack_heap::{Packet, PacketC, Point, Rectangle};
+
+// Bytes per diagram row
+const ROW: usize = 8;
+
+pub struct Field {
+    pub name: &'static str,
+    pub ty: String,
+    pub offset: usize,
+    pub size: usize,
+}
+
+pub struct TypeLayout {
+    pub name: &'static str,
+    pub size: usize,
+    pub align: usize,
+    pub fields: Vec<Field>,
+    pub niche: Niche,
+}
+
+// What wrapping `Box<T>` in an `Option` costs
+pub struct Niche {
+    pub boxed: usize,
+    pub optional: usize,
+}
+
+// Describes a struct from its field names, e.g. `type_layout!(Point { x, y })`
+macro_rules! type_layout {
+    ($ty:ident { $($field:ident),* $(,)? }) => {
+        $crate::layout::TypeLayout::new::<$ty>(
+            stringify!($ty),
+            vec![$($crate::layout::Field::new(
+                stringify!($field),
+                ::std::mem::offset_of!($ty, $field),
+                |value: &$ty| &value.$field,
+            )),*],
+        )
+    };
+}
+pub(crate) use type_layout;
+
+impl Field {
+    // The accessor is never called, it only tells the compiler the field's type
+    pub fn new<T, F>(name: &'static str, offset: usize, _field: fn(&T) -> &F) -> Field {
+        Field {
+            name,
+            ty: short_type_name::<F>(),
+            offset,
+            size: mem::size_of::<F>(),
+        }
+    }
+}
+
+impl TypeLayout {
+    pub fn new<T>(name: &'static str, fields: Vec<Field>) -> TypeLayout {
+        TypeLayout {
+            name,
+            size: mem::size_of::<T>(),
+            align: mem::align_of::<T>(),
+            fields,
+            niche: niche::<T>(),
+        }
+    }
+
+    // Byte ranges no field covers, as (offset, length)
+    pub fn padding(&self) -> Vec<(usize, usize)> {
+        let mut fields: Vec<&Field> = self.fields.iter().collect();
+        fields.sort_by_key(|field| field.offset);
+        let mut gaps = Vec::new();
+        let mut end = 0;
+        for field in fields {
+            if field.offset > end {
+                gaps.push((end, field.offset - end));
+            }
+            end = end.max(field.offset + field.size);
+        }
+        if self.size > end {
+            gaps.push((end, self.size - end));
+        }
+        gaps
+    }
+
+    pub fn padding_bytes(&self) -> usize {
+        self.padding().iter().map(|(_, len)| len).sum()
+    }
+
+    // "Packet: 8 bytes, align 4, 1 byte of padding"
+    pub fn summary(&self) -> String {
+        format!(
+            "{}: {}, align {}, {} of padding",
+            self.name,
+            bytes(self.size),
+            self.align,
+            bytes(self.padding_bytes())
+        )
+    }
+
+    // One letter per byte, `a` for the first declared field and so on, `.` for
+    // padding, eight bytes per row:
+    //
+    //   offset  0 1 2 3 4 5 6 7
+    //        0  b b b b c c a .
+    pub fn diagram(&self) -> Vec<String> {
+        let mut bytes = vec!['.'; self.size];
+        for (index, field) in self.fields.iter().enumerate() {
+            for byte in &mut bytes[field.offset..field.offset + field.size] {
+                *byte = label(index);
+            }
+        }
+
+        let header: Vec<String> = (0..ROW.min(self.size.max(1)))
+            .map(|i| i.to_string())
+            .collect();
+        let mut lines = vec![format!("offset  {}", header.join(" "))];
+        for (row, chunk) in bytes.chunks(ROW).enumerate() {
+            let cells: Vec<String> = chunk.iter().map(char::to_string).collect();
+            lines.push(format!("{:>6}  {}", row * ROW, cells.join(" ")));
+        }
+        lines
+    }
+
+    // What each diagram letter stands for, then the padding
+    pub fn legend(&self) -> Vec<String> {
+        let mut lines: Vec<String> = self
+            .fields
+            .iter()
+            .enumerate()
+            .map(|(index, field)| {
+                format!(
+                    "{} = {}: {} (offset {}, {})",
+                    label(index),
+                    field.name,
+                    field.ty,
+                    field.offset,
+                    bytes(field.size)
+                )
+            })
+            .collect();
+        for (offset, len) in self.padding() {
+            lines.push(format!(". = padding (offset {}, {})", offset, bytes(len)));
+        }
+        lines
+    }
+
+    // "Option<Box<Point>>: 8 bytes, the same as Box<Point>"
+    pub fn niche_summary(&self) -> String {
+        let Niche { boxed, optional } = self.niche;
+        if optional == boxed {
+            format!(
+                "Option<Box<{}>>: {}, the same as Box<{}> (None is the null pointer)",
+                self.name,
+                bytes(optional),
+                self.name
+            )
+        } else {
+            format!(
+                "Option<Box<{}>>: {}, Box<{}> alone is {}",
+                self.name,
+                bytes(optional),
+                self.name,
+                bytes(boxed)
+            )
+        }
+    }
+
+    // Summary, diagram and legend
+    pub fn report(&self) -> Vec<String> {
+        let mut lines = vec![self.summary()];
+        lines.extend(self.diagram());
+        lines.extend(self.legend());
+        lines
+    }
+}
+
+// Both reports in two columns
+pub fn side_by_side(left: &TypeLayout, right: &TypeLayout) -> Vec<String> {
+    let left = left.report();
+    let right = right.report();
+    let width = left
+        .iter()
+        .map(|line| line.chars().count())
+        .max()
+        .unwrap_or(0)
+        + 4;
+    (0..left.len().max(right.len()))
+        .map(|row| {
+            let a = left.get(row).map_or("", String::as_str);
+            let b = right.get(row).map_or("", String::as_str);
+            format!("{:<width$}{}", a, b, width = width)
+                .trim_end()
+                .to_string()
+        })
+        .collect()
+}
+
+// A Box is never null, so `Option<Box<T>>` can use null for None and needs no
+// separate tag
+pub fn niche<T>() -> Niche {
+    Niche {
+        boxed: mem::size_of::<Box<T>>(),
+        optional: mem::size_of::<Option<Box<T>>>(),
+    }
+}
+
+// The types `roadmap layout` knows about
+pub fn registered() -> Vec<TypeLayout> {
+    vec![
+        type_layout!(Point { x, y }),
+        type_layout!(Rectangle {
+            top_left,
+            bottom_right
+        }),
+        type_layout!(Packet {
+            tag,
+            length,
+            checksum
+        }),
+        type_layout!(PacketC {
+            tag,
+            length,
+            checksum
+        }),
+    ]
+}
+
+pub fn find(name: &str) -> Option<TypeLayout> {
+    registered()
+        .into_iter()
+        .find(|layout| layout.name.eq_ignore_ascii_case(name))
+}
+
+fn bytes(count: usize) -> String {
+    format!("{} byte{}", count, if count == 1 { "" } else { "s" })
+}
+
+fn label(index: usize) -> char {
+    (b'a' + (index % 26) as u8) as char
+}
+
+// `roadmap::ownership_system::box_stack_heap::Point` -> `Point`
+fn short_type_name<T>() -> String {
+    let name = any::type_name::<T>();
+    name.rsplit("::").next().unwrap_or(name).to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[allow(dead_code)]
+    #[repr(C)]
+    struct Header {
+        flag: u8,
+        id: u32,
+        kind: u16,
+    }
+
+    #[test]
+    fn repr_c_keeps_declaration_order_and_pads() {
+        let layout = type_layout!(Header { flag, id, kind });
+        assert_eq!((layout.size, layout.align), (12, 4));
+        assert_eq!(layout.padding(), vec![(1, 3), (10, 2)]);
+        assert_eq!(
+            layout.summary(),
+            "Header: 12 bytes, align 4, 5 bytes of padding"
+        );
+        assert_eq!(
+            layout.diagram(),
+            vec![
+                "offset  0 1 2 3 4 5 6 7",
+                "     0  a . . . b b b b",
+                "     8  c c . .",
+            ]
+        );
+        assert_eq!(layout.legend()[1], "b = id: u32 (offset 4, 4 bytes)");
+    }
+
+    #[test]
+    fn default_layout_is_never_larger_than_repr_c() {
+        let packet = find("packet").unwrap();
+        let packet_c = find("PacketC").unwrap();
+        assert!(packet.size <= packet_c.size);
+        assert_eq!(packet_c.padding_bytes(), 5);
+        assert!(side_by_side(&packet, &packet_c)[0].contains("PacketC: 12 bytes"));
+    }
+
+    #[test]
+    fn option_box_uses_the_null_niche() {
+        let niche = niche::<Point>();
+        assert!(
+            find("point")
+                .unwrap()
+                .niche_summary()
+                .starts_with("Option<Box<Point>>: 8 bytes, the same as Box<Point>")
+        );
+        assert_eq!(niche.boxed, mem::size_of::<usize>());
+        assert_eq!(niche.optional, niche.boxed);
+    }
+}
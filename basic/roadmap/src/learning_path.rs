@@ -0,0 +1,200 @@
+// Learning Path
+// Turns the prerequisites in each lesson's `Meta` into a study order. The order
+// is topological (every lesson comes after what it builds on) and otherwise
+// keeps registry order, so it only moves a lesson when a prerequisite forces it.
+
+use std::fmt;
+
+use crate::registry::{self, Entry, LookupError};
+
+#[derive(Debug, PartialEq)]
+pub enum PathError {
+    MissingPrerequisite {
+        lesson: String,
+        prerequisite: String,
+        reason: LookupError,
+    },
+    // Lesson paths around the loop, the first one repeated at the end
+    Cycle(Vec<String>),
+}
+
+impl fmt::Display for PathError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            PathError::MissingPrerequisite {
+                lesson,
+                prerequisite,
+                reason,
+            } => write!(
+                f,
+                "{} lists prerequisite '{}': {}",
+                lesson, prerequisite, reason
+            ),
+            PathError::Cycle(lessons) => {
+                write!(f, "prerequisites form a cycle: {}", lessons.join(" -> "))
+            }
+        }
+    }
+}
+
+// Resolves a prerequisite id the way Meta documents it: bare ids are looked up
+// in the lesson's own topic
+pub fn prerequisite(entry: &Entry, id: &str) -> Result<Entry, PathError> {
+    let query = if id.contains('/') {
+        id.to_string()
+    } else {
+        format!("{}/{}", entry.topic.id, id)
+    };
+    registry::find(&query).map_err(|reason| PathError::MissingPrerequisite {
+        lesson: entry.path(),
+        prerequisite: id.to_string(),
+        reason,
+    })
+}
+
+// Every lesson in an order that respects all prerequisites
+pub fn order() -> Result<Vec<Entry>, PathError> {
+    let entries: Vec<Entry> = registry::entries().collect();
+    let graph = graph(&entries)?;
+    match topological(&graph) {
+        Ok(order) => Ok(order.into_iter().map(|index| entries[index]).collect()),
+        Err(cycle) => Err(PathError::Cycle(
+            cycle
+                .into_iter()
+                .map(|index| entries[index].path())
+                .collect(),
+        )),
+    }
+}
+
+// Lessons not in `done` whose prerequisites all are, in path order
+pub fn next(done: &[Entry]) -> Result<Vec<Entry>, PathError> {
+    let is_done = |entry: &Entry| done.iter().any(|d| d.path() == entry.path());
+    let mut ready = Vec::new();
+    for entry in order()? {
+        if is_done(&entry) {
+            continue;
+        }
+        let mut unlocked = true;
+        for id in entry.lesson.meta.prerequisites {
+            unlocked &= is_done(&prerequisite(&entry, id)?);
+        }
+        if unlocked {
+            ready.push(entry);
+        }
+    }
+    Ok(ready)
+}
+
+// For each entry, the indices of its prerequisites
+fn graph(entries: &[Entry]) -> Result<Vec<Vec<usize>>, PathError> {
+    entries
+        .iter()
+        .map(|entry| {
+            entry
+                .lesson
+                .meta
+                .prerequisites
+                .iter()
+                .map(|id| {
+                    let found = prerequisite(entry, id)?;
+                    Ok(entries
+                        .iter()
+                        .position(|other| other.path() == found.path())
+                        .expect("registry entries are all in the list"))
+                })
+                .collect()
+        })
+        .collect()
+}
+
+// Kahn's algorithm, always taking the lowest ready index so the result stays
+// as close to the original order as the prerequisites allow. On failure returns
+// one cycle as a list of indices.
+fn topological(prerequisites: &[Vec<usize>]) -> Result<Vec<usize>, Vec<usize>> {
+    let count = prerequisites.len();
+    let mut placed = vec![false; count];
+    let mut order = Vec::with_capacity(count);
+
+    while order.len() < count {
+        let ready = (0..count)
+            .find(|&index| !placed[index] && prerequisites[index].iter().all(|&p| placed[p]));
+        match ready {
+            Some(index) => {
+                placed[index] = true;
+                order.push(index);
+            }
+            None => return Err(find_cycle(prerequisites, &placed)),
+        }
+    }
+    Ok(order)
+}
+
+// Every unplaced node waits on another unplaced one, so following those edges
+// from any of them must eventually revisit a node
+fn find_cycle(prerequisites: &[Vec<usize>], placed: &[bool]) -> Vec<usize> {
+    let start = placed.iter().position(|done| !done).unwrap_or(0);
+    let mut path = vec![start];
+    loop {
+        let current = path[path.len() - 1];
+        let Some(&waiting_on) = prerequisites[current].iter().find(|&&p| !placed[p]) else {
+            return path;
+        };
+        if let Some(position) = path.iter().position(|&index| index == waiting_on) {
+            let mut cycle = path.split_off(position);
+            cycle.push(waiting_on);
+            // Read the cycle as "a needs b needs c", then flip to study order
+            cycle.reverse();
+            return cycle;
+        }
+        path.push(waiting_on);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn order_keeps_registry_order_unless_a_prerequisite_moves_a_lesson() {
+        // 0 needs 2, so 2 goes first; 1 stays where it was
+        assert_eq!(topological(&[vec![2], vec![], vec![]]), Ok(vec![1, 2, 0]));
+        assert_eq!(topological(&[vec![], vec![0], vec![1]]), Ok(vec![0, 1, 2]));
+    }
+
+    #[test]
+    fn cycles_are_reported_in_order() {
+        // 0 needs 1 needs 2 needs 0; 3 is fine
+        let cycle = topological(&[vec![1], vec![2], vec![0], vec![]]).unwrap_err();
+        assert_eq!(cycle, vec![0, 2, 1, 0]);
+    }
+
+    #[test]
+    fn lesson_prerequisites_form_a_valid_path() {
+        let order = order().unwrap();
+        assert_eq!(order.len(), registry::entries().count());
+        for (index, entry) in order.iter().enumerate() {
+            for id in entry.lesson.meta.prerequisites {
+                let required = prerequisite(entry, id).unwrap().path();
+                assert!(
+                    order[..index].iter().any(|e| e.path() == required),
+                    "{} comes before its prerequisite {}",
+                    entry.path(),
+                    required
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn next_suggests_lessons_whose_prerequisites_are_done() {
+        let first = next(&[]).unwrap();
+        assert_eq!(first[0].path(), "syntax_and_semantics/variables_mutability");
+        assert!(first.iter().all(|e| e.lesson.meta.prerequisites.is_empty()));
+
+        let done = [registry::find("variables_mutability").unwrap()];
+        let paths: Vec<String> = next(&done).unwrap().iter().map(Entry::path).collect();
+        assert!(paths.contains(&"syntax_and_semantics/functions".to_string()));
+        assert!(!paths.contains(&"syntax_and_semantics/control_flow".to_string()));
+    }
+}
@@ -0,0 +1,15 @@
+// Rust Roadmap library
+// Code the lessons build up piece by piece, kept here in its finished, tested
+// form so lessons (and anyone else) can import it, e.g.
+// `use roadmap::geometry::{Rectangle, Shape};`.
+
+pub mod color;
+pub mod events;
+pub mod geometry;
+pub mod list;
+pub mod literal;
+pub mod password;
+pub mod text;
+pub mod turtle;
+pub mod unicode;
+pub mod validation;
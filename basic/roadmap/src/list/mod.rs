@@ -0,0 +1,291 @@
+// Cons List
+// The Box lesson's `enum List { Cons(i32, Box<List>), Nil }` grown into a
+// usable, generic singly linked list. `Option<Box<Node<T>>>` is the same
+// shape: `Some(node)` is a Cons cell owning the rest of the list through its
+// Box, `None` is Nil.
+//
+//   let list: List<i32> = (1..=3).collect();   // 1 -> 2 -> 3 -> Nil
+//
+// Pushing and popping happen at the front in O(1). Dropping is a loop, not
+// the default recursion through every Box, so a list of millions of nodes
+// cannot overflow the stack. `PersistentList` shares its tails through `Rc`
+// instead of owning them, so prepending leaves the original list intact.
+
+mod persistent;
+
+pub use persistent::PersistentList;
+
+use std::fmt;
+use std::iter::FromIterator;
+
+type Link<T> = Option<Box<Node<T>>>;
+
+struct Node<T> {
+    value: T,
+    next: Link<T>,
+}
+
+pub struct List<T> {
+    head: Link<T>,
+    len: usize,
+}
+
+impl<T> List<T> {
+    pub fn new() -> List<T> {
+        List { head: None, len: 0 }
+    }
+
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.head.is_none()
+    }
+
+    // Adds a value at the front
+    pub fn push(&mut self, value: T) {
+        let next = self.head.take();
+        self.head = Some(Box::new(Node { value, next }));
+        self.len += 1;
+    }
+
+    // Removes the front value
+    pub fn pop(&mut self) -> Option<T> {
+        self.head.take().map(|node| {
+            self.head = node.next;
+            self.len -= 1;
+            node.value
+        })
+    }
+
+    pub fn peek(&self) -> Option<&T> {
+        self.head.as_deref().map(|node| &node.value)
+    }
+
+    pub fn peek_mut(&mut self) -> Option<&mut T> {
+        self.head.as_deref_mut().map(|node| &mut node.value)
+    }
+
+    // Reverses in place by turning every `next` around; nothing is copied
+    pub fn reverse(&mut self) {
+        let mut reversed: Link<T> = None;
+        let mut rest = self.head.take();
+        while let Some(mut node) = rest {
+            rest = node.next.take();
+            node.next = reversed;
+            reversed = Some(node);
+        }
+        self.head = reversed;
+    }
+
+    pub fn iter(&self) -> Iter<'_, T> {
+        Iter {
+            next: self.head.as_deref(),
+        }
+    }
+
+    pub fn iter_mut(&mut self) -> IterMut<'_, T> {
+        IterMut {
+            next: self.head.as_deref_mut(),
+        }
+    }
+
+    // Consumes the list, keeping the order
+    pub fn map<U>(self, f: impl FnMut(T) -> U) -> List<U> {
+        self.into_iter().map(f).collect()
+    }
+
+    pub fn filter(self, predicate: impl FnMut(&T) -> bool) -> List<T> {
+        self.into_iter().filter(predicate).collect()
+    }
+}
+
+impl<T> Default for List<T> {
+    fn default() -> Self {
+        List::new()
+    }
+}
+
+// The derived Drop would recurse once per node: Box drops Node, which drops
+// its Box, and so on. Unlinking the nodes one at a time keeps the stack flat
+impl<T> Drop for List<T> {
+    fn drop(&mut self) {
+        let mut link = self.head.take();
+        while let Some(mut node) = link {
+            link = node.next.take();
+        }
+    }
+}
+
+// Keeps the iterator's order: every value is pushed to the front, then the
+// whole list is reversed once
+impl<T> FromIterator<T> for List<T> {
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        let mut list = List::new();
+        for value in iter {
+            list.push(value);
+        }
+        list.reverse();
+        list
+    }
+}
+
+impl<T: Clone> Clone for List<T> {
+    fn clone(&self) -> Self {
+        self.iter().cloned().collect()
+    }
+}
+
+impl<T: PartialEq> PartialEq for List<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.len == other.len && self.iter().eq(other.iter())
+    }
+}
+
+impl<T: fmt::Debug> fmt::Debug for List<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_list().entries(self.iter()).finish()
+    }
+}
+
+// "1 -> 2 -> 3 -> Nil"
+impl<T: fmt::Display> fmt::Display for List<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for value in self.iter() {
+            write!(f, "{} -> ", value)?;
+        }
+        write!(f, "Nil")
+    }
+}
+
+pub struct Iter<'a, T> {
+    next: Option<&'a Node<T>>,
+}
+
+impl<'a, T> Iterator for Iter<'a, T> {
+    type Item = &'a T;
+
+    fn next(&mut self) -> Option<&'a T> {
+        self.next.map(|node| {
+            self.next = node.next.as_deref();
+            &node.value
+        })
+    }
+}
+
+pub struct IterMut<'a, T> {
+    next: Option<&'a mut Node<T>>,
+}
+
+impl<'a, T> Iterator for IterMut<'a, T> {
+    type Item = &'a mut T;
+
+    fn next(&mut self) -> Option<&'a mut T> {
+        self.next.take().map(|node| {
+            self.next = node.next.as_deref_mut();
+            &mut node.value
+        })
+    }
+}
+
+pub struct IntoIter<T>(List<T>);
+
+impl<T> Iterator for IntoIter<T> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<T> {
+        self.0.pop()
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.0.len, Some(self.0.len))
+    }
+}
+
+impl<T> IntoIterator for List<T> {
+    type Item = T;
+    type IntoIter = IntoIter<T>;
+
+    fn into_iter(self) -> IntoIter<T> {
+        IntoIter(self)
+    }
+}
+
+impl<'a, T> IntoIterator for &'a List<T> {
+    type Item = &'a T;
+    type IntoIter = Iter<'a, T>;
+
+    fn into_iter(self) -> Iter<'a, T> {
+        self.iter()
+    }
+}
+
+impl<'a, T> IntoIterator for &'a mut List<T> {
+    type Item = &'a mut T;
+    type IntoIter = IterMut<'a, T>;
+
+    fn into_iter(self) -> IterMut<'a, T> {
+        self.iter_mut()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn push_and_pop_at_the_front() {
+        let mut list = List::new();
+        assert_eq!(list.pop(), None);
+        list.push(3);
+        list.push(2);
+        list.push(1);
+        assert_eq!((list.len(), list.peek()), (3, Some(&1)));
+        if let Some(first) = list.peek_mut() {
+            *first = 10;
+        }
+        assert_eq!(list.pop(), Some(10));
+        assert_eq!(list.pop(), Some(2));
+        assert_eq!(list.len(), 1);
+        assert_eq!(list.to_string(), "3 -> Nil");
+        assert_eq!(List::<i32>::new().to_string(), "Nil");
+    }
+
+    #[test]
+    fn iterates_by_reference_mutably_and_by_value() {
+        let mut list: List<i32> = (1..=3).collect();
+        assert_eq!(list.to_string(), "1 -> 2 -> 3 -> Nil");
+        for value in &mut list {
+            *value *= 10;
+        }
+        assert_eq!(list.iter().sum::<i32>(), 60);
+        let words: List<String> = list.iter().map(|n| n.to_string()).collect();
+        assert_eq!(
+            words.into_iter().collect::<Vec<_>>(),
+            vec!["10", "20", "30"]
+        );
+        assert_eq!(format!("{:?}", list), "[10, 20, 30]");
+    }
+
+    #[test]
+    fn reverse_map_and_filter_keep_or_flip_order() {
+        let mut list: List<i32> = (1..=5).collect();
+        list.reverse();
+        assert_eq!(list.to_string(), "5 -> 4 -> 3 -> 2 -> 1 -> Nil");
+        let evens = list.clone().filter(|n| n % 2 == 0);
+        assert_eq!(evens.to_string(), "4 -> 2 -> Nil");
+        let squares = list.map(|n| n * n);
+        assert_eq!(squares, (1..=5).rev().map(|n| n * n).collect());
+        assert_eq!(squares.len(), 5);
+    }
+
+    #[test]
+    fn drops_a_million_nodes_without_overflowing_the_stack() {
+        let list: List<u32> = (0..1_000_000).collect();
+        assert_eq!(list.len(), 1_000_000);
+        let copy = list.clone();
+        drop(list);
+        assert_eq!(copy.iter().last(), Some(&999_999));
+    }
+}
@@ -0,0 +1,204 @@
+// Persistent List
+// A cons list whose cells are shared through `Rc` instead of owned through
+// `Box`. Nothing is ever changed in place: `prepend` returns a new list whose
+// tail is the old one, so both stay usable and share every node they have in
+// common.
+//
+//   let tail: PersistentList<i32> = (2..=3).collect();   // 2 -> 3 -> Nil
+//   let a = tail.prepend(1);                            // 1 -> 2 -> 3 -> Nil
+//   let b = tail.prepend(0);                            // 0 -> 2 -> 3 -> Nil
+//   a.tail().ptr_eq(&b.tail())                          // true
+
+use std::fmt;
+use std::iter::FromIterator;
+use std::rc::Rc;
+
+type Link<T> = Option<Rc<Node<T>>>;
+
+struct Node<T> {
+    value: T,
+    next: Link<T>,
+}
+
+pub struct PersistentList<T> {
+    head: Link<T>,
+    len: usize,
+}
+
+impl<T> PersistentList<T> {
+    pub fn new() -> PersistentList<T> {
+        PersistentList { head: None, len: 0 }
+    }
+
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.head.is_none()
+    }
+
+    // A new list with `value` in front of this one; O(1), nothing is copied
+    pub fn prepend(&self, value: T) -> PersistentList<T> {
+        PersistentList {
+            head: Some(Rc::new(Node {
+                value,
+                next: self.head.clone(),
+            })),
+            len: self.len + 1,
+        }
+    }
+
+    // Everything after the head, sharing its nodes; empty stays empty
+    pub fn tail(&self) -> PersistentList<T> {
+        PersistentList {
+            head: self.head.as_ref().and_then(|node| node.next.clone()),
+            len: self.len.saturating_sub(1),
+        }
+    }
+
+    pub fn head(&self) -> Option<&T> {
+        self.head.as_deref().map(|node| &node.value)
+    }
+
+    // Do both lists start at the very same node? Then they are equal without
+    // looking at a single value
+    pub fn ptr_eq(&self, other: &PersistentList<T>) -> bool {
+        match (&self.head, &other.head) {
+            (Some(a), Some(b)) => Rc::ptr_eq(a, b),
+            (None, None) => true,
+            _ => false,
+        }
+    }
+
+    pub fn iter(&self) -> Iter<'_, T> {
+        Iter {
+            next: self.head.as_deref(),
+        }
+    }
+}
+
+impl<T> Default for PersistentList<T> {
+    fn default() -> Self {
+        PersistentList::new()
+    }
+}
+
+// Cheap: only the head's count goes up
+impl<T> Clone for PersistentList<T> {
+    fn clone(&self) -> Self {
+        PersistentList {
+            head: self.head.clone(),
+            len: self.len,
+        }
+    }
+}
+
+// Frees nodes only this list owned, stopping at the first one another list
+// still points to. A loop for the same reason as `List`'s Drop
+impl<T> Drop for PersistentList<T> {
+    fn drop(&mut self) {
+        let mut link = self.head.take();
+        while let Some(node) = link {
+            match Rc::try_unwrap(node) {
+                Ok(mut node) => link = node.next.take(),
+                Err(_) => break,
+            }
+        }
+    }
+}
+
+// Keeps the iterator's order. Values are collected first, since a persistent
+// list can only be built from the back
+impl<T> FromIterator<T> for PersistentList<T> {
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        let values: Vec<T> = iter.into_iter().collect();
+        let mut list = PersistentList::new();
+        for value in values.into_iter().rev() {
+            list = list.prepend(value);
+        }
+        list
+    }
+}
+
+impl<T: PartialEq> PartialEq for PersistentList<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.len == other.len && (self.ptr_eq(other) || self.iter().eq(other.iter()))
+    }
+}
+
+impl<T: fmt::Debug> fmt::Debug for PersistentList<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_list().entries(self.iter()).finish()
+    }
+}
+
+impl<T: fmt::Display> fmt::Display for PersistentList<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for value in self.iter() {
+            write!(f, "{} -> ", value)?;
+        }
+        write!(f, "Nil")
+    }
+}
+
+pub struct Iter<'a, T> {
+    next: Option<&'a Node<T>>,
+}
+
+impl<'a, T> Iterator for Iter<'a, T> {
+    type Item = &'a T;
+
+    fn next(&mut self) -> Option<&'a T> {
+        self.next.map(|node| {
+            self.next = node.next.as_deref();
+            &node.value
+        })
+    }
+}
+
+impl<'a, T> IntoIterator for &'a PersistentList<T> {
+    type Item = &'a T;
+    type IntoIter = Iter<'a, T>;
+
+    fn into_iter(self) -> Iter<'a, T> {
+        self.iter()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn prepending_keeps_the_original_and_shares_its_nodes() {
+        let tail: PersistentList<i32> = (2..=3).collect();
+        let a = tail.prepend(1);
+        let b = tail.prepend(0);
+        assert_eq!(a.to_string(), "1 -> 2 -> 3 -> Nil");
+        assert_eq!(b.to_string(), "0 -> 2 -> 3 -> Nil");
+        assert_eq!(tail.to_string(), "2 -> 3 -> Nil");
+        assert!(a.tail().ptr_eq(&tail) && b.tail().ptr_eq(&tail));
+        assert!(!a.ptr_eq(&b));
+        assert_eq!(a.tail(), b.tail());
+        assert_eq!((a.len(), a.head()), (3, Some(&1)));
+        assert!(PersistentList::<i32>::new().tail().is_empty());
+    }
+
+    #[test]
+    fn dropping_one_list_leaves_shared_nodes_alive() {
+        let tail: PersistentList<String> = ["b", "c"].iter().map(|s| s.to_string()).collect();
+        let list = tail.prepend("a".to_string());
+        drop(tail);
+        assert_eq!(
+            list.iter().cloned().collect::<Vec<_>>(),
+            vec!["a", "b", "c"]
+        );
+
+        let long: PersistentList<u32> = (0..1_000_000).collect();
+        let shorter = long.tail().tail();
+        drop(long);
+        assert_eq!(shorter.len(), 999_998);
+        assert_eq!(shorter.head(), Some(&2));
+    }
+}
@@ -0,0 +1,804 @@
+// Numeric Literal Evaluator
+// Reads a Rust integer, float or byte literal the way rustc does and explains
+// it: `roadmap literal 0b1111_0000u8` prints the radix, the digits without
+// separators, the type and the value. The type comes from the suffix, else
+// from an annotation (`--as u64`, like `let x: u64 = 5;`), else from the
+// defaults: i32 for integers and f64 for floats.
+//
+// Literals rustc rejects produce its error and notes, e.g. for `256u8`:
+//
+//   literal out of range for `u8`
+//     = note: the literal `256u8` does not fit into the type `u8` whose range is `0..=255`
+//     = help: consider using the type `u16` instead
+//
+// A leading `-` is the unary minus rustc allows on signed literals, so
+// `-128i8` is fine while `128i8` is out of range.
+
+use std::fmt;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Type {
+    pub name: &'static str,
+    pub float: bool,
+    pub signed: bool,
+    pub bits: u32,
+}
+
+const fn int(name: &'static str, signed: bool, bits: u32) -> Type {
+    Type {
+        name,
+        float: false,
+        signed,
+        bits,
+    }
+}
+
+const fn float(name: &'static str, bits: u32) -> Type {
+    Type {
+        name,
+        float: true,
+        signed: true,
+        bits,
+    }
+}
+
+pub const TYPES: [Type; 14] = [
+    int("i8", true, 8),
+    int("i16", true, 16),
+    int("i32", true, 32),
+    int("i64", true, 64),
+    int("i128", true, 128),
+    int("isize", true, usize::BITS),
+    int("u8", false, 8),
+    int("u16", false, 16),
+    int("u32", false, 32),
+    int("u64", false, 64),
+    int("u128", false, 128),
+    int("usize", false, usize::BITS),
+    float("f32", 32),
+    float("f64", 64),
+];
+
+pub fn find_type(name: &str) -> Option<Type> {
+    TYPES.iter().copied().find(|ty| ty.name == name)
+}
+
+impl Type {
+    // Largest value, and the magnitude of the smallest one
+    fn max(self) -> u128 {
+        let bits = if self.signed {
+            self.bits - 1
+        } else {
+            self.bits
+        };
+        if bits == 128 {
+            u128::MAX
+        } else {
+            (1 << bits) - 1
+        }
+    }
+
+    fn min_magnitude(self) -> u128 {
+        if self.signed { 1 << (self.bits - 1) } else { 0 }
+    }
+
+    fn fits(self, negative: bool, magnitude: u128) -> bool {
+        if negative {
+            magnitude <= self.min_magnitude()
+        } else {
+            magnitude <= self.max()
+        }
+    }
+
+    // "-128..=127"
+    fn range(self) -> String {
+        let min = self.min_magnitude();
+        let sign = if min == 0 { "" } else { "-" };
+        format!("{}{}..={}", sign, min, self.max())
+    }
+}
+
+// Why the literal has its type
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Inference {
+    Suffix,
+    Annotation,
+    Default,
+    Byte,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Value {
+    Int { negative: bool, magnitude: u128 },
+    F32(f32),
+    F64(f64),
+}
+
+impl fmt::Display for Value {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Value::Int {
+                negative,
+                magnitude,
+            } => write!(f, "{}{}", if *negative { "-" } else { "" }, magnitude),
+            Value::F32(value) => write!(f, "{:?}", value),
+            Value::F64(value) => write!(f, "{:?}", value),
+        }
+    }
+}
+
+#[derive(Debug, PartialEq)]
+pub struct Literal {
+    pub text: String,
+    pub radix: u32,
+    // The digits as written, without separators, prefix or suffix
+    pub digits: String,
+    pub ty: Type,
+    pub inference: Inference,
+    pub value: Value,
+}
+
+#[derive(Debug, PartialEq)]
+pub enum LiteralError {
+    NotALiteral(String),
+    NoDigits,
+    InvalidDigit {
+        digit: char,
+        radix: u32,
+    },
+    EmptyExponent,
+    FieldAccess(String),
+    UnsupportedFloatRadix(u32),
+    InvalidSuffix {
+        suffix: String,
+        float: bool,
+    },
+    InvalidWidth {
+        width: String,
+        float: bool,
+    },
+    TooLarge,
+    OutOfRange {
+        literal: String,
+        ty: Type,
+        radix: u32,
+        magnitude: u128,
+        suggestion: Option<&'static str>,
+    },
+    FloatOutOfRange {
+        literal: String,
+        ty: Type,
+    },
+    NegatedUnsigned(Type),
+    MismatchedTypes {
+        expected: String,
+        found: String,
+    },
+    UnknownType(String),
+    Byte(&'static str),
+}
+
+impl fmt::Display for LiteralError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            LiteralError::NotALiteral(text) => {
+                write!(f, "expected a numeric or byte literal, found `{}`", text)
+            }
+            LiteralError::NoDigits => write!(f, "no valid digits found for number"),
+            LiteralError::InvalidDigit { digit, radix } => {
+                write!(f, "invalid digit `{}` for a base {} literal", digit, radix)
+            }
+            LiteralError::EmptyExponent => write!(f, "expected at least one digit in exponent"),
+            LiteralError::FieldAccess(field) => write!(
+                f,
+                "`{{integer}}` is a primitive type and therefore doesn't have fields\n  \
+                 = note: `.{}` is read as a field access, a float needs a digit after the `.`",
+                field
+            ),
+            LiteralError::UnsupportedFloatRadix(radix) => {
+                write!(f, "{} float literal is not supported", radix_name(*radix))
+            }
+            LiteralError::InvalidSuffix { suffix, float } => {
+                if *float {
+                    write!(
+                        f,
+                        "invalid suffix `{}` for float literal\n  \
+                         = help: valid suffixes are `f32` and `f64`",
+                        suffix
+                    )
+                } else {
+                    write!(
+                        f,
+                        "invalid suffix `{}` for number literal\n  \
+                         = help: the suffix must be one of the numeric types (`u32`, `isize`, `f32`, etc.)",
+                        suffix
+                    )
+                }
+            }
+            LiteralError::InvalidWidth { width, float } => {
+                if *float {
+                    write!(
+                        f,
+                        "invalid width `{}` for float literal\n  \
+                         = help: valid widths are 32 and 64",
+                        width
+                    )
+                } else {
+                    write!(
+                        f,
+                        "invalid width `{}` for integer literal\n  \
+                         = help: valid widths are 8, 16, 32, 64 and 128",
+                        width
+                    )
+                }
+            }
+            LiteralError::TooLarge => write!(
+                f,
+                "integer literal is too large\n  = note: value exceeds limit of `{}`",
+                u128::MAX
+            ),
+            LiteralError::OutOfRange {
+                literal,
+                ty,
+                radix,
+                magnitude,
+                suggestion,
+            } => {
+                write!(f, "literal out of range for `{}`\n  = note: ", ty.name)?;
+                if *radix != 10 && ty.signed {
+                    write!(
+                        f,
+                        "the literal `{}` (decimal `{}`) does not fit into the type `{}` and will become `{}{}`",
+                        literal,
+                        magnitude,
+                        ty.name,
+                        wrap(*magnitude, ty.bits),
+                        ty.name
+                    )?;
+                } else {
+                    write!(
+                        f,
+                        "the literal `{}` does not fit into the type `{}` whose range is `{}`",
+                        literal,
+                        ty.name,
+                        ty.range()
+                    )?;
+                }
+                if let Some(suggestion) = suggestion {
+                    write!(
+                        f,
+                        "\n  = help: consider using the type `{}` instead",
+                        suggestion
+                    )?;
+                }
+                Ok(())
+            }
+            LiteralError::FloatOutOfRange { literal, ty } => write!(
+                f,
+                "literal out of range for `{}`\n  = note: the literal `{}` does not fit into the type `{}` and will be converted to `{}::INFINITY`",
+                ty.name, literal, ty.name, ty.name
+            ),
+            LiteralError::NegatedUnsigned(ty) => write!(
+                f,
+                "cannot apply unary operator `-` to type `{}`\n  = note: unsigned values cannot be negated",
+                ty.name
+            ),
+            LiteralError::MismatchedTypes { expected, found } => write!(
+                f,
+                "mismatched types\n  = note: expected `{}`, found {}",
+                expected, found
+            ),
+            LiteralError::UnknownType(name) => {
+                let names: Vec<&str> = TYPES.iter().map(|ty| ty.name).collect();
+                write!(
+                    f,
+                    "unknown numeric type '{}', expected one of {}",
+                    name,
+                    names.join(", ")
+                )
+            }
+            LiteralError::Byte(message) => write!(f, "{}", message),
+        }
+    }
+}
+
+fn radix_name(radix: u32) -> &'static str {
+    match radix {
+        2 => "binary",
+        8 => "octal",
+        16 => "hexadecimal",
+        _ => "decimal",
+    }
+}
+
+// The two's-complement value a signed type keeps from the low `bits` bits
+fn wrap(magnitude: u128, bits: u32) -> i128 {
+    let shift = 128 - bits;
+    ((magnitude << shift) as i128) >> shift
+}
+
+// The smallest fixed-width type that holds the value, as rustc suggests
+fn suggest(negative: bool, magnitude: u128) -> Option<&'static str> {
+    TYPES
+        .iter()
+        .filter(|ty| !ty.float && ty.signed == negative && !ty.name.ends_with("size"))
+        .find(|ty| ty.fits(negative, magnitude))
+        .map(|ty| ty.name)
+}
+
+// The pieces of a number literal before any type is decided
+struct Scanned<'a> {
+    radix: u32,
+    digits: String,
+    float: bool,
+    suffix: &'a str,
+}
+
+fn scan(text: &str) -> Result<Scanned<'_>, LiteralError> {
+    let (radix, body) = match text.get(..2) {
+        Some("0x") => (16, &text[2..]),
+        Some("0o") => (8, &text[2..]),
+        Some("0b") => (2, &text[2..]),
+        _ => (10, text),
+    };
+    // Decimal digits are read in every radix and rejected afterwards, as rustc
+    // does: `0b102` is an invalid digit, not `0b10` with the suffix `2`
+    let is_digit = |c: char| {
+        if radix == 16 {
+            c.is_ascii_hexdigit()
+        } else {
+            c.is_ascii_digit()
+        }
+    };
+    let bytes = body.as_bytes();
+    let mut end = body
+        .find(|c: char| !is_digit(c) && c != '_')
+        .unwrap_or(body.len());
+    let mut float = false;
+
+    // A fraction: `1.5` or `1.`, but not `1..2`, `1.max(2)` or `1._5`
+    if body[end..].starts_with('.') {
+        let next = bytes.get(end + 1).map(|&b| b as char);
+        match next {
+            Some(c) if c.is_ascii_digit() && radix != 10 => {
+                return Err(LiteralError::UnsupportedFloatRadix(radix));
+            }
+            Some('.') => {}
+            Some(c) if c.is_alphabetic() || c == '_' => {
+                return Err(LiteralError::FieldAccess(body[end + 1..].to_string()));
+            }
+            _ if radix != 10 => return Err(LiteralError::UnsupportedFloatRadix(radix)),
+            _ => {
+                float = true;
+                end += 1;
+                end += body[end..]
+                    .find(|c: char| !c.is_ascii_digit() && c != '_')
+                    .unwrap_or(body.len() - end);
+            }
+        }
+    }
+
+    // An exponent: `1e10`, `2.5E-3`, `1e_1`
+    if radix == 10 && matches!(bytes.get(end), Some(b'e' | b'E')) {
+        float = true;
+        end += 1;
+        if matches!(bytes.get(end), Some(b'+' | b'-')) {
+            end += 1;
+        }
+        let exponent_end = body[end..]
+            .find(|c: char| !c.is_ascii_digit() && c != '_')
+            .map_or(body.len(), |offset| end + offset);
+        if !body[end..exponent_end].contains(|c: char| c.is_ascii_digit()) {
+            return Err(LiteralError::EmptyExponent);
+        }
+        end = exponent_end;
+    }
+
+    let digits: String = body[..end].chars().filter(|&c| c != '_').collect();
+    if !digits.contains(|c: char| c.is_digit(radix.max(10))) {
+        return Err(LiteralError::NoDigits);
+    }
+    if let Some(digit) = digits.chars().find(|c| !c.is_digit(radix) && !float) {
+        return Err(LiteralError::InvalidDigit { digit, radix });
+    }
+    Ok(Scanned {
+        radix,
+        digits,
+        float,
+        suffix: &body[end..],
+    })
+}
+
+// The type a suffix names; rustc checks the width before the name
+fn suffix_type(suffix: &str, float: bool, radix: u32) -> Result<Type, LiteralError> {
+    let invalid = || LiteralError::InvalidSuffix {
+        suffix: suffix.to_string(),
+        float,
+    };
+    if let Some(ty) = find_type(suffix) {
+        return match (ty.float, float) {
+            (true, _) if radix != 10 => Err(LiteralError::UnsupportedFloatRadix(radix)),
+            (false, true) => Err(invalid()),
+            _ => Ok(ty),
+        };
+    }
+    let mut chars = suffix.chars();
+    let first = chars.next();
+    let width = chars.as_str();
+    let numeric = !width.is_empty() && width.chars().all(|c| c.is_ascii_digit());
+    match first {
+        Some('i' | 'u') if numeric && !float => Err(LiteralError::InvalidWidth {
+            width: width.to_string(),
+            float: false,
+        }),
+        Some('f') if numeric => Err(LiteralError::InvalidWidth {
+            width: width.to_string(),
+            float: true,
+        }),
+        _ => Err(invalid()),
+    }
+}
+
+// Parses `text` as a literal, optionally assigned to a variable of type
+// `annotation`, and evaluates it
+pub fn parse(text: &str, annotation: Option<&str>) -> Result<Literal, LiteralError> {
+    let text = text.trim();
+    let (negative, literal) = match text.strip_prefix('-') {
+        Some(rest) => (true, rest.trim_start()),
+        None => (false, text),
+    };
+    let expected = match annotation {
+        Some(name) => {
+            Some(find_type(name).ok_or_else(|| LiteralError::UnknownType(name.to_string()))?)
+        }
+        None => None,
+    };
+
+    if literal.starts_with("b'") {
+        return byte(text, literal, negative, expected);
+    }
+    if !literal.starts_with(|c: char| c.is_ascii_digit()) {
+        return Err(LiteralError::NotALiteral(text.to_string()));
+    }
+
+    let scanned = scan(literal)?;
+    let (ty, inference) = if scanned.suffix.is_empty() {
+        match expected {
+            Some(ty) => (ty, Inference::Annotation),
+            None if scanned.float => (find_type("f64").unwrap(), Inference::Default),
+            None => (find_type("i32").unwrap(), Inference::Default),
+        }
+    } else {
+        (
+            suffix_type(scanned.suffix, scanned.float, scanned.radix)?,
+            Inference::Suffix,
+        )
+    };
+
+    if let Some(expected) = expected
+        && expected != ty
+    {
+        let found = match inference {
+            Inference::Suffix => format!("`{}`", ty.name),
+            _ if scanned.float => "floating-point number".to_string(),
+            _ => "integer".to_string(),
+        };
+        return Err(LiteralError::MismatchedTypes {
+            expected: expected.name.to_string(),
+            found,
+        });
+    }
+    if scanned.float != ty.float && inference == Inference::Annotation {
+        let found = if scanned.float {
+            "floating-point number"
+        } else {
+            "integer"
+        };
+        return Err(LiteralError::MismatchedTypes {
+            expected: ty.name.to_string(),
+            found: found.to_string(),
+        });
+    }
+
+    let value = if ty.float {
+        float_value(literal, &scanned.digits, ty, negative)?
+    } else {
+        if negative && !ty.signed {
+            return Err(LiteralError::NegatedUnsigned(ty));
+        }
+        let mut magnitude: u128 = 0;
+        for digit in scanned.digits.chars() {
+            magnitude = magnitude
+                .checked_mul(scanned.radix as u128)
+                .and_then(|m| m.checked_add(digit.to_digit(scanned.radix).unwrap() as u128))
+                .ok_or(LiteralError::TooLarge)?;
+        }
+        if !ty.fits(negative, magnitude) {
+            return Err(LiteralError::OutOfRange {
+                literal: literal.to_string(),
+                ty,
+                radix: scanned.radix,
+                magnitude,
+                suggestion: suggest(negative, magnitude),
+            });
+        }
+        Value::Int {
+            negative: negative && magnitude != 0,
+            magnitude,
+        }
+    };
+
+    Ok(Literal {
+        text: text.to_string(),
+        radix: scanned.radix,
+        digits: scanned.digits,
+        ty,
+        inference,
+        value,
+    })
+}
+
+fn float_value(
+    literal: &str,
+    digits: &str,
+    ty: Type,
+    negative: bool,
+) -> Result<Value, LiteralError> {
+    let out_of_range = || LiteralError::FloatOutOfRange {
+        literal: literal.to_string(),
+        ty,
+    };
+    // `1.` is valid Rust but not valid for str::parse
+    let digits = digits.strip_suffix('.').unwrap_or(digits);
+    if ty.bits == 32 {
+        let value: f32 = digits.parse().map_err(|_| out_of_range())?;
+        if value.is_infinite() {
+            return Err(out_of_range());
+        }
+        Ok(Value::F32(if negative { -value } else { value }))
+    } else {
+        let value: f64 = digits.parse().map_err(|_| out_of_range())?;
+        if value.is_infinite() {
+            return Err(out_of_range());
+        }
+        Ok(Value::F64(if negative { -value } else { value }))
+    }
+}
+
+// `b'A'`, `b'\n'`, `b'\x7f'`: always a u8
+fn byte(
+    text: &str,
+    literal: &str,
+    negative: bool,
+    expected: Option<Type>,
+) -> Result<Literal, LiteralError> {
+    let inner = literal[2..]
+        .strip_suffix('\'')
+        .ok_or(LiteralError::Byte("unterminated byte constant"))?;
+    let value = match inner.as_bytes() {
+        [] => return Err(LiteralError::Byte("empty byte literal")),
+        [b'\\', b'n'] => b'\n',
+        [b'\\', b'r'] => b'\r',
+        [b'\\', b't'] => b'\t',
+        [b'\\', b'0'] => b'\0',
+        [b'\\', quote @ (b'\\' | b'\'' | b'"')] => *quote,
+        [b'\\', b'x', ..] => {
+            let hex = &inner[2..];
+            if hex.len() != 2 {
+                return Err(LiteralError::Byte(
+                    "numeric character escape is too short, `\\x` needs two hex digits",
+                ));
+            }
+            u8::from_str_radix(hex, 16)
+                .map_err(|_| LiteralError::Byte("invalid character in numeric character escape"))?
+        }
+        [b'\\', ..] => return Err(LiteralError::Byte("unknown byte escape")),
+        [b'\''] => return Err(LiteralError::Byte("byte constant must be escaped: `\\'`")),
+        [c] if c.is_ascii() => *c,
+        _ if inner.chars().count() == 1 => {
+            return Err(LiteralError::Byte("non-ASCII character in byte literal"));
+        }
+        _ => {
+            return Err(LiteralError::Byte("byte literal may only contain one byte"));
+        }
+    };
+    let ty = find_type("u8").unwrap();
+    if let Some(expected) = expected
+        && expected != ty
+    {
+        return Err(LiteralError::MismatchedTypes {
+            expected: expected.name.to_string(),
+            found: "`u8`".to_string(),
+        });
+    }
+    if negative {
+        return Err(LiteralError::NegatedUnsigned(ty));
+    }
+    Ok(Literal {
+        text: text.to_string(),
+        radix: 10,
+        digits: value.to_string(),
+        ty,
+        inference: Inference::Byte,
+        value: Value::Int {
+            negative: false,
+            magnitude: value as u128,
+        },
+    })
+}
+
+// What `roadmap literal` prints for a valid literal
+pub fn describe(literal: &Literal) -> Vec<String> {
+    let reason = match literal.inference {
+        Inference::Suffix => "from the suffix",
+        Inference::Annotation => "from the type annotation",
+        Inference::Default if literal.ty.float => "the default for an unsuffixed float",
+        Inference::Default => "the default for an unsuffixed integer",
+        Inference::Byte => "byte literals are always u8",
+    };
+    let mut lines = vec![
+        literal.text.clone(),
+        format!("  type:   {} ({})", literal.ty.name, reason),
+    ];
+    if literal.inference == Inference::Byte {
+        lines.push("  radix:  ASCII code".to_string());
+    } else {
+        lines.push(format!(
+            "  radix:  {} ({})",
+            radix_name(literal.radix),
+            literal.radix
+        ));
+        lines.push(format!("  digits: {}", literal.digits));
+    }
+    lines.push(format!("  value:  {}", literal.value));
+    lines
+}
+
+// The description, or rustc's error, one line each
+pub fn evaluate(text: &str, annotation: Option<&str>) -> Vec<String> {
+    match parse(text, annotation) {
+        Ok(literal) => describe(&literal),
+        Err(err) => {
+            let mut lines = vec![text.to_string()];
+            lines.extend(format!("error: {}", err).lines().map(str::to_string));
+            lines
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn value(text: &str) -> String {
+        parse(text, None).unwrap().value.to_string()
+    }
+
+    #[test]
+    fn evaluates_every_radix_separator_and_suffix() {
+        assert_eq!(value("98_222"), "98222");
+        assert_eq!(value("0xff"), "255");
+        assert_eq!(value("0o77"), "63");
+        assert_eq!(value("0b1111_0000"), "240");
+        assert_eq!(value("b'A'"), "65");
+        assert_eq!(value("b'\\x7f'"), "127");
+        assert_eq!(value("57u8"), "57");
+        assert_eq!(value("1_000_u64"), "1000");
+        assert_eq!(value("-128i8"), "-128");
+        assert_eq!(value("2.5E-3"), "0.0025");
+        assert_eq!(value("1."), "1.0");
+        assert_eq!(value("1f32"), "1.0");
+        assert_eq!(value("0.1f32"), "0.1");
+        assert_eq!(value("0x1f32"), "7986");
+        assert_eq!(parse("0xffu8", None).unwrap().digits, "ff");
+    }
+
+    #[test]
+    fn infers_types_like_rustc() {
+        let ty = |text: &str, annotation| {
+            let literal = parse(text, annotation).unwrap();
+            (literal.ty.name, literal.inference)
+        };
+        assert_eq!(ty("5", None), ("i32", Inference::Default));
+        assert_eq!(ty("5.0", None), ("f64", Inference::Default));
+        assert_eq!(ty("1e3", None), ("f64", Inference::Default));
+        assert_eq!(ty("5", Some("u64")), ("u64", Inference::Annotation));
+        assert_eq!(ty("5.0", Some("f32")), ("f32", Inference::Annotation));
+        assert_eq!(ty("5usize", None), ("usize", Inference::Suffix));
+        assert_eq!(ty("b'a'", Some("u8")), ("u8", Inference::Byte));
+        assert_eq!(
+            parse("5.0", Some("i32")),
+            Err(LiteralError::MismatchedTypes {
+                expected: "i32".to_string(),
+                found: "floating-point number".to_string(),
+            })
+        );
+        assert_eq!(
+            parse("5", Some("f32")).unwrap_err().to_string(),
+            "mismatched types\n  = note: expected `f32`, found integer"
+        );
+        assert_eq!(
+            parse("5u8", Some("u64")).unwrap_err().to_string(),
+            "mismatched types\n  = note: expected `u64`, found `u8`"
+        );
+    }
+
+    #[test]
+    fn reports_out_of_range_literals_like_rustc() {
+        let error = |text: &str| parse(text, None).unwrap_err().to_string();
+        assert_eq!(
+            error("256u8"),
+            "literal out of range for `u8`\n  \
+             = note: the literal `256u8` does not fit into the type `u8` whose range is `0..=255`\n  \
+             = help: consider using the type `u16` instead"
+        );
+        assert_eq!(
+            error("3_000_000_000"),
+            "literal out of range for `i32`\n  \
+             = note: the literal `3_000_000_000` does not fit into the type `i32` whose range is `-2147483648..=2147483647`\n  \
+             = help: consider using the type `u32` instead"
+        );
+        assert_eq!(
+            error("0xFFi8"),
+            "literal out of range for `i8`\n  \
+             = note: the literal `0xFFi8` (decimal `255`) does not fit into the type `i8` and will become `-1i8`\n  \
+             = help: consider using the type `u8` instead"
+        );
+        assert!(error("-129i8").contains("consider using the type `i16`"));
+        assert!(error("1e40f32").contains("converted to `f32::INFINITY`"));
+        assert!(error("-1u8").starts_with("cannot apply unary operator `-` to type `u8`"));
+        assert!(
+            error("340282366920938463463374607431768211456")
+                .starts_with("integer literal is too large")
+        );
+        assert!(parse("340282366920938463463374607431768211455u128", None).is_ok());
+        assert!(parse("-170141183460469231731687303715884105728i128", None).is_ok());
+    }
+
+    #[test]
+    fn rejects_malformed_literals() {
+        let error = |text: &str| parse(text, None).unwrap_err();
+        assert_eq!(
+            error("0b102"),
+            LiteralError::InvalidDigit {
+                digit: '2',
+                radix: 2
+            }
+        );
+        assert_eq!(error("0x"), LiteralError::NoDigits);
+        assert_eq!(error("1e"), LiteralError::EmptyExponent);
+        assert_eq!(error("0x1.5"), LiteralError::UnsupportedFloatRadix(16));
+        assert_eq!(error("0b1f32"), LiteralError::UnsupportedFloatRadix(2));
+        assert_eq!(error("1.max"), LiteralError::FieldAccess("max".to_string()));
+        assert_eq!(
+            error("1u7"),
+            LiteralError::InvalidWidth {
+                width: "7".to_string(),
+                float: false
+            }
+        );
+        assert_eq!(
+            error("1.0u8"),
+            LiteralError::InvalidSuffix {
+                suffix: "u8".to_string(),
+                float: true
+            }
+        );
+        assert_eq!(
+            error("0X10"),
+            LiteralError::InvalidSuffix {
+                suffix: "X10".to_string(),
+                float: false
+            }
+        );
+        assert_eq!(
+            error("b'é'"),
+            LiteralError::Byte("non-ASCII character in byte literal")
+        );
+        assert_eq!(
+            error("b'ab'"),
+            LiteralError::Byte("byte literal may only contain one byte")
+        );
+        assert_eq!(error("abc"), LiteralError::NotALiteral("abc".to_string()));
+    }
+}
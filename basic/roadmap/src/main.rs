@@ -1,3 +1,56 @@
+// Rust Roadmap
+// Every lesson under src/ is registered in `registry` and can be run from the
+// command line, e.g. `cargo run -- run functions --section 6`.
+
+// These shadow std's println!/print! for the whole crate so that lesson output
+// can be captured (see output.rs). They must stay above the `mod` declarations.
+macro_rules! println {
+    () => {
+        $crate::output::write_fmt(format_args!("\n"))
+    };
+    ($($arg:tt)*) => {
+        $crate::output::write_fmt(format_args!("{}\n", format_args!($($arg)*)))
+    };
+}
+
+macro_rules! print {
+    ($($arg:tt)*) => {
+        $crate::output::write_fmt(format_args!($($arg)*))
+    };
+}
+
+mod cli;
+mod compile_fail;
+mod exercise;
+mod float;
+mod layout;
+mod learning_path;
+mod output;
+mod overflow;
+mod progress;
+mod quiz;
+mod registry;
+mod sandbox;
+mod search;
+mod site;
+mod snapshot;
+mod tracked;
+
+// Lessons intentionally show verbose, redundant and "don't do this" code next to
+// the idiomatic version, so lints that would flag those examples are silenced here.
+#[allow(clippy::all)]
+mod constructs;
+#[allow(clippy::all)]
+mod data_structures;
+#[allow(clippy::all)]
+mod ownership_system;
+#[allow(clippy::all)]
+mod syntax_and_semantics;
+
 fn main() {
-    
-}
\ No newline at end of file
+    let result = cli::parse(std::env::args().skip(1)).and_then(cli::execute);
+    if let Err(err) = result {
+        eprintln!("error: {}", err);
+        std::process::exit(2);
+    }
+}
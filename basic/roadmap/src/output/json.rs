@@ -0,0 +1,89 @@
+// JSON Renderer
+// One object per lesson, sections in order, each holding its content blocks:
+//
+//   [{"lesson": "syntax_and_semantics/functions", "title": "Functions in Rust",
+//     "sections": [{"number": 1, "title": "Basic Function Definition",
+//                   "blocks": [{"kind": "note", "text": "..."}, ...]}]}]
+//
+// Blank text lines only exist for terminal layout and are left out.
+
+use super::{Document, Event, Part, documents};
+
+pub fn render(events: &[Event]) -> String {
+    let lessons: Vec<String> = documents(events).iter().map(lesson).collect();
+    format!("[{}]\n", lessons.join(",\n"))
+}
+
+fn lesson(document: &Document) -> String {
+    let sections: Vec<String> = document.parts.iter().map(section).collect();
+    format!(
+        "{{\"lesson\": {}, \"title\": {}, \"sections\": [\n{}\n]}}",
+        string(document.path),
+        string(document.title),
+        sections.join(",\n")
+    )
+}
+
+fn section(part: &Part) -> String {
+    let blocks: Vec<String> = part
+        .blocks
+        .iter()
+        .filter_map(|event| block(event))
+        .collect();
+    let header = match part.header {
+        Some((number, title)) => format!("\"number\": {}, \"title\": {}", number, string(title)),
+        None => "\"number\": null, \"title\": null".to_string(),
+    };
+    format!(
+        "  {{{}, \"blocks\": [\n{}\n  ]}}",
+        header,
+        blocks.join(",\n")
+    )
+}
+
+fn block(event: &Event) -> Option<String> {
+    let (kind, text) = match event {
+        Event::Text(text) if text.trim().is_empty() => return None,
+        Event::Text(text) => ("text", text),
+        Event::Note(text) => ("note", text),
+        Event::Code(source) => ("code", source),
+        Event::Result(text) => ("result", text),
+        Event::Warning(text) => ("warning", text),
+        Event::Lesson { .. } | Event::Section { .. } => return None,
+    };
+    Some(format!(
+        "    {{\"kind\": \"{}\", \"text\": {}}}",
+        kind,
+        string(text)
+    ))
+}
+
+// A JSON string literal with the required escapes
+fn string(text: &str) -> String {
+    let mut out = String::with_capacity(text.len() + 2);
+    out.push('"');
+    for c in text.chars() {
+        match c {
+            '"' => out.push_str("\\\""),
+            '\\' => out.push_str("\\\\"),
+            '\n' => out.push_str("\\n"),
+            '\r' => out.push_str("\\r"),
+            '\t' => out.push_str("\\t"),
+            c if (c as u32) < 0x20 => out.push_str(&format!("\\u{:04x}", c as u32)),
+            c => out.push(c),
+        }
+    }
+    out.push('"');
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn strings_are_escaped() {
+        assert_eq!(string("say \"hi\"\n\\"), "\"say \\\"hi\\\"\\n\\\\\"");
+        assert_eq!(string("\u{1}"), "\"\\u0001\"");
+    }
+}
@@ -0,0 +1,172 @@
+// Markdown Renderer
+// A heading per lesson and section; notes become paragraphs, code snippets
+// ```rust blocks, results a bullet list and warnings block quotes. Runs of
+// plain program output are kept verbatim in ```text blocks. Everything outside
+// those blocks is escaped, so "Option<T>" is not read as an HTML tag.
+
+use super::{Document, Event, Part, documents, text_block};
+
+pub fn render(events: &[Event]) -> String {
+    let lessons: Vec<String> = documents(events).iter().map(lesson).collect();
+    lessons.join("\n")
+}
+
+fn lesson(document: &Document) -> String {
+    let mut blocks = Vec::new();
+    if !document.title.is_empty() {
+        blocks.push(format!("# {}", document.title));
+        blocks.push(format!("`{}`", document.path));
+    }
+    for part in &document.parts {
+        if let Some((number, title)) = part.header {
+            blocks.push(format!("## {}. {}", number, title));
+        }
+        blocks.extend(part_blocks(part));
+    }
+    let mut markdown = blocks.join("\n\n");
+    markdown.push('\n');
+    markdown
+}
+
+// Consecutive plain text lines and consecutive results are grouped together
+fn part_blocks(part: &Part) -> Vec<String> {
+    let mut blocks = Vec::new();
+    let mut text: Vec<&str> = Vec::new();
+    let mut results: Vec<&str> = Vec::new();
+
+    for event in &part.blocks {
+        if !matches!(event, Event::Text(_)) {
+            flush_text(&mut text, &mut blocks);
+        }
+        if !matches!(event, Event::Result(_)) {
+            flush_results(&mut results, &mut blocks);
+        }
+        match event {
+            Event::Text(line) => text.push(line),
+            Event::Result(value) => results.push(value),
+            Event::Note(note) => blocks.push(escape(note)),
+            Event::Code(source) => blocks.push(format!("```rust\n{}\n```", source)),
+            Event::Warning(warning) => {
+                let quoted: Vec<String> = escape(warning)
+                    .lines()
+                    .map(|line| format!("> {}", line))
+                    .collect();
+                blocks.push(format!("> **Warning:**\n{}", quoted.join("\n")));
+            }
+            Event::Lesson { .. } | Event::Section { .. } => {}
+        }
+    }
+    flush_text(&mut text, &mut blocks);
+    flush_results(&mut results, &mut blocks);
+    blocks
+}
+
+fn flush_text(lines: &mut Vec<&str>, blocks: &mut Vec<String>) {
+    // A lone line is usually a caption, longer runs are program output
+    match text_block(lines).as_slice() {
+        [] => {}
+        [line] => blocks.push(escape(line)),
+        body => blocks.push(format!("```text\n{}\n```", body.join("\n"))),
+    }
+    lines.clear();
+}
+
+fn flush_results(results: &mut Vec<&str>, blocks: &mut Vec<String>) {
+    if !results.is_empty() {
+        let items: Vec<String> = results
+            .iter()
+            .map(|value| format!("- → {}", escape(value)))
+            .collect();
+        blocks.push(items.join("\n"));
+    }
+    results.clear();
+}
+
+// Backslash-escapes what Markdown would read as markup: inline HTML such as
+// the `<T>` in "Box<T>", emphasis in "wrapping_* methods", links, code spans,
+// and a heading or list marker at the start of a line
+fn escape(text: &str) -> String {
+    let lines: Vec<String> = text.lines().map(escape_line).collect();
+    lines.join("\n")
+}
+
+fn escape_line(line: &str) -> String {
+    let mut out = String::with_capacity(line.len() + 8);
+    let mut body = line.trim_start();
+    out.push_str(&line[..line.len() - body.len()]);
+
+    // Markers only count when a space or the end of the line follows them
+    let marker_end = |rest: &str| rest.is_empty() || rest.starts_with(' ');
+    let hashes = body.len() - body.trim_start_matches('#').len();
+    let digits = body.len() - body.trim_start_matches(|c: char| c.is_ascii_digit()).len();
+    if hashes > 0 && marker_end(&body[hashes..])
+        || body.starts_with(['-', '+']) && marker_end(&body[1..])
+    {
+        out.push('\\');
+    } else if digits > 0
+        && body[digits..].starts_with(['.', ')'])
+        && marker_end(&body[digits + 1..])
+    {
+        out.push_str(&body[..digits]);
+        out.push('\\');
+        body = &body[digits..];
+    }
+    for c in body.chars() {
+        if matches!(c, '\\' | '`' | '*' | '_' | '<' | '>' | '[' | ']') {
+            out.push('\\');
+        }
+        out.push(c);
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn markup_in_text_is_escaped() {
+        assert_eq!(escape("Option<T>"), "Option\\<T\\>");
+        assert_eq!(escape("wrapping_* methods"), "wrapping\\_\\* methods");
+        assert_eq!(escape("a\\b [x] `y`"), "a\\\\b \\[x\\] \\`y\\`");
+        assert_eq!(
+            escape("# one\n- two\n12. three"),
+            "\\# one\n\\- two\n12\\. three"
+        );
+        assert_eq!(escape("x - 1. y #2"), "x - 1. y #2");
+        assert_eq!(
+            escape("255.max(1) -5 #[derive]"),
+            "255.max(1) -5 #\\[derive\\]"
+        );
+    }
+
+    #[test]
+    fn renders_each_kind_of_event() {
+        let events = vec![
+            Event::Lesson {
+                path: "constructs/demo".to_string(),
+                title: "Demo".to_string(),
+            },
+            Event::Section {
+                number: 1,
+                title: "Boxes".to_string(),
+            },
+            Event::Note("Box<T> allocates on the heap".to_string()),
+            Event::Code("let b = Box::new(5);".to_string()),
+            Event::Result("b = 5".to_string()),
+            Event::Result("size_of::<Box<i32>>() = 8".to_string()),
+            Event::Warning("moved value".to_string()),
+            Event::Text("   line 1".to_string()),
+            Event::Text("   line 2".to_string()),
+        ];
+        assert_eq!(
+            render(&events),
+            "# Demo\n\n`constructs/demo`\n\n## 1. Boxes\n\n\
+             Box\\<T\\> allocates on the heap\n\n\
+             ```rust\nlet b = Box::new(5);\n```\n\n\
+             - → b = 5\n- → size\\_of::\\<Box\\<i32\\>\\>() = 8\n\n\
+             > **Warning:**\n> moved value\n\n\
+             ```text\nline 1\nline 2\n```\n"
+        );
+    }
+}
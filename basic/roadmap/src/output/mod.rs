@@ -0,0 +1,343 @@
+// Lesson Output
+// Lessons describe what they show as a stream of events: section headers, notes,
+// code snippets, result values and warnings, plus plain text from the crate's
+// println!/print! macros (main.rs routes those through `write_fmt` below).
+// Events go to the sink installed for the current thread, the terminal by
+// default, or get recorded and rendered as JSON or Markdown afterwards.
+
+mod json;
+mod markdown;
+mod terminal;
+
+use std::cell::RefCell;
+use std::fmt;
+use std::io::{self, Write};
+use std::rc::Rc;
+
+use terminal::Terminal;
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum Event {
+    Lesson { path: String, title: String },
+    Section { number: usize, title: String },
+    Text(String),
+    Note(String),
+    Code(String),
+    Result(String),
+    Warning(String),
+}
+
+// Anything that can consume lesson events
+pub trait Sink {
+    fn emit(&mut self, event: Event);
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Format {
+    Terminal,
+    Json,
+    Markdown,
+}
+
+impl Format {
+    pub fn parse(name: &str) -> Option<Format> {
+        match name {
+            "terminal" | "text" => Some(Format::Terminal),
+            "json" => Some(Format::Json),
+            "markdown" | "md" => Some(Format::Markdown),
+            _ => None,
+        }
+    }
+}
+
+thread_local! {
+    static SINK: RefCell<Option<Box<dyn Sink>>> = const { RefCell::new(None) };
+}
+
+fn emit(event: Event) {
+    SINK.with(|sink| {
+        sink.borrow_mut()
+            .get_or_insert_with(|| Box::new(Terminal::new(io::stdout())))
+            .emit(event)
+    });
+}
+
+// Called by the crate's println!/print! macros
+pub fn write_fmt(args: fmt::Arguments<'_>) {
+    emit(Event::Text(args.to_string()));
+}
+
+// Lesson banner, emitted by the registry before the first section
+pub fn lesson(path: &str, title: &str) {
+    emit(Event::Lesson {
+        path: path.to_string(),
+        title: title.to_string(),
+    });
+}
+
+// Numbered section header, emitted by the registry before each section runs
+pub fn section(number: usize, title: &str) {
+    emit(Event::Section {
+        number,
+        title: title.to_string(),
+    });
+}
+
+// An explanation, e.g. the one-line summary under a section header
+pub fn note(text: impl Into<String>) {
+    emit(Event::Note(text.into()));
+}
+
+// Rust source shown to the reader, one or more lines
+pub fn code(source: impl Into<String>) {
+    emit(Event::Code(source.into()));
+}
+
+// A value computed by the example, e.g. "sum = 30"
+pub fn result(text: impl Into<String>) {
+    emit(Event::Result(text.into()));
+}
+
+// Something that fails to compile, panics or is otherwise a trap
+pub fn warning(text: impl Into<String>) {
+    emit(Event::Warning(text.into()));
+}
+
+// Sends every event from `run` to `sink`, then restores the previous sink
+pub fn with_sink<F, T>(sink: Box<dyn Sink>, run: F) -> T
+where
+    F: FnOnce() -> T,
+{
+    // Restoring in Drop keeps the sink correct even if a lesson panics
+    struct Restore(Option<Box<dyn Sink>>);
+
+    impl Drop for Restore {
+        fn drop(&mut self) {
+            let previous = self.0.take();
+            SINK.with(|sink| sink.replace(previous));
+        }
+    }
+
+    let previous = SINK.with(|current| current.replace(Some(sink)));
+    let _restore = Restore(previous);
+    run()
+}
+
+// Renders everything `run` emits as terminal text into `writer`
+pub fn redirect<F>(writer: Box<dyn Write>, run: F)
+where
+    F: FnOnce(),
+{
+    with_sink(Box::new(Terminal::new(writer)), run);
+}
+
+// Runs `run` and returns the terminal text it produced
+pub fn capture<F>(run: F) -> String
+where
+    F: FnOnce(),
+{
+    let buffer = SharedBuffer::default();
+    redirect(Box::new(buffer.clone()), run);
+    buffer.contents()
+}
+
+// Runs `run` and returns its events, with plain text split into one event per line
+pub fn record<F, T>(run: F) -> (Vec<Event>, T)
+where
+    F: FnOnce() -> T,
+{
+    let recorder = Recorder::default();
+    let value = with_sink(Box::new(recorder.clone()), run);
+    let events = recorder.0.take();
+    (split_text(events), value)
+}
+
+// Runs `run` with its output shown in the requested format
+pub fn render<F, T>(format: Format, run: F) -> T
+where
+    F: FnOnce() -> T,
+{
+    let (events, value) = match format {
+        Format::Terminal => return run(),
+        Format::Json | Format::Markdown => record(run),
+    };
+    let rendered = match format {
+        Format::Json => json::render(&events),
+        _ => markdown::render(&events),
+    };
+    print!("{}", rendered);
+    value
+}
+
+// Collects events; text chunks from print! are joined until the next event
+#[derive(Clone, Default)]
+struct Recorder(Rc<RefCell<Vec<Event>>>);
+
+impl Sink for Recorder {
+    fn emit(&mut self, event: Event) {
+        let mut events = self.0.borrow_mut();
+        match (events.last_mut(), event) {
+            (Some(Event::Text(previous)), Event::Text(text)) => previous.push_str(&text),
+            (_, event) => events.push(event),
+        }
+    }
+}
+
+fn split_text(events: Vec<Event>) -> Vec<Event> {
+    let mut lines = Vec::with_capacity(events.len());
+    for event in events {
+        match event {
+            Event::Text(text) => {
+                lines.extend(text.lines().map(|line| Event::Text(line.to_string())));
+            }
+            event => lines.push(event),
+        }
+    }
+    lines
+}
+
+// Printed lines without surrounding blank lines or the indentation lessons use
+// to line up with section headers
+pub fn text_block<'a>(lines: &[&'a str]) -> Vec<&'a str> {
+    let start = lines.iter().position(|line| !line.trim().is_empty());
+    let end = lines.iter().rposition(|line| !line.trim().is_empty());
+    let (Some(start), Some(end)) = (start, end) else {
+        return Vec::new();
+    };
+    let lines = &lines[start..=end];
+    let indent = lines
+        .iter()
+        .filter(|line| !line.trim().is_empty())
+        .map(|line| line.len() - line.trim_start().len())
+        .min()
+        .unwrap_or(0);
+    lines
+        .iter()
+        .map(|line| line.get(indent..).unwrap_or(""))
+        .collect()
+}
+
+// A lesson's events grouped the way JSON and Markdown present them
+struct Document<'a> {
+    path: &'a str,
+    title: &'a str,
+    parts: Vec<Part<'a>>,
+}
+
+// Content before the first section has no number or title
+struct Part<'a> {
+    header: Option<(usize, &'a str)>,
+    blocks: Vec<&'a Event>,
+}
+
+fn documents(events: &[Event]) -> Vec<Document<'_>> {
+    let mut documents: Vec<Document> = Vec::new();
+    for event in events {
+        match event {
+            Event::Lesson { path, title } => documents.push(Document {
+                path,
+                title,
+                parts: Vec::new(),
+            }),
+            Event::Section { number, title } => {
+                if documents.is_empty() {
+                    documents.push(Document::untitled());
+                }
+                let document = documents.last_mut().unwrap();
+                document.parts.push(Part {
+                    header: Some((*number, title)),
+                    blocks: Vec::new(),
+                });
+            }
+            block => {
+                if documents.is_empty() {
+                    documents.push(Document::untitled());
+                }
+                let document = documents.last_mut().unwrap();
+                if document.parts.is_empty() {
+                    document.parts.push(Part {
+                        header: None,
+                        blocks: Vec::new(),
+                    });
+                }
+                document.parts.last_mut().unwrap().blocks.push(block);
+            }
+        }
+    }
+    documents
+}
+
+impl Document<'_> {
+    fn untitled() -> Self {
+        Document {
+            path: "",
+            title: "",
+            parts: Vec::new(),
+        }
+    }
+}
+
+// A Vec<u8> that can be handed out as a writer and read back afterwards
+#[derive(Clone, Default)]
+pub struct SharedBuffer(Rc<RefCell<Vec<u8>>>);
+
+impl SharedBuffer {
+    pub fn contents(&self) -> String {
+        String::from_utf8_lossy(&self.0.borrow()).into_owned()
+    }
+}
+
+impl Write for SharedBuffer {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        self.0.borrow_mut().extend_from_slice(buf);
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn record_splits_printed_text_into_lines() {
+        let (events, ()) = record(|| {
+            section(1, "Basics");
+            print!("a = ");
+            println!("{}\nb", 1);
+            result("sum = 3");
+        });
+        assert_eq!(
+            events,
+            vec![
+                Event::Section {
+                    number: 1,
+                    title: "Basics".to_string()
+                },
+                Event::Text("a = 1".to_string()),
+                Event::Text("b".to_string()),
+                Event::Result("sum = 3".to_string()),
+            ]
+        );
+    }
+
+    #[test]
+    fn terminal_separates_sections_with_one_blank_line() {
+        let text = capture(|| {
+            lesson("topic/demo", "Demo");
+            section(1, "First");
+            note("intro");
+            println!();
+            section(2, "Second");
+            code("let x = 1;\nlet y = 2;");
+            warning("does not compile");
+        });
+        assert_eq!(
+            text,
+            "=== Demo ===\n\n1. First\n   intro\n\n2. Second\n   let x = 1;\n   let y = 2;\n   ⚠ does not compile\n"
+        );
+    }
+}
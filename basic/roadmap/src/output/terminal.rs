@@ -0,0 +1,74 @@
+// Terminal Renderer
+// Plain text in the layout the lessons have always used: a `=== Title ===`
+// banner, numbered section headers and three-space indented content.
+
+use std::io::Write;
+
+use super::{Event, Sink};
+
+const INDENT: &str = "   ";
+
+pub struct Terminal<W: Write> {
+    out: W,
+    // Newlines at the end of what has been written so far, capped at 2.
+    // Starts at 2 so the first header is not preceded by a blank line.
+    trailing_newlines: usize,
+}
+
+impl<W: Write> Terminal<W> {
+    pub fn new(out: W) -> Self {
+        Terminal {
+            out,
+            trailing_newlines: 2,
+        }
+    }
+
+    fn write(&mut self, text: &str) {
+        if text.is_empty() {
+            return;
+        }
+        // Ignore errors such as a closed pipe (`roadmap run --all | head`)
+        let _ = self.out.write_all(text.as_bytes());
+
+        let newlines = text.len() - text.trim_end_matches('\n').len();
+        self.trailing_newlines = if newlines == text.len() {
+            self.trailing_newlines + newlines
+        } else {
+            newlines
+        }
+        .min(2);
+    }
+
+    // Headers always start after a blank line
+    fn blank_line(&mut self) {
+        let missing = 2 - self.trailing_newlines;
+        self.write(&"\n".repeat(missing));
+    }
+
+    fn indented(&mut self, marker: &str, text: &str) {
+        for line in text.lines() {
+            let line = format!("{}{}{}\n", INDENT, marker, line);
+            self.write(&line);
+        }
+    }
+}
+
+impl<W: Write> Sink for Terminal<W> {
+    fn emit(&mut self, event: Event) {
+        match event {
+            Event::Lesson { title, .. } => {
+                self.blank_line();
+                self.write(&format!("=== {} ===\n\n", title));
+            }
+            Event::Section { number, title } => {
+                self.blank_line();
+                self.write(&format!("{}. {}\n", number, title));
+            }
+            Event::Text(text) => self.write(&text),
+            Event::Note(text) => self.indented("", &text),
+            Event::Code(source) => self.indented("", &source),
+            Event::Result(text) => self.indented("→ ", &text),
+            Event::Warning(text) => self.indented("⚠ ", &text),
+        }
+    }
+}
@@ -0,0 +1,619 @@
+// Overflow Explorer
+// `roadmap overflow i8 100 + 100` shows what every flavour of an integer
+// operation does with the given operands: the plain operator in a debug and a
+// release build, then checked_*, wrapping_*, saturating_* and overflowing_*,
+// each with the two's-complement bit pattern of its result. It works for every
+// primitive integer width through the `Int` trait, implemented by macro below.
+
+use std::fmt;
+use std::str::FromStr;
+
+pub enum Operation<T> {
+    Add(T),
+    Sub(T),
+    Mul(T),
+    Div(T),
+    Rem(T),
+    Shl(u32),
+    Pow(u32),
+    Neg,
+    Abs,
+}
+
+// What one method produced
+#[derive(Debug, PartialEq)]
+pub enum Outcome<T> {
+    Value(T),
+    // checked_* found an overflow
+    Overflow,
+    // overflowing_*: the wrapped value and whether it wrapped
+    Flagged(T, bool),
+    Panics(&'static str),
+    // e.g. saturating_rem, or abs on an unsigned type
+    Missing,
+}
+
+#[derive(Debug, PartialEq)]
+pub struct Row<T> {
+    pub method: String,
+    pub outcome: Outcome<T>,
+}
+
+#[derive(Debug, PartialEq)]
+pub enum OverflowError {
+    UnknownType(String),
+    UnknownOperation(String),
+    BadOperand { text: String, ty: &'static str },
+    MissingOperand(String),
+}
+
+impl fmt::Display for OverflowError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            OverflowError::UnknownType(ty) => write!(
+                f,
+                "unknown integer type '{}', expected one of {}",
+                ty,
+                TYPES.join(", ")
+            ),
+            OverflowError::UnknownOperation(op) => write!(
+                f,
+                "unknown operation '{}', expected + - * / % << pow neg abs",
+                op
+            ),
+            OverflowError::BadOperand { text, ty } => {
+                write!(f, "'{}' is not a valid {}", text, ty)
+            }
+            OverflowError::MissingOperand(op) => write!(f, "{} needs a second operand", op),
+        }
+    }
+}
+
+pub const TYPES: [&str; 12] = [
+    "i8", "i16", "i32", "i64", "i128", "isize", "u8", "u16", "u32", "u64", "u128", "usize",
+];
+
+pub trait Int: Copy + fmt::Display + FromStr {
+    const NAME: &'static str;
+    const BITS: u32;
+
+    // Two's-complement bit pattern, most significant bit first
+    fn bits(self) -> String;
+    fn checked(self, op: &Operation<Self>) -> Option<Self>;
+    // The rest return None when the method panics or does not exist
+    fn wrapping(self, op: &Operation<Self>) -> Option<Self>;
+    fn saturating(self, op: &Operation<Self>) -> Option<Self>;
+    fn overflowing(self, op: &Operation<Self>) -> Option<(Self, bool)>;
+    fn is_zero(self) -> bool;
+    fn is_signed() -> bool;
+}
+
+// The methods shared by signed and unsigned types
+macro_rules! int_common {
+    ($ty:ident, $unsigned:ident) => {
+        const NAME: &'static str = stringify!($ty);
+        const BITS: u32 = $ty::BITS;
+
+        fn bits(self) -> String {
+            format!("{:0width$b}", self as $unsigned, width = $ty::BITS as usize)
+        }
+
+        fn is_zero(self) -> bool {
+            self == 0
+        }
+
+        fn wrapping(self, op: &Operation<$ty>) -> Option<$ty> {
+            Some(match *op {
+                Operation::Add(b) => self.wrapping_add(b),
+                Operation::Sub(b) => self.wrapping_sub(b),
+                Operation::Mul(b) => self.wrapping_mul(b),
+                Operation::Div(b) if b != 0 => self.wrapping_div(b),
+                Operation::Rem(b) if b != 0 => self.wrapping_rem(b),
+                Operation::Shl(b) => self.wrapping_shl(b),
+                Operation::Pow(b) => self.wrapping_pow(b),
+                Operation::Neg => self.wrapping_neg(),
+                Operation::Abs => return self.abs_wrapping(),
+                Operation::Div(_) | Operation::Rem(_) => return None,
+            })
+        }
+
+        fn overflowing(self, op: &Operation<$ty>) -> Option<($ty, bool)> {
+            Some(match *op {
+                Operation::Add(b) => self.overflowing_add(b),
+                Operation::Sub(b) => self.overflowing_sub(b),
+                Operation::Mul(b) => self.overflowing_mul(b),
+                Operation::Div(b) if b != 0 => self.overflowing_div(b),
+                Operation::Rem(b) if b != 0 => self.overflowing_rem(b),
+                Operation::Shl(b) => self.overflowing_shl(b),
+                Operation::Pow(b) => self.overflowing_pow(b),
+                Operation::Neg => self.overflowing_neg(),
+                Operation::Abs => return self.abs_overflowing(),
+                Operation::Div(_) | Operation::Rem(_) => return None,
+            })
+        }
+    };
+}
+
+macro_rules! int_signed {
+    ($($ty:ident => $unsigned:ident),*) => {$(
+        impl Int for $ty {
+            int_common!($ty, $unsigned);
+
+            fn is_signed() -> bool {
+                true
+            }
+
+            fn checked(self, op: &Operation<$ty>) -> Option<$ty> {
+                match *op {
+                    Operation::Add(b) => self.checked_add(b),
+                    Operation::Sub(b) => self.checked_sub(b),
+                    Operation::Mul(b) => self.checked_mul(b),
+                    Operation::Div(b) => self.checked_div(b),
+                    Operation::Rem(b) => self.checked_rem(b),
+                    Operation::Shl(b) => self.checked_shl(b),
+                    Operation::Pow(b) => self.checked_pow(b),
+                    Operation::Neg => self.checked_neg(),
+                    Operation::Abs => self.checked_abs(),
+                }
+            }
+
+            fn saturating(self, op: &Operation<$ty>) -> Option<$ty> {
+                match *op {
+                    Operation::Add(b) => Some(self.saturating_add(b)),
+                    Operation::Sub(b) => Some(self.saturating_sub(b)),
+                    Operation::Mul(b) => Some(self.saturating_mul(b)),
+                    Operation::Div(b) if b != 0 => Some(self.saturating_div(b)),
+                    Operation::Pow(b) => Some(self.saturating_pow(b)),
+                    Operation::Neg => Some(self.saturating_neg()),
+                    Operation::Abs => Some(self.saturating_abs()),
+                    Operation::Div(_) | Operation::Rem(_) | Operation::Shl(_) => None,
+                }
+            }
+        }
+
+        impl Abs for $ty {
+            fn abs_wrapping(self) -> Option<$ty> {
+                Some(self.wrapping_abs())
+            }
+
+            fn abs_overflowing(self) -> Option<($ty, bool)> {
+                Some(self.overflowing_abs())
+            }
+        }
+    )*};
+}
+
+macro_rules! int_unsigned {
+    ($($ty:ident),*) => {$(
+        impl Int for $ty {
+            int_common!($ty, $ty);
+
+            fn is_signed() -> bool {
+                false
+            }
+
+            fn checked(self, op: &Operation<$ty>) -> Option<$ty> {
+                match *op {
+                    Operation::Add(b) => self.checked_add(b),
+                    Operation::Sub(b) => self.checked_sub(b),
+                    Operation::Mul(b) => self.checked_mul(b),
+                    Operation::Div(b) => self.checked_div(b),
+                    Operation::Rem(b) => self.checked_rem(b),
+                    Operation::Shl(b) => self.checked_shl(b),
+                    Operation::Pow(b) => self.checked_pow(b),
+                    Operation::Neg => self.checked_neg(),
+                    Operation::Abs => None,
+                }
+            }
+
+            fn saturating(self, op: &Operation<$ty>) -> Option<$ty> {
+                match *op {
+                    Operation::Add(b) => Some(self.saturating_add(b)),
+                    Operation::Sub(b) => Some(self.saturating_sub(b)),
+                    Operation::Mul(b) => Some(self.saturating_mul(b)),
+                    Operation::Div(b) if b != 0 => Some(self.saturating_div(b)),
+                    Operation::Pow(b) => Some(self.saturating_pow(b)),
+                    _ => None,
+                }
+            }
+        }
+
+        // Unsigned values are their own absolute value, so there is no abs
+        impl Abs for $ty {
+            fn abs_wrapping(self) -> Option<$ty> {
+                None
+            }
+
+            fn abs_overflowing(self) -> Option<($ty, bool)> {
+                None
+            }
+        }
+    )*};
+}
+
+// Lets int_common! handle abs the same way for signed and unsigned types
+trait Abs: Sized {
+    fn abs_wrapping(self) -> Option<Self>;
+    fn abs_overflowing(self) -> Option<(Self, bool)>;
+}
+
+int_signed!(i8 => u8, i16 => u16, i32 => u32, i64 => u64, i128 => u128, isize => usize);
+int_unsigned!(u8, u16, u32, u64, u128, usize);
+
+impl<T: Int> Operation<T> {
+    // Method suffix: add, sub, ..., neg, abs
+    pub fn name(&self) -> &'static str {
+        match self {
+            Operation::Add(_) => "add",
+            Operation::Sub(_) => "sub",
+            Operation::Mul(_) => "mul",
+            Operation::Div(_) => "div",
+            Operation::Rem(_) => "rem",
+            Operation::Shl(_) => "shl",
+            Operation::Pow(_) => "pow",
+            Operation::Neg => "neg",
+            Operation::Abs => "abs",
+        }
+    }
+
+    // The expression as written with an operator, if Rust has one
+    fn expression(&self, a: T) -> Option<String> {
+        Some(match self {
+            Operation::Add(b) => format!("{} + {}", a, b),
+            Operation::Sub(b) => format!("{} - {}", a, b),
+            Operation::Mul(b) => format!("{} * {}", a, b),
+            Operation::Div(b) => format!("{} / {}", a, b),
+            Operation::Rem(b) => format!("{} % {}", a, b),
+            Operation::Shl(b) => format!("{} << {}", a, b),
+            Operation::Neg if T::is_signed() => format!("-({})", a),
+            Operation::Pow(_) | Operation::Neg | Operation::Abs => return None,
+        })
+    }
+
+    // The message a debug build panics with when the operator overflows
+    fn panic_message(&self) -> &'static str {
+        match self {
+            Operation::Add(_) => "attempt to add with overflow",
+            Operation::Sub(_) => "attempt to subtract with overflow",
+            Operation::Mul(_) => "attempt to multiply with overflow",
+            Operation::Div(b) if b.is_zero() => "attempt to divide by zero",
+            Operation::Div(_) => "attempt to divide with overflow",
+            Operation::Rem(b) if b.is_zero() => {
+                "attempt to calculate the remainder with a divisor of zero"
+            }
+            Operation::Rem(_) => "attempt to calculate the remainder with overflow",
+            Operation::Shl(_) => "attempt to shift left with overflow",
+            Operation::Pow(_) => "attempt to multiply with overflow",
+            Operation::Neg => "attempt to negate with overflow",
+            Operation::Abs => "attempt to negate with overflow",
+        }
+    }
+
+    fn divides(&self) -> bool {
+        matches!(self, Operation::Div(_) | Operation::Rem(_))
+    }
+}
+
+// Every way of computing `a op b`, in the order the table shows them
+pub fn explore<T: Int>(a: T, op: &Operation<T>) -> Vec<Row<T>> {
+    let name = op.name();
+    let mut rows = Vec::new();
+    let checked = a.checked(op);
+
+    if let Some(expression) = op.expression(a) {
+        let debug = match checked {
+            Some(value) => Outcome::Value(value),
+            None => Outcome::Panics(op.panic_message()),
+        };
+        // Release builds skip overflow checks, but division is always checked
+        let release = match (checked, op.divides()) {
+            (None, true) => Outcome::Panics(op.panic_message()),
+            _ => a.wrapping(op).map_or(Outcome::Missing, Outcome::Value),
+        };
+        rows.push(Row {
+            method: format!("{} (debug)", expression),
+            outcome: debug,
+        });
+        rows.push(Row {
+            method: format!("{} (release)", expression),
+            outcome: release,
+        });
+    }
+
+    let unsupported = matches!(op, Operation::Abs) && !T::is_signed();
+    let division_by_zero = match op {
+        Operation::Div(b) | Operation::Rem(b) => b.is_zero(),
+        _ => false,
+    };
+    let missing_or_panic = || {
+        if division_by_zero {
+            Outcome::Panics(op.panic_message())
+        } else {
+            Outcome::Missing
+        }
+    };
+
+    rows.push(Row {
+        method: format!("checked_{}", name),
+        outcome: match checked {
+            _ if unsupported => Outcome::Missing,
+            Some(value) => Outcome::Value(value),
+            None => Outcome::Overflow,
+        },
+    });
+    rows.push(Row {
+        method: format!("wrapping_{}", name),
+        outcome: a.wrapping(op).map_or_else(missing_or_panic, Outcome::Value),
+    });
+    rows.push(Row {
+        method: format!("saturating_{}", name),
+        outcome: a.saturating(op).map_or_else(
+            || match op {
+                // saturating_div panics on zero, the others do not exist
+                Operation::Div(_) => missing_or_panic(),
+                _ => Outcome::Missing,
+            },
+            Outcome::Value,
+        ),
+    });
+    rows.push(Row {
+        method: format!("overflowing_{}", name),
+        outcome: a
+            .overflowing(op)
+            .map_or_else(missing_or_panic, |(value, wrapped)| {
+                Outcome::Flagged(value, wrapped)
+            }),
+    });
+    rows
+}
+
+// The explorer's output: the operands with their bits, then one line per row
+pub fn table<T: Int>(a: T, op: &Operation<T>) -> Vec<String> {
+    let mut lines = vec![format!("{} ({} bits)", T::NAME, T::BITS)];
+    // Wide enough for the longest i64, and for any operand actually given
+    let b = match op {
+        Operation::Add(b)
+        | Operation::Sub(b)
+        | Operation::Mul(b)
+        | Operation::Div(b)
+        | Operation::Rem(b) => Some(*b),
+        _ => None,
+    };
+    let width = [Some(a), b]
+        .iter()
+        .flatten()
+        .map(|value| value.to_string().len() + 4)
+        .fold(24, usize::max);
+    lines.push(format!("  a = {:<width$}{}", a, a.bits(), width = width));
+    match op {
+        Operation::Add(b)
+        | Operation::Sub(b)
+        | Operation::Mul(b)
+        | Operation::Div(b)
+        | Operation::Rem(b) => {
+            lines.push(format!("  b = {:<width$}{}", b, b.bits(), width = width))
+        }
+        Operation::Shl(b) | Operation::Pow(b) => lines.push(format!("  b = {} (u32)", b)),
+        Operation::Neg | Operation::Abs => {}
+    }
+    if matches!(op, Operation::Shl(_)) {
+        lines.push(format!(
+            "  shl overflows only when b >= {}; bits shifted out are simply lost",
+            T::BITS
+        ));
+    }
+    lines.push(String::new());
+
+    let rows = explore(a, op);
+    let width = rows.iter().map(|row| row.method.len()).max().unwrap_or(0) + 2;
+    let cells: Vec<(String, String, String)> = rows
+        .into_iter()
+        .map(|row| {
+            let (result, bits) = match row.outcome {
+                Outcome::Value(value) => (value.to_string(), value.bits()),
+                Outcome::Overflow => ("None".to_string(), String::new()),
+                Outcome::Flagged(value, wrapped) => {
+                    (format!("({}, {})", value, wrapped), value.bits())
+                }
+                Outcome::Panics(message) => (format!("panics: {}", message), String::new()),
+                Outcome::Missing => ("(no such method)".to_string(), String::new()),
+            };
+            (row.method, result, bits)
+        })
+        .collect();
+    // Only results followed by bits need to line up
+    let result_width = cells
+        .iter()
+        .filter(|(_, _, bits)| !bits.is_empty())
+        .map(|(_, result, _)| result.len() + 2)
+        .max()
+        .unwrap_or(0);
+    for (method, result, bits) in cells {
+        let line = format!(
+            "  {:<width$}{:<result_width$}{}",
+            method,
+            result,
+            bits,
+            width = width,
+            result_width = result_width
+        );
+        lines.push(line.trim_end().to_string());
+    }
+    lines
+}
+
+// Parses the operands for `T` and builds its table
+pub fn table_for<T: Int>(a: &str, op: &str, b: Option<&str>) -> Result<Vec<String>, OverflowError> {
+    let a = operand::<T>(a)?;
+    let op = parse_operation::<T>(op, b)?;
+    Ok(table(a, &op))
+}
+
+// `roadmap overflow <type> <a> <op> [<b>]`
+pub fn table_named(
+    ty: &str,
+    a: &str,
+    op: &str,
+    b: Option<&str>,
+) -> Result<Vec<String>, OverflowError> {
+    match ty {
+        "i8" => table_for::<i8>(a, op, b),
+        "i16" => table_for::<i16>(a, op, b),
+        "i32" => table_for::<i32>(a, op, b),
+        "i64" => table_for::<i64>(a, op, b),
+        "i128" => table_for::<i128>(a, op, b),
+        "isize" => table_for::<isize>(a, op, b),
+        "u8" => table_for::<u8>(a, op, b),
+        "u16" => table_for::<u16>(a, op, b),
+        "u32" => table_for::<u32>(a, op, b),
+        "u64" => table_for::<u64>(a, op, b),
+        "u128" => table_for::<u128>(a, op, b),
+        "usize" => table_for::<usize>(a, op, b),
+        _ => Err(OverflowError::UnknownType(ty.to_string())),
+    }
+}
+
+pub fn parse_operation<T: Int>(op: &str, b: Option<&str>) -> Result<Operation<T>, OverflowError> {
+    let needs_b = || b.ok_or_else(|| OverflowError::MissingOperand(op.to_string()));
+    Ok(match op {
+        "+" | "add" => Operation::Add(operand(needs_b()?)?),
+        "-" | "sub" => Operation::Sub(operand(needs_b()?)?),
+        "*" | "x" | "mul" => Operation::Mul(operand(needs_b()?)?),
+        "/" | "div" => Operation::Div(operand(needs_b()?)?),
+        "%" | "rem" => Operation::Rem(operand(needs_b()?)?),
+        "<<" | "shl" => Operation::Shl(operand(needs_b()?)?),
+        "pow" | "**" => Operation::Pow(operand(needs_b()?)?),
+        "neg" => Operation::Neg,
+        "abs" => Operation::Abs,
+        _ => return Err(OverflowError::UnknownOperation(op.to_string())),
+    })
+}
+
+// MIN and MAX are accepted as well as numbers
+fn operand<T: Int>(text: &str) -> Result<T, OverflowError> {
+    let bad = || OverflowError::BadOperand {
+        text: text.to_string(),
+        ty: T::NAME,
+    };
+    match text {
+        "MAX" | "max" => max::<T>().ok_or_else(bad),
+        "MIN" | "min" => min::<T>().ok_or_else(bad),
+        _ => text.replace('_', "").parse().map_err(|_| bad()),
+    }
+}
+
+// Found through the bit pattern so the trait needs no MIN/MAX constants
+fn max<T: Int>() -> Option<T> {
+    let digits = if T::is_signed() { T::BITS - 1 } else { T::BITS };
+    let max = if digits == 128 {
+        u128::MAX
+    } else {
+        (1u128 << digits) - 1
+    };
+    max.to_string().parse().ok()
+}
+
+fn min<T: Int>() -> Option<T> {
+    if !T::is_signed() {
+        return "0".parse().ok();
+    }
+    format!("-{}", 1u128 << (T::BITS - 1)).parse().ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn outcomes<T: Int>(a: T, op: Operation<T>) -> Vec<Outcome<T>> {
+        explore(a, &op).into_iter().map(|row| row.outcome).collect()
+    }
+
+    #[test]
+    fn addition_overflow_in_every_flavour() {
+        assert_eq!(
+            outcomes(100i8, Operation::Add(100)),
+            vec![
+                Outcome::Panics("attempt to add with overflow"),
+                Outcome::Value(-56),
+                Outcome::Overflow,
+                Outcome::Value(-56),
+                Outcome::Value(127),
+                Outcome::Flagged(-56, true),
+            ]
+        );
+        assert_eq!(
+            outcomes(u128::MAX, Operation::Add(0)),
+            vec![
+                Outcome::Value(u128::MAX),
+                Outcome::Value(u128::MAX),
+                Outcome::Value(u128::MAX),
+                Outcome::Value(u128::MAX),
+                Outcome::Value(u128::MAX),
+                Outcome::Flagged(u128::MAX, false),
+            ]
+        );
+    }
+
+    #[test]
+    fn division_panics_in_release_too() {
+        let rows = explore(i32::MIN, &Operation::Div(-1));
+        assert_eq!(rows[1].method, "-2147483648 / -1 (release)");
+        assert_eq!(
+            rows[1].outcome,
+            Outcome::Panics("attempt to divide with overflow")
+        );
+        assert_eq!(rows[3].outcome, Outcome::Value(i32::MIN));
+        assert_eq!(
+            outcomes(7u16, Operation::Rem(0))[3..],
+            [
+                Outcome::Panics("attempt to calculate the remainder with a divisor of zero"),
+                Outcome::Missing,
+                Outcome::Panics("attempt to calculate the remainder with a divisor of zero"),
+            ]
+        );
+    }
+
+    #[test]
+    fn abs_and_neg_depend_on_signedness() {
+        assert_eq!(
+            outcomes(i8::MIN, Operation::Abs),
+            vec![
+                Outcome::Overflow,
+                Outcome::Value(i8::MIN),
+                Outcome::Value(i8::MAX),
+                Outcome::Flagged(i8::MIN, true),
+            ]
+        );
+        assert!(
+            outcomes(5usize, Operation::Abs)
+                .iter()
+                .all(|outcome| *outcome == Outcome::Missing)
+        );
+        // `-x` does not compile for unsigned types, so there is no operator row
+        assert_eq!(explore(1u8, &Operation::Neg)[0].method, "checked_neg");
+    }
+
+    #[test]
+    fn parses_names_bounds_and_bit_patterns() {
+        let lines = table_named("i8", "MIN", "neg", None).unwrap();
+        assert_eq!(lines[1], "  a = -128                    10000000");
+        assert!(lines.iter().any(|line| line.contains("(-128, true)")));
+        assert_eq!(max::<u128>(), Some(u128::MAX));
+        assert_eq!(min::<i128>(), Some(i128::MIN));
+        assert_eq!(
+            table_named("i7", "1", "+", Some("1")).unwrap_err(),
+            OverflowError::UnknownType("i7".to_string())
+        );
+        assert_eq!(
+            table_named("u8", "256", "+", Some("1")).unwrap_err(),
+            OverflowError::BadOperand {
+                text: "256".to_string(),
+                ty: "u8"
+            }
+        );
+        assert_eq!(
+            table_named("u8", "1", "+", None).unwrap_err(),
+            OverflowError::MissingOperand("+".to_string())
+        );
+    }
+}
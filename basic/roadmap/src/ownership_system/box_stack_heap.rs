@@ -1,89 +1,115 @@
+use crate::layout::{self, type_layout};
+use crate::output;
+use crate::registry::{Difficulty, Lesson, Meta, Section};
+use crate::tracked::{Timeline, Tracked};
+use roadmap::list::{List as ConsList, PersistentList};
 use std::mem;
 
-fn main() {
-    println!("=== Box, Stack, and Heap ===\n");
-
-    // 1. Stack allocation basics
-    demonstrate_stack_allocation();
-
-    // 2. Heap allocation with Box
-    demonstrate_heap_allocation();
-
-    // 3. Stack vs Heap memory size
-    demonstrate_memory_sizes();
-
-    // 4. Box dereferencing
-    demonstrate_box_dereferencing();
-
-    // 5. When to use Box
-    demonstrate_when_to_use_box();
-
-    // 6. Stack frames and function calls
-    demonstrate_stack_frames();
-
-    // 7. Box and ownership
-    demonstrate_box_ownership();
-}
+pub const LESSON: Lesson = Lesson {
+    id: "box_stack_heap",
+    title: "Box, Stack, and Heap",
+    meta: Meta {
+        difficulty: Difficulty::Advanced,
+        minutes: 20,
+        book: Some("15.1"),
+        prerequisites: &["references_borrowing_slices"],
+        tags: &["ownership", "memory", "smart-pointers"],
+    },
+    sections: &[
+        Section::new("Stack Allocation Basics", demonstrate_stack_allocation),
+        Section::new("Heap Allocation with Box", demonstrate_heap_allocation),
+        Section::new("Stack vs Heap Memory Size", demonstrate_memory_sizes),
+        Section::new("Box Dereferencing", demonstrate_box_dereferencing),
+        Section::new("When to Use Box", demonstrate_when_to_use_box),
+        Section::new("Stack Frames and Function Calls", demonstrate_stack_frames),
+        Section::new("Box and Ownership", demonstrate_box_ownership),
+    ],
+};
 
 // 1. Stack allocation basics
 fn demonstrate_stack_allocation() {
-    println!("1. Stack Allocation Basics");
-    println!("   Values are stack allocated by default\n");
+    output::note("Values are stack allocated by default");
+    println!();
 
     let x = 5; // Allocated on stack
     let y = 10; // Allocated on stack
     let z = x + y; // Allocated on stack
 
-    println!("   x = {} (on stack)", x);
-    println!("   y = {} (on stack)", y);
-    println!("   z = {} (on stack)", z);
+    output::result(format!("x = {} (on stack)", x));
+    output::result(format!("y = {} (on stack)", y));
+    output::result(format!("z = {} (on stack)", z));
 
-    println!("\n   Stack allocation is:");
-    println!("   - Fast (just move stack pointer)");
-    println!("   - Automatic (compiler manages it)");
-    println!("   - Limited in size");
-    println!("   - LIFO (Last In, First Out)\n");
+    println!();
+    output::note("Stack allocation is:");
+    output::note(
+        "- Fast (just move stack pointer)\n\
+         - Automatic (compiler manages it)\n\
+         - Limited in size\n\
+         - LIFO (Last In, First Out)",
+    );
+    println!();
 }
 
 // 2. Heap allocation with Box
 fn demonstrate_heap_allocation() {
-    println!("2. Heap Allocation with Box");
-    println!("   Box<T> allocates data on the heap\n");
+    output::note("Box<T> allocates data on the heap");
+    println!();
 
     let x = 5; // Stack allocated
     let y = Box::new(5); // Heap allocated
 
-    println!("   x = {} (stack)", x);
-    println!("   y = {} (heap via Box)", y);
+    output::result(format!("x = {} (stack)", x));
+    output::result(format!("y = {} (heap via Box)", y));
 
     // Box with larger data
     let _large_array = Box::new([0; 1000]);
-    println!("   Large array allocated on heap");
+    output::note("Large array allocated on heap");
 
-    println!("\n   Heap allocation is:");
-    println!("   - Slower than stack");
-    println!("   - Manually requested (Box::new)");
-    println!("   - Unlimited in size");
-    println!("   - Can outlive function scope\n");
+    println!();
+    output::note("Heap allocation is:");
+    output::note(
+        "- Slower than stack\n\
+         - Manually requested (Box::new)\n\
+         - Unlimited in size\n\
+         - Can outlive function scope",
+    );
+    println!();
 }
 
-// 3. Stack vs Heap memory size
-fn demonstrate_memory_sizes() {
-    println!("3. Stack vs Heap Memory Size");
-    println!("   Box stores pointer on stack, data on heap\n");
+// The types measured in section 3, also registered with `roadmap layout`
+#[allow(dead_code)]
+#[derive(Debug, Clone, Copy)]
+pub struct Point {
+    pub x: f64,
+    pub y: f64,
+}
 
-    #[allow(dead_code)]
-    #[derive(Debug, Clone, Copy)]
-    struct Point {
-        x: f64,
-        y: f64,
-    }
+#[allow(dead_code)]
+pub struct Rectangle {
+    pub top_left: Point,
+    pub bottom_right: Point,
+}
 
-    #[allow(dead_code)]
-    struct Rectangle {
-        top_left: Point,
-        bottom_right: Point,
-    }
+// The same three fields, laid out by the compiler and in C order
+#[allow(dead_code)]
+pub struct Packet {
+    pub tag: u8,
+    pub length: u32,
+    pub checksum: u16,
+}
+
+#[allow(dead_code)]
+#[repr(C)]
+pub struct PacketC {
+    pub tag: u8,
+    pub length: u32,
+    pub checksum: u16,
+}
+
+// 3. Stack vs Heap memory size
+fn demonstrate_memory_sizes() {
+    output::note("Box stores pointer on stack, data on heap");
+    println!();
 
     // Stack allocated
     let point: Point = Point { x: 0.0, y: 0.0 };
@@ -99,35 +125,76 @@ fn demonstrate_memory_sizes() {
         bottom_right: Point { x: 3.0, y: -4.0 },
     });
 
-    println!("   Point occupies {} bytes on stack", mem::size_of_val(&point));
-    println!(
-        "   Rectangle occupies {} bytes on stack",
+    output::result(format!(
+        "Point occupies {} bytes on stack",
+        mem::size_of_val(&point)
+    ));
+    output::result(format!(
+        "Rectangle occupies {} bytes on stack",
         mem::size_of_val(&rectangle)
-    );
-    println!(
-        "   Boxed point occupies {} bytes on stack (just pointer)",
+    ));
+    output::result(format!(
+        "Boxed point occupies {} bytes on stack (just pointer)",
         mem::size_of_val(&boxed_point)
-    );
-    println!(
-        "   Boxed rectangle occupies {} bytes on stack (just pointer)",
+    ));
+    output::result(format!(
+        "Boxed rectangle occupies {} bytes on stack (just pointer)",
         mem::size_of_val(&boxed_rectangle)
-    );
+    ));
+
+    println!();
+    output::note("Box size = pointer size (8 bytes on 64-bit systems)");
+    output::note("Actual data lives on the heap");
+    println!();
 
-    println!("\n   Box size = pointer size (8 bytes on 64-bit systems)");
-    println!("   Actual data lives on the heap\n");
+    // Why the sizes come out as they do: field offsets and padding
+    output::note("Each field sits at an offset that is a multiple of its alignment");
+    let point_layout = type_layout!(Point { x, y });
+    let rectangle_layout = type_layout!(Rectangle {
+        top_left,
+        bottom_right
+    });
+    for inspected in [&point_layout, &rectangle_layout] {
+        for line in inspected.report() {
+            println!("   {}", line);
+        }
+        println!();
+    }
+
+    // Option<Box<T>> needs no tag: None is the null pointer a Box never holds
+    output::result(point_layout.niche_summary());
+    output::note("None is stored as the null pointer, a value no Box can have");
+    println!();
+
+    // The compiler may reorder fields to save padding; #[repr(C)] forbids it
+    output::note("Default layout vs #[repr(C)] for the same fields");
+    let packet = type_layout!(Packet {
+        tag,
+        length,
+        checksum
+    });
+    let packet_c = type_layout!(PacketC {
+        tag,
+        length,
+        checksum
+    });
+    for line in layout::side_by_side(&packet, &packet_c) {
+        println!("   {}", line);
+    }
+    println!();
 }
 
 // 4. Box dereferencing
 fn demonstrate_box_dereferencing() {
-    println!("4. Box Dereferencing");
-    println!("   Use * to access the value inside a Box\n");
+    output::note("Use * to access the value inside a Box");
+    println!();
 
     let boxed_value = Box::new(42);
-    println!("   boxed_value = {}", boxed_value);
+    output::result(format!("boxed_value = {}", boxed_value));
 
     // Dereference to get the value
     let unboxed_value = *boxed_value;
-    println!("   *boxed_value = {}", unboxed_value);
+    output::result(format!("*boxed_value = {}", unboxed_value));
 
     // Box with struct
     #[allow(dead_code)]
@@ -138,25 +205,27 @@ fn demonstrate_box_dereferencing() {
     }
 
     let boxed_point = Box::new(Point { x: 10, y: 20 });
-    println!("\n   boxed_point = {:?}", boxed_point);
+    println!();
+    output::result(format!("boxed_point = {:?}", boxed_point));
 
     let unboxed_point = *boxed_point;
-    println!("   *boxed_point = {:?}", unboxed_point);
+    output::result(format!("*boxed_point = {:?}", unboxed_point));
     println!();
 }
 
 // 5. When to use Box
 fn demonstrate_when_to_use_box() {
-    println!("5. When to Use Box");
-    println!("   Common use cases for heap allocation\n");
+    output::note("Common use cases for heap allocation");
+    println!();
 
     // Use case 1: Large data that would overflow the stack
-    println!("   Use Case 1: Large data");
+    output::note("Use Case 1: Large data");
     let large_data = Box::new([0u8; 1_000_000]);
-    println!("   Allocated 1MB array on heap");
+    output::note("Allocated 1MB array on heap");
 
     // Use case 2: Recursive types (like linked lists)
-    println!("\n   Use Case 2: Recursive types");
+    println!();
+    output::note("Use Case 2: Recursive types");
     #[allow(dead_code)]
     enum List {
         Cons(i32, Box<List>),
@@ -165,10 +234,38 @@ fn demonstrate_when_to_use_box() {
 
     use List::{Cons, Nil};
     let _list = Cons(1, Box::new(Cons(2, Box::new(Cons(3, Box::new(Nil))))));
-    println!("   Created recursive list: 1 -> 2 -> 3 -> Nil");
+    output::note("Created recursive list: 1 -> 2 -> 3 -> Nil");
+
+    // The same shape, made generic and usable, lives in src/list
+    // (`roadmap::list`): Option<Box<Node<T>>> is Cons/Nil
+    let mut list: ConsList<i32> = (1..=3).collect();
+    list.push(0);
+    output::result(format!("roadmap::list::List after push(0): {}", list));
+    list.reverse();
+    output::result(format!("reversed: {}", list));
+    let doubled = list.map(|n| n * 2).filter(|n| *n > 0);
+    output::result(format!("map(n * 2), filter(n > 0): {}", doubled));
+
+    // Dropping a million Boxes one inside the next would recurse a million
+    // times; List's Drop walks them in a loop instead
+    let long: ConsList<u32> = (0..1_000_000).collect();
+    output::result(format!("Built and dropped a list of {} nodes", long.len()));
+    drop(long);
+
+    // With Rc instead of Box, lists can share their tails
+    let tail: PersistentList<i32> = (2..=3).collect();
+    let a = tail.prepend(1);
+    let b = tail.prepend(0);
+    output::result(format!("Persistent a: {}", a));
+    output::result(format!("Persistent b: {}", b));
+    output::result(format!(
+        "a and b share their tail nodes: {}",
+        a.tail().ptr_eq(&b.tail())
+    ));
 
     // Use case 3: Trait objects (dynamic dispatch)
-    println!("\n   Use Case 3: Trait objects");
+    println!();
+    output::note("Use Case 3: Trait objects");
     trait Animal {
         fn make_sound(&self) -> &str;
     }
@@ -181,7 +278,7 @@ fn demonstrate_when_to_use_box() {
     }
 
     let animal: Box<dyn Animal> = Box::new(Dog);
-    println!("   Animal says: {}", animal.make_sound());
+    output::result(format!("Animal says: {}", animal.make_sound()));
 
     drop(large_data); // Explicitly drop to avoid unused warning
 
@@ -190,58 +287,66 @@ fn demonstrate_when_to_use_box() {
 
 // 6. Stack frames and function calls
 fn demonstrate_stack_frames() {
-    println!("6. Stack Frames and Function Calls");
-    println!("   Each function call creates a new stack frame\n");
+    output::note("Each function call creates a new stack frame");
+    println!();
 
     fn foo() {
         let y = 5;
         let z = 100;
-        println!("   Inside foo: y = {}, z = {}", y, z);
+        output::result(format!("Inside foo: y = {}, z = {}", y, z));
     }
 
     let x = 42;
-    println!("   In main: x = {}", x);
+    output::result(format!("In main: x = {}", x));
 
     foo();
 
-    println!("   Back in main: x = {}", x);
-    println!("\n   Stack frames are created and destroyed automatically");
-    println!("   LIFO order: Last In, First Out\n");
+    output::result(format!("Back in main: x = {}", x));
+    println!();
+    output::note("Stack frames are created and destroyed automatically");
+    output::note("LIFO order: Last In, First Out");
+    println!();
 }
 
 // 7. Box and ownership
 fn demonstrate_box_ownership() {
-    println!("7. Box and Ownership");
-    println!("   Box owns its data and cleans up when dropped\n");
+    output::note("Box owns its data and cleans up when dropped");
+    println!();
 
+    let _timeline = Timeline::start();
     {
-        let boxed = Box::new(String::from("Hello, heap!"));
-        println!("   Created: {}", boxed);
+        let boxed = Tracked::new("boxed", Box::new(String::from("Hello, heap!")));
+        output::result(format!("Created: {}", boxed));
     } // boxed goes out of scope, heap memory is freed
 
-    println!("   Box dropped, heap memory freed automatically");
+    output::note("Box dropped, heap memory freed automatically");
 
     // Moving a Box
-    println!("\n   Moving a Box:");
-    let box1 = Box::new(100);
-    println!("   box1 = {}", box1);
+    println!();
+    output::note("Moving a Box:");
+    let box1 = Tracked::new("box1", Box::new(100));
+    output::result(format!("box1 = {}", box1));
 
-    let box2 = box1; // Ownership moved
-    println!("   box2 = {} (ownership moved from box1)", box2);
-    // println!("   box1 = {}", box1); // Error! box1 no longer valid
+    let box2 = box1.moved_to("box2"); // Ownership moved
+    output::result(format!("box2 = {} (ownership moved from box1)", box2));
+    // output::result(format!("box1 = {}", box1)); // Error! box1 no longer valid
 
     // Passing Box to function
-    println!("\n   Passing Box to function:");
-    fn take_ownership(b: Box<i32>) {
-        println!("   Function received: {}", b);
+    println!();
+    output::note("Passing Box to function:");
+    fn take_ownership(b: Tracked<Box<i32>>) {
+        output::result(format!("Function received: {}", b));
     } // b is dropped here, heap memory freed
 
-    let box3 = Box::new(200);
-    take_ownership(box3);
-    // println!("   box3 = {}", box3); // Error! box3 was moved
+    let box3 = Tracked::new("box3", Box::new(200));
+    take_ownership(box3.moved_to("b"));
+    // output::result(format!("box3 = {}", box3)); // Error! box3 was moved
 
-    println!("\n   Box follows ownership rules:");
-    println!("   - One owner at a time");
-    println!("   - Automatic cleanup when owner goes out of scope");
-    println!("   - Prevents memory leaks and double-free errors\n");
-}
\ No newline at end of file
+    println!();
+    output::note("Box follows ownership rules:");
+    output::note(
+        "- One owner at a time\n\
+         - Automatic cleanup when owner goes out of scope\n\
+         - Prevents memory leaks and double-free errors",
+    );
+} // box2 is dropped last, after everything above
@@ -0,0 +1,18 @@
+// Ownership System
+// Ownership rules, borrowing, references, slices, Box and stack vs heap
+
+use crate::registry::Topic;
+
+pub mod box_stack_heap;
+pub mod ownership_rules_and_memory_safety;
+pub mod references_borrowing_slices;
+
+pub const TOPIC: Topic = Topic {
+    id: "ownership_system",
+    title: "Ownership System",
+    lessons: &[
+        ownership_rules_and_memory_safety::LESSON,
+        references_borrowing_slices::LESSON,
+        box_stack_heap::LESSON,
+    ],
+};
@@ -1,214 +1,235 @@
-fn main() {
-    println!("=== Ownership in Rust ===\n");
-
-    // 1. Variable scope
-    demonstrate_variable_scope();
-
-    // 2. The String type and heap allocation
-    demonstrate_string_type();
-
-    // 3. Move semantics
-    demonstrate_move_semantics();
-
-    // 4. Clone for deep copy
-    demonstrate_clone();
-
-    // 5. Copy trait for stack data
-    demonstrate_copy_trait();
-
-    // 6. Ownership and functions
-    demonstrate_ownership_and_functions();
-
-    // 7. Return values and ownership
-    demonstrate_return_values();
-
-    // 8. Scope and assignment
-    demonstrate_scope_and_assignment();
-}
+use crate::output;
+use crate::registry::{Difficulty, Lesson, Meta, Section};
+use crate::tracked::{Timeline, Tracked};
+
+pub const LESSON: Lesson = Lesson {
+    id: "ownership_rules_and_memory_safety",
+    title: "Ownership in Rust",
+    meta: Meta {
+        difficulty: Difficulty::Intermediate,
+        minutes: 25,
+        book: Some("4.1"),
+        prerequisites: &["syntax_and_semantics/functions", "data_structures/string"],
+        tags: &["ownership", "memory"],
+    },
+    sections: &[
+        Section::new("Variable Scope", demonstrate_variable_scope),
+        Section::new(
+            "The String Type and Heap Allocation",
+            demonstrate_string_type,
+        ),
+        Section::new("Move Semantics", demonstrate_move_semantics),
+        Section::new("Clone for Deep Copy", demonstrate_clone),
+        Section::new("Copy Trait for Stack Data", demonstrate_copy_trait),
+        Section::new(
+            "Ownership and Functions",
+            demonstrate_ownership_and_functions,
+        ),
+        Section::new("Return Values and Ownership", demonstrate_return_values),
+        Section::new("Scope and Assignment", demonstrate_scope_and_assignment),
+    ],
+};
 
 // 1. Variable scope
 fn demonstrate_variable_scope() {
-    println!("1. Variable Scope");
-    println!("   Variables are valid from declaration until end of scope\n");
+    output::note("Variables are valid from declaration until end of scope");
+    println!();
 
     {
         // s is not valid here, it's not yet declared
         let s = "hello"; // s is valid from this point forward
-        println!("   Inside scope: s = {}", s);
+        output::result(format!("Inside scope: s = {}", s));
         // do stuff with s
     } // this scope is now over, and s is no longer valid
 
-    println!("   Outside scope: s is no longer accessible\n");
+    output::note("Outside scope: s is no longer accessible");
+    println!();
 }
 
 // 2. The String type and heap allocation
 fn demonstrate_string_type() {
-    println!("2. The String Type and Heap Allocation");
-    println!("   String is allocated on the heap and can grow\n");
+    output::note("String is allocated on the heap and can grow");
+    println!();
 
     // String literal (immutable, on stack)
     let s1 = "hello";
-    println!("   String literal: {}", s1);
+    output::result(format!("String literal: {}", s1));
 
     // String type (mutable, on heap)
     let mut s2 = String::from("hello");
-    println!("   String before: {}", s2);
+    output::result(format!("String before: {}", s2));
 
     s2.push_str(", world!");
-    println!("   String after push_str: {}", s2);
+    output::result(format!("String after push_str: {}", s2));
 
-    println!("\n   String literals are immutable and fixed size");
-    println!("   String type is mutable and can grow\n");
+    println!();
+    output::note("String literals are immutable and fixed size");
+    output::note("String type is mutable and can grow");
+    println!();
 }
 
 // 3. Move semantics
 fn demonstrate_move_semantics() {
-    println!("3. Move Semantics");
-    println!("   Heap data is moved, not copied\n");
+    output::note("Heap data is moved, not copied");
+    println!();
 
     // Simple types (Copy trait) - copied
     let x = 5;
     let y = x;
-    println!("   Integers (Copy trait):");
-    println!("   x = {}, y = {}", x, y);
-    println!("   Both x and y are valid\n");
+    output::note("Integers (Copy trait):");
+    output::result(format!("x = {}, y = {}", x, y));
+    output::note("Both x and y are valid");
+    println!();
 
     // String (no Copy trait) - moved
-    let s1 = String::from("hello");
-    let s2 = s1; // s1 is moved to s2
-
-    println!("   Strings (no Copy trait):");
-    println!("   s2 = {}", s2);
-    // println!("   s1 = {}", s1); // Error! s1 is no longer valid
-    println!("   s1 is no longer valid after move\n");
+    // Tracked logs each step, so the single drop at the end is visible
+    output::note("Strings (no Copy trait):");
+    let _timeline = Timeline::start();
+    let s1 = Tracked::new("s1", String::from("hello"));
+    let s2 = s1.moved_to("s2"); // s1 is moved to s2
+
+    output::result(format!("s2 = {}", s2));
+    // output::result(format!("s1 = {}", s1)); // Error! s1 is no longer valid
+    // (E0382, see compile_fail/ownership_system/use_after_move.rs)
+    output::note("s1 is no longer valid after move");
+    println!();
 
-    println!("   Why? To prevent double-free errors!");
-    println!("   Only s2 will free the heap memory\n");
-}
+    output::note("Why? To prevent double-free errors!");
+    output::note("Only s2 will free the heap memory");
+} // s2 is dropped here, exactly once
 
 // 4. Clone for deep copy
 fn demonstrate_clone() {
-    println!("4. Clone for Deep Copy");
-    println!("   Use .clone() to deeply copy heap data\n");
+    output::note("Use .clone() to deeply copy heap data");
+    println!();
 
-    let s1 = String::from("hello");
-    let s2 = s1.clone(); // Deep copy of heap data
+    let _timeline = Timeline::start();
+    let s1 = Tracked::new("s1", String::from("hello"));
+    let s2 = s1.clone_as("s2"); // Deep copy of heap data
 
-    println!("   s1 = {}", s1);
-    println!("   s2 = {}", s2);
-    println!("   Both are valid because heap data was copied\n");
+    output::result(format!("s1 = {}", s1));
+    output::result(format!("s2 = {}", s2));
+    output::note("Both are valid because heap data was copied");
+    println!();
 
-    println!("   Note: clone() can be expensive for large data\n");
-}
+    output::note("Note: clone() can be expensive for large data");
+} // Two values, two drops: s2 first, then s1 (reverse declaration order)
 
 // 5. Copy trait for stack data
 fn demonstrate_copy_trait() {
-    println!("5. Copy Trait for Stack Data");
-    println!("   Types with Copy trait are copied, not moved\n");
+    output::note("Types with Copy trait are copied, not moved");
+    println!();
 
     // Types that implement Copy
     let x = 5; // i32
     let y = x;
-    println!("   Integers: x = {}, y = {}", x, y);
+    output::result(format!("Integers: x = {}, y = {}", x, y));
 
     let b1 = true; // bool
     let b2 = b1;
-    println!("   Booleans: b1 = {}, b2 = {}", b1, b2);
+    output::result(format!("Booleans: b1 = {}, b2 = {}", b1, b2));
 
     let c1 = 'a'; // char
     let c2 = c1;
-    println!("   Characters: c1 = {}, c2 = {}", c1, c2);
+    output::result(format!("Characters: c1 = {}, c2 = {}", c1, c2));
 
     let t1 = (1, 2); // tuple of Copy types
     let t2 = t1;
-    println!("   Tuples: t1 = {:?}, t2 = {:?}", t1, t2);
-
-    println!("\n   Types that implement Copy:");
-    println!("   - All integer types (i32, u64, etc.)");
-    println!("   - Boolean (bool)");
-    println!("   - Floating point (f32, f64)");
-    println!("   - Character (char)");
-    println!("   - Tuples of Copy types\n");
+    output::result(format!("Tuples: t1 = {:?}, t2 = {:?}", t1, t2));
+
+    println!();
+    output::note("Types that implement Copy:");
+    output::note(
+        "- All integer types (i32, u64, etc.)\n\
+         - Boolean (bool)\n\
+         - Floating point (f32, f64)\n\
+         - Character (char)\n\
+         - Tuples of Copy types",
+    );
+    println!();
 }
 
 // 6. Ownership and functions
 fn demonstrate_ownership_and_functions() {
-    println!("6. Ownership and Functions");
-    println!("   Passing values to functions moves or copies them\n");
+    output::note("Passing values to functions moves or copies them");
+    println!();
 
-    let s = String::from("hello"); // s comes into scope
-    println!("   Before function: s = {}", s);
+    let timeline = Timeline::start();
+    let s = Tracked::new("s", String::from("hello")); // s comes into scope
+    output::result(format!("Before function: s = {}", s));
 
-    takes_ownership(s); // s's value moves into the function
-                        // s is no longer valid here
-    // println!("   After function: s = {}", s); // Error!
+    takes_ownership(s.moved_to("some_string")); // s's value moves into the function
+    // s is no longer valid here
+    // output::result(format!("After function: s = {}", s)); // Error!
+    // (E0382, see compile_fail/ownership_system/use_after_move_into_function.rs)
 
     let x = 5; // x comes into scope
-    println!("   Before function: x = {}", x);
+    output::result(format!("Before function: x = {}", x));
 
     makes_copy(x); // x is copied into the function
-    println!("   After function: x = {}", x); // x is still valid
-
-    println!();
+    output::result(format!("After function: x = {}", x)); // x is still valid
+    drop(timeline);
 }
 
-fn takes_ownership(some_string: String) {
-    println!("   Inside takes_ownership: {}", some_string);
+fn takes_ownership(some_string: Tracked<String>) {
+    output::result(format!("Inside takes_ownership: {}", some_string));
 } // some_string goes out of scope and drop is called
 
 fn makes_copy(some_integer: i32) {
-    println!("   Inside makes_copy: {}", some_integer);
+    output::result(format!("Inside makes_copy: {}", some_integer));
 } // some_integer goes out of scope, nothing special happens
 
 // 7. Return values and ownership
 fn demonstrate_return_values() {
-    println!("7. Return Values and Ownership");
-    println!("   Returning values transfers ownership\n");
+    output::note("Returning values transfers ownership");
+    println!();
 
-    let s1 = gives_ownership(); // gives_ownership moves its return value into s1
-    println!("   s1 from gives_ownership: {}", s1);
+    let _timeline = Timeline::start();
+    let s1 = gives_ownership().moved_to("s1"); // gives_ownership moves its return value into s1
+    output::result(format!("s1 from gives_ownership: {}", s1));
 
-    let s2 = String::from("hello"); // s2 comes into scope
-    println!("   s2 created: {}", s2);
+    let s2 = Tracked::new("s2", String::from("hello")); // s2 comes into scope
+    output::result(format!("s2 created: {}", s2));
 
-    let s3 = takes_and_gives_back(s2); // s2 is moved into function, which moves its return value into s3
-    println!("   s3 from takes_and_gives_back: {}", s3);
-    // println!("   s2 = {}", s2); // Error! s2 was moved
+    // s2 is moved into function, which moves its return value into s3
+    let s3 = takes_and_gives_back(s2.moved_to("a_string")).moved_to("s3");
+    output::result(format!("s3 from takes_and_gives_back: {}", s3));
+    // output::result(format!("s2 = {}", s2)); // Error! s2 was moved
+} // s3 and s1 are dropped; the value s2 held is freed as s3
 
-    println!();
-}
-
-fn gives_ownership() -> String {
-    let some_string = String::from("yours");
+fn gives_ownership() -> Tracked<String> {
+    let some_string = Tracked::new("some_string", String::from("yours"));
     some_string // returned and moves out to calling function
 }
 
-fn takes_and_gives_back(a_string: String) -> String {
+fn takes_and_gives_back(a_string: Tracked<String>) -> Tracked<String> {
     a_string // returned and moves out to calling function
 }
 
 // 8. Scope and assignment
 fn demonstrate_scope_and_assignment() {
-    println!("8. Scope and Assignment");
-    println!("   Assigning new value drops the old value\n");
+    output::note("Assigning new value drops the old value");
+    println!();
 
-    let mut s = String::from("hello");
-    println!("   Initial value: {}", s);
+    let _timeline = Timeline::start();
+    let mut s = Tracked::new("s", String::from("hello"));
+    output::result(format!("Initial value: {}", s));
 
-    s = String::from("ahoy"); // Old "hello" is dropped immediately
-    println!("   After reassignment: {}", s);
+    s = Tracked::new("s", String::from("ahoy")); // Old "hello" is dropped immediately
+    output::result(format!("After reassignment: {}", s));
 
-    println!("\n   The old value's memory is freed immediately\n");
+    println!();
+    output::note("The old value's memory is freed immediately");
+    println!();
 
     // Demonstrating with a function that returns ownership
-    let s1 = String::from("hello");
-    let len = calculate_length_with_tuple(s1);
-    println!("   Using tuple to return ownership:");
-    println!("   String: {}, Length: {}", len.0, len.1);
-    println!();
+    let s1 = Tracked::new("s1", String::from("hello"));
+    let len = calculate_length_with_tuple(s1.moved_to("s"));
+    output::note("Using tuple to return ownership:");
+    output::result(format!("String: {}, Length: {}", len.0, len.1));
 }
 
-fn calculate_length_with_tuple(s: String) -> (String, usize) {
+fn calculate_length_with_tuple(s: Tracked<String>) -> (Tracked<String>, usize) {
     let length = s.len();
-    (s, length) // Return both the String and its length
-}
\ No newline at end of file
+    (s.moved_to("len.0"), length) // Return both the String and its length
+}
@@ -1,48 +1,52 @@
-fn main() {
-    println!("=== References, Borrowing, and Slices ===\n");
-
-    // 1. Basic references (immutable borrowing)
-    demonstrate_basic_references();
-
-    // 2. Mutable references
-    demonstrate_mutable_references();
-
-    // 3. Rules of references
-    demonstrate_reference_rules();
-
-    // 4. Dangling references prevention
-    demonstrate_no_dangling_references();
-
-    // 5. String slices
-    demonstrate_string_slices();
-
-    // 6. Array slices
-    demonstrate_array_slices();
-
-    // 7. Practical examples
-    demonstrate_practical_examples();
-}
+use crate::output;
+use crate::registry::{Difficulty, Lesson, Meta, Section};
+use roadmap::text::{self, LineIndex};
+
+pub const LESSON: Lesson = Lesson {
+    id: "references_borrowing_slices",
+    title: "References, Borrowing, and Slices",
+    meta: Meta {
+        difficulty: Difficulty::Intermediate,
+        minutes: 25,
+        book: Some("4.2"),
+        prerequisites: &["ownership_rules_and_memory_safety"],
+        tags: &["ownership", "borrowing", "slices"],
+    },
+    sections: &[
+        Section::new(
+            "Basic References (Immutable Borrowing)",
+            demonstrate_basic_references,
+        ),
+        Section::new("Mutable References", demonstrate_mutable_references),
+        Section::new("Rules of References", demonstrate_reference_rules),
+        Section::new("No Dangling References", demonstrate_no_dangling_references),
+        Section::new("String Slices", demonstrate_string_slices),
+        Section::new("Array Slices", demonstrate_array_slices),
+        Section::new("Practical Examples", demonstrate_practical_examples),
+    ],
+};
 
 // 1. Basic references (immutable borrowing)
 fn demonstrate_basic_references() {
-    println!("1. Basic References (Immutable Borrowing)");
-    println!("   References let you refer to a value without taking ownership\n");
+    output::note("References let you refer to a value without taking ownership");
+    println!();
 
     let s1 = String::from("hello");
 
     // Pass a reference instead of moving ownership
     let len = calculate_length(&s1);
 
-    println!("   The length of '{}' is {}.", s1, len);
-    println!("   s1 is still valid after the function call!\n");
+    output::result(format!("The length of '{}' is {}.", s1, len));
+    output::note("s1 is still valid after the function call!");
+    println!();
 
     // Multiple immutable references are allowed
     let r1 = &s1;
     let r2 = &s1;
     let r3 = &s1;
 
-    println!("   Multiple immutable references:");
-    println!("   r1: {}, r2: {}, r3: {}", r1, r2, r3);
+    output::note("Multiple immutable references:");
+    output::result(format!("r1: {}, r2: {}, r3: {}", r1, r2, r3));
     println!();
 }
 
@@ -52,16 +56,18 @@ fn calculate_length(s: &String) -> usize {
 
 // 2. Mutable references
 fn demonstrate_mutable_references() {
-    println!("2. Mutable References");
-    println!("   Mutable references allow you to modify borrowed data\n");
+    output::note("Mutable references allow you to modify borrowed data");
+    println!();
 
     let mut s = String::from("hello");
-    println!("   Before: {}", s);
+    output::result(format!("Before: {}", s));
 
     change(&mut s);
-    println!("   After: {}", s);
+    output::result(format!("After: {}", s));
 
-    println!("\n   Note: You can only have ONE mutable reference at a time\n");
+    println!();
+    output::note("Note: You can only have ONE mutable reference at a time");
+    println!();
 }
 
 fn change(some_string: &mut String) {
@@ -70,8 +76,8 @@ fn change(some_string: &mut String) {
 
 // 3. Rules of references
 fn demonstrate_reference_rules() {
-    println!("3. Rules of References");
-    println!("   Rust enforces strict borrowing rules at compile time\n");
+    output::note("Rust enforces strict borrowing rules at compile time");
+    println!();
 
     let mut s = String::from("hello");
 
@@ -79,48 +85,55 @@ fn demonstrate_reference_rules() {
     {
         let r1 = &s;
         let r2 = &s;
-        println!("   Multiple immutable refs: {} and {}", r1, r2);
+        output::result(format!("Multiple immutable refs: {} and {}", r1, r2));
     }
 
     // Rule 2: Only one mutable reference at a time
     {
         let r1 = &mut s;
         r1.push_str("!");
-        println!("   One mutable ref: {}", r1);
+        output::result(format!("One mutable ref: {}", r1));
         // let r2 = &mut s; // Error! Can't have two mutable refs
+        // (compile_fail/ownership_system/two_mutable_borrows.rs, E0499)
     }
 
     // Rule 3: Can't mix mutable and immutable references
     {
         let r1 = &s; // immutable
         let r2 = &s; // immutable
-        println!("   Immutable refs: {} and {}", r1, r2);
-        // r1 and r2 are no longer used after this point
+        output::result(format!("Immutable refs: {} and {}", r1, r2));
+        // r1 and r2 are no longer used after this point; using them after
+        // r3 is E0502 (compile_fail/ownership_system/mutable_while_borrowed.rs)
 
         let r3 = &mut s; // mutable - OK because r1 and r2 are done
         r3.push_str(" world");
-        println!("   Mutable ref: {}", r3);
+        output::result(format!("Mutable ref: {}", r3));
     }
 
-    println!("\n   Reference Rules:");
-    println!("   1. Any number of immutable references");
-    println!("   2. OR exactly one mutable reference");
-    println!("   3. References must always be valid\n");
+    println!();
+    output::note("Reference Rules:");
+    output::note("1. Any number of immutable references");
+    output::note("2. OR exactly one mutable reference");
+    output::note("3. References must always be valid");
+    println!();
 }
 
 // 4. Dangling references prevention
 fn demonstrate_no_dangling_references() {
-    println!("4. No Dangling References");
-    println!("   Rust prevents dangling references at compile time\n");
+    output::note("Rust prevents dangling references at compile time");
+    println!();
 
     let reference = no_dangle();
-    println!("   Valid reference: {}", reference);
+    output::result(format!("Valid reference: {}", reference));
 
-    println!("\n   Rust won't let you return a reference to local data");
-    println!("   Instead, return the owned value\n");
+    println!();
+    output::note("Rust won't let you return a reference to local data");
+    output::note("Instead, return the owned value");
+    println!();
 }
 
 // This would cause an error if we tried to return &String
+// (E0106, see compile_fail/ownership_system/dangling_reference.rs)
 // fn dangle() -> &String {
 //     let s = String::from("hello");
 //     &s // Error! s will be dropped, leaving a dangling reference
@@ -134,8 +147,8 @@ fn no_dangle() -> String {
 
 // 5. String slices
 fn demonstrate_string_slices() {
-    println!("5. String Slices");
-    println!("   Slices let you reference a contiguous sequence of elements\n");
+    output::note("Slices let you reference a contiguous sequence of elements");
+    println!();
 
     let s = String::from("hello world");
 
@@ -144,19 +157,30 @@ fn demonstrate_string_slices() {
     let world = &s[6..11]; // or &s[6..]
     let whole = &s[..]; // entire string
 
-    println!("   Original: {}", s);
-    println!("   First word: {}", hello);
-    println!("   Second word: {}", world);
-    println!("   Whole string: {}", whole);
+    output::result(format!("Original: {}", s));
+    output::result(format!("First word: {}", hello));
+    output::result(format!("Second word: {}", world));
+    output::result(format!("Whole string: {}", whole));
 
     // Using first_word function
     let s = String::from("hello world");
     let word = first_word(&s);
-    println!("\n   First word using function: {}", word);
+    println!();
+    output::result(format!("First word using function: {}", word));
+
+    // The byte loop only stops at ' '. roadmap::text::first_word also splits
+    // on tabs, newlines and punctuation, and still returns a slice of s
+    let s = String::from("hello,\tworld");
+    output::result(format!("first_word({:?}) = {:?}", s, first_word(&s)));
+    output::result(format!(
+        "text::first_word({:?}) = {:?}",
+        s,
+        text::first_word(&s)
+    ));
 
     // String literals are slices
     let s = "Hello, world!"; // type is &str
-    println!("   String literal (already a slice): {}", s);
+    output::result(format!("String literal (already a slice): {}", s));
 
     println!();
 }
@@ -175,23 +199,24 @@ fn first_word(s: &str) -> &str {
 
 // 6. Array slices
 fn demonstrate_array_slices() {
-    println!("6. Array Slices");
-    println!("   Slices work with arrays too\n");
+    output::note("Slices work with arrays too");
+    println!();
 
     let a = [1, 2, 3, 4, 5];
 
     let slice = &a[1..3];
 
-    println!("   Original array: {:?}", a);
-    println!("   Slice [1..3]: {:?}", slice);
-    println!("   Slice type: &[i32]\n");
+    output::result(format!("Original array: {:?}", a));
+    output::result(format!("Slice [1..3]: {:?}", slice));
+    output::note("Slice type: &[i32]");
+    println!();
 
     // Using slices in functions
     let sum = sum_slice(&a[..]);
-    println!("   Sum of entire array: {}", sum);
+    output::result(format!("Sum of entire array: {}", sum));
 
     let sum = sum_slice(&a[1..4]);
-    println!("   Sum of slice [1..4]: {}", sum);
+    output::result(format!("Sum of slice [1..4]: {}", sum));
 
     println!();
 }
@@ -206,40 +231,53 @@ fn sum_slice(slice: &[i32]) -> i32 {
 
 // 7. Practical examples
 fn demonstrate_practical_examples() {
-    println!("7. Practical Examples\n");
-
     // Example 1: Avoiding unnecessary clones
-    println!("   Example 1: Efficient string processing");
+    output::note("Example 1: Efficient string processing");
     let text = String::from("The quick brown fox");
-    let word_count = count_words(&text);
-    println!("   '{}' has {} words", text, word_count);
-    println!("   (text is still usable because we borrowed it)\n");
+    let word_count = text::count_words(&text);
+    output::result(format!("'{}' has {} words", text, word_count));
+    output::note("(text is still usable because we borrowed it)");
+    println!();
 
     // Example 2: Modifying data in place
-    println!("   Example 2: Modifying data in place");
+    output::note("Example 2: Modifying data in place");
     let mut numbers = vec![1, 2, 3, 4, 5];
-    println!("   Before: {:?}", numbers);
+    output::result(format!("Before: {:?}", numbers));
     double_values(&mut numbers);
-    println!("   After doubling: {:?}", numbers);
+    output::result(format!("After doubling: {:?}", numbers));
     println!();
 
     // Example 3: Safe string manipulation
-    println!("   Example 3: Safe string manipulation");
+    output::note("Example 3: Safe string manipulation");
     let mut message = String::from("Hello");
     append_exclamation(&mut message);
-    println!("   Message: {}", message);
+    output::result(format!("Message: {}", message));
     println!();
 
     // Example 4: Working with slices
-    println!("   Example 4: Finding longest word");
+    output::note("Example 4: Finding longest word");
     let sentence = "Rust is a systems programming language";
-    let longest = find_longest_word(sentence);
-    println!("   Longest word in '{}': {}", sentence, longest);
+    if let Some(longest) = text::longest_word(sentence) {
+        output::result(format!("Longest word in '{}': {}", sentence, longest));
+    }
     println!();
-}
 
-fn count_words(s: &str) -> usize {
-    s.split_whitespace().count()
+    // Example 5: Words are slices of the document, so nothing is copied
+    output::note("Example 5: Words borrowed from a document");
+    let document = String::from("Slices borrow.\nSlices don't copy;\tthey point into the String.");
+    let index = LineIndex::new(&document);
+    for (word, count) in text::frequencies(&document).into_iter().take(2) {
+        output::result(format!("'{}' appears {} time(s)", word, count));
+    }
+    for word in text::words(&document).filter(|&word| word == "Slices") {
+        if let Some(at) = index.position_of(word) {
+            output::result(format!(
+                "'Slices' at line {}, column {}",
+                at.line, at.column
+            ));
+        }
+    }
+    println!();
 }
 
 fn double_values(vec: &mut Vec<i32>) {
@@ -251,13 +289,3 @@ fn double_values(vec: &mut Vec<i32>) {
 fn append_exclamation(s: &mut String) {
     s.push_str("!!!");
 }
-
-fn find_longest_word(s: &str) -> &str {
-    let mut longest = "";
-    for word in s.split_whitespace() {
-        if word.len() > longest.len() {
-            longest = word;
-        }
-    }
-    longest
-}
\ No newline at end of file
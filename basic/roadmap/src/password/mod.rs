@@ -0,0 +1,495 @@
+// Password Strength
+// `is_strong_password` in the boolean lesson asks four yes/no questions and
+// calls "Password123" strong. This estimates how many guesses a password
+// takes instead. Every char costs log2(pool) bits, where the pool is the
+// character classes the password uses, unless it is part of a pattern an
+// attacker tries first, which costs far less:
+//
+//   dictionary word   log2(rank in words.txt), more for capitals and l33t
+//   keyboard walk     "qwerty", "1qaz": a start key and a direction
+//   repeat            "aaaa", "abcabc": the unit once, plus the count
+//   sequence          "abcd", "9876": a start char and a direction
+//
+// The cheapest way to cover the whole password with patterns and single
+// chars is its entropy in bits. Lengths are counted in chars, not bytes.
+
+use std::fmt;
+
+const WORDS: &str = include_str!("words.txt");
+
+// Bits below which a password gets each score, from 0 (very weak) up
+const THRESHOLDS: [f64; 4] = [28.0, 36.0, 60.0, 80.0];
+const LABELS: [&str; 5] = ["very weak", "weak", "fair", "strong", "very strong"];
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum Kind {
+    Dictionary { word: &'static str, l33t: bool },
+    KeyboardWalk,
+    Repeat { unit: String, count: usize },
+    Sequence,
+}
+
+// A pattern found in the password, over chars `start..end`
+#[derive(Debug, Clone, PartialEq)]
+pub struct Match {
+    pub kind: Kind,
+    pub start: usize,
+    pub end: usize,
+    pub text: String,
+    pub bits: f64,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Strength {
+    pub chars: usize,
+    pub entropy: f64,
+    pub score: usize,
+    // The patterns the estimate used, left to right
+    pub matches: Vec<Match>,
+    pub feedback: Vec<String>,
+}
+
+impl Strength {
+    pub fn label(&self) -> &'static str {
+        LABELS[self.score]
+    }
+
+    pub fn is_strong(&self) -> bool {
+        self.score >= 3
+    }
+}
+
+impl fmt::Display for Strength {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{} ({}/4, {:.0} bits)",
+            self.label(),
+            self.score,
+            self.entropy
+        )
+    }
+}
+
+pub fn estimate(password: &str) -> Strength {
+    let chars: Vec<char> = password.chars().collect();
+    let per_char = pool_size(&chars).log2();
+    let mut found = dictionary(&chars);
+    found.extend(keyboard_walks(&chars));
+    found.extend(repeats(&chars, per_char));
+    found.extend(sequences(&chars));
+
+    // Matches by the position they end at, so each is looked at once
+    let mut ending: Vec<Vec<usize>> = vec![Vec::new(); chars.len() + 1];
+    for (index, found) in found.iter().enumerate() {
+        ending[found.end].push(index);
+    }
+    // best[i]: the fewest bits that cover chars[..i], and how the cover ends
+    let mut best: Vec<(f64, Option<usize>)> = vec![(0.0, None); chars.len() + 1];
+    for end in 1..=chars.len() {
+        best[end] = (best[end - 1].0 + per_char, None);
+        for &index in &ending[end] {
+            let bits = best[found[index].start].0 + found[index].bits;
+            if bits < best[end].0 {
+                best[end] = (bits, Some(index));
+            }
+        }
+    }
+    let mut matches = Vec::new();
+    let mut end = chars.len();
+    while end > 0 {
+        match best[end].1 {
+            Some(index) => {
+                matches.push(found[index].clone());
+                end = found[index].start;
+            }
+            None => end -= 1,
+        }
+    }
+    matches.reverse();
+
+    let entropy = best[chars.len()].0;
+    let mut score = THRESHOLDS.iter().filter(|&&bits| entropy >= bits).count();
+    // However random, a short password falls to brute force
+    if chars.len() < 8 {
+        score = score.min(1);
+    }
+    Strength {
+        chars: chars.len(),
+        entropy,
+        score,
+        feedback: feedback(&chars, &matches, score),
+        matches,
+    }
+}
+
+// Whether a char belongs to a class, and how many chars the class has
+type Class = (fn(&char) -> bool, f64);
+
+// How many different chars an attacker must try per position
+fn pool_size(chars: &[char]) -> f64 {
+    let classes: [Class; 5] = [
+        (char::is_ascii_lowercase, 26.0),
+        (char::is_ascii_uppercase, 26.0),
+        (char::is_ascii_digit, 10.0),
+        (|c| c.is_ascii() && !c.is_ascii_alphanumeric(), 33.0),
+        (|c| !c.is_ascii(), 100.0),
+    ];
+    let pool: f64 = classes
+        .iter()
+        .filter(|(is_in, _)| chars.iter().any(is_in))
+        .map(|(_, size)| size)
+        .sum();
+    pool.max(1.0)
+}
+
+// Common l33t substitutions, undone before looking words up
+fn unl33t(c: char) -> char {
+    match c {
+        '4' | '@' => 'a',
+        '8' => 'b',
+        '3' => 'e',
+        '6' | '9' => 'g',
+        '1' | '!' | '|' => 'i',
+        '0' => 'o',
+        '5' | '$' => 's',
+        '7' | '+' => 't',
+        '2' => 'z',
+        other => other.to_ascii_lowercase(),
+    }
+}
+
+fn dictionary(chars: &[char]) -> Vec<Match> {
+    let plain: Vec<char> = chars.iter().map(|c| c.to_ascii_lowercase()).collect();
+    let decoded: Vec<char> = chars.iter().map(|&c| unl33t(c)).collect();
+    let words = WORDS
+        .lines()
+        .filter(|line| !line.is_empty() && !line.starts_with('#'));
+
+    let mut matches = Vec::new();
+    for (rank, word) in words.enumerate() {
+        let word_chars: Vec<char> = word.chars().collect();
+        let len = word_chars.len();
+        for start in 0..chars.len().saturating_sub(len - 1) {
+            let end = start + len;
+            let l33t = if plain[start..end] == word_chars[..] {
+                false
+            } else if decoded[start..end] == word_chars[..] {
+                true
+            } else {
+                continue;
+            };
+            let text: String = chars[start..end].iter().collect();
+            let mut bits = ((rank + 1) as f64).log2();
+            // Which letters are capitalised: none, the first, all, or any mix
+            let upper = text.chars().filter(char::is_ascii_uppercase).count();
+            if upper == len || (upper == 1 && chars[start].is_ascii_uppercase()) {
+                bits += 1.0;
+            } else if upper > 0 {
+                bits += len as f64;
+            }
+            if l33t {
+                bits += 2.0;
+            }
+            matches.push(Match {
+                kind: Kind::Dictionary { word, l33t },
+                start,
+                end,
+                text,
+                bits,
+            });
+        }
+    }
+    matches
+}
+
+// US QWERTY rows, shifted then unshifted, aligned so `1qaz` is a column
+const ROWS: [(&str, &str); 4] = [
+    ("~!@#$%^&*()_+", "`1234567890-="),
+    ("\0QWERTYUIOP{}|", "\0qwertyuiop[]\\"),
+    ("\0ASDFGHJKL:\"", "\0asdfghjkl;'"),
+    ("\0ZXCVBNM<>?", "\0zxcvbnm,./"),
+];
+
+fn key_position(c: char) -> Option<(i32, i32)> {
+    ROWS.iter().enumerate().find_map(|(row, (shifted, plain))| {
+        plain
+            .chars()
+            .position(|key| key == c)
+            .or_else(|| shifted.chars().position(|key| key == c))
+            .map(|column| (row as i32, column as i32))
+    })
+}
+
+// Runs of 4 or more keys in a straight line along a row or down a column
+fn keyboard_walks(chars: &[char]) -> Vec<Match> {
+    let positions: Vec<Option<(i32, i32)>> = chars.iter().map(|&c| key_position(c)).collect();
+    let step = |i: usize| match (positions[i], positions[i + 1]) {
+        (Some((r1, c1)), Some((r2, c2))) if (r2 - r1).abs() + (c2 - c1).abs() == 1 => {
+            Some((r2 - r1, c2 - c1))
+        }
+        _ => None,
+    };
+    runs(chars, 4, step, |_| 4.0 + 47f64.log2(), Kind::KeyboardWalk)
+}
+
+// Runs of 3 or more chars counting up or down by one: "abc", "4321"
+fn sequences(chars: &[char]) -> Vec<Match> {
+    let step = |i: usize| {
+        let (a, b) = (chars[i], chars[i + 1]);
+        let same_class = (a.is_ascii_digit() && b.is_ascii_digit())
+            || (a.is_ascii_lowercase() && b.is_ascii_lowercase())
+            || (a.is_ascii_uppercase() && b.is_ascii_uppercase());
+        match b as i32 - a as i32 {
+            delta @ (1 | -1) if same_class => Some((delta, 0)),
+            _ => None,
+        }
+    };
+    let bits = |text: &str| {
+        let first = text.chars().next().unwrap_or('a');
+        let start = if first.is_ascii_digit() { 10f64 } else { 26f64 };
+        start.log2() + 1.0 + (text.chars().count() as f64).log2()
+    };
+    runs(chars, 3, step, bits, Kind::Sequence)
+}
+
+// The longest stretches where every step between neighbours is the same
+fn runs(
+    chars: &[char],
+    min: usize,
+    step: impl Fn(usize) -> Option<(i32, i32)>,
+    bits: impl Fn(&str) -> f64,
+    kind: Kind,
+) -> Vec<Match> {
+    let mut matches = Vec::new();
+    let mut start = 0;
+    while start + 1 < chars.len() {
+        let Some(first) = step(start) else {
+            start += 1;
+            continue;
+        };
+        let mut end = start + 2;
+        while end < chars.len() && step(end - 1) == Some(first) {
+            end += 1;
+        }
+        if end - start >= min {
+            let text: String = chars[start..end].iter().collect();
+            matches.push(Match {
+                kind: kind.clone(),
+                start,
+                end,
+                bits: bits(&text),
+                text,
+            });
+        }
+        start = end - 1;
+    }
+    matches
+}
+
+// Longest repeated unit looked for; longer ones are left to the other
+// patterns, which keeps the search linear in the password's length
+const MAX_UNIT: usize = 32;
+
+// Is `unit` itself a shorter unit repeated, like "abab"?
+fn is_repeated(unit: &[char]) -> bool {
+    (1..unit.len())
+        .filter(|&period| unit.len().is_multiple_of(period))
+        .any(|period| (period..unit.len()).all(|i| unit[i] == unit[i - period]))
+}
+
+// A unit repeated back to back: 3+ times for one char, 2+ for longer units.
+// Each stretch with period `unit` is found once, by walking the positions
+// where a char equals the one `unit` later, so there are at most
+// MAX_UNIT matches per char rather than one per start and unit
+fn repeats(chars: &[char], per_char: f64) -> Vec<Match> {
+    let mut matches = Vec::new();
+    for unit in 1..=MAX_UNIT.min(chars.len() / 2) {
+        let mut start = 0;
+        while start + unit < chars.len() {
+            let mut end = start;
+            while end + unit < chars.len() && chars[end] == chars[end + unit] {
+                end += 1;
+            }
+            // chars[start..end + unit] repeats with period `unit`
+            let count = (end + unit - start) / unit;
+            let pattern = &chars[start..start + unit];
+            // A unit that is itself a repeat is better covered by the shorter one
+            if count >= 2 && (unit > 1 || count >= 3) && !is_repeated(pattern) {
+                let end = start + count * unit;
+                matches.push(Match {
+                    kind: Kind::Repeat {
+                        unit: pattern.iter().collect(),
+                        count,
+                    },
+                    start,
+                    end,
+                    text: chars[start..end].iter().collect(),
+                    bits: unit as f64 * per_char + (count as f64).log2(),
+                });
+            }
+            start = end + 1;
+        }
+    }
+    matches
+}
+
+fn feedback(chars: &[char], matches: &[Match], score: usize) -> Vec<String> {
+    let mut tips = Vec::new();
+    if chars.len() < 12 {
+        tips.push(format!(
+            "Use at least 12 characters (this has {})",
+            chars.len()
+        ));
+    }
+    for found in matches {
+        tips.push(match &found.kind {
+            Kind::Dictionary { l33t: true, .. } => format!(
+                "\"{}\" is a common word with predictable substitutions like @ for a",
+                found.text
+            ),
+            Kind::Dictionary { .. } => {
+                format!("\"{}\" is a common password or word", found.text)
+            }
+            Kind::KeyboardWalk => format!("\"{}\" is a straight line on the keyboard", found.text),
+            Kind::Repeat { unit, count } => {
+                format!(
+                    "\"{}\" is \"{}\" repeated {} times",
+                    found.text, unit, count
+                )
+            }
+            Kind::Sequence => format!("\"{}\" is an easy-to-guess sequence", found.text),
+        });
+    }
+    let classes = [
+        chars.iter().any(char::is_ascii_lowercase),
+        chars.iter().any(char::is_ascii_uppercase),
+        chars.iter().any(char::is_ascii_digit),
+        chars.iter().any(|c| !c.is_ascii_alphanumeric()),
+    ];
+    if classes.iter().filter(|&&used| used).count() == 1 && score < 3 {
+        tips.push("Mix in another kind of character: capitals, digits or symbols".to_string());
+    }
+    if score < 3 && tips.is_empty() {
+        tips.push("Add another word or two that aren't related to each other".to_string());
+    }
+    tips
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn kinds(password: &str) -> Vec<(String, Kind)> {
+        estimate(password)
+            .matches
+            .into_iter()
+            .map(|found| (found.text, found.kind))
+            .collect()
+    }
+
+    #[test]
+    fn four_boolean_checks_are_not_strength() {
+        // Passes is_strong_password: 8+ bytes, upper, lower and a digit
+        let strength = estimate("Password123");
+        assert!(!strength.is_strong());
+        assert_eq!(strength.label(), "very weak");
+        assert_eq!(
+            kinds("Password123"),
+            vec![
+                (
+                    "Password".to_string(),
+                    Kind::Dictionary {
+                        word: "password",
+                        l33t: false
+                    }
+                ),
+                ("123".to_string(), Kind::Sequence),
+            ]
+        );
+        assert!(estimate("correct horse battery staple").is_strong());
+        assert_eq!(estimate("").score, 0);
+    }
+
+    #[test]
+    fn finds_walks_repeats_sequences_and_l33t() {
+        assert_eq!(kinds("1qaz")[0].1, Kind::KeyboardWalk);
+        assert_eq!(
+            kinds("zxcvbnm")[0],
+            ("zxcvbnm".to_string(), Kind::KeyboardWalk)
+        );
+        assert_eq!(kinds("9876")[0], ("9876".to_string(), Kind::Sequence));
+        assert_eq!(
+            kinds("aaaa")[0].1,
+            Kind::Repeat {
+                unit: "a".to_string(),
+                count: 4
+            }
+        );
+        assert_eq!(
+            kinds("xkcdxkcdxkcd")[0].1,
+            Kind::Repeat {
+                unit: "xkcd".to_string(),
+                count: 3
+            }
+        );
+        assert_eq!(
+            kinds("P@ssw0rd")[0].1,
+            Kind::Dictionary {
+                word: "password",
+                l33t: true
+            }
+        );
+        // "were" turns back on itself, so it is not a walk
+        assert!(kinds("were").is_empty());
+    }
+
+    #[test]
+    fn counts_chars_not_bytes() {
+        // 8 bytes but 4 chars: short, whatever the byte length says
+        let strength = estimate("ñññ!");
+        assert_eq!(strength.chars, 4);
+        assert!(strength.score <= 1);
+        assert!(strength.feedback[0].contains("this has 4"));
+        // Non-ASCII letters widen the pool instead of being ignored
+        assert!(estimate("пароль-ключ-дом-рыба").entropy > estimate("parol-klyuch-dom").entropy);
+    }
+
+    #[test]
+    fn long_input_stays_linear() {
+        // Every repeat at every start, each checked at every end position,
+        // used to make this take minutes
+        let long = "abcab!Z9".repeat(1_000) + &"x".repeat(10_000);
+        let strength = estimate(&long);
+        assert_eq!(strength.chars, 18_000);
+        assert!(strength.matches.iter().any(|found| found.kind
+            == Kind::Repeat {
+                unit: "x".to_string(),
+                count: 10_000
+            }));
+    }
+
+    #[test]
+    fn feedback_names_each_weakness() {
+        let strength = estimate("qwerty2024!!!");
+        assert!(
+            strength
+                .feedback
+                .iter()
+                .any(|tip| tip.contains("\"qwerty\""))
+        );
+        assert!(
+            strength
+                .feedback
+                .iter()
+                .any(|tip| tip.contains("\"!!!\" is \"!\" repeated 3 times"))
+        );
+        let strong = estimate("vX7#pL2q@Wz9!mK4");
+        assert_eq!(strong.score, 4);
+        assert!(strong.feedback.is_empty());
+        assert_eq!(
+            strong.to_string(),
+            format!("very strong (4/4, {:.0} bits)", strong.entropy)
+        );
+    }
+}
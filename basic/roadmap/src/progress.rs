@@ -0,0 +1,418 @@
+// Learner Progress
+// `roadmap run` and `roadmap quiz` append what was done to a plain text log,
+// one record per line, so the file can be read (or mailed to a team lead) as is:
+//
+//     learner alice
+//     run 1760688000 syntax_and_semantics/functions all
+//     run 1760688120 ownership_system/box_stack_heap 3
+//     quiz 1760688300 syntax_and_semantics 7/10
+//
+// Timestamps are seconds since the Unix epoch. `roadmap progress` turns the log
+// into a per-topic completion report.
+
+use std::env;
+use std::fmt;
+use std::fs::{self, OpenOptions};
+use std::io::{self, Write};
+use std::path::{Path, PathBuf};
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use crate::learning_path::{self, PathError};
+use crate::registry::{self, Entry, TOPICS};
+
+#[derive(Debug, PartialEq)]
+pub enum Record {
+    Run {
+        at: u64,
+        lesson: String,
+        // None when the whole lesson was run
+        section: Option<usize>,
+    },
+    Quiz {
+        at: u64,
+        scope: String,
+        correct: usize,
+        asked: usize,
+    },
+}
+
+impl fmt::Display for Record {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Record::Run {
+                at,
+                lesson,
+                section: Some(section),
+            } => write!(f, "run {} {} {}", at, lesson, section),
+            Record::Run {
+                at,
+                lesson,
+                section: None,
+            } => write!(f, "run {} {} all", at, lesson),
+            Record::Quiz {
+                at,
+                scope,
+                correct,
+                asked,
+            } => write!(f, "quiz {} {} {}/{}", at, scope, correct, asked),
+        }
+    }
+}
+
+#[derive(Debug, Default, PartialEq)]
+pub struct Progress {
+    pub learner: String,
+    pub records: Vec<Record>,
+}
+
+#[derive(Debug)]
+pub enum ProgressError {
+    Io(io::Error),
+    Parse { line: usize, message: String },
+    Path(PathError),
+}
+
+impl fmt::Display for ProgressError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ProgressError::Io(err) => write!(f, "{}", err),
+            ProgressError::Parse { line, message } => {
+                write!(f, "progress file line {}: {}", line, message)
+            }
+            ProgressError::Path(err) => write!(f, "{}", err),
+        }
+    }
+}
+
+impl From<io::Error> for ProgressError {
+    fn from(err: io::Error) -> Self {
+        ProgressError::Io(err)
+    }
+}
+
+impl From<PathError> for ProgressError {
+    fn from(err: PathError) -> Self {
+        ProgressError::Path(err)
+    }
+}
+
+// $ROADMAP_PROGRESS, or ~/.roadmap/progress.txt
+pub fn default_path() -> PathBuf {
+    if let Some(path) = env::var_os("ROADMAP_PROGRESS") {
+        return PathBuf::from(path);
+    }
+    let home = env::var_os("HOME").unwrap_or_else(|| ".".into());
+    PathBuf::from(home).join(".roadmap").join("progress.txt")
+}
+
+// $ROADMAP_LEARNER, else the login name
+pub fn learner_name() -> String {
+    ["ROADMAP_LEARNER", "USER", "USERNAME"]
+        .iter()
+        .find_map(|name| env::var(name).ok().filter(|value| !value.is_empty()))
+        .unwrap_or_else(|| "learner".to_string())
+}
+
+pub fn now() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|elapsed| elapsed.as_secs())
+        .unwrap_or(0)
+}
+
+// A file that doesn't exist yet is an empty log for the current learner
+pub fn load(path: &Path) -> Result<Progress, ProgressError> {
+    match fs::read_to_string(path) {
+        Ok(text) => parse(&text),
+        Err(err) if err.kind() == io::ErrorKind::NotFound => Ok(Progress {
+            learner: learner_name(),
+            records: Vec::new(),
+        }),
+        Err(err) => Err(err.into()),
+    }
+}
+
+// Adds one record, starting the file with the learner's name when it is new
+pub fn append(path: &Path, record: &Record) -> io::Result<()> {
+    if let Some(dir) = path.parent() {
+        fs::create_dir_all(dir)?;
+    }
+    let is_new = !path.exists();
+    let mut file = OpenOptions::new().create(true).append(true).open(path)?;
+    if is_new {
+        writeln!(file, "learner {}", learner_name())?;
+    }
+    writeln!(file, "{}", record)
+}
+
+pub fn parse(text: &str) -> Result<Progress, ProgressError> {
+    let mut progress = Progress::default();
+    for (index, line) in text.lines().enumerate() {
+        let error = |message: &str| ProgressError::Parse {
+            line: index + 1,
+            message: message.to_string(),
+        };
+        let words: Vec<&str> = line.split_whitespace().collect();
+        let record = match words.as_slice() {
+            [] => continue,
+            ["learner", name @ ..] => {
+                progress.learner = name.join(" ");
+                continue;
+            }
+            ["run", at, lesson, section] => Record::Run {
+                at: at.parse().map_err(|_| error("bad timestamp"))?,
+                lesson: lesson.to_string(),
+                section: match *section {
+                    "all" => None,
+                    number => Some(number.parse().map_err(|_| error("bad section"))?),
+                },
+            },
+            ["quiz", at, scope, score] => {
+                let parsed = score
+                    .split_once('/')
+                    .and_then(|(c, a)| Some((c.parse().ok()?, a.parse().ok()?)));
+                let Some((correct, asked)) = parsed else {
+                    return Err(error("score must look like 7/10"));
+                };
+                Record::Quiz {
+                    at: at.parse().map_err(|_| error("bad timestamp"))?,
+                    scope: scope.to_string(),
+                    correct,
+                    asked,
+                }
+            }
+            _ => return Err(error("unrecognised record")),
+        };
+        progress.records.push(record);
+    }
+    Ok(progress)
+}
+
+impl Progress {
+    // Which sections of the lesson have been run, and when the last missing
+    // one was (i.e. when the lesson was completed)
+    pub fn lesson_status(&self, entry: &Entry) -> (Vec<bool>, Option<u64>) {
+        let path = entry.path();
+        let mut seen = vec![false; entry.lesson.sections.len()];
+        let mut completed = None;
+        for record in &self.records {
+            let Record::Run {
+                at,
+                lesson,
+                section,
+            } = record
+            else {
+                continue;
+            };
+            if *lesson != path {
+                continue;
+            }
+            match section {
+                None => seen.iter_mut().for_each(|done| *done = true),
+                Some(number) => {
+                    if let Some(done) = number.checked_sub(1).and_then(|i| seen.get_mut(i)) {
+                        *done = true;
+                    }
+                }
+            }
+            if completed.is_none() && seen.iter().all(|&done| done) {
+                completed = Some(*at);
+            }
+        }
+        (seen, completed)
+    }
+
+    pub fn completed(&self) -> Vec<Entry> {
+        registry::entries()
+            .filter(|entry| self.lesson_status(entry).1.is_some())
+            .collect()
+    }
+
+    // Best score per quiz scope, with the time it was reached, in first-taken order
+    pub fn quiz_scores(&self) -> Vec<(&str, usize, usize, u64)> {
+        let mut best: Vec<(&str, usize, usize, u64)> = Vec::new();
+        for record in &self.records {
+            let Record::Quiz {
+                at,
+                scope,
+                correct,
+                asked,
+            } = record
+            else {
+                continue;
+            };
+            let score = (scope.as_str(), *correct, *asked, *at);
+            match best.iter_mut().find(|(s, ..)| *s == scope) {
+                // Compare correct/asked as fractions without floats
+                Some(entry) if correct * entry.2 > entry.1 * asked => *entry = score,
+                Some(_) => {}
+                None => best.push(score),
+            }
+        }
+        best
+    }
+}
+
+// Per-topic completion report
+pub fn report(progress: &Progress, path: &Path) -> Result<(), ProgressError> {
+    println!("Progress of {} ({})", progress.learner, path.display());
+
+    for topic in TOPICS {
+        let entries: Vec<Entry> = topic
+            .lessons
+            .iter()
+            .map(|lesson| Entry { topic, lesson })
+            .collect();
+        let statuses: Vec<(Vec<bool>, Option<u64>)> = entries
+            .iter()
+            .map(|entry| progress.lesson_status(entry))
+            .collect();
+        let done = statuses.iter().filter(|(_, at)| at.is_some()).count();
+        println!();
+        println!(
+            "{:<24} {:>2}/{:<2} lessons  {}",
+            topic.title,
+            done,
+            entries.len(),
+            bar(done, entries.len())
+        );
+
+        for (entry, (seen, completed)) in entries.iter().zip(&statuses) {
+            let run = seen.iter().filter(|&&done| done).count();
+            let status = match completed {
+                Some(at) => format!("✓ completed {}", date(*at)),
+                None if run > 0 => format!("◐ {}/{} sections", run, seen.len()),
+                None => "·".to_string(),
+            };
+            println!("  {:<36} {}", entry.lesson.id, status);
+        }
+    }
+
+    let scores = progress.quiz_scores();
+    if !scores.is_empty() {
+        println!();
+        println!("Quizzes (best score)");
+        for (scope, correct, asked, at) in scores {
+            let percent = (correct * 100).checked_div(asked).unwrap_or(0);
+            println!(
+                "  {:<36} {}/{} ({}%) on {}",
+                scope,
+                correct,
+                asked,
+                percent,
+                date(at)
+            );
+        }
+    }
+
+    let next = learning_path::next(&progress.completed())?;
+    if let Some(entry) = next.first() {
+        println!();
+        println!("Next up: {} ({})", entry.path(), entry.lesson.title);
+    }
+    Ok(())
+}
+
+fn bar(done: usize, total: usize) -> String {
+    const WIDTH: usize = 20;
+    let filled = (done * WIDTH).checked_div(total).unwrap_or(0);
+    let percent = (done * 100).checked_div(total).unwrap_or(0);
+    format!(
+        "{}{} {:>3}%",
+        "█".repeat(filled),
+        "░".repeat(WIDTH - filled),
+        percent
+    )
+}
+
+// YYYY-MM-DD (UTC) of a Unix timestamp, using the days-to-civil algorithm
+// from Howard Hinnant's date library
+pub fn date(timestamp: u64) -> String {
+    let days = (timestamp / 86_400) as i64 + 719_468;
+    let era = days.div_euclid(146_097);
+    let day_of_era = days.rem_euclid(146_097);
+    let year_of_era =
+        (day_of_era - day_of_era / 1460 + day_of_era / 36_524 - day_of_era / 146_096) / 365;
+    let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+    let month_index = (5 * day_of_year + 2) / 153;
+    let day = day_of_year - (153 * month_index + 2) / 5 + 1;
+    let month = if month_index < 10 {
+        month_index + 3
+    } else {
+        month_index - 9
+    };
+    let year = year_of_era + era * 400 + i64::from(month <= 2);
+    format!("{:04}-{:02}-{:02}", year, month, day)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const LOG: &str = "\
+learner Alice Smith
+run 1760688000 syntax_and_semantics/constants all
+run 1760688060 syntax_and_semantics/shadowing 1
+run 1760688120 syntax_and_semantics/shadowing 3
+quiz 1760688180 syntax_and_semantics 6/10
+quiz 1760688240 syntax_and_semantics 8/10
+quiz 1760688300 syntax_and_semantics 7/10
+";
+
+    #[test]
+    fn records_round_trip_through_the_log_format() {
+        let progress = parse(LOG).unwrap();
+        assert_eq!(progress.learner, "Alice Smith");
+        assert_eq!(progress.records.len(), 6);
+        let text: Vec<String> = progress.records.iter().map(Record::to_string).collect();
+        assert_eq!(
+            text.join("\n"),
+            LOG.lines().skip(1).collect::<Vec<_>>().join("\n")
+        );
+
+        let err = parse("run soon functions all").unwrap_err();
+        assert_eq!(err.to_string(), "progress file line 1: bad timestamp");
+    }
+
+    #[test]
+    fn a_lesson_completes_when_every_section_has_run() {
+        let mut progress = parse(LOG).unwrap();
+        let shadowing = registry::find("shadowing").unwrap();
+        let (seen, completed) = progress.lesson_status(&shadowing);
+        assert_eq!(seen, vec![true, false, true, false]);
+        assert_eq!(completed, None);
+
+        for section in [2, 4] {
+            progress.records.push(Record::Run {
+                at: 1760690000 + section as u64,
+                lesson: shadowing.path(),
+                section: Some(section),
+            });
+        }
+        assert_eq!(progress.lesson_status(&shadowing).1, Some(1760690004));
+        let completed: Vec<String> = progress.completed().iter().map(Entry::path).collect();
+        assert_eq!(
+            completed,
+            vec![
+                "syntax_and_semantics/constants",
+                "syntax_and_semantics/shadowing"
+            ]
+        );
+    }
+
+    #[test]
+    fn quiz_scores_keep_the_best_attempt() {
+        let progress = parse(LOG).unwrap();
+        assert_eq!(
+            progress.quiz_scores(),
+            vec![("syntax_and_semantics", 8, 10, 1760688240)]
+        );
+    }
+
+    #[test]
+    fn dates_are_utc_calendar_days() {
+        assert_eq!(date(0), "1970-01-01");
+        assert_eq!(date(951_782_400), "2000-02-29");
+        assert_eq!(date(1_760_688_000), "2025-10-17");
+    }
+}
@@ -0,0 +1,232 @@
+// Quizzes
+// Question banks live in quizzes/<topic>.quiz (the format is described at the
+// top of quizzes/syntax_and_semantics.quiz). "Predict the output" questions do
+// not store their answer: it is read from the lesson's actual output, so a quiz
+// can never disagree with the lesson it is about.
+
+mod parse;
+mod runner;
+
+use std::fmt;
+use std::fs;
+use std::io;
+use std::path::PathBuf;
+
+use crate::output::{self, Event};
+use crate::registry::{self, LookupError, TOPICS};
+
+pub use runner::run;
+
+pub const QUIZ_DIR: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/quizzes");
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Question {
+    // "topic/lesson" once the bank has been loaded
+    pub lesson: Option<String>,
+    pub ask: String,
+    pub kind: Kind,
+    pub explain: Option<String>,
+    // Where the question starts in its .quiz file, for error messages
+    pub line: usize,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum Kind {
+    Choice {
+        options: Vec<String>,
+        correct: usize,
+    },
+    Text {
+        keywords: Vec<String>,
+        answer: String,
+    },
+    Output {
+        section: usize,
+        pattern: String,
+    },
+}
+
+// The line of lesson output an output question is about, split around the blank
+#[derive(Debug, PartialEq)]
+pub struct Blank {
+    pub before: String,
+    pub answer: String,
+    pub after: String,
+}
+
+#[derive(Debug)]
+pub enum QuizError {
+    Io(io::Error),
+    Parse {
+        file: String,
+        line: usize,
+        message: String,
+    },
+    Lookup(LookupError),
+    NoQuestions(String),
+    NoMatchingOutput {
+        lesson: String,
+        pattern: String,
+    },
+}
+
+impl fmt::Display for QuizError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            QuizError::Io(err) => write!(f, "{}", err),
+            QuizError::Parse {
+                file,
+                line,
+                message,
+            } => write!(f, "{}:{}: {}", file, line, message),
+            QuizError::Lookup(err) => write!(f, "{}", err),
+            QuizError::NoQuestions(query) => write!(f, "no quiz questions for '{}'", query),
+            QuizError::NoMatchingOutput { lesson, pattern } => {
+                write!(f, "no line of {} output matches '{}'", lesson, pattern)
+            }
+        }
+    }
+}
+
+impl From<io::Error> for QuizError {
+    fn from(err: io::Error) -> Self {
+        QuizError::Io(err)
+    }
+}
+
+impl From<LookupError> for QuizError {
+    fn from(err: LookupError) -> Self {
+        QuizError::Lookup(err)
+    }
+}
+
+pub fn path(topic_id: &str) -> PathBuf {
+    PathBuf::from(QUIZ_DIR).join(format!("{}.quiz", topic_id))
+}
+
+// Every question of a topic, in file order; a topic without a bank has none
+pub fn load(topic_id: &str) -> Result<Vec<Question>, QuizError> {
+    let path = path(topic_id);
+    let text = match fs::read_to_string(&path) {
+        Ok(text) => text,
+        Err(err) if err.kind() == io::ErrorKind::NotFound => return Ok(Vec::new()),
+        Err(err) => return Err(err.into()),
+    };
+    let file = format!("{}.quiz", topic_id);
+    let mut questions = parse::questions(&text).map_err(|(line, message)| QuizError::Parse {
+        file: file.clone(),
+        line,
+        message,
+    })?;
+
+    // Lesson ids are written bare; resolve them within the topic
+    for question in &mut questions {
+        if let Some(lesson) = &question.lesson {
+            let query = if lesson.contains('/') {
+                lesson.clone()
+            } else {
+                format!("{}/{}", topic_id, lesson)
+            };
+            let entry = registry::find(&query).map_err(|err| QuizError::Parse {
+                file: file.clone(),
+                line: question.line,
+                message: err.to_string(),
+            })?;
+            question.lesson = Some(entry.path());
+        }
+    }
+    Ok(questions)
+}
+
+// Questions for a topic id, a lesson ("topic/lesson" or a unique bare id), or
+// every topic when `query` is None
+pub fn select(query: Option<&str>) -> Result<Vec<Question>, QuizError> {
+    let Some(query) = query else {
+        let mut all = Vec::new();
+        for topic in TOPICS {
+            all.extend(load(topic.id)?);
+        }
+        return Ok(all);
+    };
+
+    let questions = match TOPICS.iter().find(|topic| topic.id == query) {
+        Some(topic) => load(topic.id)?,
+        None => {
+            let entry = registry::find(query)?;
+            let path = entry.path();
+            load(entry.topic.id)?
+                .into_iter()
+                .filter(|question| question.lesson.as_deref() == Some(path.as_str()))
+                .collect()
+        }
+    };
+    if questions.is_empty() {
+        return Err(QuizError::NoQuestions(query.to_string()));
+    }
+    Ok(questions)
+}
+
+// Runs the lesson section and finds the output line matching `pattern`
+pub fn blank(lesson: &str, section: usize, pattern: &str) -> Result<Blank, QuizError> {
+    let entry = registry::find(lesson)?;
+    let run = entry.lesson.section(section)?.run;
+    let (events, ()) = output::record(run);
+
+    let (before, after) = pattern.split_once("{}").unwrap_or((pattern, ""));
+    events
+        .iter()
+        .filter_map(|event| match event {
+            Event::Text(text) | Event::Result(text) | Event::Note(text) => Some(text.trim()),
+            _ => None,
+        })
+        .find_map(|line| {
+            let rest = line.strip_prefix(before)?;
+            let answer = if after.is_empty() {
+                rest
+            } else {
+                rest.strip_suffix(after)?
+            };
+            Some(Blank {
+                before: before.to_string(),
+                answer: answer.trim().to_string(),
+                after: after.to_string(),
+            })
+        })
+        .ok_or_else(|| QuizError::NoMatchingOutput {
+            lesson: lesson.to_string(),
+            pattern: pattern.to_string(),
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Keeps the banks honest: every file parses and every output question
+    // still finds its line in the lesson output
+    #[test]
+    fn every_bank_loads_and_every_blank_resolves() {
+        let questions = select(None).unwrap();
+        assert!(!questions.is_empty());
+        for question in &questions {
+            if let Kind::Output { section, pattern } = &question.kind {
+                let lesson = question.lesson.as_deref().unwrap();
+                let blank = blank(lesson, *section, pattern)
+                    .unwrap_or_else(|err| panic!("line {}: {}", question.line, err));
+                assert!(!blank.answer.is_empty(), "line {}", question.line);
+            }
+        }
+    }
+
+    #[test]
+    fn blank_reads_the_answer_from_lesson_output() {
+        let blank = blank(
+            "syntax_and_semantics/numeric_operations",
+            2,
+            "7 / 3 = {} (not 2.333...)",
+        )
+        .unwrap();
+        assert_eq!(blank.answer, "2");
+        assert_eq!(blank.before, "7 / 3 = ");
+    }
+}
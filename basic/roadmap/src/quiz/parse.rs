@@ -0,0 +1,188 @@
+// Quiz File Parser
+// A .quiz file is a list of blank-line separated blocks of `key: value` lines.
+// Errors carry the 1-based line number they were found on.
+
+use super::{Kind, Question};
+
+type ParseResult<T> = Result<T, (usize, String)>;
+
+pub fn questions(text: &str) -> ParseResult<Vec<Question>> {
+    let mut questions = Vec::new();
+    let mut block: Vec<(usize, &str)> = Vec::new();
+
+    for (index, line) in text.lines().enumerate() {
+        let line = line.trim();
+        if line.starts_with('#') {
+            continue;
+        }
+        if line.is_empty() {
+            if !block.is_empty() {
+                questions.push(question(&block)?);
+                block.clear();
+            }
+            continue;
+        }
+        block.push((index + 1, line));
+    }
+    if !block.is_empty() {
+        questions.push(question(&block)?);
+    }
+    Ok(questions)
+}
+
+fn question(block: &[(usize, &str)]) -> ParseResult<Question> {
+    let start = block[0].0;
+    let mut fields: Vec<(usize, &str, &str)> = Vec::new();
+    for &(number, line) in block {
+        let Some((key, value)) = line.split_once(':') else {
+            return Err((number, format!("expected 'key: value', found '{}'", line)));
+        };
+        fields.push((number, key.trim(), value.trim()));
+    }
+
+    let (_, first_key, kind) = fields[0];
+    if first_key != "question" {
+        return Err((start, "a question must start with 'question:'".to_string()));
+    }
+
+    let mut lesson = None;
+    let mut ask = None;
+    let mut explain = None;
+    let mut options = Vec::new();
+    let mut correct = Vec::new();
+    let mut keywords = None;
+    let mut answer = None;
+    let mut section = None;
+    let mut pattern = None;
+
+    for &(number, key, value) in &fields[1..] {
+        match key {
+            "lesson" => lesson = Some(value.to_string()),
+            "ask" => ask = Some(value.to_string()),
+            "explain" => explain = Some(value.to_string()),
+            "option" | "option*" => {
+                if key == "option*" {
+                    correct.push(options.len());
+                }
+                options.push(value.to_string());
+            }
+            "keywords" => {
+                let words = value
+                    .split(',')
+                    .map(|word| word.trim().to_lowercase())
+                    .filter(|word| !word.is_empty())
+                    .collect();
+                keywords = Some(words);
+            }
+            "answer" => answer = Some(value.to_string()),
+            "section" => match value.parse::<usize>() {
+                Ok(n) if n > 0 => section = Some(n),
+                _ => return Err((number, format!("'{}' is not a section number", value))),
+            },
+            "line" if value.contains("{}") => pattern = Some(value.to_string()),
+            "line" => return Err((number, "line needs a {} where the answer goes".to_string())),
+            other => return Err((number, format!("unknown key '{}'", other))),
+        }
+    }
+
+    let Some(ask) = ask else {
+        return Err((start, "question has no 'ask:'".to_string()));
+    };
+    let kind = match kind {
+        "choice" => match (options.len(), correct.as_slice()) {
+            (0..=1, _) => return Err((start, "choice needs at least two options".to_string())),
+            (_, [correct]) => Kind::Choice {
+                options,
+                correct: *correct,
+            },
+            _ => return Err((start, "choice needs exactly one 'option*:'".to_string())),
+        },
+        "text" => match (keywords, answer) {
+            (Some(keywords), Some(answer)) => Kind::Text { keywords, answer },
+            _ => return Err((start, "text needs 'keywords:' and 'answer:'".to_string())),
+        },
+        "output" => match (&lesson, section, pattern) {
+            (Some(_), Some(section), Some(pattern)) => Kind::Output { section, pattern },
+            _ => {
+                return Err((
+                    start,
+                    "output needs 'lesson:', 'section:' and 'line:'".to_string(),
+                ));
+            }
+        },
+        other => return Err((start, format!("unknown question type '{}'", other))),
+    };
+
+    Ok(Question {
+        lesson,
+        ask,
+        kind,
+        explain,
+        line: start,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_each_question_type() {
+        let text = "\
+# comment
+question: choice
+ask: Pick one
+option: a
+option*: b
+
+question: text
+ask: Why?
+keywords: Let, new
+answer: Because
+
+question: output
+lesson: functions
+section: 5
+ask: Sum?
+line: sum = {}
+";
+        let questions = questions(text).unwrap();
+        assert_eq!(questions.len(), 3);
+        assert_eq!(
+            questions[0].kind,
+            Kind::Choice {
+                options: vec!["a".to_string(), "b".to_string()],
+                correct: 1,
+            }
+        );
+        assert_eq!(questions[0].line, 2);
+        assert_eq!(
+            questions[1].kind,
+            Kind::Text {
+                keywords: vec!["let".to_string(), "new".to_string()],
+                answer: "Because".to_string(),
+            }
+        );
+        assert_eq!(
+            questions[2].kind,
+            Kind::Output {
+                section: 5,
+                pattern: "sum = {}".to_string(),
+            }
+        );
+    }
+
+    #[test]
+    fn errors_point_at_the_offending_line() {
+        let text = "question: choice\nask: Pick\noption: a\nchoices: b\n";
+        assert_eq!(
+            questions(text).unwrap_err(),
+            (4, "unknown key 'choices'".to_string())
+        );
+        let text = "question: choice\nask: Pick\noption: a\noption: b\n";
+        assert_eq!(
+            questions(text).unwrap_err(),
+            (1, "choice needs exactly one 'option*:'".to_string())
+        );
+    }
+}
@@ -0,0 +1,200 @@
+// Terminal Quiz Runner
+// Asks each question, reads one answer line per question from `input`, and
+// keeps score. Typing `q` (or closing stdin) ends the quiz early.
+
+use std::io::{self, BufRead, Write};
+
+use super::{Kind, Question, QuizError, blank};
+
+#[derive(Debug, Default, PartialEq)]
+pub struct Score {
+    pub correct: usize,
+    pub asked: usize,
+    // Lessons of the questions answered wrongly, worth revisiting
+    pub review: Vec<String>,
+}
+
+impl Score {
+    pub fn percent(&self) -> usize {
+        match self.asked {
+            0 => 0,
+            asked => self.correct * 100 / asked,
+        }
+    }
+}
+
+pub fn run<R: BufRead>(questions: &[Question], input: &mut R) -> Result<Score, QuizError> {
+    let mut score = Score::default();
+
+    for (index, question) in questions.iter().enumerate() {
+        let lesson = question.lesson.as_deref().unwrap_or("general");
+        println!("Question {}/{} ({})", index + 1, questions.len(), lesson);
+        println!("{}", question.ask);
+
+        let (correct, solution) = match &question.kind {
+            Kind::Choice { options, correct } => {
+                for (number, option) in options.iter().enumerate() {
+                    println!("  {}) {}", letter(number), option);
+                }
+                let Some(answer) = read_answer(input, "answer")? else {
+                    break;
+                };
+                let chosen = answer.trim().to_lowercase();
+                let solution = format!("{}) {}", letter(*correct), options[*correct]);
+                (chosen == letter(*correct).to_string(), solution)
+            }
+            Kind::Text { keywords, answer } => {
+                let Some(given) = read_answer(input, "answer")? else {
+                    break;
+                };
+                let correct = keywords.iter().all(|word| has_word(&given, word));
+                (correct, answer.clone())
+            }
+            Kind::Output { section, pattern } => {
+                let blank = blank(lesson, *section, pattern)?;
+                println!("  {}___{}", blank.before, blank.after);
+                let Some(given) = read_answer(input, "fill in the blank")? else {
+                    break;
+                };
+                (same_words(&given, &blank.answer), blank.answer)
+            }
+        };
+
+        score.asked += 1;
+        if correct {
+            score.correct += 1;
+            println!("✓ correct");
+        } else {
+            println!("✗ expected: {}", solution);
+            if !score.review.iter().any(|seen| seen == lesson) {
+                score.review.push(lesson.to_string());
+            }
+        }
+        if let Some(explain) = &question.explain {
+            println!("  {}", explain);
+        }
+        println!();
+    }
+
+    println!(
+        "Score: {}/{} ({}%)",
+        score.correct,
+        score.asked,
+        score.percent()
+    );
+    if !score.review.is_empty() {
+        println!("Worth reviewing: {}", score.review.join(", "));
+    }
+    Ok(score)
+}
+
+fn letter(index: usize) -> char {
+    (b'a' + index as u8) as char
+}
+
+// None when the reader quits or input ends
+fn read_answer<R: BufRead>(input: &mut R, prompt: &str) -> io::Result<Option<String>> {
+    print!("{}> ", prompt);
+    io::stdout().flush()?;
+
+    let mut line = String::new();
+    if input.read_line(&mut line)? == 0 {
+        println!();
+        return Ok(None);
+    }
+    match line.trim() {
+        "q" | "Q" => Ok(None),
+        answer => Ok(Some(answer.to_string())),
+    }
+}
+
+// Keywords match whole words in any case, so "mut" is not found in "immutable"
+fn has_word(given: &str, keyword: &str) -> bool {
+    given
+        .to_lowercase()
+        .split(|c: char| !c.is_alphanumeric() && c != '_')
+        .any(|word| word == keyword)
+}
+
+// Output answers ignore case and spacing, "Some(5)" equals "some( 5 )"
+fn same_words(given: &str, expected: &str) -> bool {
+    let normalize = |text: &str| -> String {
+        text.chars()
+            .filter(|c| !c.is_whitespace())
+            .flat_map(char::to_lowercase)
+            .collect()
+    };
+    normalize(given) == normalize(expected)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::output;
+    use std::io::Cursor;
+
+    fn question(kind: Kind) -> Question {
+        Question {
+            lesson: Some("syntax_and_semantics/numeric_operations".to_string()),
+            ask: "?".to_string(),
+            kind,
+            explain: None,
+            line: 1,
+        }
+    }
+
+    #[test]
+    fn scores_each_question_type() {
+        let questions = vec![
+            question(Kind::Choice {
+                options: vec!["one".to_string(), "two".to_string()],
+                correct: 1,
+            }),
+            question(Kind::Text {
+                keywords: vec!["truncates".to_string()],
+                answer: "It truncates".to_string(),
+            }),
+            question(Kind::Output {
+                section: 2,
+                pattern: "7 / 3 = {} (not 2.333...)".to_string(),
+            }),
+        ];
+        let mut input = Cursor::new("b\nit rounds\n 2 \n");
+        let mut score = None;
+        let text = output::capture(|| score = Some(run(&questions, &mut input).unwrap()));
+        let score = score.unwrap();
+
+        assert_eq!((score.correct, score.asked), (2, 3));
+        assert_eq!(
+            score.review,
+            vec!["syntax_and_semantics/numeric_operations"]
+        );
+        assert!(text.contains("  7 / 3 = ___ (not 2.333...)\n"));
+        assert!(text.contains("✗ expected: It truncates\n"));
+        assert!(text.ends_with(
+            "Score: 2/3 (66%)\nWorth reviewing: syntax_and_semantics/numeric_operations\n"
+        ));
+    }
+
+    #[test]
+    fn keywords_match_whole_words() {
+        assert!(has_word("Adding `mut` makes it mutable", "mut"));
+        assert!(has_word("Use MUT.", "mut"));
+        assert!(!has_word("an immutable binding", "mut"));
+        assert!(!has_word("mutable", "mut"));
+        assert!(has_word("call to_string()", "to_string"));
+        assert!(!has_word("call to_string()", "string"));
+    }
+
+    #[test]
+    fn quitting_stops_the_quiz() {
+        let questions = vec![question(Kind::Text {
+            keywords: vec![],
+            answer: String::new(),
+        })];
+        let mut input = Cursor::new("q\n");
+        let mut score = None;
+        output::capture(|| score = Some(run(&questions, &mut input).unwrap()));
+        assert_eq!(score.unwrap().asked, 0);
+    }
+}
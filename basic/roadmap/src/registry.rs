@@ -0,0 +1,248 @@
+// Lesson Registry
+// Every lesson file exposes a `LESSON` constant listing its sections in the
+// order they are taught. Topics group lessons the same way the src/ folders do,
+// so "data_structures/arrays" and "syntax_and_semantics/arrays" can coexist.
+
+use std::fmt;
+
+use crate::output;
+use crate::{constructs, data_structures, ownership_system, syntax_and_semantics};
+
+// A single runnable part of a lesson, usually one `demonstrate_*` function
+pub struct Section {
+    pub title: &'static str,
+    pub run: fn(),
+}
+
+impl Section {
+    pub const fn new(title: &'static str, run: fn()) -> Self {
+        Self { title, run }
+    }
+}
+
+// One lesson file, e.g. `syntax_and_semantics/functions.rs`
+pub struct Lesson {
+    pub id: &'static str,
+    pub title: &'static str,
+    pub meta: Meta,
+    pub sections: &'static [Section],
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Difficulty {
+    Beginner,
+    Intermediate,
+    Advanced,
+}
+
+impl fmt::Display for Difficulty {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let name = match self {
+            Difficulty::Beginner => "beginner",
+            Difficulty::Intermediate => "intermediate",
+            Difficulty::Advanced => "advanced",
+        };
+        f.pad(name)
+    }
+}
+
+// What to know about a lesson before starting it; see learning_path.rs for
+// how prerequisites turn into a study order
+pub struct Meta {
+    pub difficulty: Difficulty,
+    // Rough time to read and run every section
+    pub minutes: u32,
+    // Matching chapter of "The Rust Programming Language", e.g. "4.2"
+    pub book: Option<&'static str>,
+    // Lesson ids: a bare id is in the same topic, otherwise "topic/lesson"
+    pub prerequisites: &'static [&'static str],
+    pub tags: &'static [&'static str],
+}
+
+impl Lesson {
+    // Sections are numbered from 1, in the order they are listed
+    pub fn section(&self, number: usize) -> Result<&'static Section, LookupError> {
+        number
+            .checked_sub(1)
+            .and_then(|index| self.sections.get(index))
+            .ok_or(LookupError::NoSuchSection {
+                lesson: self.id,
+                number,
+                available: self.sections.len(),
+            })
+    }
+}
+
+// One folder under src/
+pub struct Topic {
+    pub id: &'static str,
+    pub title: &'static str,
+    pub lessons: &'static [Lesson],
+}
+
+// Topics in the order they should be studied
+pub const TOPICS: &[Topic] = &[
+    syntax_and_semantics::TOPIC,
+    data_structures::TOPIC,
+    ownership_system::TOPIC,
+    constructs::TOPIC,
+];
+
+// A lesson together with the topic it belongs to
+#[derive(Clone, Copy)]
+pub struct Entry {
+    pub topic: &'static Topic,
+    pub lesson: &'static Lesson,
+}
+
+impl Entry {
+    // Fully qualified id, e.g. "ownership_system/box_stack_heap"
+    pub fn path(&self) -> String {
+        format!("{}/{}", self.topic.id, self.lesson.id)
+    }
+
+    // Runs every section in order under the lesson banner
+    pub fn run(&self) {
+        output::lesson(&self.path(), self.lesson.title);
+        for (index, section) in self.lesson.sections.iter().enumerate() {
+            output::section(index + 1, section.title);
+            (section.run)();
+        }
+    }
+
+    pub fn run_section(&self, number: usize) -> Result<(), LookupError> {
+        let section = self.lesson.section(number)?;
+        output::lesson(&self.path(), self.lesson.title);
+        output::section(number, section.title);
+        (section.run)();
+        Ok(())
+    }
+}
+
+#[derive(Debug, PartialEq)]
+pub enum LookupError {
+    UnknownTopic(String),
+    UnknownLesson(String),
+    Ambiguous {
+        lesson: String,
+        candidates: Vec<String>,
+    },
+    NoSuchSection {
+        lesson: &'static str,
+        number: usize,
+        available: usize,
+    },
+}
+
+impl fmt::Display for LookupError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            LookupError::UnknownTopic(topic) => write!(f, "unknown topic '{}'", topic),
+            LookupError::UnknownLesson(lesson) => write!(f, "unknown lesson '{}'", lesson),
+            LookupError::Ambiguous { lesson, candidates } => write!(
+                f,
+                "lesson '{}' is ambiguous, use one of: {}",
+                lesson,
+                candidates.join(", ")
+            ),
+            LookupError::NoSuchSection {
+                lesson,
+                number,
+                available,
+            } => write!(
+                f,
+                "lesson '{}' has no section {} (sections are 1..={})",
+                lesson, number, available
+            ),
+        }
+    }
+}
+
+// Every registered lesson, in study order
+pub fn entries() -> impl Iterator<Item = Entry> {
+    TOPICS.iter().flat_map(|topic| {
+        topic
+            .lessons
+            .iter()
+            .map(move |lesson| Entry { topic, lesson })
+    })
+}
+
+// Resolves "topic/lesson" or a bare "lesson" id when it is unique
+pub fn find(query: &str) -> Result<Entry, LookupError> {
+    if let Some((topic_id, lesson_id)) = query.split_once('/') {
+        let Some(topic) = TOPICS.iter().find(|topic| topic.id == topic_id) else {
+            return Err(LookupError::UnknownTopic(topic_id.to_string()));
+        };
+        return topic
+            .lessons
+            .iter()
+            .find(|lesson| lesson.id == lesson_id)
+            .map(|lesson| Entry { topic, lesson })
+            .ok_or_else(|| LookupError::UnknownLesson(query.to_string()));
+    }
+
+    let matches: Vec<Entry> = entries().filter(|entry| entry.lesson.id == query).collect();
+    match matches.as_slice() {
+        [] => Err(LookupError::UnknownLesson(query.to_string())),
+        [entry] => Ok(*entry),
+        _ => Err(LookupError::Ambiguous {
+            lesson: query.to_string(),
+            candidates: matches.iter().map(Entry::path).collect(),
+        }),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn finds_a_unique_bare_id() {
+        let entry = find("box_stack_heap").unwrap();
+        assert_eq!(entry.path(), "ownership_system/box_stack_heap");
+    }
+
+    #[test]
+    fn finds_topic_and_lesson() {
+        assert_eq!(
+            find("data_structures/arrays").unwrap().path(),
+            "data_structures/arrays"
+        );
+        assert_eq!(
+            find("syntax_and_semantics/arrays").unwrap().path(),
+            "syntax_and_semantics/arrays"
+        );
+        assert_eq!(
+            find("nowhere/arrays").err(),
+            Some(LookupError::UnknownTopic("nowhere".to_string()))
+        );
+        assert_eq!(
+            find("constructs/arrays").err(),
+            Some(LookupError::UnknownLesson("constructs/arrays".to_string()))
+        );
+    }
+
+    #[test]
+    fn a_bare_id_in_two_topics_is_ambiguous() {
+        let err = find("arrays").err().unwrap();
+        assert_eq!(
+            err,
+            LookupError::Ambiguous {
+                lesson: "arrays".to_string(),
+                candidates: vec![
+                    "syntax_and_semantics/arrays".to_string(),
+                    "data_structures/arrays".to_string(),
+                ],
+            }
+        );
+        assert_eq!(
+            err.to_string(),
+            "lesson 'arrays' is ambiguous, use one of: syntax_and_semantics/arrays, data_structures/arrays"
+        );
+        assert_eq!(
+            find("no_such_lesson").err(),
+            Some(LookupError::UnknownLesson("no_such_lesson".to_string()))
+        );
+    }
+}
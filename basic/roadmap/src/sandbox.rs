@@ -0,0 +1,277 @@
+// Panic Sandbox
+// Lets a lesson run the code that panics instead of only describing it.
+// `catch` runs a closure under catch_unwind and hands back the panic message
+// and the file it happened in. `run_program` compiles a small program with
+// rustc and runs it as a child process: overflow checks are decided at compile
+// time, so that is how a lesson shows a debug and a release build of the same
+// code side by side. The exercise runner builds its test binaries with the
+// same `in_scratch_dir` and `compile`.
+
+use std::cell::{Cell, RefCell};
+use std::env;
+use std::fmt;
+use std::fs;
+use std::io;
+use std::panic::{self, AssertUnwindSafe};
+use std::path::{Path, PathBuf};
+use std::process::Command;
+use std::sync::Once;
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+#[derive(Debug, PartialEq)]
+pub struct Panic {
+    pub message: String,
+    // "file:line:column" from `run_program`; just "file.rs" from `catch`,
+    // whose line numbers would change every time the lesson is edited
+    pub location: Option<String>,
+}
+
+// Same layout as the standard panic message
+impl fmt::Display for Panic {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match &self.location {
+            Some(location) => write!(f, "panicked at {}:\n{}", location, self.message),
+            None => write!(f, "panicked:\n{}", self.message),
+        }
+    }
+}
+
+thread_local! {
+    static CAPTURING: Cell<bool> = const { Cell::new(false) };
+    static CAUGHT: RefCell<Option<Panic>> = const { RefCell::new(None) };
+}
+
+static INSTALL_HOOK: Once = Once::new();
+
+// Panics inside `catch` are recorded instead of printed; every other panic
+// still goes to the default hook
+fn install_hook() {
+    INSTALL_HOOK.call_once(|| {
+        let default = panic::take_hook();
+        panic::set_hook(Box::new(move |info| {
+            if !CAPTURING.get() {
+                default(info);
+                return;
+            }
+            let caught = Panic {
+                message: info.payload_as_str().unwrap_or("Box<dyn Any>").to_string(),
+                location: info.location().map(|location| file_name(location.file())),
+            };
+            CAUGHT.set(Some(caught));
+        }));
+    });
+}
+
+// "src/syntax_and_semantics/arrays.rs" -> "arrays.rs"
+fn file_name(path: &str) -> String {
+    Path::new(path)
+        .file_name()
+        .map_or(path.to_string(), |name| name.to_string_lossy().into_owned())
+}
+
+pub fn catch<T>(run: impl FnOnce() -> T) -> Result<T, Panic> {
+    install_hook();
+    let was_capturing = CAPTURING.replace(true);
+    let result = panic::catch_unwind(AssertUnwindSafe(run));
+    CAPTURING.set(was_capturing);
+
+    result.map_err(|_| {
+        CAUGHT.take().unwrap_or_else(|| Panic {
+            message: "unknown panic".to_string(),
+            location: None,
+        })
+    })
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Profile {
+    Debug,
+    Release,
+}
+
+impl Profile {
+    // The settings cargo uses for its dev and release profiles
+    fn flags(self) -> [&'static str; 4] {
+        match self {
+            Profile::Debug => ["-C", "opt-level=0", "-C", "overflow-checks=on"],
+            Profile::Release => ["-C", "opt-level=3", "-C", "overflow-checks=off"],
+        }
+    }
+}
+
+#[derive(Debug, PartialEq)]
+pub enum Outcome {
+    Exited { stdout: String },
+    Panicked(Panic),
+}
+
+#[derive(Debug)]
+pub enum BuildError {
+    Io(io::Error),
+    // rustc's error output
+    Compile(String),
+}
+
+impl fmt::Display for BuildError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            BuildError::Io(err) => write!(f, "{}", err),
+            BuildError::Compile(errors) => write!(f, "the program did not compile:\n{}", errors),
+        }
+    }
+}
+
+impl From<io::Error> for BuildError {
+    fn from(err: io::Error) -> Self {
+        BuildError::Io(err)
+    }
+}
+
+// Each program gets its own scratch directory, tests run lessons in parallel
+static RUNS: AtomicUsize = AtomicUsize::new(0);
+
+// Runs `run` in a fresh temporary directory and removes it afterwards
+pub fn in_scratch_dir<T, E: From<io::Error>>(
+    run: impl FnOnce(&Path) -> Result<T, E>,
+) -> Result<T, E> {
+    let dir = env::temp_dir().join(format!(
+        "roadmap-sandbox-{}-{}",
+        std::process::id(),
+        RUNS.fetch_add(1, Ordering::Relaxed)
+    ));
+    fs::create_dir_all(&dir)?;
+    let result = run(&dir);
+    let _ = fs::remove_dir_all(&dir);
+    result
+}
+
+// The rustc on PATH (or $RUSTC), compiling for the edition the lessons use
+pub fn rustc() -> Command {
+    let mut rustc = Command::new(env::var("RUSTC").unwrap_or_else(|_| "rustc".to_string()));
+    rustc.args(["--edition", "2024"]);
+    rustc
+}
+
+// Writes `source` to <dir>/<name>.rs, compiles it with `rustc()` plus
+// `flags`, and returns the path of the binary
+pub fn compile(
+    dir: &Path,
+    name: &str,
+    source: &str,
+    flags: &[&str],
+) -> Result<PathBuf, BuildError> {
+    let file = dir.join(format!("{}.rs", name));
+    let binary = dir.join(name);
+    fs::write(&file, source)?;
+
+    let compiled = rustc()
+        .args(flags)
+        // Panic locations read "overflow.rs:3:13" rather than a temp path
+        .arg(format!("--remap-path-prefix={}/=", dir.display()))
+        .arg("-o")
+        .arg(&binary)
+        .arg(&file)
+        .output()?;
+    if !compiled.status.success() {
+        let errors = String::from_utf8_lossy(&compiled.stderr);
+        return Err(BuildError::Compile(errors.trim_end().to_string()));
+    }
+    Ok(binary)
+}
+
+// Compiles `source` as <name>.rs and runs it
+pub fn run_program(name: &str, source: &str, profile: Profile) -> io::Result<Outcome> {
+    in_scratch_dir(|dir| {
+        let binary = compile(dir, name, source, &profile.flags()).map_err(|err| match err {
+            BuildError::Io(err) => err,
+            BuildError::Compile(errors) => {
+                io::Error::other(format!("{}.rs did not compile:\n{}", name, errors))
+            }
+        })?;
+        run(&binary)
+    })
+}
+
+fn run(binary: &Path) -> io::Result<Outcome> {
+    let run = Command::new(binary).env("RUST_BACKTRACE", "0").output()?;
+    if run.status.success() {
+        return Ok(Outcome::Exited {
+            stdout: String::from_utf8_lossy(&run.stdout).into_owned(),
+        });
+    }
+    Ok(Outcome::Panicked(parse_panic(&String::from_utf8_lossy(
+        &run.stderr,
+    ))))
+}
+
+// thread 'main' panicked at overflow.rs:3:13:
+// attempt to add with overflow
+// note: run with `RUST_BACKTRACE=1` ...
+fn parse_panic(stderr: &str) -> Panic {
+    let mut lines = stderr
+        .lines()
+        .skip_while(|line| !line.contains("panicked at "));
+    let location = lines.next().and_then(|line| {
+        let (_, location) = line.split_once("panicked at ")?;
+        Some(location.trim_end_matches(':').to_string())
+    });
+    let message: Vec<&str> = lines
+        .take_while(|line| !line.starts_with("note:"))
+        .collect();
+    Panic {
+        message: message.join("\n"),
+        location,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::hint::black_box;
+
+    #[test]
+    fn catch_returns_the_panic_message_and_location() {
+        let a = [1, 2, 3];
+        let caught = catch(|| a[black_box(5)]).unwrap_err();
+        assert_eq!(
+            caught.message,
+            "index out of bounds: the len is 3 but the index is 5"
+        );
+        assert_eq!(caught.location.as_deref(), Some("sandbox.rs"));
+        assert_eq!(catch(|| a[black_box(1)]), Ok(2));
+    }
+
+    #[test]
+    fn parse_panic_reads_the_child_stderr() {
+        let stderr = "\
+thread 'main' panicked at overflow.rs:3:13:
+attempt to add with overflow
+note: run with `RUST_BACKTRACE=1` environment variable to display a backtrace
+";
+        assert_eq!(
+            parse_panic(stderr),
+            Panic {
+                message: "attempt to add with overflow".to_string(),
+                location: Some("overflow.rs:3:13".to_string()),
+            }
+        );
+    }
+
+    #[test]
+    fn overflow_panics_in_debug_and_wraps_in_release() {
+        let source = "fn main() {\n    let x: u8 = std::hint::black_box(255);\n    println!(\"{}\", x + 1);\n}\n";
+        match run_program("overflow", source, Profile::Debug).unwrap() {
+            Outcome::Panicked(panic) => {
+                assert_eq!(panic.message, "attempt to add with overflow");
+                assert_eq!(panic.location.as_deref(), Some("overflow.rs:3:20"));
+            }
+            other => panic!("debug build did not panic: {:?}", other),
+        }
+        assert_eq!(
+            run_program("overflow", source, Profile::Release).unwrap(),
+            Outcome::Exited {
+                stdout: "0\n".to_string()
+            }
+        );
+    }
+}
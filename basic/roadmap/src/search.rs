@@ -0,0 +1,260 @@
+// Concept Search
+// `roadmap search <term>...` answers "where is saturating_sub explained?". Each
+// lesson section is indexed from its source: the section title, the function
+// name, identifiers, comments and string literals (which is most of what a
+// lesson prints). Helper functions a section calls from the same file are
+// indexed with it, so `first_word` is found in the section that uses it.
+
+use std::cmp::Reverse;
+use std::fs;
+use std::io;
+
+use crate::registry::{self, Entry};
+use crate::site::highlight::{self, Kind};
+use crate::site::{function_source, section_functions, source_path};
+
+// How much a match is worth, by where it was found
+const TITLE: u32 = 10;
+const FUNCTION: u32 = 8;
+const IDENTIFIER: u32 = 6;
+const PARTIAL_IDENTIFIER: u32 = 3;
+const COMMENT: u32 = 3;
+const TEXT: u32 = 2;
+// Repeated mentions count, up to this many lines per kind
+const MAX_LINES: usize = 3;
+
+// One indexed lesson section
+pub struct Document {
+    pub entry: Entry,
+    pub section: usize,
+    title: String,
+    function: String,
+    identifiers: Vec<String>,
+    comments: Vec<String>,
+    text: Vec<String>,
+    // The code the section runs, to pick a snippet from
+    source: String,
+}
+
+pub struct Hit<'a> {
+    pub document: &'a Document,
+    pub score: u32,
+    pub snippet: String,
+}
+
+impl Document {
+    // `roadmap run` invocation for exactly this section
+    pub fn command(&self) -> String {
+        format!(
+            "roadmap run {} --section {}",
+            self.entry.path(),
+            self.section
+        )
+    }
+
+    pub fn title(&self) -> &str {
+        &self.title
+    }
+
+    // Zero when the term appears nowhere in the section
+    fn score(&self, term: &str) -> u32 {
+        let mut score = 0;
+        if self.title.to_lowercase().contains(term) {
+            score += TITLE;
+        }
+        if self.function.to_lowercase().contains(term) {
+            score += FUNCTION;
+        }
+        if self
+            .identifiers
+            .iter()
+            .any(|ident| ident.to_lowercase() == term)
+        {
+            score += IDENTIFIER;
+        } else if self
+            .identifiers
+            .iter()
+            .any(|ident| ident.to_lowercase().contains(term))
+        {
+            score += PARTIAL_IDENTIFIER;
+        }
+        let mentions = |lines: &[String]| {
+            lines
+                .iter()
+                .filter(|line| line.to_lowercase().contains(term))
+                .take(MAX_LINES)
+                .count() as u32
+        };
+        score + COMMENT * mentions(&self.comments) + TEXT * mentions(&self.text)
+    }
+
+    // The most explanatory source line mentioning a term: a comment or a
+    // printed string when there is one, otherwise the first line of code
+    fn snippet(&self, terms: &[String]) -> String {
+        let lines: Vec<&str> = self
+            .source
+            .lines()
+            .map(str::trim)
+            .filter(|line| {
+                let lower = line.to_lowercase();
+                terms.iter().any(|term| lower.contains(term.as_str()))
+            })
+            .collect();
+        let best = lines
+            .iter()
+            .find(|line| line.starts_with("//") || line.contains('"'))
+            .or(lines.first())
+            .copied()
+            .unwrap_or(&self.title);
+        best.to_string()
+    }
+}
+
+// Indexes every section of every lesson
+pub fn index() -> io::Result<Vec<Document>> {
+    let mut documents = Vec::new();
+    for entry in registry::entries() {
+        let source = fs::read_to_string(source_path(&entry))?;
+        let functions = section_functions(&source);
+        for (index, section) in entry.lesson.sections.iter().enumerate() {
+            let function = functions.get(index).copied().unwrap_or_default();
+            let code = function_source(&source, function).unwrap_or_default();
+            documents.push(document(
+                entry,
+                index + 1,
+                section.title,
+                function,
+                code,
+                &source,
+            ));
+        }
+    }
+    Ok(documents)
+}
+
+fn document(
+    entry: Entry,
+    section: usize,
+    title: &str,
+    function: &str,
+    code: &str,
+    file: &str,
+) -> Document {
+    // The section function plus the same-file helpers it calls
+    let mut sources = vec![code.to_string()];
+    for (kind, text) in highlight::tokens(code) {
+        if kind == Kind::Ident
+            && text != function
+            && let Some(helper) = function_source(file, text)
+            && !sources.iter().any(|seen| seen == helper)
+        {
+            sources.push(helper.to_string());
+        }
+    }
+    let source = sources.join("\n\n");
+
+    let mut identifiers: Vec<String> = Vec::new();
+    let mut comments = Vec::new();
+    let mut text = Vec::new();
+    for (kind, token) in highlight::tokens(&source) {
+        match kind {
+            Kind::Ident | Kind::Type | Kind::Macro => {
+                let ident = token.trim_end_matches('!');
+                if !identifiers.iter().any(|seen| seen == ident) {
+                    identifiers.push(ident.to_string());
+                }
+            }
+            Kind::Comment => comments.extend(
+                token
+                    .lines()
+                    .map(|line| line.trim_start_matches(['/', '*', ' ']).to_string()),
+            ),
+            Kind::Str => text.extend(token.lines().map(|line| line.trim().to_string())),
+            _ => {}
+        }
+    }
+
+    Document {
+        entry,
+        section,
+        title: title.to_string(),
+        function: function.to_string(),
+        identifiers,
+        comments,
+        text,
+        source,
+    }
+}
+
+// Sections matching every term, best first; ties keep study order
+pub fn search<'a>(documents: &'a [Document], query: &str) -> Vec<Hit<'a>> {
+    let terms: Vec<String> = query.split_whitespace().map(str::to_lowercase).collect();
+    if terms.is_empty() {
+        return Vec::new();
+    }
+
+    let mut hits: Vec<Hit> = documents
+        .iter()
+        .filter_map(|document| {
+            let mut score = 0;
+            for term in &terms {
+                match document.score(term) {
+                    0 => return None,
+                    points => score += points,
+                }
+            }
+            Some(Hit {
+                document,
+                score,
+                snippet: document.snippet(&terms),
+            })
+        })
+        .collect();
+    // The sort is stable, so equal scores stay in registry order
+    hits.sort_by_key(|hit| Reverse(hit.score));
+    hits
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn finds_where_a_method_is_explained() {
+        let documents = index().unwrap();
+        let hits = search(&documents, "saturating_sub");
+        let paths: Vec<String> = hits
+            .iter()
+            .map(|hit| format!("{} {}", hit.document.entry.path(), hit.document.section))
+            .collect();
+        assert!(paths.contains(&"syntax_and_semantics/overflow_methods 3".to_string()));
+        assert!(paths.contains(&"syntax_and_semantics/integer_overflow 4".to_string()));
+        assert!(hits[0].snippet.contains("saturating_sub"));
+    }
+
+    #[test]
+    fn every_term_must_match_and_titles_rank_first() {
+        let documents = index().unwrap();
+        let hits = search(&documents, "semicolon trap");
+        assert_eq!(
+            hits[0].document.entry.path(),
+            "syntax_and_semantics/functions"
+        );
+        assert_eq!(hits[0].document.section, 6);
+        assert_eq!(
+            hits[0].document.command(),
+            "roadmap run syntax_and_semantics/functions --section 6"
+        );
+        assert!(search(&documents, "semicolon zzzz").is_empty());
+    }
+
+    #[test]
+    fn helpers_are_indexed_with_the_section_that_calls_them() {
+        let documents = index().unwrap();
+        let hits = search(&documents, "first_word");
+        assert_eq!(
+            hits[0].document.entry.path(),
+            "ownership_system/references_borrowing_slices"
+        );
+    }
+}
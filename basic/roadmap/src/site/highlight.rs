@@ -0,0 +1,239 @@
+// Rust Syntax Highlighting
+// A small tokenizer that is good enough for the lesson files: it knows about
+// comments, string/char literals, lifetimes, numbers, keywords, macros and
+// type names. Everything it does not recognise is passed through as-is.
+
+use super::html::escape;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Kind {
+    Comment,
+    Str,
+    Char,
+    Lifetime,
+    Number,
+    Keyword,
+    Macro,
+    Type,
+    Ident,
+    Punct,
+    Space,
+}
+
+impl Kind {
+    // CSS class used in the generated pages, None for unstyled tokens
+    fn class(self) -> Option<&'static str> {
+        match self {
+            Kind::Comment => Some("cm"),
+            Kind::Str | Kind::Char => Some("st"),
+            Kind::Lifetime => Some("lt"),
+            Kind::Number => Some("nu"),
+            Kind::Keyword => Some("kw"),
+            Kind::Macro => Some("ma"),
+            Kind::Type => Some("ty"),
+            Kind::Ident | Kind::Punct | Kind::Space => None,
+        }
+    }
+}
+
+const KEYWORDS: &[&str] = &[
+    "as", "break", "const", "continue", "crate", "dyn", "else", "enum", "false", "fn", "for", "if",
+    "impl", "in", "let", "loop", "match", "mod", "move", "mut", "pub", "ref", "return", "self",
+    "Self", "static", "struct", "super", "trait", "true", "type", "unsafe", "use", "where",
+    "while",
+];
+
+// Splits `source` into classified tokens; concatenating them gives back `source`
+pub fn tokens(source: &str) -> Vec<(Kind, &str)> {
+    let bytes = source.as_bytes();
+    let mut tokens = Vec::new();
+    let mut start = 0;
+
+    while start < source.len() {
+        let rest = &source[start..];
+        let first = rest.chars().next().unwrap();
+        let (kind, len) = if rest.starts_with("//") {
+            (Kind::Comment, rest.find('\n').unwrap_or(rest.len()))
+        } else if rest.starts_with("/*") {
+            (
+                Kind::Comment,
+                rest.find("*/").map_or(rest.len(), |end| end + 2),
+            )
+        } else if let Some(len) = raw_string(rest) {
+            (Kind::Str, len)
+        } else if first == '"' || rest.starts_with("b\"") {
+            (Kind::Str, quoted(rest, rest.find('"').unwrap(), '"'))
+        } else if first == '\'' {
+            char_or_lifetime(rest)
+        } else if first.is_whitespace() {
+            (Kind::Space, take_while(rest, char::is_whitespace))
+        } else if first.is_ascii_digit() {
+            (Kind::Number, number(rest))
+        } else if first.is_alphabetic() || first == '_' {
+            let len = take_while(rest, |c| c.is_alphanumeric() || c == '_');
+            let word = &rest[..len];
+            if bytes.get(start + len) == Some(&b'!') && !KEYWORDS.contains(&word) {
+                (Kind::Macro, len + 1)
+            } else if KEYWORDS.contains(&word) {
+                (Kind::Keyword, len)
+            } else if word.starts_with(|c: char| c.is_uppercase()) {
+                (Kind::Type, len)
+            } else {
+                (Kind::Ident, len)
+            }
+        } else {
+            (Kind::Punct, first.len_utf8())
+        };
+        tokens.push((kind, &rest[..len]));
+        start += len;
+    }
+    tokens
+}
+
+// Source wrapped in <span>s for the stylesheet in html.rs
+pub fn rust(source: &str) -> String {
+    let mut out = String::with_capacity(source.len() * 2);
+    for (kind, text) in tokens(source) {
+        match kind.class() {
+            Some(class) => {
+                out.push_str(&format!(
+                    "<span class=\"{}\">{}</span>",
+                    class,
+                    escape(text)
+                ));
+            }
+            None => out.push_str(&escape(text)),
+        }
+    }
+    out
+}
+
+fn take_while(text: &str, keep: impl Fn(char) -> bool) -> usize {
+    text.find(|c: char| !keep(c)).unwrap_or(text.len())
+}
+
+// A `.` belongs to a number only when a digit follows, so neither the range in
+// `1..5` nor the method call in `0u8.saturating_sub(1)` is swallowed
+fn number(text: &str) -> usize {
+    let mut chars = text.char_indices().peekable();
+    while let Some((index, c)) = chars.next() {
+        let fraction = c == '.' && chars.peek().is_some_and(|(_, next)| next.is_ascii_digit());
+        if !(c.is_alphanumeric() || c == '_' || fraction) {
+            return index;
+        }
+    }
+    text.len()
+}
+
+// Length of a literal that opens at `open` and ends at the next unescaped `close`
+fn quoted(text: &str, open: usize, close: char) -> usize {
+    let mut escaped = false;
+    for (index, c) in text[open + 1..].char_indices() {
+        match c {
+            _ if escaped => escaped = false,
+            '\\' => escaped = true,
+            c if c == close => return open + 1 + index + c.len_utf8(),
+            _ => {}
+        }
+    }
+    text.len()
+}
+
+// r"..." and r#"..."# (optionally prefixed with b)
+fn raw_string(text: &str) -> Option<usize> {
+    let after_prefix = text.strip_prefix("br").or_else(|| text.strip_prefix('r'))?;
+    let hashes = take_while(after_prefix, |c| c == '#');
+    if !after_prefix[hashes..].starts_with('"') {
+        return None;
+    }
+    let prefix = text.len() - after_prefix.len();
+    let closing = format!("\"{}", "#".repeat(hashes));
+    let body = prefix + hashes + 1;
+    Some(
+        text[body..]
+            .find(&closing)
+            .map_or(text.len(), |end| body + end + closing.len()),
+    )
+}
+
+// 'a' and '\n' are chars, 'a and 'static are lifetimes
+fn char_or_lifetime(text: &str) -> (Kind, usize) {
+    let mut chars = text.char_indices().skip(1);
+    match (chars.next(), chars.next()) {
+        (Some((_, '\\')), _) => (Kind::Char, quoted(text, 0, '\'')),
+        (Some(_), Some((index, '\''))) => (Kind::Char, index + 1),
+        (Some((_, c)), _) if c.is_alphabetic() || c == '_' => {
+            let len = take_while(&text[1..], |c| c.is_alphanumeric() || c == '_');
+            (Kind::Lifetime, 1 + len)
+        }
+        _ => (Kind::Punct, 1),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn kinds(source: &str) -> Vec<(Kind, &str)> {
+        tokens(source)
+            .into_iter()
+            .filter(|(kind, _)| *kind != Kind::Space)
+            .collect()
+    }
+
+    #[test]
+    fn classifies_common_tokens() {
+        assert_eq!(
+            kinds("let s: &'static str = \"a\\\"b\"; // done"),
+            vec![
+                (Kind::Keyword, "let"),
+                (Kind::Ident, "s"),
+                (Kind::Punct, ":"),
+                (Kind::Punct, "&"),
+                (Kind::Lifetime, "'static"),
+                (Kind::Ident, "str"),
+                (Kind::Punct, "="),
+                (Kind::Str, "\"a\\\"b\""),
+                (Kind::Punct, ";"),
+                (Kind::Comment, "// done"),
+            ]
+        );
+        assert_eq!(
+            kinds("println!(\"{}\", 'x'); 1..=5"),
+            vec![
+                (Kind::Macro, "println!"),
+                (Kind::Punct, "("),
+                (Kind::Str, "\"{}\""),
+                (Kind::Punct, ","),
+                (Kind::Char, "'x'"),
+                (Kind::Punct, ")"),
+                (Kind::Punct, ";"),
+                (Kind::Number, "1"),
+                (Kind::Punct, "."),
+                (Kind::Punct, "."),
+                (Kind::Punct, "="),
+                (Kind::Number, "5"),
+            ]
+        );
+        assert_eq!(
+            kinds("2.5 + 0u8.saturating_sub(1)"),
+            vec![
+                (Kind::Number, "2.5"),
+                (Kind::Punct, "+"),
+                (Kind::Number, "0u8"),
+                (Kind::Punct, "."),
+                (Kind::Ident, "saturating_sub"),
+                (Kind::Punct, "("),
+                (Kind::Number, "1"),
+                (Kind::Punct, ")"),
+            ]
+        );
+    }
+
+    #[test]
+    fn tokens_cover_the_whole_source() {
+        let source = include_str!("../syntax_and_semantics/functions.rs");
+        let joined: String = tokens(source).iter().map(|(_, text)| *text).collect();
+        assert_eq!(joined, source);
+    }
+}
@@ -0,0 +1,73 @@
+// HTML Helpers
+// Escaping, the shared page layout and the stylesheet written next to the pages.
+
+pub fn escape(text: &str) -> String {
+    let mut out = String::with_capacity(text.len());
+    for c in text.chars() {
+        match c {
+            '&' => out.push_str("&amp;"),
+            '<' => out.push_str("&lt;"),
+            '>' => out.push_str("&gt;"),
+            '"' => out.push_str("&quot;"),
+            c => out.push(c),
+        }
+    }
+    out
+}
+
+// A complete page; `root` is the relative path back to the site root ("" or "../")
+pub fn page(title: &str, root: &str, breadcrumbs: &str, body: &str) -> String {
+    format!(
+        "<!DOCTYPE html>
+<html lang=\"en\">
+<head>
+<meta charset=\"utf-8\">
+<meta name=\"viewport\" content=\"width=device-width, initial-scale=1\">
+<title>{title} · Rust Roadmap</title>
+<link rel=\"stylesheet\" href=\"{root}style.css\">
+</head>
+<body>
+<nav class=\"crumbs\">{breadcrumbs}</nav>
+<main>
+{body}
+</main>
+</body>
+</html>
+",
+        title = escape(title),
+        root = root,
+        breadcrumbs = breadcrumbs,
+        body = body
+    )
+}
+
+pub const STYLESHEET: &str = "\
+body { margin: 0; font: 16px/1.55 system-ui, sans-serif; color: #1f2328; background: #fff; }
+main { max-width: 960px; margin: 0 auto; padding: 0 24px 64px; }
+nav.crumbs { padding: 12px 24px; background: #f6f8fa; border-bottom: 1px solid #d0d7de; }
+nav.crumbs a { color: #0969da; text-decoration: none; }
+h1, h2, h3 { line-height: 1.25; }
+h2.section { margin-top: 48px; padding-top: 16px; border-top: 1px solid #d0d7de; }
+a { color: #0969da; }
+pre { padding: 12px 16px; overflow-x: auto; border-radius: 6px; font: 14px/1.45 ui-monospace, monospace; }
+pre.code { background: #f6f8fa; border: 1px solid #d0d7de; }
+pre.output { background: #0d1117; color: #e6edf3; }
+code { font: 0.9em ui-monospace, monospace; background: #eff1f3; padding: 1px 4px; border-radius: 4px; }
+pre code { background: none; padding: 0; }
+.label { margin: 16px 0 4px; font-size: 13px; text-transform: uppercase; color: #656d76; }
+.note { color: #424a53; }
+ul.results { list-style: none; padding-left: 0; }
+ul.results li::before { content: '→ '; color: #1a7f37; }
+.warning { border-left: 4px solid #d1242f; background: #fff5f5; padding: 8px 12px; white-space: pre-wrap; }
+.notes { border: 1px solid #d0d7de; border-radius: 6px; padding: 0 20px; margin: 24px 0; }
+.pager { display: flex; justify-content: space-between; margin-top: 48px; }
+table.lessons { border-collapse: collapse; width: 100%; }
+table.lessons td { padding: 6px 12px 6px 0; border-bottom: 1px solid #eaeef2; }
+details summary { cursor: pointer; margin: 16px 0; }
+.cm { color: #6e7781; font-style: italic; }
+.st { color: #0a3069; }
+.lt, .ma { color: #8250df; }
+.nu { color: #0550ae; }
+.kw { color: #cf222e; }
+.ty { color: #953800; }
+";
@@ -0,0 +1,157 @@
+// Markdown to HTML
+// Just the subset the companion notes use: headings, paragraphs, bullet and
+// numbered lists, fenced code blocks, **bold** and `code`. Code spans that
+// hold a URL become links, since the notes write references that way.
+
+use super::highlight;
+use super::html::escape;
+
+pub fn to_html(markdown: &str) -> String {
+    let mut out = String::new();
+    let mut paragraph: Vec<&str> = Vec::new();
+    let mut list: Option<&str> = None;
+    let mut lines = markdown.lines();
+
+    while let Some(line) = lines.next() {
+        let trimmed = line.trim();
+
+        if let Some(language) = trimmed.strip_prefix("```") {
+            close_paragraph(&mut paragraph, &mut out);
+            close_list(&mut list, &mut out);
+            let code: Vec<&str> = lines
+                .by_ref()
+                .take_while(|l| !l.trim().starts_with("```"))
+                .collect();
+            let code = code.join("\n");
+            let body = match language.trim() {
+                "" | "rust" => highlight::rust(&code),
+                _ => escape(&code),
+            };
+            out.push_str(&format!(
+                "<pre class=\"code\"><code>{}</code></pre>\n",
+                body
+            ));
+            continue;
+        }
+
+        if trimmed.is_empty() {
+            close_paragraph(&mut paragraph, &mut out);
+            continue;
+        }
+
+        let heading = trimmed.chars().take_while(|&c| c == '#').count();
+        if (1..=6).contains(&heading) && trimmed[heading..].starts_with(' ') {
+            close_paragraph(&mut paragraph, &mut out);
+            close_list(&mut list, &mut out);
+            // Notes sit below the page's own <h1>, so shift every level down one
+            let level = (heading + 1).min(6);
+            let text = inline(trimmed[heading..].trim());
+            out.push_str(&format!("<h{0}>{1}</h{0}>\n", level, text));
+            continue;
+        }
+
+        if let Some((tag, item)) = list_item(trimmed) {
+            close_paragraph(&mut paragraph, &mut out);
+            if list != Some(tag) {
+                close_list(&mut list, &mut out);
+                out.push_str(&format!("<{}>\n", tag));
+                list = Some(tag);
+            }
+            out.push_str(&format!("<li>{}</li>\n", inline(item)));
+            continue;
+        }
+
+        close_list(&mut list, &mut out);
+        paragraph.push(trimmed);
+    }
+    close_paragraph(&mut paragraph, &mut out);
+    close_list(&mut list, &mut out);
+    out
+}
+
+fn close_paragraph(lines: &mut Vec<&str>, out: &mut String) {
+    if !lines.is_empty() {
+        out.push_str(&format!("<p>{}</p>\n", inline(&lines.join(" "))));
+        lines.clear();
+    }
+}
+
+fn close_list(list: &mut Option<&str>, out: &mut String) {
+    if let Some(tag) = list.take() {
+        out.push_str(&format!("</{}>\n", tag));
+    }
+}
+
+// "- item" / "* item" are bullets, "1. item" is numbered
+fn list_item(line: &str) -> Option<(&'static str, &str)> {
+    if let Some(item) = line.strip_prefix("- ").or_else(|| line.strip_prefix("* ")) {
+        return Some(("ul", item));
+    }
+    let digits = line.chars().take_while(char::is_ascii_digit).count();
+    match line[digits..].strip_prefix(". ") {
+        Some(item) if digits > 0 => Some(("ol", item)),
+        _ => None,
+    }
+}
+
+// **bold** and `code` inside a line of text
+fn inline(text: &str) -> String {
+    let mut out = String::new();
+    let mut rest = text;
+    let mut bold = false;
+    while !rest.is_empty() {
+        if let Some(after) = rest.strip_prefix('`')
+            && let Some(end) = after.find('`')
+        {
+            out.push_str(&code_span(&after[..end]));
+            rest = &after[end + 1..];
+            continue;
+        }
+        if let Some(after) = rest.strip_prefix("**") {
+            out.push_str(if bold { "</strong>" } else { "<strong>" });
+            bold = !bold;
+            rest = after;
+            continue;
+        }
+        let next = rest
+            .char_indices()
+            .skip(1)
+            .find(|&(_, c)| c == '`' || c == '*')
+            .map_or(rest.len(), |(index, _)| index);
+        out.push_str(&escape(&rest[..next]));
+        rest = &rest[next..];
+    }
+    if bold {
+        out.push_str("</strong>");
+    }
+    out
+}
+
+fn code_span(code: &str) -> String {
+    if code.starts_with("https://") || code.starts_with("http://") {
+        let url = escape(code);
+        format!("<a href=\"{0}\"><code>{0}</code></a>", url)
+    } else {
+        format!("<code>{}</code>", escape(code))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn converts_the_subset_used_by_the_notes() {
+        let html = to_html(
+            "## Constants\n\n**WHAT ?**: never <change>\n\n- one\n- ref: `https://x.dev`\n\n```rust\nlet x = 5;\n```\n",
+        );
+        assert_eq!(
+            html,
+            "<h3>Constants</h3>\n\
+             <p><strong>WHAT ?</strong>: never &lt;change&gt;</p>\n\
+             <ul>\n<li>one</li>\n\
+             <li>ref: <a href=\"https://x.dev\"><code>https://x.dev</code></a></li>\n</ul>\n\
+             <pre class=\"code\"><code><span class=\"kw\">let</span> x = <span class=\"nu\">5</span>;</code></pre>\n"
+        );
+    }
+}
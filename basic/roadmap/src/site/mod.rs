@@ -0,0 +1,335 @@
+// Course Site
+// `roadmap site` turns the registry into a static, offline HTML course: an index
+// of topics, a page per topic (with the topic's README.md notes) and a page per
+// lesson that pairs each section's source with the output it produces, plus the
+// lesson's companion <lesson>.md notes when there are any.
+
+pub mod highlight;
+mod html;
+mod markdown;
+
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+use crate::output::{self, Event};
+use crate::registry::{self, Entry, TOPICS, Topic};
+use highlight::Kind;
+use html::escape;
+
+pub const SOURCE_DIR: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/src");
+pub const DEFAULT_OUT_DIR: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/target/site");
+
+// Writes the whole site into `out_dir` and returns the number of pages
+pub fn generate(out_dir: &Path) -> io::Result<usize> {
+    fs::create_dir_all(out_dir)?;
+    fs::write(out_dir.join("style.css"), html::STYLESHEET)?;
+    fs::write(out_dir.join("index.html"), home_page())?;
+    let mut pages = 1;
+
+    for topic in TOPICS {
+        let dir = out_dir.join(topic.id);
+        fs::create_dir_all(&dir)?;
+        fs::write(dir.join("index.html"), topic_page(topic)?)?;
+        pages += 1;
+    }
+
+    let entries: Vec<Entry> = registry::entries().collect();
+    for (index, entry) in entries.iter().enumerate() {
+        let previous = index.checked_sub(1).map(|i| entries[i]);
+        let next = entries.get(index + 1).copied();
+        let page = lesson_page(*entry, previous, next)?;
+        let file = out_dir
+            .join(entry.topic.id)
+            .join(format!("{}.html", entry.lesson.id));
+        fs::write(file, page)?;
+        pages += 1;
+    }
+    Ok(pages)
+}
+
+pub fn source_path(entry: &Entry) -> PathBuf {
+    PathBuf::from(SOURCE_DIR)
+        .join(entry.topic.id)
+        .join(format!("{}.rs", entry.lesson.id))
+}
+
+// Companion notes are optional, only a few lessons have them
+fn read_notes(path: PathBuf) -> io::Result<Option<String>> {
+    match fs::read_to_string(path) {
+        Ok(notes) => Ok(Some(notes)),
+        Err(err) if err.kind() == io::ErrorKind::NotFound => Ok(None),
+        Err(err) => Err(err),
+    }
+}
+
+fn home_page() -> String {
+    let mut body = String::from("<h1>Rust Roadmap</h1>\n");
+    for topic in TOPICS {
+        body.push_str(&format!(
+            "<h2><a href=\"{0}/index.html\">{1}</a></h2>\n<ol>\n",
+            topic.id,
+            escape(topic.title)
+        ));
+        for lesson in topic.lessons {
+            body.push_str(&format!(
+                "<li><a href=\"{}/{}.html\">{}</a></li>\n",
+                topic.id,
+                lesson.id,
+                escape(lesson.title)
+            ));
+        }
+        body.push_str("</ol>\n");
+    }
+    html::page("Rust Roadmap", "", "Rust Roadmap", &body)
+}
+
+fn topic_page(topic: &Topic) -> io::Result<String> {
+    let mut body = format!(
+        "<h1>{}</h1>\n<table class=\"lessons\">\n",
+        escape(topic.title)
+    );
+    for (index, lesson) in topic.lessons.iter().enumerate() {
+        let count = lesson.sections.len();
+        let noun = if count == 1 { "section" } else { "sections" };
+        body.push_str(&format!(
+            "<tr><td>{}.</td><td><a href=\"{}.html\">{}</a></td><td>{} {}</td></tr>\n",
+            index + 1,
+            lesson.id,
+            escape(lesson.title),
+            count,
+            noun
+        ));
+    }
+    body.push_str("</table>\n");
+
+    let readme = PathBuf::from(SOURCE_DIR).join(topic.id).join("README.md");
+    if let Some(notes) = read_notes(readme)? {
+        body.push_str("<h2>Notes</h2>\n<div class=\"notes\">\n");
+        body.push_str(&markdown::to_html(&notes));
+        body.push_str("</div>\n");
+    }
+
+    let crumbs = format!(
+        "<a href=\"../index.html\">Rust Roadmap</a> / {}",
+        escape(topic.title)
+    );
+    Ok(html::page(topic.title, "../", &crumbs, &body))
+}
+
+fn lesson_page(entry: Entry, previous: Option<Entry>, next: Option<Entry>) -> io::Result<String> {
+    let source = fs::read_to_string(source_path(&entry))?;
+    let functions = section_functions(&source);
+    let lesson = entry.lesson;
+
+    let mut body = format!(
+        "<h1>{}</h1>\n<p><code>{}</code></p>\n<ol>\n",
+        escape(lesson.title),
+        entry.path()
+    );
+    for (index, section) in lesson.sections.iter().enumerate() {
+        body.push_str(&format!(
+            "<li><a href=\"#section-{}\">{}</a></li>\n",
+            index + 1,
+            escape(section.title)
+        ));
+    }
+    body.push_str("</ol>\n");
+
+    let notes = PathBuf::from(SOURCE_DIR)
+        .join(entry.topic.id)
+        .join(format!("{}.md", lesson.id));
+    if let Some(notes) = read_notes(notes)? {
+        body.push_str("<div class=\"notes\">\n");
+        body.push_str(&markdown::to_html(&notes));
+        body.push_str("</div>\n");
+    }
+
+    for (index, section) in lesson.sections.iter().enumerate() {
+        let number = index + 1;
+        body.push_str(&format!(
+            "<h2 class=\"section\" id=\"section-{0}\">{0}. {1}</h2>\n",
+            number,
+            escape(section.title)
+        ));
+        let code = functions
+            .get(index)
+            .and_then(|name| function_source(&source, name));
+        if let Some(code) = code {
+            body.push_str("<div class=\"label\">Source</div>\n");
+            body.push_str(&format!(
+                "<pre class=\"code\"><code>{}</code></pre>\n",
+                highlight::rust(code)
+            ));
+        }
+        let (events, ()) = output::record(section.run);
+        body.push_str("<div class=\"label\">Output</div>\n");
+        body.push_str(&events_html(&events));
+    }
+
+    body.push_str(&format!(
+        "<details><summary>Full source of {}.rs</summary>\n<pre class=\"code\"><code>{}</code></pre>\n</details>\n",
+        lesson.id,
+        highlight::rust(&source)
+    ));
+
+    let link = |entry: Option<Entry>, label: &str| match entry {
+        Some(entry) => format!(
+            "<a href=\"../{}/{}.html\">{} {}</a>",
+            entry.topic.id,
+            entry.lesson.id,
+            label,
+            escape(entry.lesson.title)
+        ),
+        None => "<span></span>".to_string(),
+    };
+    body.push_str(&format!(
+        "<div class=\"pager\">{}{}</div>\n",
+        link(previous, "←"),
+        link(next, "→")
+    ));
+
+    let crumbs = format!(
+        "<a href=\"../index.html\">Rust Roadmap</a> / <a href=\"index.html\">{}</a> / {}",
+        escape(entry.topic.title),
+        escape(lesson.title)
+    );
+    Ok(html::page(lesson.title, "../", &crumbs, &body))
+}
+
+// A section's recorded output, grouped like the Markdown renderer does
+fn events_html(events: &[Event]) -> String {
+    let mut out = String::new();
+    let mut text: Vec<&str> = Vec::new();
+    let mut results: Vec<&str> = Vec::new();
+
+    let flush_text = |text: &mut Vec<&str>, out: &mut String| {
+        let block = output::text_block(text);
+        if !block.is_empty() {
+            out.push_str(&format!(
+                "<pre class=\"output\">{}</pre>\n",
+                escape(&block.join("\n"))
+            ));
+        }
+        text.clear();
+    };
+    let flush_results = |results: &mut Vec<&str>, out: &mut String| {
+        if !results.is_empty() {
+            out.push_str("<ul class=\"results\">\n");
+            for result in results.iter() {
+                out.push_str(&format!("<li><code>{}</code></li>\n", escape(result)));
+            }
+            out.push_str("</ul>\n");
+        }
+        results.clear();
+    };
+
+    for event in events {
+        if !matches!(event, Event::Text(_)) {
+            flush_text(&mut text, &mut out);
+        }
+        if !matches!(event, Event::Result(_)) {
+            flush_results(&mut results, &mut out);
+        }
+        match event {
+            Event::Text(line) => text.push(line),
+            Event::Result(value) => results.push(value),
+            Event::Note(note) => out.push_str(&format!("<p class=\"note\">{}</p>\n", escape(note))),
+            Event::Code(code) => out.push_str(&format!(
+                "<pre class=\"code\"><code>{}</code></pre>\n",
+                highlight::rust(code)
+            )),
+            Event::Warning(warning) => out.push_str(&format!(
+                "<div class=\"warning\">⚠ {}</div>\n",
+                escape(warning)
+            )),
+            Event::Lesson { .. } | Event::Section { .. } => {}
+        }
+    }
+    flush_text(&mut text, &mut out);
+    flush_results(&mut results, &mut out);
+    out
+}
+
+// Function names from the `Section::new("Title", function)` entries, in order
+pub fn section_functions(source: &str) -> Vec<&str> {
+    let tokens: Vec<(Kind, &str)> = highlight::tokens(source)
+        .into_iter()
+        .filter(|(kind, _)| !matches!(kind, Kind::Space | Kind::Comment))
+        .collect();
+    tokens
+        .windows(8)
+        .filter_map(|window| match window {
+            [
+                (_, "Section"),
+                (_, ":"),
+                (_, ":"),
+                (_, "new"),
+                (_, "("),
+                (Kind::Str, _),
+                (_, ","),
+                (Kind::Ident, name),
+            ] => Some(*name),
+            _ => None,
+        })
+        .collect()
+}
+
+// `fn name() { ... }` together with the comment lines directly above it
+pub fn function_source<'a>(source: &'a str, name: &str) -> Option<&'a str> {
+    let signature = format!("fn {}(", name);
+    let line_start = source
+        .match_indices(&signature)
+        .map(|(index, _)| index)
+        .find(|&index| index == 0 || source[..index].ends_with('\n'))?;
+
+    let mut start = line_start;
+    for line in source[..line_start].lines().rev() {
+        if !line.trim_start().starts_with("//") {
+            break;
+        }
+        start -= line.len() + 1;
+    }
+
+    let mut depth = 0;
+    let mut offset = line_start;
+    for (kind, text) in highlight::tokens(&source[line_start..]) {
+        offset += text.len();
+        match (kind, text) {
+            (Kind::Punct, "{") => depth += 1,
+            (Kind::Punct, "}") => {
+                depth -= 1;
+                if depth == 0 {
+                    return Some(&source[start..offset]);
+                }
+            }
+            _ => {}
+        }
+    }
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const FUNCTIONS: &str = include_str!("../syntax_and_semantics/functions.rs");
+
+    #[test]
+    fn section_functions_follow_the_lesson() {
+        let functions = section_functions(FUNCTIONS);
+        assert_eq!(functions.len(), 6);
+        assert_eq!(functions[0], "demonstrate_basic_functions");
+        assert_eq!(functions[5], "demonstrate_semicolon_trap");
+    }
+
+    #[test]
+    fn function_source_includes_leading_comment() {
+        let code = function_source(FUNCTIONS, "demonstrate_parameters").unwrap();
+        assert!(code.starts_with(
+            "// Function parameters - must specify types!\nfn demonstrate_parameters() {"
+        ));
+        assert!(code.ends_with("\n}"));
+        assert!(!code.contains("fn another_function_with_param"));
+    }
+}
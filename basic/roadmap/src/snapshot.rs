@@ -0,0 +1,192 @@
+// Golden-Output Snapshots
+// Each lesson's full output is checked in under snapshots/<topic>/<lesson>.txt.
+// `cargo test` fails when a lesson prints something different, and
+// `roadmap snapshot review` shows the diff and lets you accept the new output.
+
+use std::fs;
+use std::io;
+use std::path::PathBuf;
+
+use crate::output;
+use crate::registry::Entry;
+
+pub const SNAPSHOT_DIR: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/snapshots");
+
+pub enum Status {
+    Matches,
+    Missing,
+    Changed { expected: String },
+}
+
+// The result of running one lesson and comparing it with its snapshot
+pub struct Report {
+    pub entry: Entry,
+    pub actual: String,
+    pub status: Status,
+}
+
+impl Report {
+    pub fn matches(&self) -> bool {
+        matches!(self.status, Status::Matches)
+    }
+
+    // Human readable diff, expected lines prefixed with '-' and actual with '+'
+    pub fn diff(&self) -> String {
+        match &self.status {
+            Status::Matches => String::new(),
+            Status::Missing => render_diff("", &self.actual),
+            Status::Changed { expected } => render_diff(expected, &self.actual),
+        }
+    }
+}
+
+pub fn path(entry: &Entry) -> PathBuf {
+    PathBuf::from(SNAPSHOT_DIR)
+        .join(entry.topic.id)
+        .join(format!("{}.txt", entry.lesson.id))
+}
+
+pub fn check(entry: Entry) -> Report {
+    let actual = output::capture(|| entry.run());
+    let status = match fs::read_to_string(path(&entry)) {
+        Ok(expected) if expected == actual => Status::Matches,
+        Ok(expected) => Status::Changed { expected },
+        Err(_) => Status::Missing,
+    };
+    Report {
+        entry,
+        actual,
+        status,
+    }
+}
+
+// Overwrites the snapshot with the output from the report
+pub fn accept(report: &Report) -> io::Result<()> {
+    let path = path(&report.entry);
+    if let Some(dir) = path.parent() {
+        fs::create_dir_all(dir)?;
+    }
+    fs::write(path, &report.actual)
+}
+
+#[derive(Debug, PartialEq)]
+pub enum DiffLine<'a> {
+    Same(&'a str),
+    Removed(&'a str),
+    Added(&'a str),
+}
+
+// Line diff based on the longest common subsequence
+pub fn diff_lines<'a>(expected: &'a str, actual: &'a str) -> Vec<DiffLine<'a>> {
+    let old: Vec<&str> = expected.lines().collect();
+    let new: Vec<&str> = actual.lines().collect();
+
+    // lcs[i][j] = length of the LCS of old[i..] and new[j..]
+    let mut lcs = vec![vec![0usize; new.len() + 1]; old.len() + 1];
+    for i in (0..old.len()).rev() {
+        for j in (0..new.len()).rev() {
+            lcs[i][j] = if old[i] == new[j] {
+                lcs[i + 1][j + 1] + 1
+            } else {
+                lcs[i + 1][j].max(lcs[i][j + 1])
+            };
+        }
+    }
+
+    let mut lines = Vec::new();
+    let (mut i, mut j) = (0, 0);
+    while i < old.len() && j < new.len() {
+        if old[i] == new[j] {
+            lines.push(DiffLine::Same(old[i]));
+            i += 1;
+            j += 1;
+        } else if lcs[i + 1][j] >= lcs[i][j + 1] {
+            lines.push(DiffLine::Removed(old[i]));
+            i += 1;
+        } else {
+            lines.push(DiffLine::Added(new[j]));
+            j += 1;
+        }
+    }
+    lines.extend(old[i..].iter().map(|line| DiffLine::Removed(line)));
+    lines.extend(new[j..].iter().map(|line| DiffLine::Added(line)));
+    lines
+}
+
+// Only changed lines plus a little context, like `diff -u`
+pub fn render_diff(expected: &str, actual: &str) -> String {
+    const CONTEXT: usize = 2;
+
+    let lines = diff_lines(expected, actual);
+    let changed: Vec<usize> = lines
+        .iter()
+        .enumerate()
+        .filter(|(_, line)| !matches!(line, DiffLine::Same(_)))
+        .map(|(index, _)| index)
+        .collect();
+
+    let mut out = String::new();
+    let mut last_printed = None;
+    for (index, line) in lines.iter().enumerate() {
+        let near_change = changed
+            .iter()
+            .any(|&c| index + CONTEXT >= c && index <= c + CONTEXT);
+        if !near_change {
+            continue;
+        }
+        if last_printed.is_some_and(|last| last + 1 != index) {
+            out.push_str("   ...\n");
+        }
+        let (marker, text) = match line {
+            DiffLine::Same(text) => (' ', text),
+            DiffLine::Removed(text) => ('-', text),
+            DiffLine::Added(text) => ('+', text),
+        };
+        out.push_str(&format!("{} {}\n", marker, text));
+        last_printed = Some(index);
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::registry;
+
+    #[test]
+    fn every_lesson_matches_its_snapshot() {
+        let failures: Vec<String> = registry::entries()
+            .map(check)
+            .filter(|report| !report.matches())
+            .map(|report| format!("{}:\n{}", report.entry.path(), report.diff()))
+            .collect();
+
+        assert!(
+            failures.is_empty(),
+            "lesson output changed, review with `cargo run -- snapshot review`\n\n{}",
+            failures.join("\n")
+        );
+    }
+
+    #[test]
+    fn diff_marks_removed_and_added_lines() {
+        let lines = diff_lines("a\nb\nc\n", "a\nx\nc\n");
+        assert_eq!(
+            lines,
+            vec![
+                DiffLine::Same("a"),
+                DiffLine::Removed("b"),
+                DiffLine::Added("x"),
+                DiffLine::Same("c"),
+            ]
+        );
+    }
+
+    #[test]
+    fn capture_collects_lesson_output() {
+        let entry = registry::find("constants").unwrap();
+        let text = output::capture(|| entry.run());
+        assert!(text.starts_with("=== Constants ===\n"));
+        assert!(text.contains("THREE_HOURS_IN_SECONDS = 10800"));
+    }
+}
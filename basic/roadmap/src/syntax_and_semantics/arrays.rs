@@ -1,160 +1,185 @@
-fn main() {
-    println!("=== Array Type in Rust ===\n");
-
-    // 1. Creating arrays
-    demonstrate_creating_arrays();
-
-    // 2. Array type syntax
-    demonstrate_array_type_syntax();
-
-    // 3. Initializing arrays with same value
-    demonstrate_array_initialization();
-
-    // 4. Accessing array elements
-    demonstrate_accessing_elements();
-
-    // 5. Arrays vs tuples
-    demonstrate_arrays_vs_tuples();
-
-    // 6. Practical example: months
-    demonstrate_months_example();
-
-    // 7. Array bounds checking
-    demonstrate_bounds_checking();
-}
+use std::hint::black_box;
+
+use crate::output;
+use crate::registry::{Difficulty, Lesson, Meta, Section};
+use crate::sandbox;
+
+pub const LESSON: Lesson = Lesson {
+    id: "arrays",
+    title: "Array Type in Rust",
+    meta: Meta {
+        difficulty: Difficulty::Beginner,
+        minutes: 15,
+        book: Some("3.2"),
+        prerequisites: &["data_types"],
+        tags: &["types", "arrays", "panics"],
+    },
+    sections: &[
+        Section::new("Creating Arrays", demonstrate_creating_arrays),
+        Section::new("Array Type Syntax", demonstrate_array_type_syntax),
+        Section::new(
+            "Initializing Arrays with Same Value",
+            demonstrate_array_initialization,
+        ),
+        Section::new("Accessing Array Elements", demonstrate_accessing_elements),
+        Section::new("Arrays vs Tuples", demonstrate_arrays_vs_tuples),
+        Section::new(
+            "Practical Example: Months Array",
+            demonstrate_months_example,
+        ),
+        Section::new("Array Bounds Checking", demonstrate_bounds_checking),
+    ],
+};
 
 // Arrays: fixed-length collection of same type elements
 fn demonstrate_creating_arrays() {
-    println!("1. Creating Arrays");
-    println!("   Arrays have fixed length and all elements must be the same type\n");
+    output::note("Arrays have fixed length and all elements must be the same type");
+    println!();
 
     // Basic array creation
     let a = [1, 2, 3, 4, 5];
-    println!("   let a = [1, 2, 3, 4, 5];");
-    println!("   → Array with 5 elements, type inferred as [i32; 5]");
-    println!("   → First element: a[0] = {}\n", a[0]);
+    output::code("let a = [1, 2, 3, 4, 5];");
+    output::note("Array with 5 elements, type inferred as [i32; 5]");
+    output::result(format!("First element: a[0] = {}", a[0]));
+    println!();
 
     // Arrays are allocated on the stack (not heap)
-    println!("   Key characteristics:");
-    println!("   - Fixed length (cannot grow or shrink)");
-    println!("   - All elements must be the same type");
-    println!("   - Stored on the stack (fast access)");
-    println!("   - Useful when you know the size at compile time\n");
+    output::note("Key characteristics:");
+    output::note(
+        "- Fixed length (cannot grow or shrink)\n\
+         - All elements must be the same type\n\
+         - Stored on the stack (fast access)\n\
+         - Useful when you know the size at compile time",
+    );
+    println!();
 }
 
 // Array type syntax: [type; length]
 fn demonstrate_array_type_syntax() {
-    println!("2. Array Type Syntax");
-    println!("   Format: [element_type; length]\n");
+    output::note("Format: [element_type; length]");
+    println!();
 
     // Explicit type annotation
     let a: [i32; 5] = [1, 2, 3, 4, 5];
-    println!("   let a: [i32; 5] = [1, 2, 3, 4, 5];");
-    println!("   → [i32; 5] means: array of i32, with 5 elements");
-    println!("   → Example access: a[0] = {}\n", a[0]);
+    output::code("let a: [i32; 5] = [1, 2, 3, 4, 5];");
+    output::note("[i32; 5] means: array of i32, with 5 elements");
+    output::result(format!("Example access: a[0] = {}", a[0]));
+    println!();
 
     // Different types
     let floats: [f64; 3] = [1.1, 2.2, 3.3];
-    println!("   let floats: [f64; 3] = [1.1, 2.2, 3.3];");
-    println!("   → Array of f64 with 3 elements");
-    println!("   → Example access: floats[1] = {}\n", floats[1]);
+    output::code("let floats: [f64; 3] = [1.1, 2.2, 3.3];");
+    output::note("Array of f64 with 3 elements");
+    output::result(format!("Example access: floats[1] = {}", floats[1]));
+    println!();
 
     let chars: [char; 4] = ['R', 'u', 's', 't'];
-    println!("   let chars: [char; 4] = ['R', 'u', 's', 't'];");
-    println!("   → Array of char with 4 elements");
-    println!("   → Example access: chars[0] = '{}'\n", chars[0]);
+    output::code("let chars: [char; 4] = ['R', 'u', 's', 't'];");
+    output::note("Array of char with 4 elements");
+    output::result(format!("Example access: chars[0] = '{}'", chars[0]));
+    println!();
 }
 
 // Initialize array with same value for all elements
 fn demonstrate_array_initialization() {
-    println!("3. Initializing Arrays with Same Value");
-    println!("   Syntax: [value; length]\n");
+    output::note("Syntax: [value; length]");
+    println!();
 
     // Create array with 5 elements, all set to 3
     let a = [3; 5];
-    println!("   let a = [3; 5];");
-    println!("   → Creates: [3, 3, 3, 3, 3]");
-    println!("   → Equivalent to: let a = [3, 3, 3, 3, 3];");
-    println!("   → Example: a[0] = {}, a[4] = {}\n", a[0], a[4]);
+    output::code("let a = [3; 5];");
+    output::note("Creates: [3, 3, 3, 3, 3]");
+    output::note("Equivalent to: let a = [3, 3, 3, 3, 3];");
+    output::result(format!("Example: a[0] = {}, a[4] = {}", a[0], a[4]));
+    println!();
 
     // More examples
     let zeros: [i32; 10] = [0; 10];
-    println!("   let zeros: [i32; 10] = [0; 10];");
-    println!("   → Creates array of 10 zeros");
-    println!(
-        "   → Example: zeros[0] = {}, zeros[9] = {}\n",
+    output::code("let zeros: [i32; 10] = [0; 10];");
+    output::note("Creates array of 10 zeros");
+    output::result(format!(
+        "Example: zeros[0] = {}, zeros[9] = {}",
         zeros[0], zeros[9]
-    );
+    ));
+    println!();
 
     let trues = [true; 4];
-    println!("   let trues = [true; 4];");
-    println!("   → Creates: [true, true, true, true]");
-    println!(
-        "   → Example: trues[0] = {}, trues[3] = {}\n",
+    output::code("let trues = [true; 4];");
+    output::note("Creates: [true, true, true, true]");
+    output::result(format!(
+        "Example: trues[0] = {}, trues[3] = {}",
         trues[0], trues[3]
-    );
+    ));
+    println!();
 }
 
 // Accessing array elements using indexing
 fn demonstrate_accessing_elements() {
-    println!("4. Accessing Array Elements");
-    println!("   Use square brackets with index: array[index]\n");
+    output::note("Use square brackets with index: array[index]");
+    println!();
 
     let a = [1, 2, 3, 4, 5];
-    println!("   let a = [1, 2, 3, 4, 5];\n");
+    output::code("let a = [1, 2, 3, 4, 5];");
+    println!();
 
     // Access elements by index (starting at 0)
     let first = a[0];
     let second = a[1];
     let last = a[4];
 
-    println!("   Accessing elements:");
-    println!("   a[0] = {} (first element)", first);
-    println!("   a[1] = {} (second element)", second);
-    println!("   a[4] = {} (last element)\n", last);
+    output::note("Accessing elements:");
+    output::result(format!("a[0] = {} (first element)", first));
+    output::result(format!("a[1] = {} (second element)", second));
+    output::result(format!("a[4] = {} (last element)", last));
+    println!();
 
     // Using array elements in expressions
     let sum = a[0] + a[1] + a[2];
-    println!("   Using in expressions:");
-    println!(
-        "   a[0] + a[1] + a[2] = {} + {} + {} = {}\n",
+    output::note("Using in expressions:");
+    output::result(format!(
+        "a[0] + a[1] + a[2] = {} + {} + {} = {}",
         a[0], a[1], a[2], sum
-    );
+    ));
+    println!();
 
     // Iterating over array
-    println!("   Iterating over array:");
-    print!("   Elements: ");
+    output::note("Iterating over array:");
+    let mut elements = Vec::new();
     for element in a.iter() {
-        print!("{} ", element);
+        elements.push(element.to_string());
     }
-    println!("\n");
+    output::result(format!("Elements: {}", elements.join(" ")));
+    println!();
 }
 
 // Arrays vs tuples
 fn demonstrate_arrays_vs_tuples() {
-    println!("5. Arrays vs Tuples");
-    println!("   Key differences:\n");
+    output::note("Key differences:");
+    println!();
 
     // Array: same type, fixed length
     let array: [i32; 3] = [1, 2, 3];
-    println!("   Array: [i32; 3] = {:?}", array);
-    println!("   → All elements must be the same type (i32)\n");
+    output::result(format!("Array: [i32; 3] = {:?}", array));
+    output::note("All elements must be the same type (i32)");
+    println!();
 
     // Tuple: different types, fixed length
     let tuple: (i32, f64, char) = (1, 2.0, '3');
-    println!("   Tuple: (i32, f64, char) = {:?}", tuple);
-    println!("   → Elements can be different types\n");
+    output::result(format!("Tuple: (i32, f64, char) = {:?}", tuple));
+    output::note("Elements can be different types");
+    println!();
 
     // Access syntax
-    println!("   Access syntax:");
-    println!("   Array: array[0] = {}", array[0]);
-    println!("   Tuple: tuple.0 = {}\n", tuple.0);
+    output::note("Access syntax:");
+    output::result(format!("Array: array[0] = {}", array[0]));
+    output::result(format!("Tuple: tuple.0 = {}", tuple.0));
+    println!();
 }
 
 // Practical example: months array
 fn demonstrate_months_example() {
-    println!("6. Practical Example: Months Array");
-    println!("   Arrays are perfect when you know the size won't change\n");
+    output::note("Arrays are perfect when you know the size won't change");
+    println!();
 
     let months = [
         "January",
@@ -171,55 +196,70 @@ fn demonstrate_months_example() {
         "December",
     ];
 
-    println!("   let months = [\"January\", \"February\", ...];");
-    println!("   → Always 12 months, perfect for an array!\n");
+    output::code("let months = [\"January\", \"February\", ...];");
+    output::note("Always 12 months, perfect for an array!");
+    println!();
 
-    println!("   Accessing months:");
-    println!("   months[0] = {} (first month)", months[0]);
-    println!("   months[11] = {} (last month)\n", months[11]);
+    output::note("Accessing months:");
+    output::result(format!("months[0] = {} (first month)", months[0]));
+    output::result(format!("months[11] = {} (last month)", months[11]));
+    println!();
 
     // Type of months array
-    println!("   Type: [&str; 12]");
-    println!("   → Array of string slices, 12 elements\n");
+    output::note("Type: [&str; 12]");
+    output::note("Array of string slices, 12 elements");
+    println!();
 }
 
 // Array bounds checking - Rust's memory safety
 fn demonstrate_bounds_checking() {
-    println!("7. Array Bounds Checking");
-    println!("   Rust checks array bounds at runtime for memory safety\n");
+    output::note("Rust checks array bounds at runtime for memory safety");
+    println!();
 
     let a = [1, 2, 3, 4, 5];
-    println!("   let a = [1, 2, 3, 4, 5];");
-    println!("   → Valid indices: 0, 1, 2, 3, 4\n");
+    output::code("let a = [1, 2, 3, 4, 5];");
+    output::note("Valid indices: 0, 1, 2, 3, 4");
+    println!();
 
     // Valid access
     let valid = a[2];
-    println!("   Valid access: a[2] = {} ✓", valid);
-
-    // Invalid access would cause panic
-    println!("\n   Invalid access example:");
-    println!("   If you try: a[10]");
-    println!("   → Rust will PANIC at runtime:");
-    println!("   → 'index out of bounds: the len is 5 but the index is 10'\n");
+    output::result(format!("Valid access: a[2] = {} ✓", valid));
+
+    // Invalid access panics; the sandbox catches it so the lesson goes on.
+    // black_box hides the index from the compiler, which would otherwise
+    // reject a constant out-of-bounds index before the program ever runs
+    println!();
+    output::note("Invalid access example:");
+    output::code("let index = 10;\nlet value = a[index];");
+    let index = black_box(10);
+    match sandbox::catch(|| a[index]) {
+        Ok(value) => output::result(format!("a[10] = {}", value)),
+        Err(panic) => output::warning(format!("Rust will PANIC at runtime:\n{}", panic)),
+    }
+    println!();
 
     // Safe way to access (using get method)
-    println!("   Safe access using .get() method:");
+    output::note("Safe access using .get() method:");
     match a.get(2) {
-        Some(value) => println!("   a.get(2) = Some({}) ✓", value),
-        None => println!("   a.get(2) = None (out of bounds)"),
+        Some(value) => output::result(format!("a.get(2) = Some({}) ✓", value)),
+        None => output::result("a.get(2) = None (out of bounds)"),
     }
 
     match a.get(10) {
-        Some(value) => println!("   a.get(10) = Some({})", value),
-        None => println!("   a.get(10) = None (out of bounds) ✓"),
+        Some(value) => output::result(format!("a.get(10) = Some({})", value)),
+        None => output::result("a.get(10) = None (out of bounds) ✓"),
     }
 
-    println!("\n   Memory safety:");
-    println!("   - Rust prevents invalid memory access");
-    println!("   - Panics instead of allowing undefined behavior");
-    println!("   - Use .get() for safe access that returns Option\n");
+    println!();
+    output::note("Memory safety:");
+    output::note(
+        "- Rust prevents invalid memory access\n\
+         - Panics instead of allowing undefined behavior\n\
+         - Use .get() for safe access that returns Option",
+    );
+    println!();
 
     // Array length
-    println!("   Getting array length:");
-    println!("   a.len() = {} (number of elements)", a.len());
+    output::note("Getting array length:");
+    output::result(format!("a.len() = {} (number of elements)", a.len()));
 }
@@ -1 +1,36 @@
+use crate::output;
+use crate::registry::{Difficulty, Lesson, Meta, Section};
+
 const THREE_HOURS_IN_SECONDS: u32 = 60 * 60 * 3;
+
+pub const LESSON: Lesson = Lesson {
+    id: "constants",
+    title: "Constants",
+    meta: Meta {
+        difficulty: Difficulty::Beginner,
+        minutes: 5,
+        book: Some("3.1"),
+        prerequisites: &["variables_mutability"],
+        tags: &["variables", "constants"],
+    },
+    sections: &[Section::new("Declaring Constants", demonstrate_constants)],
+};
+
+// Constants must be typed, are never mutable and are evaluated at compile time
+fn demonstrate_constants() {
+    output::note("const NAME: type = value; (type annotation is required)");
+    println!();
+
+    output::code("const THREE_HOURS_IN_SECONDS: u32 = 60 * 60 * 3;");
+    output::result(format!(
+        "THREE_HOURS_IN_SECONDS = {}",
+        THREE_HOURS_IN_SECONDS
+    ));
+    println!();
+
+    // const MAX: u32; // ERROR: free constant item without body
+    // const mut MAX: u32 = 1; // ERROR: const globals cannot be mutable
+    output::note("Naming convention: SCREAMING_SNAKE_CASE");
+    output::note("Valid for the whole program in the scope they are declared in");
+    println!();
+}
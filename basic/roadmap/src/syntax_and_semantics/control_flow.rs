@@ -1,96 +1,97 @@
-fn main() {
-    println!("=== Control Flow in Rust ===\n");
-
-    // 1. if expressions
-    demonstrate_if_expressions();
-
-    // 2. else if
-    demonstrate_else_if();
-
-    // 3. Using if in let statements
-    demonstrate_if_in_let();
-
-    // 4. loop (infinite loops)
-    demonstrate_loop();
-
-    // 5. Returning values from loops
-    demonstrate_loop_return();
-
-    // 6. Loop labels
-    demonstrate_loop_labels();
-
-    // 7. while loops
-    demonstrate_while();
-
-    // 8. for loops
-    demonstrate_for();
-
-    // 9. Ranges
-    demonstrate_ranges();
-}
+use crate::output;
+use crate::registry::{Difficulty, Lesson, Meta, Section};
+
+pub const LESSON: Lesson = Lesson {
+    id: "control_flow",
+    title: "Control Flow in Rust",
+    meta: Meta {
+        difficulty: Difficulty::Beginner,
+        minutes: 20,
+        book: Some("3.5"),
+        prerequisites: &["functions"],
+        tags: &["control-flow", "loops"],
+    },
+    sections: &[
+        Section::new("if Expressions", demonstrate_if_expressions),
+        Section::new("else if - Multiple Conditions", demonstrate_else_if),
+        Section::new("Using if in let Statements", demonstrate_if_in_let),
+        Section::new("loop - Infinite Loops", demonstrate_loop),
+        Section::new("Returning Values from Loops", demonstrate_loop_return),
+        Section::new("Loop Labels", demonstrate_loop_labels),
+        Section::new("while - Conditional Loops", demonstrate_while),
+        Section::new("for - Looping Through Collections", demonstrate_for),
+        Section::new("Ranges - Generating Number Sequences", demonstrate_ranges),
+    ],
+};
 
 // if expressions: branch code based on conditions
 fn demonstrate_if_expressions() {
-    println!("1. if Expressions");
-    println!("   Condition must be a bool (not like JavaScript/Python)\n");
+    output::note("Condition must be a bool (not like JavaScript/Python)");
+    println!();
 
     let number = 3;
 
     if number < 5 {
-        println!("   number < 5: condition was true");
+        output::result("number < 5: condition was true");
     } else {
-        println!("   number < 5: condition was false");
+        output::result("number < 5: condition was false");
     }
 
     // Rust requires explicit boolean - this won't compile:
     // if number { ... }  // ERROR: expected bool, found integer
+    // (E0308, see compile_fail/syntax_and_semantics/integer_condition.rs)
 
     // Must be explicit:
     if number != 0 {
-        println!("   number != 0: number is not zero\n");
+        output::result("number != 0: number is not zero");
+        println!();
     }
 
     // Without else
     let value = 7;
     if value > 5 {
-        println!("   value > 5: condition was true (no else needed)\n");
+        output::result("value > 5: condition was true (no else needed)");
+        println!();
     }
 }
 
 // Handling multiple conditions with else if
 fn demonstrate_else_if() {
-    println!("2. else if - Multiple Conditions");
-    println!("   Check multiple conditions in sequence\n");
+    output::note("Check multiple conditions in sequence");
+    println!();
 
     let number = 6;
 
     if number % 4 == 0 {
-        println!("   {} is divisible by 4", number);
+        output::result(format!("{} is divisible by 4", number));
     } else if number % 3 == 0 {
-        println!("   {} is divisible by 3", number);
+        output::result(format!("{} is divisible by 3", number));
     } else if number % 2 == 0 {
-        println!("   {} is divisible by 2", number);
+        output::result(format!("{} is divisible by 2", number));
     } else {
-        println!("   {} is not divisible by 4, 3, or 2", number);
+        output::result(format!("{} is not divisible by 4, 3, or 2", number));
     }
 
-    println!("   → Only the FIRST true condition executes\n");
+    output::note("Only the FIRST true condition executes");
+    println!();
 }
 
 // Using if in a let statement (if is an expression!)
 fn demonstrate_if_in_let() {
-    println!("3. Using if in let Statements");
-    println!("   if is an expression, so it can return a value\n");
+    output::note("if is an expression, so it can return a value");
+    println!();
 
     let condition = true;
     let number = if condition { 5 } else { 6 };
 
-    println!("   let number = if condition {{ 5 }} else {{ 6 }};");
-    println!("   → number = {}\n", number);
+    output::code("let number = if condition { 5 } else { 6 };");
+    output::result(format!("number = {}", number));
+    println!();
 
     // Both arms must return the same type
     // This would cause an error:
     // let number = if condition { 5 } else { "six" };  // ERROR!
+    // (E0308, see compile_fail/syntax_and_semantics/if_arms_differ.rs)
 
     // Blocks evaluate to the last expression
     let result = if condition {
@@ -99,32 +100,34 @@ fn demonstrate_if_in_let() {
     } else {
         0
     };
-    println!("   Block example: result = {}\n", result);
+    output::result(format!("Block example: result = {}", result));
+    println!();
 }
 
 // loop: infinite loop until break
 fn demonstrate_loop() {
-    println!("4. loop - Infinite Loops");
-    println!("   Loop until you explicitly break\n");
+    output::note("Loop until you explicitly break");
+    println!();
 
     let mut counter = 0;
 
     loop {
         counter += 1;
-        println!("   Counter: {}", counter);
+        output::result(format!("Counter: {}", counter));
 
         if counter >= 3 {
             break; // Exit the loop
         }
     }
 
-    println!("   → Loop exited with break\n");
+    output::note("Loop exited with break");
+    println!();
 }
 
 // Returning values from loops
 fn demonstrate_loop_return() {
-    println!("5. Returning Values from Loops");
-    println!("   Use break with a value to return from loop\n");
+    output::note("Use break with a value to return from loop");
+    println!();
 
     let mut counter = 0;
 
@@ -136,22 +139,23 @@ fn demonstrate_loop_return() {
         }
     };
 
-    println!("   let result = loop {{ ... break counter * 2; }};");
-    println!("   → result = {} (returned from loop)\n", result);
+    output::code("let result = loop { ... break counter * 2; };");
+    output::result(format!("result = {} (returned from loop)", result));
+    println!();
 }
 
 // Loop labels: disambiguate between nested loops
 fn demonstrate_loop_labels() {
-    println!("6. Loop Labels");
-    println!("   Label loops to break/continue outer loops\n");
+    output::note("Label loops to break/continue outer loops");
+    println!();
 
     let mut count = 0;
     'counting_up: loop {
-        println!("   Outer loop: count = {}", count);
+        output::result(format!("Outer loop: count = {}", count));
         let mut remaining = 3;
 
         loop {
-            println!("     Inner loop: remaining = {}", remaining);
+            output::result(format!("Inner loop: remaining = {}", remaining));
             if remaining == 2 {
                 break; // Breaks inner loop only
             }
@@ -163,93 +167,103 @@ fn demonstrate_loop_labels() {
 
         count += 1;
     }
-    println!("   → End count = {}\n", count);
+    output::result(format!("End count = {}", count));
+    println!();
 }
 
 // while: conditional loop
 fn demonstrate_while() {
-    println!("7. while - Conditional Loops");
-    println!("   Loop while condition is true\n");
+    output::note("Loop while condition is true");
+    println!();
 
     let mut number = 3;
 
     while number != 0 {
-        println!("   {}!", number);
+        output::result(format!("{}!", number));
         number -= 1;
     }
 
-    println!("   LIFTOFF!!!\n");
+    output::result("LIFTOFF!!!");
+    println!();
 
     // while loop through array (less safe)
-    println!("   Looping through array with while:");
+    output::note("Looping through array with while:");
     let a = [10, 20, 30, 40, 50];
     let mut index = 0;
 
     while index < a.len() {
-        println!("   a[{}] = {}", index, a[index]);
+        output::result(format!("a[{}] = {}", index, a[index]));
         index += 1;
     }
-    println!("   → Note: Easy to make mistakes with index bounds\n");
+    output::note("Note: Easy to make mistakes with index bounds");
+    println!();
 }
 
 // for: safest way to loop through collections
 fn demonstrate_for() {
-    println!("8. for - Looping Through Collections");
-    println!("   Safest and most idiomatic way to iterate\n");
+    output::note("Safest and most idiomatic way to iterate");
+    println!();
 
     let a = [10, 20, 30, 40, 50];
 
-    println!("   for element in a:");
+    output::note("for element in a:");
     for element in a {
-        println!("   the value is: {}", element);
+        output::result(format!("the value is: {}", element));
     }
     println!();
 
     // Using iter() explicitly
-    println!("   for element in a.iter():");
+    output::note("for element in a.iter():");
     for element in a.iter() {
-        println!("   the value is: {}", element);
+        output::result(format!("the value is: {}", element));
     }
     println!();
 
     // With index using enumerate
-    println!("   for (index, element) in a.iter().enumerate():");
+    output::note("for (index, element) in a.iter().enumerate():");
     for (index, element) in a.iter().enumerate() {
-        println!("   a[{}] = {}", index, element);
+        output::result(format!("a[{}] = {}", index, element));
     }
     println!();
 }
 
 // Ranges: generating sequences of numbers
 fn demonstrate_ranges() {
-    println!("9. Ranges - Generating Number Sequences");
-    println!("   Use ranges with for loops\n");
+    output::note("Use ranges with for loops");
+    println!();
 
     // Range: start..end (exclusive end)
-    println!("   Range (1..4) - exclusive end:");
+    output::note("Range (1..4) - exclusive end:");
+    let mut numbers = Vec::new();
     for number in 1..4 {
-        print!("   {} ", number);
+        numbers.push(number.to_string());
     }
-    println!("\n   → Prints: 1, 2, 3 (not 4)\n");
+    output::result(numbers.join(" "));
+    output::note("Prints: 1, 2, 3 (not 4)");
+    println!();
 
     // Range: start..=end (inclusive end)
-    println!("   Range (1..=4) - inclusive end:");
+    output::note("Range (1..=4) - inclusive end:");
+    let mut numbers = Vec::new();
     for number in 1..=4 {
-        print!("   {} ", number);
+        numbers.push(number.to_string());
     }
-    println!("\n   → Prints: 1, 2, 3, 4\n");
+    output::result(numbers.join(" "));
+    output::note("Prints: 1, 2, 3, 4");
+    println!();
 
     // Countdown using rev()
-    println!("   Countdown using (1..4).rev():");
+    output::note("Countdown using (1..4).rev():");
     for number in (1..4).rev() {
-        println!("   {}!", number);
+        output::result(format!("{}!", number));
     }
-    println!("   LIFTOFF!!!\n");
+    output::result("LIFTOFF!!!");
+    println!();
 
     // Practical example: iterate array indices
     let arr = [100, 200, 300];
-    println!("   Iterating array indices with range:");
+    output::note("Iterating array indices with range:");
     for i in 0..arr.len() {
-        println!("   arr[{}] = {}", i, arr[i]);
+        output::result(format!("arr[{}] = {}", i, arr[i]));
     }
 }
@@ -1,3 +1,6 @@
+use crate::output;
+use crate::registry::{Difficulty, Lesson, Meta, Section};
+
 // Define types for examples
 #[allow(dead_code)]
 #[derive(Debug)]
@@ -28,88 +31,94 @@ enum Coin {
     Quarter(UsState),
 }
 
-fn main() {
-    println!("=== if let and let else Control Flow ===\n");
-
-    // 1. Basic if let
-    demonstrate_basic_if_let();
-
-    // 2. if let vs match
-    demonstrate_if_let_vs_match();
-
-    // 3. if let with else
-    demonstrate_if_let_else();
-
-    // 4. let else syntax
-    demonstrate_let_else();
-
-    // 5. Practical examples
-    demonstrate_practical_examples();
-}
+pub const LESSON: Lesson = Lesson {
+    id: "control_flow_if_let",
+    title: "if let and let else Control Flow",
+    meta: Meta {
+        difficulty: Difficulty::Intermediate,
+        minutes: 15,
+        book: Some("6.3"),
+        prerequisites: &["match"],
+        tags: &["control-flow", "pattern-matching"],
+    },
+    sections: &[
+        Section::new("Basic if let", demonstrate_basic_if_let),
+        Section::new("if let vs match", demonstrate_if_let_vs_match),
+        Section::new("if let with else", demonstrate_if_let_else),
+        Section::new("let else - Staying on the Happy Path", demonstrate_let_else),
+        Section::new("Practical Examples", demonstrate_practical_examples),
+    ],
+};
 
 // 1. Basic if let
 fn demonstrate_basic_if_let() {
-    println!("1. Basic if let");
-    println!("   Concise syntax for matching one pattern\n");
+    output::note("Concise syntax for matching one pattern");
+    println!();
 
     let config_max = Some(3u8);
 
     // Using match (verbose)
-    println!("   With match:");
+    output::note("With match:");
     match config_max {
-        Some(max) => println!("   The maximum is configured to be {}", max),
+        Some(max) => output::result(format!("The maximum is configured to be {}", max)),
         _ => (),
     }
 
     // Using if let (concise)
-    println!("\n   With if let:");
+    println!();
+    output::note("With if let:");
     if let Some(max) = config_max {
-        println!("   The maximum is configured to be {}", max);
+        output::result(format!("The maximum is configured to be {}", max));
     }
 
-    println!("\n   if let is syntax sugar for a match with one pattern\n");
+    println!();
+    output::note("if let is syntax sugar for a match with one pattern");
+    println!();
 }
 
 // 2. if let vs match
 fn demonstrate_if_let_vs_match() {
-    println!("2. if let vs match");
-    println!("   Trade-offs between conciseness and exhaustiveness\n");
+    output::note("Trade-offs between conciseness and exhaustiveness");
+    println!();
 
     let number = Some(7);
 
     // match: exhaustive, must handle all cases
-    println!("   match (exhaustive):");
+    output::note("match (exhaustive):");
     match number {
-        Some(n) if n < 5 => println!("   Small number: {}", n),
-        Some(n) => println!("   Large number: {}", n),
-        None => println!("   No number"),
+        Some(n) if n < 5 => output::result(format!("Small number: {}", n)),
+        Some(n) => output::result(format!("Large number: {}", n)),
+        None => output::result("No number"),
     }
 
     // if let: concise, only handles one case
-    println!("\n   if let (concise, non-exhaustive):");
+    println!();
+    output::note("if let (concise, non-exhaustive):");
     if let Some(n) = number {
-        println!("   Got a number: {}", n);
+        output::result(format!("Got a number: {}", n));
     }
 
-    println!("\n   Use match for exhaustive checking");
-    println!("   Use if let when you only care about one pattern\n");
+    println!();
+    output::note("Use match for exhaustive checking");
+    output::note("Use if let when you only care about one pattern");
+    println!();
 }
 
 // 3. if let with else
 fn demonstrate_if_let_else() {
-    println!("3. if let with else");
-    println!("   Handle the non-matching case\n");
+    output::note("Handle the non-matching case");
+    println!();
 
     let coin = Coin::Penny;
     let mut count = 0;
 
     // Using match
-    println!("   With match:");
+    output::note("With match:");
     match coin {
-        Coin::Quarter(state) => println!("   State quarter from {:?}!", state),
+        Coin::Quarter(state) => output::result(format!("State quarter from {:?}!", state)),
         _ => {
             count += 1;
-            println!("   Not a quarter, count = {}", count);
+            output::result(format!("Not a quarter, count = {}", count));
         }
     }
 
@@ -117,20 +126,21 @@ fn demonstrate_if_let_else() {
     let coin2 = Coin::Quarter(UsState::Alaska);
     let mut count2 = 0;
 
-    println!("\n   With if let...else:");
+    println!();
+    output::note("With if let...else:");
     if let Coin::Quarter(state) = coin2 {
-        println!("   State quarter from {:?}!", state);
+        output::result(format!("State quarter from {:?}!", state));
     } else {
         count2 += 1;
-        println!("   Not a quarter, count = {}", count2);
+        output::result(format!("Not a quarter, count = {}", count2));
     }
     println!();
 }
 
 // 4. let else syntax
 fn demonstrate_let_else() {
-    println!("4. let else - Staying on the Happy Path");
-    println!("   Extract value or return early\n");
+    output::note("Extract value or return early");
+    println!();
 
     // Example 1: Using if let with early return
     fn describe_quarter_v1(coin: Coin) -> Option<String> {
@@ -160,71 +170,75 @@ fn demonstrate_let_else() {
         }
     }
 
-    println!("   Testing with Quarter(Alaska):");
+    output::note("Testing with Quarter(Alaska):");
     if let Some(desc) = describe_quarter_v1(Coin::Quarter(UsState::Alaska)) {
-        println!("   if let version: {}", desc);
+        output::result(format!("if let version: {}", desc));
     }
 
     if let Some(desc) = describe_quarter_v2(Coin::Quarter(UsState::Alaska)) {
-        println!("   let else version: {}", desc);
+        output::result(format!("let else version: {}", desc));
     }
 
-    println!("\n   Testing with Penny:");
+    println!();
+    output::note("Testing with Penny:");
     if let Some(desc) = describe_quarter_v1(Coin::Penny) {
-        println!("   {}", desc);
+        output::result(desc);
     } else {
-        println!("   Not a quarter, no description");
+        output::result("Not a quarter, no description");
     }
 
-    println!("\n   let else keeps code on the 'happy path'\n");
+    println!();
+    output::note("let else keeps code on the 'happy path'");
+    println!();
 }
 
 // 5. Practical examples
 fn demonstrate_practical_examples() {
-    println!("5. Practical Examples\n");
-
     // Example 1: Parsing configuration
-    println!("   Example 1: Configuration parsing");
+    output::note("Example 1: Configuration parsing");
     let config: Option<&str> = Some("debug");
 
     if let Some(mode) = config {
-        println!("   Running in {} mode", mode);
+        output::result(format!("Running in {} mode", mode));
     } else {
-        println!("   Running in default mode");
+        output::result("Running in default mode");
     }
 
     // Example 2: Nested if let
-    println!("\n   Example 2: Nested if let");
+    println!();
+    output::note("Example 2: Nested if let");
     let favorite_color: Option<&str> = None;
     let is_tuesday = false;
     let age: Result<u8, _> = "34".parse();
 
     if let Some(color) = favorite_color {
-        println!("   Using favorite color: {}", color);
+        output::result(format!("Using favorite color: {}", color));
     } else if is_tuesday {
-        println!("   Tuesday is green day!");
+        output::result("Tuesday is green day!");
     } else if let Ok(age) = age {
         if age > 30 {
-            println!("   Using purple (age > 30)");
+            output::result("Using purple (age > 30)");
         } else {
-            println!("   Using orange (age <= 30)");
+            output::result("Using orange (age <= 30)");
         }
     } else {
-        println!("   Using blue as default");
+        output::result("Using blue as default");
     }
 
     // Example 3: while let for iteration
-    println!("\n   Example 3: while let for iteration");
+    println!();
+    output::note("Example 3: while let for iteration");
     let mut stack = vec![1, 2, 3];
 
-    println!("   Popping from stack:");
+    output::note("Popping from stack:");
     while let Some(top) = stack.pop() {
-        println!("   {}", top);
+        output::result(top.to_string());
     }
-    println!("   Stack is empty");
+    output::result("Stack is empty");
 
     // Example 4: let else with validation
-    println!("\n   Example 4: let else with validation");
+    println!();
+    output::note("Example 4: let else with validation");
     fn process_number(input: &str) -> Result<i32, String> {
         let Ok(num) = input.parse::<i32>() else {
             return Err(format!("'{}' is not a valid number", input));
@@ -235,14 +249,14 @@ fn demonstrate_practical_examples() {
     }
 
     match process_number("42") {
-        Ok(n) => println!("   Result: {}", n),
-        Err(e) => println!("   Error: {}", e),
+        Ok(n) => output::result(format!("Result: {}", n)),
+        Err(e) => output::result(format!("Error: {}", e)),
     }
 
     match process_number("abc") {
-        Ok(n) => println!("   Result: {}", n),
-        Err(e) => println!("   Error: {}", e),
+        Ok(n) => output::result(format!("Result: {}", n)),
+        Err(e) => output::result(format!("Error: {}", e)),
     }
 
     println!();
-}
\ No newline at end of file
+}
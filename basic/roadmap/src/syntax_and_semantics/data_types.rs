@@ -1,12 +1,26 @@
-fn main() {
-    example_scalars();
-    example_compounds();
-}
+use crate::output;
+use crate::registry::{Difficulty, Lesson, Meta, Section};
+
+pub const LESSON: Lesson = Lesson {
+    id: "data_types",
+    title: "Data Types",
+    meta: Meta {
+        difficulty: Difficulty::Beginner,
+        minutes: 10,
+        book: Some("3.2"),
+        prerequisites: &["variables_mutability"],
+        tags: &["types"],
+    },
+    sections: &[
+        Section::new("Scalar Types", example_scalars),
+        Section::new("Compound Types", example_compounds),
+    ],
+};
 
 fn example_scalars() {
     let x = 2.0;
     let y: f32 = 3.0;
-    println!("floats: x = {x}, y = {y}");
+    output::result(format!("floats: x = {x}, y = {y}"));
 
     let sum = 5 + 10;
     let difference = 95.5 - 4.3;
@@ -14,30 +28,32 @@ fn example_scalars() {
     let quotient = 56.7 / 32.2;
     let truncated = -5 / 3;
     let remainder = 43 % 5;
-    println!(
+    output::result(format!(
         "ints: sum = {sum}, diff = {difference}, prod = {product}, quot = {quotient}, trunc = {truncated}, rem = {remainder}"
-    );
+    ));
 
     let t = true;
     let f: bool = false;
-    println!("bools: t = {t}, f = {f}");
+    output::result(format!("bools: t = {t}, f = {f}"));
 
     let c = 'z';
     let z: char = 'ℤ';
     let heart_eyed_cat = '😻';
-    println!("chars: c = {c}, z = {z}, cat = {heart_eyed_cat}");
+    output::result(format!("chars: c = {c}, z = {z}, cat = {heart_eyed_cat}"));
 }
 
 fn example_compounds() {
     let tup: (i32, f64, u8) = (500, 6.4, 1);
     let (x, y, z) = tup;
-    println!("tuple destructured: x = {x}, y = {y}, z = {z}");
-    println!(
+    output::result(format!("tuple destructured: x = {x}, y = {y}, z = {z}"));
+    output::result(format!(
         "tuple direct: .0 = {}, .1 = {}, .2 = {}",
         tup.0, tup.1, tup.2
-    );
+    ));
 
     let a = [1, 2, 3, 4, 5];
+    output::result(format!("array a: {:?}", a));
+
     let months = [
         "January",
         "February",
@@ -54,5 +70,5 @@ fn example_compounds() {
     ];
     let first = months[0];
     let second = months[1];
-    println!("array months: first = {first}, second = {second}");
+    output::result(format!("array months: first = {first}, second = {second}"));
 }
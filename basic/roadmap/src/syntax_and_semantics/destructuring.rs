@@ -1,3 +1,10 @@
+use crate::output;
+use crate::registry::{Difficulty, Lesson, Meta, Section};
+use roadmap::color::{Cmyk, Hsl, Hsv, Rgb};
+use roadmap::events::{self, Dispatcher, Kind, Log};
+use std::cell::RefCell;
+use std::rc::Rc;
+
 // Define types for destructuring examples
 #[allow(dead_code)]
 #[derive(Debug)]
@@ -20,45 +27,55 @@ enum WebEvent {
     Click { x: i64, y: i64 },
 }
 
-fn main() {
-    println!("=== Destructuring in Rust ===\n");
-
-    // 1. Destructuring tuples
-    demonstrate_tuple_destructuring();
-
-    // 2. Destructuring arrays and slices
-    demonstrate_array_slice_destructuring();
-
-    // 3. Destructuring enums
-    demonstrate_enum_destructuring();
-
-    // 4. Destructuring pointers/references
-    demonstrate_pointer_destructuring();
-
-    // 5. Destructuring structures
-    demonstrate_struct_destructuring();
-}
+pub const LESSON: Lesson = Lesson {
+    id: "destructuring",
+    title: "Destructuring in Rust",
+    meta: Meta {
+        difficulty: Difficulty::Intermediate,
+        minutes: 15,
+        book: Some("19.3"),
+        prerequisites: &["tuple", "arrays", "match"],
+        tags: &["pattern-matching", "tuples", "arrays"],
+    },
+    sections: &[
+        Section::new("Destructuring Tuples", demonstrate_tuple_destructuring),
+        Section::new(
+            "Destructuring Arrays and Slices",
+            demonstrate_array_slice_destructuring,
+        ),
+        Section::new("Destructuring Enums", demonstrate_enum_destructuring),
+        Section::new(
+            "Destructuring Pointers/References",
+            demonstrate_pointer_destructuring,
+        ),
+        Section::new("Destructuring Structures", demonstrate_struct_destructuring),
+        Section::new(
+            "Destructuring in Event Handlers",
+            demonstrate_event_handlers,
+        ),
+    ],
+};
 
 // 1. Destructuring Tuples
 fn demonstrate_tuple_destructuring() {
-    println!("1. Destructuring Tuples");
-    println!("   Extract values from tuples\n");
+    output::note("Extract values from tuples");
+    println!();
 
     let triple = (0, -2, 3);
 
     match triple {
-        (0, y, z) => println!("   First is 0, y = {}, z = {}", y, z),
-        (1, ..) => println!("   First is 1 and the rest doesn't matter"),
-        (.., 2) => println!("   Last is 2 and the rest doesn't matter"),
-        (3, .., 4) => println!("   First is 3, last is 4, middle doesn't matter"),
-        _ => println!("   It doesn't matter what they are"),
+        (0, y, z) => output::result(format!("First is 0, y = {}, z = {}", y, z)),
+        (1, ..) => output::result("First is 1 and the rest doesn't matter"),
+        (.., 2) => output::result("Last is 2 and the rest doesn't matter"),
+        (3, .., 4) => output::result("First is 3, last is 4, middle doesn't matter"),
+        _ => output::result("It doesn't matter what they are"),
     }
 
     // Nested tuple destructuring
     let nested = ((1, 2), (3, 4));
     match nested {
         ((a, b), (c, d)) => {
-            println!("   Nested tuple: a={}, b={}, c={}, d={}", a, b, c, d);
+            output::result(format!("Nested tuple: a={}, b={}, c={}, d={}", a, b, c, d));
         }
     }
     println!();
@@ -66,35 +83,44 @@ fn demonstrate_tuple_destructuring() {
 
 // 2. Destructuring Arrays and Slices
 fn demonstrate_array_slice_destructuring() {
-    println!("2. Destructuring Arrays and Slices");
-    println!("   Pattern match on arrays and slices\n");
+    output::note("Pattern match on arrays and slices");
+    println!();
 
     let array = [1, -2, 6];
 
     match array {
         [0, second, third] => {
-            println!("   array[0] = 0, array[1] = {}, array[2] = {}", second, third);
+            output::result(format!(
+                "array[0] = 0, array[1] = {}, array[2] = {}",
+                second, third
+            ));
         }
-        [1, _, third] => println!("   array[0] = 1, array[2] = {} (middle ignored)", third),
+        [1, _, third] => output::result(format!(
+            "array[0] = 1, array[2] = {} (middle ignored)",
+            third
+        )),
         [-1, second, ..] => {
-            println!("   array[0] = -1, array[1] = {} (rest ignored)", second);
+            output::result(format!(
+                "array[0] = -1, array[1] = {} (rest ignored)",
+                second
+            ));
         }
         [first, middle @ .., last] => {
-            println!(
-                "   array[0] = {}, middle = {:?}, array[last] = {}",
+            output::result(format!(
+                "array[0] = {}, middle = {:?}, array[last] = {}",
                 first, middle, last
-            );
+            ));
         }
     }
 
     // Slice destructuring with different patterns
     fn match_slice(slice: &[i32]) {
         match slice {
-            [] => println!("   Empty slice"),
-            [first] => println!("   Single element: {}", first),
-            [first, second] => println!("   Two elements: {}, {}", first, second),
+            [] => output::result("Empty slice"),
+            [first] => output::result(format!("Single element: {}", first)),
+            [first, second] => output::result(format!("Two elements: {}, {}", first, second)),
             [first, second, ..] => {
-                println!("   Slice first two: {}, {} (and more)", first, second);
+                output::result(format!("Slice first two: {}, {} (and more)", first, second));
             }
         }
     }
@@ -107,36 +133,70 @@ fn demonstrate_array_slice_destructuring() {
 
 // 3. Destructuring Enums
 fn demonstrate_enum_destructuring() {
-    println!("3. Destructuring Enums");
-    println!("   Extract data from enum variants\n");
+    output::note("Extract data from enum variants");
+    println!();
 
     let color = Color::RGB(122, 17, 40);
 
     match color {
-        Color::Red => println!("   The color is Red!"),
-        Color::Blue => println!("   The color is Blue!"),
-        Color::Green => println!("   The color is Green!"),
+        Color::Red => output::result("The color is Red!"),
+        Color::Blue => output::result("The color is Blue!"),
+        Color::Green => output::result("The color is Green!"),
         Color::RGB(r, g, b) => {
-            println!("   Red: {}, green: {}, blue: {}", r, g, b);
+            output::result(format!("Red: {}, green: {}, blue: {}", r, g, b));
         }
         Color::HSV(h, s, v) => {
-            println!("   Hue: {}, saturation: {}, value: {}", h, s, v);
+            output::result(format!("Hue: {}, saturation: {}, value: {}", h, s, v));
         }
         Color::CMYK(c, m, y, k) => {
-            println!("   Cyan: {}, magenta: {}, yellow: {}, key: {}", c, m, y, k);
+            output::result(format!(
+                "Cyan: {}, magenta: {}, yellow: {}, key: {}",
+                c, m, y, k
+            ));
         }
     }
 
+    // roadmap::color turns those variants into real colour spaces, each a
+    // struct that destructures just like the enum did
+    let rgb = Rgb::new(122, 17, 40);
+    let Hsv { h, s, v } = Hsv::from(rgb);
+    println!();
+    output::result(format!(
+        "{} in HSV: hue {:.0}°, saturation {:.2}, value {:.2}",
+        rgb, h, s, v
+    ));
+    let Cmyk { c, m, y, k } = Cmyk::from(rgb);
+    output::result(format!(
+        "{} in CMYK: {:.2}, {:.2}, {:.2}, {:.2}",
+        rgb, c, m, y, k
+    ));
+    output::result(format!("{} in HSL: {}", rgb, Hsl::from(rgb)));
+    output::result(format!("Back from HSV: {}", Rgb::from(Hsv { h, s, v })));
+
+    for text in ["#7a1128", "rgb(255, 165, 0)", "crimson", "#12"] {
+        match Rgb::parse(text) {
+            Ok(Rgb { r, g, b }) => {
+                output::result(format!("parse {:<18} r={}, g={}, b={}", text, r, g, b))
+            }
+            Err(err) => output::result(format!("parse {:<18} {}", text, err)),
+        }
+    }
+
+    let white = Rgb::new(255, 255, 255);
+    output::result(format!("Contrast with white: {:.1}:1", rgb.contrast(white)));
+    output::result(format!("Halfway to white: {}", rgb.mix(white, 0.5)));
+    println!();
+
     // Destructuring enum with different variant types
     let event = WebEvent::Click { x: 20, y: 80 };
 
     match event {
-        WebEvent::PageLoad => println!("   Page loaded"),
-        WebEvent::PageUnload => println!("   Page unloaded"),
-        WebEvent::KeyPress(c) => println!("   Pressed '{}'", c),
-        WebEvent::Paste(s) => println!("   Pasted \"{}\"", s),
+        WebEvent::PageLoad => output::result("Page loaded"),
+        WebEvent::PageUnload => output::result("Page unloaded"),
+        WebEvent::KeyPress(c) => output::result(format!("Pressed '{}'", c)),
+        WebEvent::Paste(s) => output::result(format!("Pasted \"{}\"", s)),
         WebEvent::Click { x, y } => {
-            println!("   Clicked at x={}, y={}", x, y);
+            output::result(format!("Clicked at x={}, y={}", x, y));
         }
     }
     println!();
@@ -144,24 +204,24 @@ fn demonstrate_enum_destructuring() {
 
 // 4. Destructuring Pointers/References
 fn demonstrate_pointer_destructuring() {
-    println!("4. Destructuring Pointers/References");
-    println!("   Dereference and match references\n");
+    output::note("Dereference and match references");
+    println!();
 
     let reference = &4;
 
     match reference {
-        &val => println!("   Got a value via destructuring: {:?}", val),
+        &val => output::result(format!("Got a value via destructuring: {:?}", val)),
     }
 
     // Dereferencing vs destructuring
     match *reference {
-        val => println!("   Got a value via dereferencing: {:?}", val),
+        val => output::result(format!("Got a value via dereferencing: {:?}", val)),
     }
 
     // ref creates a reference in patterns
     let value = 5;
     match value {
-        ref r => println!("   Got a reference to a value: {:?}", r),
+        ref r => output::result(format!("Got a reference to a value: {:?}", r)),
     }
 
     // ref mut for mutable references
@@ -169,16 +229,17 @@ fn demonstrate_pointer_destructuring() {
     match mut_value {
         ref mut m => {
             *m += 10;
-            println!("   We added 10. `mut_value`: {:?}", m);
+            output::result(format!("We added 10. `mut_value`: {:?}", m));
         }
     }
-    println!("   Final mut_value: {}\n", mut_value);
+    output::result(format!("Final mut_value: {}", mut_value));
+    println!();
 }
 
 // 5. Destructuring Structures
 fn demonstrate_struct_destructuring() {
-    println!("5. Destructuring Structures");
-    println!("   Extract fields from structs\n");
+    output::note("Extract fields from structs");
+    println!();
 
     struct Foo {
         x: (u32, u32),
@@ -189,18 +250,86 @@ fn demonstrate_struct_destructuring() {
 
     match foo {
         Foo { x: (1, b), y } => {
-            println!("   First of x is 1, b = {}, y = {}", b, y);
+            output::result(format!("First of x is 1, b = {}, y = {}", b, y));
         }
         Foo { y: 2, x: i } => {
-            println!("   y is 2, i = {:?}", i);
+            output::result(format!("y is 2, i = {:?}", i));
         }
         Foo { y, .. } => {
-            println!("   y = {}, we don't care about x", y);
+            output::result(format!("y = {}, we don't care about x", y));
         }
     }
 
     // Destructuring with let
     let Foo { x: (a, b), y } = foo;
-    println!("   Destructured with let: a={}, b={}, y={}", a, b, y);
+    output::result(format!("Destructured with let: a={}, b={}, y={}", a, b, y));
+    println!();
+}
+
+// 6. Destructuring in event handlers
+fn demonstrate_event_handlers() {
+    output::note("Handlers destructure the events a dispatcher hands them");
+    println!();
+
+    // roadmap::events has the WebEvent enum from section 3 and a dispatcher
+    // that calls the handlers registered for each kind of event
+    use events::WebEvent;
+
+    // Handlers share the page's text through Rc<RefCell<..>>
+    fn page(events: &mut Dispatcher) -> Rc<RefCell<String>> {
+        let text = Rc::new(RefCell::new(String::new()));
+        let typed = Rc::clone(&text);
+        events.on(Kind::KeyPress, move |event, _| {
+            if let WebEvent::KeyPress(c) = event {
+                typed.borrow_mut().push(*c);
+            }
+        });
+        // A paste queues one KeyPress per char
+        events.on(Kind::Paste, |event, queue| {
+            if let WebEvent::Paste(pasted) = event {
+                pasted
+                    .chars()
+                    .for_each(|c| queue.push(WebEvent::KeyPress(c)));
+            }
+        });
+        events.on(Kind::Click, |event, _| {
+            if let &WebEvent::Click { x, y } = event {
+                output::result(format!("Clicked at x={}, y={}", x, y));
+            }
+        });
+        text
+    }
+
+    let mut live = Dispatcher::new();
+    let text = page(&mut live);
+    live.record();
+    live.emit(WebEvent::PageLoad);
+    live.emit(WebEvent::Click { x: 20, y: 80 });
+    live.emit(WebEvent::Paste(String::from("Hi")));
+    live.emit(WebEvent::KeyPress('!'));
+    output::result(format!("Dispatched {} events", live.run()));
+    output::result(format!("Typed: {:?}", text.borrow()));
+
+    // The emitted events, and when, as a line-based log
+    let log = live.take_log().unwrap_or_default();
     println!();
-}
\ No newline at end of file
+    output::note("Recorded log:");
+    for line in log.to_text().lines() {
+        println!("   | {}", line);
+    }
+
+    // Replaying the log into fresh handlers rebuilds the same state
+    println!();
+    output::note("Replaying it:");
+    let mut replayed = Dispatcher::new();
+    let replayed_text = page(&mut replayed);
+    match Log::parse(&log.to_text()).and_then(|log| events::log::replay(&log, &mut replayed)) {
+        Ok(count) => output::result(format!(
+            "Replayed {} events, typed: {:?}",
+            count,
+            replayed_text.borrow()
+        )),
+        Err(err) => output::result(format!("Replay failed: {}", err)),
+    }
+    println!();
+}
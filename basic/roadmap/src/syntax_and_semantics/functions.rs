@@ -1,85 +1,95 @@
-fn main() {
-    println!("=== Functions in Rust ===\n");
-
-    // 1. Basic function definition
-    demonstrate_basic_functions();
-
-    // 2. Function parameters
-    demonstrate_parameters();
-
-    // 3. Multiple parameters
-    demonstrate_multiple_parameters();
-
-    // 4. Statements vs Expressions
-    demonstrate_statements_vs_expressions();
-
-    // 5. Functions with return values
-    demonstrate_return_values();
-
-    // 6. The semicolon trap
-    demonstrate_semicolon_trap();
-}
+use crate::output;
+use crate::registry::{Difficulty, Lesson, Meta, Section};
+
+pub const LESSON: Lesson = Lesson {
+    id: "functions",
+    title: "Functions in Rust",
+    meta: Meta {
+        difficulty: Difficulty::Beginner,
+        minutes: 15,
+        book: Some("3.3"),
+        prerequisites: &["variables_mutability"],
+        tags: &["functions", "expressions"],
+    },
+    sections: &[
+        Section::new("Basic Function Definition", demonstrate_basic_functions),
+        Section::new("Function Parameters", demonstrate_parameters),
+        Section::new("Multiple Parameters", demonstrate_multiple_parameters),
+        Section::new(
+            "Statements vs Expressions",
+            demonstrate_statements_vs_expressions,
+        ),
+        Section::new("Functions with Return Values", demonstrate_return_values),
+        Section::new("The Semicolon Trap", demonstrate_semicolon_trap),
+    ],
+};
 
 // Basic function definition
 fn demonstrate_basic_functions() {
-    println!("1. Basic Function Definition");
-    println!("   Functions use snake_case naming convention\n");
+    output::note("Functions use snake_case naming convention");
+    println!();
 
-    println!("   Calling another_function:");
+    output::note("Calling another_function:");
     another_function();
 
-    println!("   → Functions can be defined before or after main()");
-    println!("   → Rust doesn't care about order, only that they're defined\n");
+    output::note("Functions can be defined before or after main()");
+    output::note("Rust doesn't care about order, only that they're defined");
+    println!();
 }
 
 fn another_function() {
-    println!("   Another function.");
+    output::result("Another function.");
 }
 
 // Function parameters - must specify types!
 fn demonstrate_parameters() {
-    println!("2. Function Parameters");
-    println!("   Parameter types MUST be declared in function signature\n");
+    output::note("Parameter types MUST be declared in function signature");
+    println!();
 
     another_function_with_param(5);
     another_function_with_param(42);
 
-    println!("   → Type annotations required: fn func_name(param: type)");
-    println!("   → This helps compiler give better error messages\n");
+    output::note("Type annotations required: fn func_name(param: type)");
+    output::note("This helps compiler give better error messages");
+    println!();
 }
 
 fn another_function_with_param(x: i32) {
-    println!("   The value of x is: {}", x);
+    output::result(format!("The value of x is: {}", x));
 }
 
 // Multiple parameters
 fn demonstrate_multiple_parameters() {
-    println!("3. Multiple Parameters");
-    println!("   Separate parameters with commas\n");
+    output::note("Separate parameters with commas");
+    println!();
 
     print_labeled_measurement(5, 'h');
     print_labeled_measurement(100, 'm');
 
-    println!("   → Each parameter needs its type specified\n");
+    output::note("Each parameter needs its type specified");
+    println!();
 }
 
 fn print_labeled_measurement(value: i32, unit_label: char) {
-    println!("   The measurement is: {}{}", value, unit_label);
+    output::result(format!("The measurement is: {}{}", value, unit_label));
 }
 
 // Statements vs Expressions
 fn demonstrate_statements_vs_expressions() {
-    println!("4. Statements vs Expressions");
-    println!("   Important distinction in Rust!\n");
+    output::note("Important distinction in Rust!");
+    println!();
 
-    println!("   Statements: perform action, don't return value");
-    println!("   - let x = 5; is a statement");
-    println!("   - Function definitions are statements\n");
+    output::note("Statements: perform action, don't return value");
+    output::note("- let x = 5; is a statement\n- Function definitions are statements");
+    println!();
 
-    println!("   Expressions: evaluate to a value");
-    println!("   - 5 + 6 is an expression (evaluates to 11)");
-    println!("   - Function calls are expressions");
-    println!("   - Blocks {{}} are expressions\n");
+    output::note("Expressions: evaluate to a value");
+    output::note(
+        "- 5 + 6 is an expression (evaluates to 11)\n\
+         - Function calls are expressions\n\
+         - Blocks {} are expressions",
+    );
+    println!();
 
     // Block expression example
     let y = {
@@ -87,36 +97,44 @@ fn demonstrate_statements_vs_expressions() {
         x + 1 // No semicolon! This is an expression
     };
 
-    println!("   Block expression example:");
-    println!("   let y = {{");
-    println!("       let x = 3;");
-    println!("       x + 1  // No semicolon!");
-    println!("   }};");
-    println!("   → y = {}\n", y);
-
-    println!("   Key point: Expressions don't end with semicolons!");
-    println!("   Adding semicolon turns expression into statement\n");
+    output::note("Block expression example:");
+    output::code(concat!(
+        "let y = {\n",
+        "    let x = 3;\n",
+        "    x + 1  // No semicolon!\n",
+        "};",
+    ));
+    output::result(format!("y = {}", y));
+    println!();
+
+    output::note("Key point: Expressions don't end with semicolons!");
+    output::note("Adding semicolon turns expression into statement");
+    println!();
 }
 
 // Functions with return values
 fn demonstrate_return_values() {
-    println!("5. Functions with Return Values");
-    println!("   Return type specified with -> type\n");
+    output::note("Return type specified with -> type");
+    println!();
 
     let x = five();
-    println!("   let x = five();");
-    println!("   → x = {}\n", x);
+    output::code("let x = five();");
+    output::result(format!("x = {}", x));
+    println!();
 
     let result = plus_one(5);
-    println!("   let result = plus_one(5);");
-    println!("   → result = {}\n", result);
+    output::code("let result = plus_one(5);");
+    output::result(format!("result = {}", result));
+    println!();
 
     let sum = add(10, 20);
-    println!("   let sum = add(10, 20);");
-    println!("   → sum = {}\n", sum);
+    output::code("let sum = add(10, 20);");
+    output::result(format!("sum = {}", sum));
+    println!();
 
-    println!("   Return value = last expression in function body");
-    println!("   No 'return' keyword needed (but can use it)\n");
+    output::note("Return value = last expression in function body");
+    output::note("No 'return' keyword needed (but can use it)");
+    println!();
 }
 
 // Function that returns a value
@@ -135,31 +153,42 @@ fn add(a: i32, b: i32) -> i32 {
 }
 
 // The semicolon trap - common mistake!
+// The WRONG version is compile_fail/syntax_and_semantics/semicolon_trap.rs (E0308)
 fn demonstrate_semicolon_trap() {
-    println!("6. The Semicolon Trap");
-    println!("   Adding semicolon to return expression causes error!\n");
-
-    println!("   CORRECT (no semicolon):");
-    println!("   fn plus_one(x: i32) -> i32 {{");
-    println!("       x + 1  // Expression - returns value");
-    println!("   }}\n");
-
-    println!("   WRONG (with semicolon):");
-    println!("   fn plus_one(x: i32) -> i32 {{");
-    println!("       x + 1;  // Statement - returns ()");
-    println!("   }}");
-    println!("   → ERROR: expected i32, found ()\n");
+    output::note("Adding semicolon to return expression causes error!");
+    println!();
+
+    output::note("CORRECT (no semicolon):");
+    output::code(concat!(
+        "fn plus_one(x: i32) -> i32 {\n",
+        "    x + 1  // Expression - returns value\n",
+        "}",
+    ));
+    println!();
+
+    output::note("WRONG (with semicolon):");
+    output::code(concat!(
+        "fn plus_one(x: i32) -> i32 {\n",
+        "    x + 1;  // Statement - returns ()\n",
+        "}",
+    ));
+    output::warning("ERROR: expected i32, found ()");
+    println!();
 
     // Demonstrate with explicit return
     let result = explicit_return_example(10);
-    println!("   Using explicit 'return' keyword:");
-    println!("   fn explicit_return_example(x: i32) -> i32 {{");
-    println!("       return x * 2;  // Explicit return");
-    println!("   }}");
-    println!("   → result = {}\n", result);
-
-    println!("   Note: Most functions use implicit return (no semicolon)");
-    println!("   Use 'return' for early returns\n");
+    output::note("Using explicit 'return' keyword:");
+    output::code(concat!(
+        "fn explicit_return_example(x: i32) -> i32 {\n",
+        "    return x * 2;  // Explicit return\n",
+        "}",
+    ));
+    output::result(format!("result = {}", result));
+    println!();
+
+    output::note("Note: Most functions use implicit return (no semicolon)");
+    output::note("Use 'return' for early returns");
+    println!();
 }
 
 fn explicit_return_example(x: i32) -> i32 {
@@ -2,158 +2,214 @@
 // This file demonstrates how Rust handles integer overflow in different modes
 // and the various methods to handle overflow explicitly.
 
-pub fn demonstrate_integer_overflow() {
-    println!("=== Integer Overflow in Rust ===\n");
-
-    println!("1. Understanding u8 type (0-255 range)");
-    explain_u8_range();
-
-    println!("\n2. Debug Mode: Overflow causes panic");
-    demonstrate_debug_overflow();
-
-    println!("\n3. Release Mode: Two's complement wrapping");
-    demonstrate_release_wrapping();
-
-    println!("\n4. Explicit Overflow Handling Methods");
-    demonstrate_overflow_methods();
-}
+use std::io;
+
+use crate::output;
+use crate::registry::{Difficulty, Lesson, Meta, Section};
+use crate::sandbox::{self, Outcome, Profile};
+
+pub const LESSON: Lesson = Lesson {
+    id: "integer_overflow",
+    title: "Integer Overflow in Rust",
+    meta: Meta {
+        difficulty: Difficulty::Intermediate,
+        minutes: 15,
+        book: Some("3.2"),
+        prerequisites: &["numeric_operations"],
+        tags: &["integers", "overflow", "panics"],
+    },
+    sections: &[
+        Section::new("Understanding u8 type (0-255 range)", explain_u8_range),
+        Section::new(
+            "Debug Mode: Overflow causes panic",
+            demonstrate_debug_overflow,
+        ),
+        Section::new(
+            "Release Mode: Two's complement wrapping",
+            demonstrate_release_wrapping,
+        ),
+        Section::new(
+            "Explicit Overflow Handling Methods",
+            demonstrate_overflow_methods,
+        ),
+        Section::new(
+            "Practical Example: Safe Arithmetic",
+            safe_arithmetic_example,
+        ),
+    ],
+};
 
 // u8 can hold values from 0 to 255
 fn explain_u8_range() {
     let min: u8 = 0;
     let max: u8 = 255;
-    println!("  u8 range: {} to {}", min, max);
-    println!("  Maximum value: {}", max);
+    output::result(format!("u8 range: {} to {}", min, max));
+    output::result(format!("Maximum value: {}", max));
 
     // This would overflow if we tried: let overflow = 256u8; // 256u8 is called integer literals
     // But we can't even write that - it's a compile error!
-    println!("  Trying to store 256 would overflow!");
+    output::note("Trying to store 256 would overflow!");
+}
+
+// The same program in both profiles: overflow checks are a compile-time
+// setting, so the sandbox builds it twice with rustc and runs each binary
+const OVERFLOW_PROGRAM: &str = "\
+fn main() {
+    let value: u8 = std::hint::black_box(255);
+    let value = value + 1;
+    println!(\"255 + 1 = {}\", value);
 }
+";
 
 // In DEBUG mode, Rust checks for overflow and panics
 fn demonstrate_debug_overflow() {
-    println!("  Running in DEBUG mode (default: cargo run)");
-    println!("  Overflow causes program to PANIC (crash with error)");
-
-    let mut value: u8 = 255;
-    println!("  Starting value: {}", value);
-
-    // In debug mode, this would panic:
-    value = value + 1; // PANIC: attempt to add with overflow
-
-    // To see the panic, uncomment the line above and run: cargo run
-    // You'll see: "thread 'main' panicked at 'attempt to add with overflow'"
-
-    println!("  If we tried: value = 255 + 1");
-    println!("  Result: PANIC in debug mode!");
+    output::note("Running in DEBUG mode (default: cargo run)");
+    output::warning("Overflow causes program to PANIC (crash with error)");
+
+    let value: u8 = 255;
+    output::result(format!("Starting value: {}", value));
+    output::note("Building overflow.rs with overflow checks on:");
+    output::code(OVERFLOW_PROGRAM.trim_end());
+    show_outcome(sandbox::run_program(
+        "overflow",
+        OVERFLOW_PROGRAM,
+        Profile::Debug,
+    ));
 }
 
 // In RELEASE mode, Rust uses two's complement wrapping
 fn demonstrate_release_wrapping() {
-    println!("  Running in RELEASE mode (cargo run --release)");
-    println!("  Overflow causes WRAPPING (no panic, but unexpected value)");
+    output::note("Running in RELEASE mode (cargo run --release)");
+    output::note("Overflow causes WRAPPING (no panic, but unexpected value)");
+
+    output::note("The same overflow.rs with overflow checks off:");
+    show_outcome(sandbox::run_program(
+        "overflow",
+        OVERFLOW_PROGRAM,
+        Profile::Release,
+    ));
 
     // Two's complement wrapping examples:
-    println!("  u8 wrapping examples:");
-    println!("    255 + 1 = 0   (wraps to minimum)");
-    println!("    255 + 2 = 1");
-    println!("    0 - 1 = 255   (wraps to maximum)");
-
-    // Note: We can't demonstrate this easily in code without actually
-    // causing overflow, which would panic in debug mode.
-    // But in release mode, these would wrap:
-    // let x: u8 = 255;
-    // let y = x.wrapping_add(1);  // y = 0
+    output::note("u8 wrapping examples:");
+    output::result(format!(
+        "255 + 1 = {}   (wraps to minimum)",
+        255u8.wrapping_add(1)
+    ));
+    output::result(format!("255 + 2 = {}", 255u8.wrapping_add(2)));
+    output::result(format!(
+        "0 - 1 = {}   (wraps to maximum)",
+        0u8.wrapping_sub(1)
+    ));
+}
+
+fn show_outcome(outcome: io::Result<Outcome>) {
+    match outcome {
+        Ok(Outcome::Exited { stdout }) => output::result(stdout.trim_end()),
+        Ok(Outcome::Panicked(panic)) => output::warning(panic.to_string()),
+        Err(err) => output::warning(format!("could not run the sandbox: {}", err)),
+    }
 }
 
 // Explicit overflow handling methods
 fn demonstrate_overflow_methods() {
-    println!("\n  Rust provides methods to handle overflow explicitly:");
+    println!();
+    output::note("Rust provides methods to handle overflow explicitly:");
 
     // 1. wrapping_* methods - always wrap, never panic
-    println!("\n  A. wrapping_* methods (wrap in all modes):");
+    println!();
+    output::note("A. wrapping_* methods (wrap in all modes):");
     let x: u8 = 255;
     let result = x.wrapping_add(1);
-    println!("     255.wrapping_add(1) = {}", result);
+    output::result(format!("255.wrapping_add(1) = {}", result));
 
     let y: u8 = 0;
     let result2 = y.wrapping_sub(1);
-    println!("     0.wrapping_sub(1) = {}", result2);
+    output::result(format!("0.wrapping_sub(1) = {}", result2));
 
     // 2. checked_* methods - return Option
-    println!("\n  B. checked_* methods (return None on overflow):");
+    println!();
+    output::note("B. checked_* methods (return None on overflow):");
     let x: u8 = 255;
     match x.checked_add(1) {
-        Some(value) => println!("     255.checked_add(1) = Some({})", value),
-        None => println!("     255.checked_add(1) = None (overflow!)"),
+        Some(value) => output::result(format!("255.checked_add(1) = Some({})", value)),
+        None => output::result("255.checked_add(1) = None (overflow!)"),
     }
 
     let x: u8 = 200;
     match x.checked_add(50) {
-        Some(value) => println!("     200.checked_add(50) = Some({})", value),
-        None => println!("     200.checked_add(50) = None (overflow!)"),
+        Some(value) => output::result(format!("200.checked_add(50) = Some({})", value)),
+        None => output::result("200.checked_add(50) = None (overflow!)"),
     }
 
     // 3. overflowing_* methods - return (value, overflow_flag)
-    println!("\n  C. overflowing_* methods (return value + overflow flag):");
+    println!();
+    output::note("C. overflowing_* methods (return value + overflow flag):");
     let x: u8 = 255;
     let (result, overflowed) = x.overflowing_add(1);
-    println!(
-        "     255.overflowing_add(1) = ({}, overflowed: {})",
+    output::result(format!(
+        "255.overflowing_add(1) = ({}, overflowed: {})",
         result, overflowed
-    );
+    ));
 
     let x: u8 = 200;
     let (result, overflowed) = x.overflowing_add(50);
-    println!(
-        "     200.overflowing_add(50) = ({}, overflowed: {})",
+    output::result(format!(
+        "200.overflowing_add(50) = ({}, overflowed: {})",
         result, overflowed
-    );
+    ));
 
     // 4. saturating_* methods - clamp to min/max
-    println!("\n  D. saturating_* methods (clamp at boundaries):");
+    println!();
+    output::note("D. saturating_* methods (clamp at boundaries):");
     let x: u8 = 255;
     let result = x.saturating_add(1);
-    println!("     255.saturating_add(1) = {} (clamped at max)", result);
+    output::result(format!(
+        "255.saturating_add(1) = {} (clamped at max)",
+        result
+    ));
 
     let x: u8 = 200;
     let result = x.saturating_add(100);
-    println!("     200.saturating_add(100) = {} (clamped at max)", result);
+    output::result(format!(
+        "200.saturating_add(100) = {} (clamped at max)",
+        result
+    ));
 
     let x: u8 = 0;
     let result = x.saturating_sub(1);
-    println!("     0.saturating_sub(1) = {} (clamped at min)", result);
+    output::result(format!("0.saturating_sub(1) = {} (clamped at min)", result));
 }
 
 // Practical example: Safe arithmetic
-pub fn safe_arithmetic_example() {
-    println!("\n=== Practical Example: Safe Arithmetic ===");
-
+fn safe_arithmetic_example() {
     let user_input: u8 = 250;
     let increment: u8 = 10;
 
     // Option 1: Check for overflow first
     match user_input.checked_add(increment) {
         Some(result) => {
-            println!("Safe addition: {} + {} = {}", user_input, increment, result);
+            output::result(format!(
+                "Safe addition: {} + {} = {}",
+                user_input, increment, result
+            ));
         }
         None => {
-            println!("Error: Addition would overflow!");
+            output::warning("Error: Addition would overflow!");
             // Handle error appropriately (return error, use default, etc.)
         }
     }
 
     // Option 2: Saturate at boundaries
     let result = user_input.saturating_add(increment);
-    println!(
+    output::result(format!(
         "Saturating addition: {} + {} = {} (clamped)",
         user_input, increment, result
-    );
+    ));
 
     // Option 3: Explicitly allow wrapping (when you know it's intentional)
     let result = user_input.wrapping_add(increment);
-    println!(
+    output::result(format!(
         "Wrapping addition: {} + {} = {} (wrapped)",
         user_input, increment, result
-    );
+    ));
 }
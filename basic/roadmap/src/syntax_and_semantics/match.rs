@@ -1,53 +1,52 @@
-fn main() {
-    println!("=== Match Control Flow in Rust ===\n");
-
-    // 1. Basic match - like C switch but more powerful
-    demonstrate_basic_match();
-
-    // 2. Match as an expression
-    demonstrate_match_expression();
-
-    // 3. Match with multiple patterns
-    demonstrate_multiple_patterns();
-
-    // 4. Match with ranges
-    demonstrate_range_matching();
-
-    // 5. Match must be exhaustive
-    demonstrate_exhaustive_matching();
-
-    // 6. Match with guards
-    demonstrate_match_guards();
-
-    // 7. Match with binding
-    demonstrate_binding();
-}
+use crate::output;
+use crate::registry::{Difficulty, Lesson, Meta, Section};
+
+pub const LESSON: Lesson = Lesson {
+    id: "match",
+    title: "Match Control Flow in Rust",
+    meta: Meta {
+        difficulty: Difficulty::Beginner,
+        minutes: 20,
+        book: Some("6.2"),
+        prerequisites: &["control_flow"],
+        tags: &["control-flow", "pattern-matching"],
+    },
+    sections: &[
+        Section::new("Basic Match", demonstrate_basic_match),
+        Section::new("Match as an Expression", demonstrate_match_expression),
+        Section::new("Multiple Patterns with |", demonstrate_multiple_patterns),
+        Section::new("Range Matching", demonstrate_range_matching),
+        Section::new("Exhaustive Matching", demonstrate_exhaustive_matching),
+        Section::new("Match Guards", demonstrate_match_guards),
+        Section::new("Binding with @", demonstrate_binding),
+    ],
+};
 
 // 1. Basic match - similar to C switch
 fn demonstrate_basic_match() {
-    println!("1. Basic Match");
-    println!("   Pattern matching like C switch, but more powerful\n");
+    output::note("Pattern matching like C switch, but more powerful");
+    println!();
 
     let number = 13;
 
-    println!("   Tell me about {}", number);
+    output::result(format!("Tell me about {}", number));
     match number {
         // Match a single value
-        1 => println!("   One!"),
+        1 => output::result("One!"),
         // Match several values
-        2 | 3 | 5 | 7 | 11 => println!("   This is a prime"),
+        2 | 3 | 5 | 7 | 11 => output::result("This is a prime"),
         // Match an inclusive range
-        13..=19 => println!("   A teen"),
+        13..=19 => output::result("A teen"),
         // Handle the rest of cases
-        _ => println!("   Ain't special"),
+        _ => output::result("Ain't special"),
     }
     println!();
 }
 
 // 2. Match as an expression
 fn demonstrate_match_expression() {
-    println!("2. Match as an Expression");
-    println!("   Match returns a value\n");
+    output::note("Match returns a value");
+    println!();
 
     let boolean = true;
 
@@ -57,7 +56,7 @@ fn demonstrate_match_expression() {
         true => 1,
     };
 
-    println!("   {} -> {}", boolean, binary);
+    output::result(format!("{} -> {}", boolean, binary));
 
     // Another example with more complex expressions
     let number = 3;
@@ -68,71 +67,71 @@ fn demonstrate_match_expression() {
         _ => "many",
     };
 
-    println!("   {} is {}", number, description);
+    output::result(format!("{} is {}", number, description));
     println!();
 }
 
 // 3. Match with multiple patterns using |
 fn demonstrate_multiple_patterns() {
-    println!("3. Multiple Patterns with |");
-    println!("   Match multiple values in one arm\n");
+    output::note("Match multiple values in one arm");
+    println!();
 
     let number = 7;
 
     match number {
-        1 => println!("   One"),
-        2 | 3 | 5 | 7 | 11 | 13 => println!("   {} is a prime number", number),
-        _ => println!("   {} is not a prime (or not in our list)", number),
+        1 => output::result("One"),
+        2 | 3 | 5 | 7 | 11 | 13 => output::result(format!("{} is a prime number", number)),
+        _ => output::result(format!("{} is not a prime (or not in our list)", number)),
     }
 
     // Multiple patterns with different types
     let character = 'x';
     match character {
-        'a' | 'e' | 'i' | 'o' | 'u' => println!("   '{}' is a vowel", character),
-        'y' => println!("   '{}' is sometimes a vowel", character),
-        _ => println!("   '{}' is a consonant", character),
+        'a' | 'e' | 'i' | 'o' | 'u' => output::result(format!("'{}' is a vowel", character)),
+        'y' => output::result(format!("'{}' is sometimes a vowel", character)),
+        _ => output::result(format!("'{}' is a consonant", character)),
     }
     println!();
 }
 
 // 4. Match with ranges
 fn demonstrate_range_matching() {
-    println!("4. Range Matching");
-    println!("   Match inclusive ranges with ..=\n");
+    output::note("Match inclusive ranges with ..=");
+    println!();
 
     let age = 17;
 
     match age {
-        0 => println!("   Just born!"),
-        1..=12 => println!("   {} years old - child", age),
-        13..=19 => println!("   {} years old - teenager", age),
-        20..=64 => println!("   {} years old - adult", age),
-        65..=120 => println!("   {} years old - senior", age),
-        _ => println!("   Age {} seems unlikely!", age),
+        0 => output::result("Just born!"),
+        1..=12 => output::result(format!("{} years old - child", age)),
+        13..=19 => output::result(format!("{} years old - teenager", age)),
+        20..=64 => output::result(format!("{} years old - adult", age)),
+        65..=120 => output::result(format!("{} years old - senior", age)),
+        _ => output::result(format!("Age {} seems unlikely!", age)),
     }
 
     // Ranges work with chars too
     let grade = 'B';
     match grade {
-        'A'..='C' => println!("   Grade {} - passing", grade),
-        'D' | 'F' => println!("   Grade {} - failing", grade),
-        _ => println!("   Invalid grade"),
+        'A'..='C' => output::result(format!("Grade {} - passing", grade)),
+        'D' | 'F' => output::result(format!("Grade {} - failing", grade)),
+        _ => output::result("Invalid grade"),
     }
     println!();
 }
 
 // 5. Match must be exhaustive
 fn demonstrate_exhaustive_matching() {
-    println!("5. Exhaustive Matching");
-    println!("   All possible values must be covered\n");
+    output::note("All possible values must be covered");
+    println!();
 
     let number = 42;
 
     // This match is exhaustive because _ catches everything else
     match number {
-        0 => println!("   Zero"),
-        1..=10 => println!("   Between 1 and 10"),
-        _ => println!("   {} - something else", number),
+        0 => output::result("Zero"),
+        1..=10 => output::result("Between 1 and 10"),
+        _ => output::result(format!("{} - something else", number)),
     }
 
     // For enums, all variants must be covered
@@ -148,10 +147,10 @@ fn demonstrate_exhaustive_matching() {
     let direction = Direction::North;
 
     match direction {
-        Direction::North => println!("   Going North"),
-        Direction::South => println!("   Going South"),
-        Direction::East => println!("   Going East"),
-        Direction::West => println!("   Going West"),
+        Direction::North => output::result("Going North"),
+        Direction::South => output::result("Going South"),
+        Direction::East => output::result("Going East"),
+        Direction::West => output::result("Going West"),
         // No _ needed - all variants covered
     }
     println!();
@@ -159,51 +158,51 @@ fn demonstrate_exhaustive_matching() {
 
 // 6. Match with guards (additional conditions)
 fn demonstrate_match_guards() {
-    println!("6. Match Guards");
-    println!("   Add conditions with if after the pattern\n");
+    output::note("Add conditions with if after the pattern");
+    println!();
 
     let pair = (2, -2);
 
     match pair {
-        (x, y) if x == y => println!("   ({}, {}) - These are twins", x, y),
-        (x, y) if x + y == 0 => println!("   ({}, {}) - These sum to zero", x, y),
-        (x, _) if x % 2 == 0 => println!("   ({}, _) - First is even", x),
-        _ => println!("   No special relationship"),
+        (x, y) if x == y => output::result(format!("({}, {}) - These are twins", x, y)),
+        (x, y) if x + y == 0 => output::result(format!("({}, {}) - These sum to zero", x, y)),
+        (x, _) if x % 2 == 0 => output::result(format!("({}, _) - First is even", x)),
+        _ => output::result("No special relationship"),
     }
 
     // Guard with range
     let number = 4;
     match number {
-        n if n < 0 => println!("   {} is negative", n),
-        n if n == 0 => println!("   {} is zero", n),
-        n if n > 0 && n < 10 => println!("   {} is a small positive number", n),
-        n => println!("   {} is a large positive number", n),
+        n if n < 0 => output::result(format!("{} is negative", n)),
+        n if n == 0 => output::result(format!("{} is zero", n)),
+        n if n > 0 && n < 10 => output::result(format!("{} is a small positive number", n)),
+        n => output::result(format!("{} is a large positive number", n)),
     }
     println!();
 }
 
 // 7. Match with binding using @
 fn demonstrate_binding() {
-    println!("7. Binding with @");
-    println!("   Bind values while testing patterns\n");
+    output::note("Bind values while testing patterns");
+    println!();
 
     let age = 15;
 
     match age {
-        0 => println!("   Not born yet? Impossible!"),
-        n @ 1..=12 => println!("   Child of age {}", n),
-        n @ 13..=19 => println!("   Teen of age {}", n),
-        n => println!("   Adult of age {}", n),
+        0 => output::result("Not born yet? Impossible!"),
+        n @ 1..=12 => output::result(format!("Child of age {}", n)),
+        n @ 13..=19 => output::result(format!("Teen of age {}", n)),
+        n => output::result(format!("Adult of age {}", n)),
     }
 
     // Binding in more complex patterns
     let point = (3, 10);
     match point {
-        (0, 0) => println!("   Origin"),
+        (0, 0) => output::result("Origin"),
         (x @ 0..=5, y @ 0..=5) => {
-            println!("   Point ({}, {}) is in the small box", x, y);
+            output::result(format!("Point ({}, {}) is in the small box", x, y));
         }
-        (x, y) => println!("   Point ({}, {}) is outside the small box", x, y),
+        (x, y) => output::result(format!("Point ({}, {}) is outside the small box", x, y)),
     }
     println!();
-}
\ No newline at end of file
+}
@@ -0,0 +1,42 @@
+// Syntax and Semantics
+// Core language features: variables, data types, functions, control flow and patterns
+
+use crate::registry::Topic;
+
+pub mod arrays;
+pub mod constants;
+pub mod control_flow;
+pub mod control_flow_if_let;
+pub mod data_types;
+pub mod destructuring;
+pub mod functions;
+pub mod integer_overflow;
+pub mod r#match;
+pub mod numeric_operations;
+pub mod overflow_methods;
+pub mod patterns_and_matching;
+pub mod shadowing;
+pub mod tuple;
+pub mod variables_mutability;
+
+pub const TOPIC: Topic = Topic {
+    id: "syntax_and_semantics",
+    title: "Syntax and Semantics",
+    lessons: &[
+        variables_mutability::LESSON,
+        constants::LESSON,
+        shadowing::LESSON,
+        data_types::LESSON,
+        numeric_operations::LESSON,
+        integer_overflow::LESSON,
+        overflow_methods::LESSON,
+        tuple::LESSON,
+        arrays::LESSON,
+        functions::LESSON,
+        control_flow::LESSON,
+        r#match::LESSON,
+        control_flow_if_let::LESSON,
+        patterns_and_matching::LESSON,
+        destructuring::LESSON,
+    ],
+};
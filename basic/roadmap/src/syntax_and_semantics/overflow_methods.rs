@@ -1,120 +1,185 @@
-fn main() {
-    println!("=== Integer Overflow in Rust ===\n");
-
-    explain_integer_overflow();
-    demonstrate_overflow_methods();
-    practical_example();
-}
+use crate::output;
+use crate::overflow::{self, Operation};
+use crate::registry::{Difficulty, Lesson, Meta, Section};
+
+pub const LESSON: Lesson = Lesson {
+    id: "overflow_methods",
+    title: "Integer Overflow Methods",
+    meta: Meta {
+        difficulty: Difficulty::Intermediate,
+        minutes: 10,
+        book: Some("3.2"),
+        prerequisites: &["integer_overflow"],
+        tags: &["integers", "overflow"],
+    },
+    sections: &[
+        Section::new("Understanding u8 Type", explain_integer_overflow),
+        Section::new("Debug Mode vs Release Mode", explain_debug_vs_release),
+        Section::new(
+            "Explicit Overflow Handling Methods",
+            demonstrate_overflow_methods,
+        ),
+        Section::new("Practical Example: Safe Arithmetic", practical_example),
+        Section::new("Every Integer Width", demonstrate_every_width),
+    ],
+};
 
 // Understanding u8: can hold values from 0 to 255
 fn explain_integer_overflow() {
-    println!("1. Understanding u8 Type");
-    println!("   u8 can hold values from 0 to 255");
-    println!("   Maximum value: 255");
-    println!("   If you try to store 256, it OVERFLOWS!\n");
-
-    println!("2. Debug Mode vs Release Mode");
-    println!("   DEBUG mode (cargo run):");
-    println!("   - Rust checks for overflow");
-    println!("   - Program PANICS (crashes) if overflow occurs");
-    println!("   - Example: let x: u8 = 255; x = x + 1; → PANIC!\n");
-
-    println!("   RELEASE mode (cargo run --release):");
-    println!("   - No overflow checks (for performance)");
-    println!("   - Uses TWO'S COMPLEMENT WRAPPING");
-    println!("   - 255 + 1 = 0 (wraps to minimum)");
-    println!("   - 0 - 1 = 255 (wraps to maximum)");
-    println!("   - Program doesn't panic, but value is unexpected!\n");
+    output::note("u8 can hold values from 0 to 255");
+    output::note("Maximum value: 255");
+    output::note("If you try to store 256, it OVERFLOWS!");
+    println!();
+}
+
+// Overflow checks depend on the build profile
+fn explain_debug_vs_release() {
+    output::note("DEBUG mode (cargo run):");
+    output::note(
+        "- Rust checks for overflow\n\
+         - Program PANICS (crashes) if overflow occurs\n\
+         - Example: let x: u8 = 255; x = x + 1; → PANIC!",
+    );
+    println!();
+
+    output::note("RELEASE mode (cargo run --release):");
+    output::note(
+        "- No overflow checks (for performance)\n\
+         - Uses TWO'S COMPLEMENT WRAPPING\n\
+         - 255 + 1 = 0 (wraps to minimum)\n\
+         - 0 - 1 = 255 (wraps to maximum)\n\
+         - Program doesn't panic, but value is unexpected!",
+    );
+    println!();
 }
 
 // Demonstrating the different overflow handling methods
 fn demonstrate_overflow_methods() {
-    println!("3. Explicit Overflow Handling Methods\n");
-
     let max_u8: u8 = 255;
 
     // A. wrapping_* methods - always wrap, never panic
-    println!("   A. wrapping_* methods (wrap in all modes):");
-    println!(
-        "      {} + 1 = {} (wrapped)",
+    output::note("A. wrapping_* methods (wrap in all modes):");
+    output::result(format!(
+        "{} + 1 = {} (wrapped)",
         max_u8,
         max_u8.wrapping_add(1)
-    );
-    println!("      0 - 1 = {} (wrapped)", 0u8.wrapping_sub(1));
-    println!("      Use when: You want wrapping behavior in all modes\n");
+    ));
+    output::result(format!("0 - 1 = {} (wrapped)", 0u8.wrapping_sub(1)));
+    output::note("Use when: You want wrapping behavior in all modes");
+    println!();
 
     // B. checked_* methods - return Option<u8>
-    println!("   B. checked_* methods (return None on overflow):");
+    output::note("B. checked_* methods (return None on overflow):");
     match max_u8.checked_add(1) {
-        Some(value) => println!("      {} + 1 = Some({})", max_u8, value),
-        None => println!("      {} + 1 = None (overflow detected!)", max_u8),
+        Some(value) => output::result(format!("{} + 1 = Some({})", max_u8, value)),
+        None => output::result(format!("{} + 1 = None (overflow detected!)", max_u8)),
     }
     match 200u8.checked_add(50) {
-        Some(value) => println!("      200 + 50 = Some({})", value),
-        None => println!("      200 + 50 = None (overflow detected!)"),
+        Some(value) => output::result(format!("200 + 50 = Some({})", value)),
+        None => output::result("200 + 50 = None (overflow detected!)"),
     }
-    println!("      Use when: You want to detect and handle overflow\n");
+    output::note("Use when: You want to detect and handle overflow");
+    println!();
 
     // C. overflowing_* methods - return (value, bool)
-    println!("   C. overflowing_* methods (return value + overflow flag):");
+    output::note("C. overflowing_* methods (return value + overflow flag):");
     let (result, overflowed) = max_u8.overflowing_add(1);
-    println!(
-        "      {} + 1 = ({}, overflowed: {})",
+    output::result(format!(
+        "{} + 1 = ({}, overflowed: {})",
         max_u8, result, overflowed
-    );
+    ));
 
     let (result, overflowed) = 200u8.overflowing_add(50);
-    println!("      200 + 50 = ({}, overflowed: {})", result, overflowed);
-    println!("      Use when: You need both the result AND overflow status\n");
+    output::result(format!(
+        "200 + 50 = ({}, overflowed: {})",
+        result, overflowed
+    ));
+    output::note("Use when: You need both the result AND overflow status");
+    println!();
 
     // D. saturating_* methods - clamp at boundaries
-    println!("   D. saturating_* methods (clamp at min/max):");
-    println!(
-        "      {} + 1 = {} (clamped at max)",
+    output::note("D. saturating_* methods (clamp at min/max):");
+    output::result(format!(
+        "{} + 1 = {} (clamped at max)",
         max_u8,
         max_u8.saturating_add(1)
-    );
-    println!(
-        "      200 + 100 = {} (clamped at max)",
+    ));
+    output::result(format!(
+        "200 + 100 = {} (clamped at max)",
         200u8.saturating_add(100)
-    );
-    println!("      0 - 1 = {} (clamped at min)", 0u8.saturating_sub(1));
-    println!("      Use when: You want to prevent overflow by clamping\n");
+    ));
+    output::result(format!(
+        "0 - 1 = {} (clamped at min)",
+        0u8.saturating_sub(1)
+    ));
+    output::note("Use when: You want to prevent overflow by clamping");
+    println!();
 }
 
 // Practical example showing safe arithmetic
 fn practical_example() {
-    println!("4. Practical Example: Safe Arithmetic\n");
-
     let user_balance: u8 = 250;
     let deposit: u8 = 10;
 
-    println!(
-        "   Scenario: User has {} coins, deposits {}",
+    output::result(format!(
+        "Scenario: User has {} coins, deposits {}",
         user_balance, deposit
-    );
+    ));
 
     // Option 1: Check for overflow
     match user_balance.checked_add(deposit) {
         Some(new_balance) => {
-            println!("   ✓ Safe: New balance = {}", new_balance);
+            output::result(format!("Safe: New balance = {}", new_balance));
         }
         None => {
-            println!("   ✗ Error: Addition would overflow!");
-            println!("   → Handle error: reject transaction or use saturating_add");
+            output::warning("Error: Addition would overflow!");
+            output::result("Handle error: reject transaction or use saturating_add");
         }
     }
 
     // Option 2: Saturate (clamp at max)
     let safe_balance = user_balance.saturating_add(deposit);
-    println!("   → Saturating: Balance clamped at {}", safe_balance);
+    output::result(format!("Saturating: Balance clamped at {}", safe_balance));
 
     // Option 3: Wrap (only if intentional, like in circular buffers)
     let wrapped_balance = user_balance.wrapping_add(deposit);
-    println!(
-        "   → Wrapping: Balance wrapped to {} (probably wrong!)",
+    output::result(format!(
+        "Wrapping: Balance wrapped to {} (probably wrong!)",
         wrapped_balance
-    );
+    ));
+
+    println!();
+    output::note("Best practice: Use checked_* for user input, saturating_* for UI limits");
+}
+
+// The same methods exist for i8..i128, u8..u128, isize and usize; only the
+// boundaries move. `roadmap overflow <type> <a> <op> [<b>]` prints these tables
+fn demonstrate_every_width() {
+    output::note("Two's complement: the top bit of a signed type counts as negative");
+    println!();
+
+    print_table(overflow::table(100i8, &Operation::Add(100)));
+    print_table(overflow::table(0u32, &Operation::Sub(1)));
+
+    // There is no positive i32 for |i32::MIN|, so abs overflows
+    print_table(overflow::table(i32::MIN, &Operation::Abs));
 
-    println!("\n   Best practice: Use checked_* for user input, saturating_* for UI limits");
+    // Integer division only overflows for MIN / -1, and that panics even in release
+    print_table(overflow::table(i64::MIN, &Operation::Div(-1)));
+
+    // 3^20 still fits in a u32, 3^21 does not
+    print_table(overflow::table(3u32, &Operation::Pow(21)));
+
+    output::code("cargo run -- overflow u16 MAX '*' 2");
+}
+
+fn print_table(lines: Vec<String>) {
+    for line in lines {
+        if line.is_empty() {
+            println!();
+        } else {
+            println!("   {}", line);
+        }
+    }
+    println!();
 }
@@ -1,3 +1,6 @@
+use crate::output;
+use crate::registry::{Difficulty, Lesson, Meta, Section};
+
 // Define some types for pattern matching examples
 #[allow(dead_code)]
 #[derive(Debug)]
@@ -23,47 +26,38 @@ struct Point {
     y: i32,
 }
 
-fn main() {
-    println!("=== Patterns and Matching in Rust ===\n");
-
-    // 1. Basic match expressions
-    demonstrate_basic_match();
-
-    // 2. Matching literals
-    demonstrate_literal_patterns();
-
-    // 3. Destructuring tuples
-    demonstrate_tuple_destructuring();
-
-    // 4. Destructuring structs
-    demonstrate_struct_destructuring();
-
-    // 5. Destructuring enums
-    demonstrate_enum_destructuring();
-
-    // 6. Variables in patterns
-    demonstrate_variables_in_patterns();
-
-    // 7. Wildcards and placeholders
-    demonstrate_wildcards();
-
-    // 8. Multiple patterns
-    demonstrate_multiple_patterns();
-
-    // 9. Pattern guards
-    demonstrate_pattern_guards();
-
-    // 10. if let and while let
-    demonstrate_if_let();
-
-    // 11. Refutable vs Irrefutable patterns
-    demonstrate_refutable_patterns();
-}
+pub const LESSON: Lesson = Lesson {
+    id: "patterns_and_matching",
+    title: "Patterns and Matching in Rust",
+    meta: Meta {
+        difficulty: Difficulty::Intermediate,
+        minutes: 30,
+        book: Some("19"),
+        prerequisites: &["match", "control_flow_if_let", "destructuring"],
+        tags: &["pattern-matching"],
+    },
+    sections: &[
+        Section::new("Basic Match Expressions", demonstrate_basic_match),
+        Section::new("Matching Literals", demonstrate_literal_patterns),
+        Section::new("Destructuring Tuples", demonstrate_tuple_destructuring),
+        Section::new("Destructuring Structs", demonstrate_struct_destructuring),
+        Section::new("Destructuring Enums", demonstrate_enum_destructuring),
+        Section::new("Variables in Patterns", demonstrate_variables_in_patterns),
+        Section::new("Wildcards and Placeholders", demonstrate_wildcards),
+        Section::new("Multiple Patterns", demonstrate_multiple_patterns),
+        Section::new("Pattern Guards", demonstrate_pattern_guards),
+        Section::new("if let and while let", demonstrate_if_let),
+        Section::new(
+            "Refutable vs Irrefutable Patterns",
+            demonstrate_refutable_patterns,
+        ),
+    ],
+};
 
 // Basic match expression
 fn demonstrate_basic_match() {
-    println!("1. Basic Match Expressions");
-    println!("   Match compares a value against patterns\n");
+    output::note("Match compares a value against patterns");
+    println!();
 
     let coin = Coin::Dime;
     let value = match coin {
@@ -73,92 +67,98 @@ fn demonstrate_basic_match() {
         Coin::Quarter => 25,
     };
 
-    println!("   match coin {{");
-    println!("       Coin::Penny => 1,");
-    println!("       Coin::Nickel => 5,");
-    println!("       Coin::Dime => 10,");
-    println!("       Coin::Quarter => 25,");
-    println!("   }}");
-    println!("   → value = {}\n", value);
+    output::code(concat!(
+        "match coin {\n",
+        "    Coin::Penny => 1,\n",
+        "    Coin::Nickel => 5,\n",
+        "    Coin::Dime => 10,\n",
+        "    Coin::Quarter => 25,\n",
+        "}",
+    ));
+    output::result(format!("value = {}", value));
+    println!();
 
-    println!("   Match must be exhaustive (cover all cases)");
-    println!("   Each arm returns a value\n");
+    output::note("Match must be exhaustive (cover all cases)");
+    output::note("Each arm returns a value");
+    println!();
 }
 
 // Matching literals
 fn demonstrate_literal_patterns() {
-    println!("2. Matching Literals");
-    println!("   Match against specific values\n");
+    output::note("Match against specific values");
+    println!();
 
     let number = 3;
 
     match number {
-        1 => println!("   number is one"),
-        2 => println!("   number is two"),
-        3 => println!("   number is three"),
-        _ => println!("   number is something else"),
+        1 => output::result("number is one"),
+        2 => output::result("number is two"),
+        3 => output::result("number is three"),
+        _ => output::result("number is something else"),
     }
     println!();
 }
 
 // Destructuring tuples
 fn demonstrate_tuple_destructuring() {
-    println!("3. Destructuring Tuples");
-    println!("   Extract values from tuples in patterns\n");
+    output::note("Extract values from tuples in patterns");
+    println!();
 
     let point = (3, 5);
 
     match point {
-        (0, 0) => println!("   Point is at origin"),
-        (0, y) => println!("   Point is on y-axis at y = {}", y),
-        (x, 0) => println!("   Point is on x-axis at x = {}", x),
-        (x, y) => println!("   Point is at ({}, {})", x, y),
+        (0, 0) => output::result("Point is at origin"),
+        (0, y) => output::result(format!("Point is on y-axis at y = {}", y)),
+        (x, 0) => output::result(format!("Point is on x-axis at x = {}", x)),
+        (x, y) => output::result(format!("Point is at ({}, {})", x, y)),
     }
     println!();
 
     // Using tuple destructuring in let
     let (x, y) = point;
-    println!("   let (x, y) = point;");
-    println!("   → x = {}, y = {}\n", x, y);
+    output::code("let (x, y) = point;");
+    output::result(format!("x = {}, y = {}", x, y));
+    println!();
 }
 
 // Destructuring structs
 fn demonstrate_struct_destructuring() {
-    println!("4. Destructuring Structs");
-    println!("   Extract fields from structs\n");
+    output::note("Extract fields from structs");
+    println!();
 
     let point = Point { x: 0, y: 7 };
 
     match point {
-        Point { x: 0, y } => println!("   Point is on y-axis at y = {}", y),
-        Point { x, y: 0 } => println!("   Point is on x-axis at x = {}", x),
-        Point { x, y } => println!("   Point is at ({}, {})", x, y),
+        Point { x: 0, y } => output::result(format!("Point is on y-axis at y = {}", y)),
+        Point { x, y: 0 } => output::result(format!("Point is on x-axis at x = {}", x)),
+        Point { x, y } => output::result(format!("Point is at ({}, {})", x, y)),
     }
     println!();
 
     // Shorthand when variable names match field names
     let Point { x, y } = point;
-    println!("   let Point {{ x, y }} = point;");
-    println!("   → x = {}, y = {}\n", x, y);
+    output::code("let Point { x, y } = point;");
+    output::result(format!("x = {}, y = {}", x, y));
+    println!();
 }
 
 // Destructuring enums
 fn demonstrate_enum_destructuring() {
-    println!("5. Destructuring Enums");
-    println!("   Extract data from enum variants\n");
+    output::note("Extract data from enum variants");
+    println!();
 
     let msg = Message::Move { x: 10, y: 20 };
 
     match msg {
-        Message::Quit => println!("   Quit message"),
+        Message::Quit => output::result("Quit message"),
         Message::Move { x, y } => {
-            println!("   Move to ({}, {})", x, y);
+            output::result(format!("Move to ({}, {})", x, y));
         }
         Message::Write(text) => {
-            println!("   Write: {}", text);
+            output::result(format!("Write: {}", text));
         }
         Message::ChangeColor(r, g, b) => {
-            println!("   Change color to RGB({}, {}, {})", r, g, b);
+            output::result(format!("Change color to RGB({}, {}, {})", r, g, b));
         }
     }
     println!();
@@ -166,30 +166,33 @@ fn demonstrate_enum_destructuring() {
     // Matching nested data
     let msg2 = Message::Write(String::from("Hello"));
     match msg2 {
-        Message::Write(s) => println!("   Message::Write contains: \"{}\"\n", s),
+        Message::Write(s) => {
+            output::result(format!("Message::Write contains: \"{}\"", s));
+            println!();
+        }
         _ => (),
     }
 }
 
 // Variables in patterns
 fn demonstrate_variables_in_patterns() {
-    println!("6. Variables in Patterns");
-    println!("   Bind values to variables in patterns\n");
+    output::note("Bind values to variables in patterns");
+    println!();
 
     let x = Some(5);
     let y = 10;
 
     match x {
-        Some(50) => println!("   Got 50"),
-        Some(y) => println!("   Matched, y = {} (shadows outer y)", y),
-        _ => println!("   Default case, x = {:?}", x),
+        Some(50) => output::result("Got 50"),
+        Some(y) => output::result(format!("Matched, y = {} (shadows outer y)", y)),
+        _ => output::result(format!("Default case, x = {:?}", x)),
     }
 
-    println!("   → Outer y = {} (not affected by match)", y);
+    output::result(format!("Outer y = {} (not affected by match)", y));
     println!();
 
     // Using @ to bind and match
-    println!("   @ Bindings - bind value while testing pattern:");
+    output::note("@ Bindings - bind value while testing pattern:");
 
     #[derive(Debug)]
     enum Message2 {
@@ -200,26 +203,26 @@ fn demonstrate_variables_in_patterns() {
 
     match msg {
         Message2::Hello { id: id @ 3..=7 } => {
-            println!("   Found an id in range: {}", id);
+            output::result(format!("Found an id in range: {}", id));
         }
         Message2::Hello { id: 10..=12 } => {
-            println!("   Found an id in another range");
+            output::result("Found an id in another range");
         }
-        Message2::Hello { id } => println!("   Found some other id: {}", id),
+        Message2::Hello { id } => output::result(format!("Found some other id: {}", id)),
     }
     println!();
 }
 
 // Wildcards and placeholders
 fn demonstrate_wildcards() {
-    println!("7. Wildcards and Placeholders");
-    println!("   Use _ to ignore values\n");
+    output::note("Use _ to ignore values");
+    println!();
 
     let number = 42;
 
     match number {
-        1 => println!("   number is one"),
-        _ => println!("   number is something else (wildcard matches anything)"),
+        1 => output::result("number is one"),
+        _ => output::result("number is something else (wildcard matches anything)"),
     }
     println!();
 
@@ -227,14 +230,17 @@ fn demonstrate_wildcards() {
     let tuple = (1, 2, 3, 4, 5);
     match tuple {
         (first, _, third, _, fifth) => {
-            println!("   First: {}, Third: {}, Fifth: {}", first, third, fifth);
-            println!("   → Second and fourth ignored with _");
+            output::result(format!(
+                "First: {}, Third: {}, Fifth: {}",
+                first, third, fifth
+            ));
+            output::result("Second and fourth ignored with _");
         }
     }
     println!();
 
     // Using .. to ignore remaining parts
-    println!("   Using .. to ignore remaining parts:");
+    output::note("Using .. to ignore remaining parts:");
 
     #[allow(dead_code)]
     struct Point3D {
@@ -246,55 +252,56 @@ fn demonstrate_wildcards() {
     let origin = Point3D { x: 0, y: 0, z: 0 };
 
     match origin {
-        Point3D { x, .. } => println!("   x is {} (y and z ignored with ..)", x),
+        Point3D { x, .. } => output::result(format!("x is {} (y and z ignored with ..)", x)),
     }
 
     let numbers = (2, 4, 8, 16, 32);
     match numbers {
         (first, .., last) => {
-            println!(
-                "   First: {}, Last: {} (middle ignored with ..)",
+            output::result(format!(
+                "First: {}, Last: {} (middle ignored with ..)",
                 first, last
-            );
+            ));
         }
     }
     println!();
 
     // Ignoring unused variables with _
     let _unused = 5; // Prefix with _ to avoid unused variable warning
-    println!("   Prefix unused variables with _ to avoid warnings");
-    println!("   Note: _x binds value, _ doesn't bind at all\n");
+    output::note("Prefix unused variables with _ to avoid warnings");
+    output::note("Note: _x binds value, _ doesn't bind at all");
+    println!();
 }
 
 // Multiple patterns with |
 fn demonstrate_multiple_patterns() {
-    println!("8. Multiple Patterns");
-    println!("   Use | to match multiple patterns\n");
+    output::note("Use | to match multiple patterns");
+    println!();
 
     let number = 3;
 
     match number {
-        1 | 2 | 3 => println!("   number is 1, 2, or 3"),
-        4 | 5 => println!("   number is 4 or 5"),
-        _ => println!("   number is something else"),
+        1 | 2 | 3 => output::result("number is 1, 2, or 3"),
+        4 | 5 => output::result("number is 4 or 5"),
+        _ => output::result("number is something else"),
     }
     println!();
 
     // Matching ranges with ..=
-    println!("   Matching ranges with ..=:");
+    output::note("Matching ranges with ..=:");
     let x = 5;
 
     match x {
-        1..=5 => println!("   {} is in range 1 through 5", x),
-        _ => println!("   {} is something else", x),
+        1..=5 => output::result(format!("{} is in range 1 through 5", x)),
+        _ => output::result(format!("{} is something else", x)),
     }
 
     // Ranges work with char too
     let letter = 'c';
     match letter {
-        'a'..='j' => println!("   '{}' is an early ASCII letter", letter),
-        'k'..='z' => println!("   '{}' is a late ASCII letter", letter),
-        _ => println!("   '{}' is something else", letter),
+        'a'..='j' => output::result(format!("'{}' is an early ASCII letter", letter)),
+        'k'..='z' => output::result(format!("'{}' is a late ASCII letter", letter)),
+        _ => output::result(format!("'{}' is something else", letter)),
     }
     println!();
 
@@ -302,10 +309,10 @@ fn demonstrate_multiple_patterns() {
     let point = (0, 5);
     match point {
         (0, y) | (y, 0) => {
-            println!("   Point is on an axis, coordinate = {}", y);
+            output::result(format!("Point is on an axis, coordinate = {}", y));
         }
         (x, y) => {
-            println!("   Point is at ({}, {})", x, y);
+            output::result(format!("Point is at ({}, {})", x, y));
         }
     }
     println!();
@@ -313,15 +320,15 @@ fn demonstrate_multiple_patterns() {
 
 // Pattern guards
 fn demonstrate_pattern_guards() {
-    println!("9. Pattern Guards");
-    println!("   Add conditions with if after pattern\n");
+    output::note("Add conditions with if after pattern");
+    println!();
 
     let num = Some(4);
 
     match num {
-        Some(x) if x < 5 => println!("   {} is less than 5", x),
-        Some(x) => println!("   {} is 5 or greater", x),
-        None => println!("   No value"),
+        Some(x) if x < 5 => output::result(format!("{} is less than 5", x)),
+        Some(x) => output::result(format!("{} is 5 or greater", x)),
+        None => output::result("No value"),
     }
     println!();
 
@@ -330,16 +337,16 @@ fn demonstrate_pattern_guards() {
     let y = false;
 
     match x {
-        4 | 5 | 6 if y => println!("   x is 4, 5, or 6 AND y is true"),
-        _ => println!("   Other case"),
+        4 | 5 | 6 if y => output::result("x is 4, 5, or 6 AND y is true"),
+        _ => output::result("Other case"),
     }
     println!();
 }
 
 // if let and while let
 fn demonstrate_if_let() {
-    println!("10. if let and while let");
-    println!("   Concise syntax for matching one pattern\n");
+    output::note("Concise syntax for matching one pattern");
+    println!();
 
     // if let: match one pattern, ignore others
     let favorite_color: Option<&str> = None;
@@ -347,17 +354,20 @@ fn demonstrate_if_let() {
     let age: Result<u8, _> = "34".parse();
 
     if let Some(color) = favorite_color {
-        println!("   Using your favorite color, {}, as the background", color);
+        output::result(format!(
+            "Using your favorite color, {}, as the background",
+            color
+        ));
     } else if is_tuesday {
-        println!("   Tuesday is green day!");
+        output::result("Tuesday is green day!");
     } else if let Ok(age) = age {
         if age > 30 {
-            println!("   Using purple as the background color");
+            output::result("Using purple as the background color");
         } else {
-            println!("   Using orange as the background color");
+            output::result("Using orange as the background color");
         }
     } else {
-        println!("   Using blue as the background color");
+        output::result("Using blue as the background color");
     }
     println!();
 
@@ -367,54 +377,60 @@ fn demonstrate_if_let() {
     stack.push(2);
     stack.push(3);
 
-    println!("   Popping from stack:");
+    output::note("Popping from stack:");
     while let Some(top) = stack.pop() {
-        println!("   {}", top);
+        output::result(top.to_string());
     }
-    println!("   → Stack is now empty\n");
+    output::result("Stack is now empty");
+    println!();
 
     // for loops also use patterns
     let v = vec!['a', 'b', 'c'];
-    println!("   Iterating with pattern matching:");
+    output::note("Iterating with pattern matching:");
     for (index, value) in v.iter().enumerate() {
-        println!("   v[{}] = {}", index, value);
+        output::result(format!("v[{}] = {}", index, value));
     }
     println!();
 }
 
 // Refutable vs Irrefutable patterns
 fn demonstrate_refutable_patterns() {
-    println!("11. Refutable vs Irrefutable Patterns");
-    println!("   Patterns that can fail vs patterns that always match\n");
+    output::note("Patterns that can fail vs patterns that always match");
+    println!();
 
     // Irrefutable: always matches (used in let, function parameters)
     let x = 5; // This always works - irrefutable
-    println!("   let x = 5;  // Irrefutable - always matches");
-    println!("   → x = {}\n", x);
+    output::code("let x = 5;  // Irrefutable - always matches");
+    output::result(format!("x = {}", x));
+    println!();
 
     // Refutable: might not match (used in if let, while let, match)
     let some_option: Option<i32> = Some(5);
 
     // This is refutable - might not match
     if let Some(x) = some_option {
-        println!("   if let Some(x) = some_option {{");
-        println!("       // This pattern is refutable");
-        println!("       // x = {}", x);
-        println!("   }}\n");
+        output::code(format!(
+            "if let Some(x) = some_option {{\n    // This pattern is refutable\n    // x = {}\n}}",
+            x
+        ));
+        println!();
     }
 
     // Function parameters are irrefutable
     fn print_coordinates(&(x, y): &(i32, i32)) {
-        println!("   Function parameter pattern: &(x, y)");
-        println!("   → Current location: ({}, {})", x, y);
+        output::note("Function parameter pattern: &(x, y)");
+        output::result(format!("Current location: ({}, {})", x, y));
     }
 
     let point = (3, 5);
     print_coordinates(&point);
     println!();
 
-    println!("   Key points:");
-    println!("   - let statements require irrefutable patterns");
-    println!("   - if let and while let accept refutable patterns");
-    println!("   - match arms must be refutable (except _)\n");
+    output::note("Key points:");
+    output::note(
+        "- let statements require irrefutable patterns\n\
+         - if let and while let accept refutable patterns\n\
+         - match arms must be refutable (except _)",
+    );
+    println!();
 }
@@ -1,16 +1,26 @@
-fn main() {
-    println!("=== Example 1: Shadowing (Current Code) ===");
-    example_shadowing();
-
-    println!("\n=== Example 2: Mutability (mut keyword) ===");
-    example_mutability();
-
-    println!("\n=== Example 3: Why Shadowing is Safer ===");
-    example_shadowing_safety();
-
-    println!("\n=== Example 4: Transformations with Shadowing ===");
-    example_shadowing_transformations();
-}
+use crate::output;
+use crate::registry::{Difficulty, Lesson, Meta, Section};
+
+pub const LESSON: Lesson = Lesson {
+    id: "shadowing",
+    title: "Shadowing",
+    meta: Meta {
+        difficulty: Difficulty::Beginner,
+        minutes: 10,
+        book: Some("3.1"),
+        prerequisites: &["variables_mutability"],
+        tags: &["variables", "shadowing"],
+    },
+    sections: &[
+        Section::new("Shadowing (Current Code)", example_shadowing),
+        Section::new("Mutability (mut keyword)", example_mutability),
+        Section::new("Why Shadowing is Safer", example_shadowing_safety),
+        Section::new(
+            "Transformations with Shadowing",
+            example_shadowing_transformations,
+        ),
+    ],
+};
 
 // Example 1: Shadowing - creating a NEW variable with the same name
 fn example_shadowing() {
@@ -20,7 +30,7 @@ fn example_shadowing() {
     let x = x + 1; // OK: Using 'let' creates a new variable
 
     // After shadowing, the new 'x' is also immutable
-    println!("After shadowing: x = {}", x);
+    output::result(format!("After shadowing: x = {}", x));
 
     // Try to reassign without 'let' - THIS WILL CAUSE A COMPILE ERROR!
     // Uncomment the next line to see the error:
@@ -34,11 +44,11 @@ fn example_mutability() {
     // Can reassign directly without 'let'
     x = x + 1; // OK: x is mutable, so we can reassign
 
-    println!("After reassignment: x = {}", x);
+    output::result(format!("After reassignment: x = {}", x));
 
     // Can reassign again
     x = x * 2; // OK: still mutable
-    println!("After another reassignment: x = {}", x);
+    output::result(format!("After another reassignment: x = {}", x));
 }
 
 // Example 3: Why shadowing is safer - compile-time protection
@@ -50,24 +60,25 @@ fn example_shadowing_safety() {
 
     // If you forget 'let', you get a compile error:
     // x = x + 1;  // ERROR: cannot assign to immutable variable
+    // (E0384, see compile_fail/syntax_and_semantics/assign_without_let.rs)
 
     // This prevents accidental reassignment!
     // With 'mut', you could accidentally do: x = x + 1; and it would work
     // With shadowing, you MUST use 'let', making it clear you're creating a new variable
 
-    println!("x = {} (immutable after shadowing)", x);
+    output::result(format!("x = {} (immutable after shadowing)", x));
 }
 
 // Example 4: Transformations with shadowing - common use case
 fn example_shadowing_transformations() {
     let spaces = "   "; // String type
-    println!("spaces type: string, value: '{}'", spaces);
+    output::result(format!("spaces type: string, value: '{}'", spaces));
 
     // Transform: convert string to number
     // After this, 'spaces' is immutable and is a number, not a string
     let spaces = spaces.len(); // Creates NEW immutable variable (usize type)
 
-    println!("spaces type: usize, value: {}", spaces);
+    output::result(format!("spaces type: usize, value: {}", spaces));
 
     // The old 'spaces' (string) is gone - we can't accidentally use it
     // The new 'spaces' (number) is immutable - safe from accidental changes
@@ -77,5 +88,5 @@ fn example_shadowing_transformations() {
 
     // If we need to change it, we MUST use 'let' (making it explicit)
     let spaces = spaces + 1; // OK: creating new variable
-    println!("After transformation: spaces = {}", spaces);
+    output::result(format!("After transformation: spaces = {}", spaces));
 }
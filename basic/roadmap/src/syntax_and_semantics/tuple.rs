@@ -1,133 +1,161 @@
-fn main() {
-    println!("=== Tuple Type in Rust ===\n");
-
-    // 1. Creating tuples
-    demonstrate_creating_tuples();
-
-    // 2. Destructuring tuples
-    demonstrate_destructuring();
-
-    // 3. Accessing tuple elements by index
-    demonstrate_index_access();
-
-    // 4. Tuples with different types
-    demonstrate_mixed_types();
-
-    // 5. The unit type
-    demonstrate_unit_type();
-}
+use crate::output;
+use crate::registry::{Difficulty, Lesson, Meta, Section};
+
+pub const LESSON: Lesson = Lesson {
+    id: "tuple",
+    title: "Tuple Type in Rust",
+    meta: Meta {
+        difficulty: Difficulty::Beginner,
+        minutes: 10,
+        book: Some("3.2"),
+        prerequisites: &["data_types"],
+        tags: &["types", "tuples"],
+    },
+    sections: &[
+        Section::new("Creating Tuples", demonstrate_creating_tuples),
+        Section::new("Destructuring Tuples", demonstrate_destructuring),
+        Section::new(
+            "Accessing Tuple Elements by Index",
+            demonstrate_index_access,
+        ),
+        Section::new("Tuples with Mixed Types", demonstrate_mixed_types),
+        Section::new("The Unit Type", demonstrate_unit_type),
+    ],
+};
 
 // Tuples group multiple values of different types into one compound type
 fn demonstrate_creating_tuples() {
-    println!("1. Creating Tuples");
-    println!("   Tuples have a fixed length and can contain different types\n");
+    output::note("Tuples have a fixed length and can contain different types");
+    println!();
 
     // Tuple with type annotation
     let tup: (i32, f64, u8) = (500, 6.4, 1);
-    println!("   With type annotation:");
-    println!("   let tup: (i32, f64, u8) = (500, 6.4, 1);");
-    println!(
-        "   → tup contains: integer {}, float {}, unsigned {}\n",
+    output::note("With type annotation:");
+    output::code("let tup: (i32, f64, u8) = (500, 6.4, 1);");
+    output::result(format!(
+        "tup contains: integer {}, float {}, unsigned {}",
         tup.0, tup.1, tup.2
-    );
+    ));
+    println!();
 
     // Tuple without type annotation (type inference)
     let tup2 = (42, 3.14, 'A', true);
-    println!("   Without type annotation (inferred):");
-    println!("   let tup2 = (42, 3.14, 'A', true);");
-    println!("   → Types inferred: (i32, f64, char, bool)");
-    println!("   → Access: tup2.0 = {}, tup2.2 = '{}'\n", tup2.0, tup2.2);
+    output::note("Without type annotation (inferred):");
+    output::code("let tup2 = (42, 3.14, 'A', true);");
+    output::note("Types inferred: (i32, f64, char, bool)");
+    output::result(format!(
+        "Access: tup2.0 = {}, tup2.2 = '{}'",
+        tup2.0, tup2.2
+    ));
+    println!();
 
     // Empty tuple (unit type)
     let _unit = ();
-    println!("   Empty tuple (unit type):");
-    println!("   let unit = ();");
-    println!("   → This is the unit type\n");
+    output::note("Empty tuple (unit type):");
+    output::code("let unit = ();");
+    output::note("This is the unit type");
+    println!();
 }
 
 // Destructuring: breaking a tuple into separate variables
 fn demonstrate_destructuring() {
-    println!("2. Destructuring Tuples");
-    println!("   Use pattern matching to extract values from tuples\n");
+    output::note("Use pattern matching to extract values from tuples");
+    println!();
 
     // Create a tuple
     let tup = (500, 6.4, 1);
-    println!("   let tup = (500, 6.4, 1);");
+    output::code("let tup = (500, 6.4, 1);");
 
     // Destructure into separate variables
     let (x, y, z) = tup;
-    println!("   let (x, y, z) = tup;");
-    println!("   → x = {}, y = {}, z = {}\n", x, y, z);
+    output::code("let (x, y, z) = tup;");
+    output::result(format!("x = {}, y = {}, z = {}", x, y, z));
+    println!();
 
     // Destructuring with different variable names
     let coordinates = (10, 20, 30);
     let (x_coord, y_coord, z_coord) = coordinates;
-    println!("   Coordinates example:");
-    println!("   let coordinates = (10, 20, 30);");
-    println!("   let (x_coord, y_coord, z_coord) = coordinates;");
-    println!("   → x: {}, y: {}, z: {}\n", x_coord, y_coord, z_coord);
+    output::note("Coordinates example:");
+    output::code(concat!(
+        "let coordinates = (10, 20, 30);\n",
+        "let (x_coord, y_coord, z_coord) = coordinates;",
+    ));
+    output::result(format!("x: {}, y: {}, z: {}", x_coord, y_coord, z_coord));
+    println!();
 
     // Partial destructuring (using _ to ignore values)
     let person = ("Alice", 30, true);
     let (name, age, _) = person;
-    println!("   Partial destructuring (ignoring some values):");
-    println!("   let person = (\"Alice\", 30, true);");
-    println!("   let (name, age, _) = person;");
-    println!("   → name: {}, age: {} (ignored the boolean)\n", name, age);
+    output::note("Partial destructuring (ignoring some values):");
+    output::code(concat!(
+        "let person = (\"Alice\", 30, true);\n",
+        "let (name, age, _) = person;",
+    ));
+    output::result(format!(
+        "name: {}, age: {} (ignored the boolean)",
+        name, age
+    ));
+    println!();
 }
 
 // Accessing tuple elements directly by index
 fn demonstrate_index_access() {
-    println!("3. Accessing Tuple Elements by Index");
-    println!("   Use a period (.) followed by the index (starting at 0)\n");
+    output::note("Use a period (.) followed by the index (starting at 0)");
+    println!();
 
     let x: (i32, f64, u8) = (500, 6.4, 1);
-    println!("   let x: (i32, f64, u8) = (500, 6.4, 1);\n");
+    output::code("let x: (i32, f64, u8) = (500, 6.4, 1);");
+    println!();
 
     // Access each element by index
     let five_hundred = x.0;
     let six_point_four = x.1;
     let one = x.2;
 
-    println!("   Accessing by index:");
-    println!("   x.0 = {} (first element)", five_hundred);
-    println!("   x.1 = {} (second element)", six_point_four);
-    println!("   x.2 = {} (third element)\n", one);
+    output::note("Accessing by index:");
+    output::result(format!("x.0 = {} (first element)", five_hundred));
+    output::result(format!("x.1 = {} (second element)", six_point_four));
+    output::result(format!("x.2 = {} (third element)", one));
+    println!();
 
     // You can use tuple elements directly in expressions
     let sum = x.0 + x.2 as i32;
-    println!("   Using tuple elements in expressions:");
-    println!("   x.0 + x.2 = {} + {} = {}\n", x.0, x.2, sum);
+    output::note("Using tuple elements in expressions:");
+    output::result(format!("x.0 + x.2 = {} + {} = {}", x.0, x.2, sum));
+    println!();
 }
 
 // Tuples can contain different types
 fn demonstrate_mixed_types() {
-    println!("4. Tuples with Mixed Types");
-    println!("   Each position can have a different type\n");
+    output::note("Each position can have a different type");
+    println!();
 
     // Tuple with various types
     let mixed: (i32, f64, bool, char, &str) = (42, 3.14, true, 'R', "Rust");
-    println!("   let mixed: (i32, f64, bool, char, &str) = (42, 3.14, true, 'R', \"Rust\");");
-    println!("   → Types: integer, float, boolean, character, string slice");
-    println!(
-        "   → Access: mixed.0 = {}, mixed.4 = \"{}\"\n",
+    output::code("let mixed: (i32, f64, bool, char, &str) = (42, 3.14, true, 'R', \"Rust\");");
+    output::note("Types: integer, float, boolean, character, string slice");
+    output::result(format!(
+        "Access: mixed.0 = {}, mixed.4 = \"{}\"",
         mixed.0, mixed.4
-    );
+    ));
+    println!();
 
     // Nested tuples
     let nested: ((i32, i32), (f64, f64)) = ((1, 2), (3.0, 4.0));
-    println!("   Nested tuples:");
-    println!("   let nested: ((i32, i32), (f64, f64)) = ((1, 2), (3.0, 4.0));");
-    println!(
-        "   → Access: nested.0.0 = {}, nested.1.1 = {}\n",
+    output::note("Nested tuples:");
+    output::code("let nested: ((i32, i32), (f64, f64)) = ((1, 2), (3.0, 4.0));");
+    output::result(format!(
+        "Access: nested.0.0 = {}, nested.1.1 = {}",
         nested.0.0, nested.1.1
-    );
+    ));
+    println!();
 
     // Practical example: returning multiple values from a function
     let (result, remainder) = divide_with_remainder(17, 5);
-    println!("   Practical example: Function returning tuple");
-    println!("   divide_with_remainder(17, 5)");
-    println!("   → result: {}, remainder: {}\n", result, remainder);
+    output::note("Practical example: Function returning tuple");
+    output::note("divide_with_remainder(17, 5)");
+    output::result(format!("result: {}, remainder: {}", result, remainder));
+    println!();
 }
 
 // Helper function that returns a tuple
@@ -139,28 +167,30 @@ fn divide_with_remainder(dividend: i32, divisor: i32) -> (i32, i32) {
 
 // The unit type: empty tuple
 fn demonstrate_unit_type() {
-    println!("5. The Unit Type");
-    println!("   The empty tuple () is called 'unit'\n");
+    output::note("The empty tuple () is called 'unit'");
+    println!();
 
     // Unit type represents an empty value
     let _unit_value = ();
-    println!("   let unit_value = ();");
-    println!("   → This is the unit type and unit value\n");
+    output::code("let unit_value = ();");
+    output::note("This is the unit type and unit value");
+    println!();
 
     // Functions that don't return a value implicitly return unit
     let result = print_message();
-    println!("   Functions without return value:");
-    println!("   fn print_message() {{ println!(\"Hello\"); }}");
-    println!("   → Returns: {:?}\n", result);
+    output::note("Functions without return value:");
+    output::code("fn print_message() { println!(\"Hello\"); }");
+    output::result(format!("Returns: {:?}", result));
+    println!();
 
     // Explicit unit return
     fn explicit_unit() -> () {
-        println!("      This function explicitly returns unit");
+        output::result("This function explicitly returns unit");
     }
     let _ = explicit_unit();
 }
 
 // Function that implicitly returns unit
 fn print_message() {
-    println!("      Hello from a function!");
+    output::result("Hello from a function!");
 }
@@ -1,30 +1,44 @@
+use crate::output;
+use crate::registry::{Difficulty, Lesson, Meta, Section};
+
 const THREE_HOURS_IN_SECONDS: u32 = 60 * 60 * 3;
 
-fn main() {
-    example_mutability();
-    example_constants();
-    example_shadowing();
-}
+pub const LESSON: Lesson = Lesson {
+    id: "variables_mutability",
+    title: "Variables and Mutability",
+    meta: Meta {
+        difficulty: Difficulty::Beginner,
+        minutes: 10,
+        book: Some("3.1"),
+        prerequisites: &[],
+        tags: &["variables", "mutability"],
+    },
+    sections: &[
+        Section::new("Mutability", example_mutability),
+        Section::new("Constants", example_constants),
+        Section::new("Shadowing", example_shadowing),
+    ],
+};
 
 fn example_mutability() {
     let mut x = 5;
-    println!("mutability: {x}");
+    output::result(format!("mutability: {x}"));
     x = 6;
-    println!("mutability: {x}");
+    output::result(format!("mutability: {x}"));
 }
 
 fn example_constants() {
-    println!("constant: {THREE_HOURS_IN_SECONDS}");
+    output::result(format!("constant: {THREE_HOURS_IN_SECONDS}"));
     let seconds = 60 * 60 * 3;
-    println!("constant (expr): {seconds}");
+    output::result(format!("constant (expr): {seconds}"));
 }
 
 fn example_shadowing() {
     let x = 5;
     let x = x + 1;
-    println!("shadowing: {x}");
+    output::result(format!("shadowing: {x}"));
 
     let spaces = "   ";
     let spaces = spaces.len();
-    println!("shadowing transform: {spaces}");
+    output::result(format!("shadowing transform: {spaces}"));
 }
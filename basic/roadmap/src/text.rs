@@ -0,0 +1,316 @@
+// Text
+// Word utilities that never copy: every word is a `&str` slice borrowed from
+// the text it was found in, so results live exactly as long as that text.
+//
+//   let text = String::from("Don't panic,\tit's only\nRust.");
+//   words(&text).collect::<Vec<_>>()   // ["Don't", "panic", "it's", "only", "Rust"]
+//
+// Segmentation follows the main rules of Unicode word boundaries (UAX #29):
+// a word is a run of letters, digits and combining marks; apostrophes and
+// `.` join letters ("don't", "e.g"), `.` and `,` join digits ("3.14",
+// "1,000"); whitespace and other punctuation separate words. Chinese and
+// Japanese are written without spaces, so each Han or Hiragana char is a word
+// of its own while Katakana runs stay together. Scripts like Thai that need a
+// dictionary to segment come out as one word per run.
+
+use std::collections::HashMap;
+
+use crate::unicode::is_combining_mark;
+
+// Chars that are a word on their own
+fn is_ideograph(c: char) -> bool {
+    matches!(c as u32,
+        0x3040..=0x309F       // Hiragana
+        | 0x3400..=0x4DBF     // CJK extension A
+        | 0x4E00..=0x9FFF     // CJK unified ideographs
+        | 0xF900..=0xFAFF
+        | 0x20000..=0x3FFFF
+    ) && !is_combining_mark(c)
+}
+
+fn is_word_char(c: char) -> bool {
+    c.is_alphanumeric() || is_combining_mark(c)
+}
+
+// May `c` join the chars on either side of it into one word?
+fn joins(before: char, c: char, after: char) -> bool {
+    let letters = before.is_alphabetic() && after.is_alphabetic();
+    let digits = before.is_numeric() && after.is_numeric();
+    match c {
+        '\'' | '\u{2019}' => letters,
+        '.' => letters || digits,
+        ',' => digits,
+        _ => false,
+    }
+}
+
+// Every word with the byte offset it starts at
+pub struct WordIndices<'a> {
+    text: &'a str,
+    position: usize,
+}
+
+impl<'a> Iterator for WordIndices<'a> {
+    type Item = (usize, &'a str);
+
+    fn next(&mut self) -> Option<(usize, &'a str)> {
+        let rest = &self.text[self.position..];
+        let (offset, first) = rest
+            .char_indices()
+            .find(|&(_, c)| is_word_char(c) && !is_combining_mark(c))?;
+        let start = self.position + offset;
+        let mut end = start + first.len_utf8();
+
+        let mut chars = self.text[end..].chars().peekable();
+        let mut previous = first;
+        while let Some(c) = chars.next() {
+            let extends = if is_combining_mark(c) {
+                true
+            } else if is_ideograph(first) || is_ideograph(c) {
+                false
+            } else if is_word_char(c) {
+                true
+            } else {
+                chars.peek().is_some_and(|&after| joins(previous, c, after))
+            };
+            if !extends {
+                break;
+            }
+            end += c.len_utf8();
+            previous = c;
+        }
+
+        self.position = end;
+        Some((start, &self.text[start..end]))
+    }
+}
+
+pub fn word_indices(text: &str) -> WordIndices<'_> {
+    WordIndices { text, position: 0 }
+}
+
+pub struct Words<'a> {
+    inner: WordIndices<'a>,
+}
+
+impl<'a> Iterator for Words<'a> {
+    type Item = &'a str;
+
+    fn next(&mut self) -> Option<&'a str> {
+        self.inner.next().map(|(_, word)| word)
+    }
+}
+
+pub fn words(text: &str) -> Words<'_> {
+    Words {
+        inner: word_indices(text),
+    }
+}
+
+pub fn first_word(text: &str) -> Option<&str> {
+    words(text).next()
+}
+
+pub fn count_words(text: &str) -> usize {
+    words(text).count()
+}
+
+// By chars, not bytes, so "naïve" is 5 long; the first one wins a tie
+pub fn longest_word(text: &str) -> Option<&str> {
+    words(text).fold(None, |best: Option<&str>, word| match best {
+        Some(best) if best.chars().count() >= word.chars().count() => Some(best),
+        _ => Some(word),
+    })
+}
+
+pub fn shortest_word(text: &str) -> Option<&str> {
+    words(text).fold(None, |best: Option<&str>, word| match best {
+        Some(best) if best.chars().count() <= word.chars().count() => Some(best),
+        _ => Some(word),
+    })
+}
+
+// How often each word occurs, ignoring case, most frequent first (ties in
+// order of first appearance). Each word is shown as it was first written
+pub fn frequencies(text: &str) -> Vec<(&str, usize)> {
+    let mut counts: HashMap<String, (usize, &str, usize)> = HashMap::new();
+    for (order, word) in words(text).enumerate() {
+        counts
+            .entry(word.to_lowercase())
+            .or_insert((order, word, 0))
+            .2 += 1;
+    }
+    let mut counted: Vec<(usize, &str, usize)> = counts.into_values().collect();
+    counted.sort_by_key(|&(order, _, count)| (std::cmp::Reverse(count), order));
+    counted
+        .into_iter()
+        .map(|(_, word, count)| (word, count))
+        .collect()
+}
+
+// Where a slice borrowed from `text` starts in it, found from the pointers
+// alone; None if `part` points somewhere else
+pub fn offset_in(text: &str, part: &str) -> Option<usize> {
+    let start = text.as_ptr() as usize;
+    let offset = (part.as_ptr() as usize).checked_sub(start)?;
+    (offset + part.len() <= text.len()).then_some(offset)
+}
+
+// 1-based, with the column counted in chars as an editor shows it
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Position {
+    pub line: usize,
+    pub column: usize,
+}
+
+// Maps byte offsets to lines and columns; `\n` and `\r\n` both end a line
+pub struct LineIndex<'a> {
+    text: &'a str,
+    starts: Vec<usize>,
+}
+
+impl<'a> LineIndex<'a> {
+    pub fn new(text: &'a str) -> LineIndex<'a> {
+        let mut starts = vec![0];
+        starts.extend(text.match_indices('\n').map(|(index, _)| index + 1));
+        LineIndex { text, starts }
+    }
+
+    pub fn line_count(&self) -> usize {
+        self.starts.len()
+    }
+
+    // None past the end of the text or inside a multi-byte char
+    pub fn position(&self, offset: usize) -> Option<Position> {
+        if !self.text.is_char_boundary(offset) {
+            return None;
+        }
+        let line = self.starts.partition_point(|&start| start <= offset);
+        let column = self.text[self.starts[line - 1]..offset].chars().count() + 1;
+        Some(Position { line, column })
+    }
+
+    // Where a word (or any slice of the text) starts
+    pub fn position_of(&self, part: &str) -> Option<Position> {
+        self.position(offset_in(self.text, part)?)
+    }
+
+    // Line `number` (1-based) without its line ending
+    pub fn line(&self, number: usize) -> Option<&'a str> {
+        let start = *self.starts.get(number.checked_sub(1)?)?;
+        let end = self
+            .starts
+            .get(number)
+            .map_or(self.text.len(), |&next| next - 1);
+        let line = &self.text[start..end];
+        Some(line.strip_suffix('\r').unwrap_or(line))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn split(text: &str) -> Vec<&str> {
+        words(text).collect()
+    }
+
+    #[test]
+    fn splits_on_punctuation_tabs_and_newlines() {
+        assert_eq!(
+            split("Don't panic,\tit's only\nRust."),
+            vec!["Don't", "panic", "it's", "only", "Rust"]
+        );
+        assert_eq!(
+            split("rock’n’roll — e.g. 3.14, 1,000 items!"),
+            vec!["rock’n’roll", "e.g", "3.14", "1,000", "items"]
+        );
+        assert_eq!(split("'quoted' end..."), vec!["quoted", "end"]);
+        assert_eq!(split("x=1;y=2"), vec!["x", "1", "y", "2"]);
+        assert_eq!(split(" \t\n"), Vec::<&str>::new());
+    }
+
+    #[test]
+    fn segments_other_scripts() {
+        assert_eq!(
+            split("東京へ行きます"),
+            vec!["東", "京", "へ", "行", "き", "ま", "す"]
+        );
+        assert_eq!(split("コーヒーを飲む"), vec!["コーヒー", "を", "飲", "む"]);
+        assert_eq!(split("naïve café"), vec!["naïve", "café"]);
+        // "e" + combining acute stays one word
+        assert_eq!(split("cafe\u{301} noir"), vec!["cafe\u{301}", "noir"]);
+        assert_eq!(split("Привет, мир"), vec!["Привет", "мир"]);
+        assert_eq!(split("नमस्ते दुनिया"), vec!["नमस्ते", "दुनिया"]);
+        // The avagraha is a letter, so it can start a word; Bengali vowel
+        // signs are marks
+        assert_eq!(split("\u{93D} \u{9BE}"), vec!["\u{93D}"]);
+        assert_eq!(split("বাংলা ভাষা"), vec!["বাংলা", "ভাষা"]);
+    }
+
+    #[test]
+    fn words_are_borrowed_from_the_text() {
+        let text = String::from("hello\tworld");
+        let word = first_word(&text).unwrap();
+        assert_eq!(word, "hello");
+        assert_eq!(offset_in(&text, word), Some(0));
+        let indices: Vec<(usize, &str)> = word_indices(&text).collect();
+        assert_eq!(indices, vec![(0, "hello"), (6, "world")]);
+        assert_eq!(offset_in(&text, "world"), None);
+        assert_eq!(count_words("Rust is a systems programming language"), 6);
+        assert_eq!(first_word("..."), None);
+    }
+
+    #[test]
+    fn longest_and_shortest_count_chars() {
+        let text = "Rust is a systems programming language";
+        assert_eq!(longest_word(text), Some("programming"));
+        assert_eq!(shortest_word(text), Some("a"));
+        // 6 chars, but 12 bytes in UTF-8
+        assert_eq!(longest_word("привет hello"), Some("привет"));
+        assert_eq!(longest_word("Здравствуйте, hi"), Some("Здравствуйте"));
+        assert_eq!(longest_word(""), None);
+    }
+
+    #[test]
+    fn counts_frequencies_ignoring_case() {
+        assert_eq!(
+            frequencies("The cat and the hat. THE end, and"),
+            vec![("The", 3), ("and", 2), ("cat", 1), ("hat", 1), ("end", 1)]
+        );
+    }
+
+    #[test]
+    fn maps_offsets_to_lines_and_columns() {
+        let text = "first line\r\nsecond\nthird ünïcode line";
+        let index = LineIndex::new(text);
+        assert_eq!(index.line_count(), 3);
+        assert_eq!(index.line(1), Some("first line"));
+        assert_eq!(index.line(2), Some("second"));
+        assert_eq!(index.line(4), None);
+        assert_eq!(index.position(0), Some(Position { line: 1, column: 1 }));
+        assert_eq!(index.position(12), Some(Position { line: 2, column: 1 }));
+        let line = words(text).find(|&word| word == "line").unwrap();
+        assert_eq!(
+            index.position_of(line),
+            Some(Position { line: 1, column: 7 })
+        );
+        let last = words(text).last().unwrap();
+        // Column 15: "ünïcode" is 7 chars but 9 bytes
+        assert_eq!(
+            index.position_of(last),
+            Some(Position {
+                line: 3,
+                column: 15
+            })
+        );
+        assert_eq!(
+            index.position(text.len()),
+            Some(Position {
+                line: 3,
+                column: 19
+            })
+        );
+        assert_eq!(index.position(26), None);
+    }
+}
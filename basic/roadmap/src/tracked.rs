@@ -0,0 +1,326 @@
+// Ownership Timeline
+// `Tracked<T>` wraps a value and logs what happens to it: created, cloned,
+// moved to a new owner, dropped. Rust moves are plain memory copies that no
+// code can observe, so a move is logged where the lesson spells it out:
+//
+//   let timeline = Timeline::start();
+//   let s = Tracked::new("s", String::from("hello"));   // [1] create #1
+//   takes_ownership(s.moved_to("some_string"));          // [2] move #1
+//                                                        // [3] drop #1
+//
+// Each event is printed as it happens. When the timeline itself goes out of
+// scope (after every value created in the section has been dropped) it prints
+// who owned each value and at which step it was freed.
+
+use std::cell::RefCell;
+use std::fmt;
+use std::ops::{Deref, DerefMut};
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum Event {
+    Created {
+        id: usize,
+        owner: String,
+        value: String,
+    },
+    Cloned {
+        id: usize,
+        from: usize,
+        owner: String,
+    },
+    Moved {
+        id: usize,
+        from: String,
+        to: String,
+    },
+    Dropped {
+        id: usize,
+        owner: String,
+    },
+}
+
+impl fmt::Display for Event {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Event::Created { id, owner, value } => {
+                write!(f, "create #{} {}, owned by {}", id, value, owner)
+            }
+            Event::Cloned { id, from, owner } => {
+                write!(f, "clone  #{} into #{}, owned by {}", from, id, owner)
+            }
+            Event::Moved { id, from, to } => write!(f, "move   #{} from {} to {}", id, from, to),
+            Event::Dropped { id, owner } => {
+                write!(f, "drop   #{} (owner {}), memory freed", id, owner)
+            }
+        }
+    }
+}
+
+#[derive(Default)]
+struct State {
+    next_id: usize,
+    events: Vec<Event>,
+    printing: bool,
+}
+
+thread_local! {
+    static STATE: RefCell<State> = RefCell::new(State::default());
+}
+
+fn log(event: Event) {
+    STATE.with(|state| {
+        let mut state = state.borrow_mut();
+        state.events.push(event.clone());
+        if state.printing {
+            println!("   [{}] {}", state.events.len(), event);
+        }
+    });
+}
+
+fn next_id() -> usize {
+    STATE.with(|state| {
+        let mut state = state.borrow_mut();
+        state.next_id += 1;
+        state.next_id
+    })
+}
+
+pub struct Tracked<T: fmt::Debug> {
+    id: usize,
+    owner: String,
+    value: T,
+}
+
+impl<T: fmt::Debug> Tracked<T> {
+    pub fn new(owner: &str, value: T) -> Tracked<T> {
+        let id = next_id();
+        log(Event::Created {
+            id,
+            owner: owner.to_string(),
+            value: format!("{:?}", value),
+        });
+        Tracked {
+            id,
+            owner: owner.to_string(),
+            value,
+        }
+    }
+
+    // Hands the value to a new owner: a variable, or a function parameter
+    pub fn moved_to(mut self, owner: &str) -> Tracked<T> {
+        log(Event::Moved {
+            id: self.id,
+            from: self.owner.clone(),
+            to: owner.to_string(),
+        });
+        self.owner = owner.to_string();
+        self
+    }
+}
+
+impl<T: fmt::Debug + Clone> Tracked<T> {
+    // A deep copy with its own id, its own owner and its own drop
+    pub fn clone_as(&self, owner: &str) -> Tracked<T> {
+        let id = next_id();
+        log(Event::Cloned {
+            id,
+            from: self.id,
+            owner: owner.to_string(),
+        });
+        Tracked {
+            id,
+            owner: owner.to_string(),
+            value: self.value.clone(),
+        }
+    }
+}
+
+impl<T: fmt::Debug> Drop for Tracked<T> {
+    fn drop(&mut self) {
+        log(Event::Dropped {
+            id: self.id,
+            owner: self.owner.clone(),
+        });
+    }
+}
+
+impl<T: fmt::Debug> Deref for Tracked<T> {
+    type Target = T;
+
+    fn deref(&self) -> &T {
+        &self.value
+    }
+}
+
+impl<T: fmt::Debug> DerefMut for Tracked<T> {
+    fn deref_mut(&mut self) -> &mut T {
+        &mut self.value
+    }
+}
+
+impl<T: fmt::Debug + fmt::Display> fmt::Display for Tracked<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(&self.value, f)
+    }
+}
+
+// Numbers values from #1 and collects their events until it is dropped.
+// Created first in a section, so it is dropped after the section's values.
+pub struct Timeline {
+    _private: (),
+}
+
+impl Timeline {
+    pub fn start() -> Timeline {
+        STATE.with(|state| {
+            *state.borrow_mut() = State {
+                printing: true,
+                ..State::default()
+            }
+        });
+        Timeline { _private: () }
+    }
+
+    #[cfg(test)]
+    pub fn events(&self) -> Vec<Event> {
+        STATE.with(|state| state.borrow().events.clone())
+    }
+}
+
+impl Drop for Timeline {
+    fn drop(&mut self) {
+        let events = STATE.with(|state| {
+            let mut state = state.borrow_mut();
+            state.printing = false;
+            std::mem::take(&mut state.events)
+        });
+        if events.is_empty() {
+            return;
+        }
+        println!("\n   Who owned what:");
+        for line in lifetimes(&events) {
+            println!("   {}", line);
+        }
+        println!();
+    }
+}
+
+// One line per value: its owners in order and the step that freed it
+//
+//   #1 "hello": s -> some_string, dropped at step 3
+pub fn lifetimes(events: &[Event]) -> Vec<String> {
+    struct Lifetime {
+        id: usize,
+        value: String,
+        owners: Vec<String>,
+        dropped: Option<usize>,
+    }
+
+    let mut values: Vec<Lifetime> = Vec::new();
+    for (index, event) in events.iter().enumerate() {
+        match event {
+            Event::Created { id, owner, value } => values.push(Lifetime {
+                id: *id,
+                value: value.clone(),
+                owners: vec![owner.clone()],
+                dropped: None,
+            }),
+            Event::Cloned { id, from, owner } => {
+                let value = values
+                    .iter()
+                    .find(|other| other.id == *from)
+                    .map(|other| other.value.clone())
+                    .unwrap_or_default();
+                values.push(Lifetime {
+                    id: *id,
+                    value,
+                    owners: vec![owner.clone()],
+                    dropped: None,
+                });
+            }
+            Event::Moved { id, to, .. } => {
+                if let Some(moved) = values.iter_mut().find(|other| other.id == *id) {
+                    moved.owners.push(to.clone());
+                }
+            }
+            Event::Dropped { id, .. } => {
+                if let Some(dropped) = values.iter_mut().find(|other| other.id == *id) {
+                    dropped.dropped = Some(index + 1);
+                }
+            }
+        }
+    }
+    values
+        .iter()
+        .map(|value| {
+            let end = match value.dropped {
+                Some(step) => format!("dropped at step {}", step),
+                None => "never dropped".to_string(),
+            };
+            format!(
+                "#{} {}: {}, {}",
+                value.id,
+                value.value,
+                value.owners.join(" -> "),
+                end
+            )
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn takes_ownership(some_string: Tracked<String>) -> usize {
+        some_string.len()
+    }
+
+    #[test]
+    fn logs_create_move_clone_and_drop_in_order() {
+        let timeline = Timeline::start();
+        let copy;
+        {
+            let s = Tracked::new("s", String::from("hello"));
+            copy = s.clone_as("copy");
+            assert_eq!(takes_ownership(s.moved_to("some_string")), 5);
+        }
+        drop(copy);
+
+        let events = timeline.events();
+        assert_eq!(
+            events.iter().map(Event::to_string).collect::<Vec<_>>(),
+            vec![
+                "create #1 \"hello\", owned by s",
+                "clone  #1 into #2, owned by copy",
+                "move   #1 from s to some_string",
+                "drop   #1 (owner some_string), memory freed",
+                "drop   #2 (owner copy), memory freed",
+            ]
+        );
+        assert_eq!(
+            lifetimes(&events),
+            vec![
+                "#1 \"hello\": s -> some_string, dropped at step 4",
+                "#2 \"hello\": copy, dropped at step 5",
+            ]
+        );
+    }
+
+    #[test]
+    fn reassignment_drops_the_old_value_after_creating_the_new_one() {
+        let timeline = Timeline::start();
+        let mut s = Tracked::new("s", String::from("hello"));
+        assert_eq!(s.len(), 5);
+        s = Tracked::new("s", String::from("ahoy"));
+        s.push('!');
+        assert_eq!(s.to_string(), "ahoy!");
+        assert_eq!(
+            timeline.events()[2],
+            Event::Dropped {
+                id: 1,
+                owner: "s".to_string()
+            }
+        );
+    }
+}
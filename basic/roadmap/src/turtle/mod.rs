@@ -0,0 +1,514 @@
+// Turtle
+// The `Message` enum from the enum and pattern lessons, turned into a tiny
+// drawing language. A script is one message per line, written exactly like
+// the Rust value (the `Message::` prefix is optional):
+//
+//   // a red line with a label at its end
+//   ChangeColor(255, 0, 0)
+//   Move { x: 40, y: 0 }
+//   Write("east")
+//   Quit
+//
+// Running it moves a pen over a canvas: `Move` goes `x` right and `y` down
+// from where the pen is, drawing a line in the current colour; `Write` puts a
+// label at the pen; `ChangeColor` picks the colour for what follows; `Quit`
+// ends the script, later messages are ignored. The pen starts black at
+// (0, 0). `render::svg` and `render::ppm` turn the finished canvas into an
+// image.
+
+pub mod render;
+
+use std::fmt;
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum Message {
+    Quit,
+    Move { x: i32, y: i32 },
+    Write(String),
+    ChangeColor(i32, i32, i32),
+}
+
+// Quotes `text` with just the escapes the tokenizer reads back: `\"`, `\\`,
+// `\n` and `\t`. Everything else, control chars included, is kept as is
+fn quote(text: &str) -> String {
+    let mut quoted = String::from('"');
+    for c in text.chars() {
+        match c {
+            '"' => quoted.push_str("\\\""),
+            '\\' => quoted.push_str("\\\\"),
+            '\n' => quoted.push_str("\\n"),
+            '\t' => quoted.push_str("\\t"),
+            c => quoted.push(c),
+        }
+    }
+    quoted.push('"');
+    quoted
+}
+
+// Written back as script syntax, so a message parses to itself
+impl fmt::Display for Message {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Message::Quit => write!(f, "Quit"),
+            Message::Move { x, y } => write!(f, "Move {{ x: {}, y: {} }}", x, y),
+            Message::Write(text) => write!(f, "Write({})", quote(text)),
+            Message::ChangeColor(r, g, b) => write!(f, "ChangeColor({}, {}, {})", r, g, b),
+        }
+    }
+}
+
+#[derive(Debug, PartialEq)]
+pub struct ScriptError {
+    pub line: usize,
+    pub message: String,
+}
+
+impl fmt::Display for ScriptError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "line {}: {}", self.line, self.message)
+    }
+}
+
+#[derive(Debug, PartialEq)]
+enum Token {
+    Ident(String),
+    Int(i64),
+    Str(String),
+    Punct(char),
+}
+
+impl fmt::Display for Token {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Token::Ident(name) => write!(f, "`{}`", name),
+            Token::Int(n) => write!(f, "`{}`", n),
+            Token::Str(text) => write!(f, "{:?}", text),
+            Token::Punct(c) => write!(f, "`{}`", c),
+        }
+    }
+}
+
+fn tokenize(line: &str) -> Result<Vec<Token>, String> {
+    let mut tokens = Vec::new();
+    let mut chars = line.chars().peekable();
+    while let Some(&c) = chars.peek() {
+        if c.is_whitespace() {
+            chars.next();
+        } else if c == '/' {
+            chars.next();
+            if chars.next() != Some('/') {
+                return Err("expected `//` to start a comment".to_string());
+            }
+            break;
+        } else if c.is_ascii_digit() || c == '-' {
+            let mut number = String::from(c);
+            chars.next();
+            while let Some(&d) = chars.peek().filter(|d| d.is_ascii_digit()) {
+                number.push(d);
+                chars.next();
+            }
+            let value = number
+                .parse()
+                .map_err(|_| format!("`{}` is not a number", number))?;
+            tokens.push(Token::Int(value));
+        } else if c.is_alphabetic() || c == '_' {
+            let mut name = String::new();
+            while let Some(&d) = chars.peek().filter(|d| d.is_alphanumeric() || **d == '_') {
+                name.push(d);
+                chars.next();
+            }
+            tokens.push(Token::Ident(name));
+        } else if c == '"' {
+            chars.next();
+            let mut text = String::new();
+            loop {
+                match chars.next() {
+                    Some('"') => break,
+                    Some('\\') => match chars.next() {
+                        Some('n') => text.push('\n'),
+                        Some('t') => text.push('\t'),
+                        Some(e @ ('"' | '\\')) => text.push(e),
+                        Some(e) => return Err(format!("unknown escape `\\{}`", e)),
+                        None => return Err("unterminated string".to_string()),
+                    },
+                    Some(d) => text.push(d),
+                    None => return Err("unterminated string".to_string()),
+                }
+            }
+            tokens.push(Token::Str(text));
+        } else {
+            tokens.push(Token::Punct(c));
+            chars.next();
+        }
+    }
+    Ok(tokens)
+}
+
+// A cursor over one line's tokens
+struct Parser {
+    tokens: Vec<Token>,
+    position: usize,
+}
+
+impl Parser {
+    fn next(&mut self) -> Option<&Token> {
+        let token = self.tokens.get(self.position);
+        self.position += 1;
+        token
+    }
+
+    fn found(&self) -> String {
+        match self.tokens.get(self.position) {
+            Some(token) => format!("found {}", token),
+            None => "found the end of the line".to_string(),
+        }
+    }
+
+    fn peek_punct(&self, c: char) -> bool {
+        self.tokens.get(self.position) == Some(&Token::Punct(c))
+    }
+
+    fn punct(&mut self, c: char) -> Result<(), String> {
+        if self.peek_punct(c) {
+            self.position += 1;
+            Ok(())
+        } else {
+            Err(format!("expected `{}`, {}", c, self.found()))
+        }
+    }
+
+    fn ident(&mut self) -> Result<String, String> {
+        let found = self.found();
+        match self.next() {
+            Some(Token::Ident(name)) => Ok(name.clone()),
+            _ => Err(format!("expected a message, {}", found)),
+        }
+    }
+
+    fn int(&mut self) -> Result<i32, String> {
+        let found = self.found();
+        match self.next() {
+            Some(&Token::Int(n)) => {
+                i32::try_from(n).map_err(|_| format!("{} does not fit in an i32", n))
+            }
+            _ => Err(format!("expected a number, {}", found)),
+        }
+    }
+
+    fn string(&mut self) -> Result<String, String> {
+        let found = self.found();
+        match self.next() {
+            Some(Token::Str(text)) => Ok(text.clone()),
+            _ => Err(format!("expected a string, {}", found)),
+        }
+    }
+
+    fn component(&mut self) -> Result<i32, String> {
+        let value = self.int()?;
+        if (0..=255).contains(&value) {
+            Ok(value)
+        } else {
+            Err(format!("colour component {} is not in 0..=255", value))
+        }
+    }
+
+    fn message(&mut self) -> Result<Message, String> {
+        let mut name = self.ident()?;
+        if name == "Message" {
+            self.punct(':')?;
+            self.punct(':')?;
+            name = self.ident()?;
+        }
+        let message = match name.as_str() {
+            "Quit" => Message::Quit,
+            "Move" => {
+                self.punct('{')?;
+                let (mut x, mut y) = (None, None);
+                while !self.peek_punct('}') {
+                    let field = self.ident()?;
+                    self.punct(':')?;
+                    let value = self.int()?;
+                    let slot = match field.as_str() {
+                        "x" => &mut x,
+                        "y" => &mut y,
+                        other => return Err(format!("`Move` has no field `{}`", other)),
+                    };
+                    if slot.replace(value).is_some() {
+                        return Err(format!("field `{}` given twice", field));
+                    }
+                    if !self.peek_punct('}') {
+                        self.punct(',')?;
+                    }
+                }
+                self.punct('}')?;
+                match (x, y) {
+                    (Some(x), Some(y)) => Message::Move { x, y },
+                    (None, _) => return Err("missing field `x` in `Move`".to_string()),
+                    (_, None) => return Err("missing field `y` in `Move`".to_string()),
+                }
+            }
+            "Write" => {
+                self.punct('(')?;
+                let text = self.string()?;
+                self.punct(')')?;
+                Message::Write(text)
+            }
+            "ChangeColor" => {
+                self.punct('(')?;
+                let r = self.component()?;
+                self.punct(',')?;
+                let g = self.component()?;
+                self.punct(',')?;
+                let b = self.component()?;
+                self.punct(')')?;
+                Message::ChangeColor(r, g, b)
+            }
+            other => {
+                return Err(format!(
+                    "unknown message `{}` (expected Quit, Move, Write or ChangeColor)",
+                    other
+                ));
+            }
+        };
+        // Lines copied out of a vec![...] or a statement may keep their `,` or `;`
+        if self.peek_punct(',') || self.peek_punct(';') {
+            self.position += 1;
+        }
+        if self.position < self.tokens.len() {
+            return Err(format!("expected the end of the line, {}", self.found()));
+        }
+        Ok(message)
+    }
+}
+
+// Blank lines and `//` comments are skipped; the first bad line is an error
+pub fn parse(script: &str) -> Result<Vec<Message>, ScriptError> {
+    let mut messages = Vec::new();
+    for (index, text) in script.lines().enumerate() {
+        let error = |message| ScriptError {
+            line: index + 1,
+            message,
+        };
+        let tokens = tokenize(text).map_err(error)?;
+        if tokens.is_empty() {
+            continue;
+        }
+        let mut parser = Parser {
+            tokens,
+            position: 0,
+        };
+        messages.push(parser.message().map_err(error)?);
+    }
+    Ok(messages)
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct Color {
+    pub r: u8,
+    pub g: u8,
+    pub b: u8,
+}
+
+impl Color {
+    // Out-of-range components are clamped, as a message built in code may hold any i32
+    pub fn clamped(r: i32, g: i32, b: i32) -> Color {
+        let clamp = |value: i32| value.clamp(0, 255) as u8;
+        Color {
+            r: clamp(r),
+            g: clamp(g),
+            b: clamp(b),
+        }
+    }
+}
+
+impl fmt::Display for Color {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "rgb({}, {}, {})", self.r, self.g, self.b)
+    }
+}
+
+// (x, y) with y growing downwards, as on screen
+pub type Pen = (i32, i32);
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Segment {
+    pub from: Pen,
+    pub to: Pen,
+    pub color: Color,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Label {
+    pub at: Pen,
+    pub text: String,
+    pub color: Color,
+}
+
+#[derive(Debug, Default)]
+pub struct Canvas {
+    pub pen: Pen,
+    pub color: Color,
+    pub segments: Vec<Segment>,
+    pub labels: Vec<Label>,
+    pub quit: bool,
+}
+
+impl Canvas {
+    pub fn new() -> Canvas {
+        Canvas::default()
+    }
+
+    // Carries out one message; false once the script has quit
+    pub fn execute(&mut self, message: &Message) -> bool {
+        if self.quit {
+            return false;
+        }
+        match message {
+            Message::Quit => self.quit = true,
+            Message::Move { x, y } => {
+                let from = self.pen;
+                self.pen = (from.0.saturating_add(*x), from.1.saturating_add(*y));
+                self.segments.push(Segment {
+                    from,
+                    to: self.pen,
+                    color: self.color,
+                });
+            }
+            Message::Write(text) => self.labels.push(Label {
+                at: self.pen,
+                text: text.clone(),
+                color: self.color,
+            }),
+            Message::ChangeColor(r, g, b) => self.color = Color::clamped(*r, *g, *b),
+        }
+        !self.quit
+    }
+
+    // How many messages were carried out, counting the Quit
+    pub fn run(&mut self, messages: &[Message]) -> usize {
+        let mut executed = 0;
+        for message in messages {
+            if self.quit {
+                break;
+            }
+            self.execute(message);
+            executed += 1;
+        }
+        executed
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_every_variant_in_rust_syntax() {
+        let script = "\
+            // comment lines and blank lines are skipped
+
+            Message::Move { x: 10, y: -20 },
+            Move { y: 5, x: 0 }
+            Write(\"say \\\"hi\\\"\");
+            ChangeColor(255, 0, 0) // trailing comment
+            Quit";
+        let messages = parse(script).unwrap();
+        assert_eq!(
+            messages,
+            vec![
+                Message::Move { x: 10, y: -20 },
+                Message::Move { x: 0, y: 5 },
+                Message::Write("say \"hi\"".to_string()),
+                Message::ChangeColor(255, 0, 0),
+                Message::Quit,
+            ]
+        );
+        for message in &messages {
+            assert_eq!(parse(&message.to_string()).unwrap(), vec![message.clone()]);
+        }
+    }
+
+    #[test]
+    fn written_text_round_trips_through_display() {
+        for text in [
+            "tab\tnewline\nreturn\rnul\0bell\u{7}",
+            "quotes \"'\" and a backslash \\",
+            "naïve café, 東京, 🦀\u{200D}\u{301}",
+            "",
+        ] {
+            let message = Message::Write(text.to_string());
+            assert_eq!(parse(&message.to_string()), Ok(vec![message]));
+        }
+        assert_eq!(
+            Message::Write("a\"b\n".to_string()).to_string(),
+            "Write(\"a\\\"b\\n\")"
+        );
+    }
+
+    #[test]
+    fn reports_the_line_of_the_first_error() {
+        let error = |script: &str| parse(script).unwrap_err().to_string();
+        assert_eq!(
+            error("Quit\nJump(1)"),
+            "line 2: unknown message `Jump` (expected Quit, Move, Write or ChangeColor)"
+        );
+        assert_eq!(
+            error("Move { x: 1 }"),
+            "line 1: missing field `y` in `Move`"
+        );
+        assert_eq!(
+            error("Move { x: 1, x: 2 }"),
+            "line 1: field `x` given twice"
+        );
+        assert_eq!(
+            error("ChangeColor(0, 300, 0)"),
+            "line 1: colour component 300 is not in 0..=255"
+        );
+        assert_eq!(
+            error("Write(hello)"),
+            "line 1: expected a string, found `hello`"
+        );
+        assert_eq!(
+            error("ChangeColor(1, 2"),
+            "line 1: expected `,`, found the end of the line"
+        );
+        assert_eq!(error("Write(\"open"), "line 1: unterminated string");
+        assert_eq!(
+            error("Quit Quit"),
+            "line 1: expected the end of the line, found `Quit`"
+        );
+    }
+
+    #[test]
+    fn executes_against_the_canvas_until_quit() {
+        let messages = parse(
+            "Move { x: 10, y: 0 }\nChangeColor(0, 0, 255)\nMove { x: 0, y: 10 }\n\
+             Write(\"corner\")\nQuit\nMove { x: 99, y: 99 }",
+        )
+        .unwrap();
+        let mut canvas = Canvas::new();
+        assert_eq!(canvas.run(&messages), 5);
+        assert!(canvas.quit);
+        assert_eq!(canvas.pen, (10, 10));
+        assert_eq!(
+            canvas.segments[1],
+            Segment {
+                from: (10, 0),
+                to: (10, 10),
+                color: Color { r: 0, g: 0, b: 255 },
+            }
+        );
+        assert_eq!(canvas.segments[0].color, Color::default());
+        assert_eq!(canvas.labels[0].at, (10, 10));
+        assert!(!canvas.execute(&Message::Move { x: 1, y: 1 }));
+        assert_eq!(canvas.segments.len(), 2);
+        assert_eq!(
+            Color::clamped(-5, 128, 999),
+            Color {
+                r: 0,
+                g: 128,
+                b: 255
+            }
+        );
+    }
+}
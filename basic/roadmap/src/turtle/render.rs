@@ -0,0 +1,341 @@
+// Turtle Rendering
+// Draws a finished `Canvas` on a white background, cropped to what was drawn
+// (and the starting point) with a margin around it.
+//
+// `svg` keeps lines and labels as vector shapes and text. `ppm` rasterizes to
+// a binary P6 image, which every image viewer opens and which needs no
+// encoder: lines are Bresenham's, labels use a built-in 3×5 pixel font that
+// draws letters as capitals and leaves out chars it does not have. A pen
+// can travel anywhere an i32 reaches, so `ppm` refuses images of more than
+// `MAX_PIXELS` instead of allocating them.
+
+use std::fmt;
+
+use super::{Canvas, Color, Pen};
+
+const MARGIN: i64 = 10;
+const SVG_FONT_SIZE: i64 = 12;
+// 4000×4000, 48 MB of pixels
+pub const MAX_PIXELS: i64 = 16_000_000;
+
+#[derive(Debug, PartialEq)]
+pub enum RenderError {
+    TooLarge { width: i64, height: i64 },
+}
+
+impl fmt::Display for RenderError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            RenderError::TooLarge { width, height } => write!(
+                f,
+                "the image would be {}×{} pixels, more than the {} a PPM may have",
+                width, height, MAX_PIXELS
+            ),
+        }
+    }
+}
+
+// Coordinates widened to i64, so bounds and margins around any i32 pen
+// position cannot overflow
+type Point = (i64, i64);
+
+fn widen((x, y): Pen) -> Point {
+    (x as i64, y as i64)
+}
+
+// Smallest (left, top, right, bottom) holding the origin, every line and
+// every label, given how big one char of a label is
+fn bounds(canvas: &Canvas, char_width: i64, char_height: i64) -> (i64, i64, i64, i64) {
+    let mut bounds = (0, 0, 0, 0);
+    let mut include = |(x, y): Point| {
+        bounds.0 = bounds.0.min(x);
+        bounds.1 = bounds.1.min(y);
+        bounds.2 = bounds.2.max(x);
+        bounds.3 = bounds.3.max(y);
+    };
+    for segment in &canvas.segments {
+        include(widen(segment.from));
+        include(widen(segment.to));
+    }
+    // Labels sit on their baseline, growing up and to the right
+    for label in &canvas.labels {
+        let chars = label.text.chars().count() as i64;
+        let (x, y) = widen(label.at);
+        include((x, y));
+        include((x + chars * char_width, y - char_height));
+    }
+    (
+        bounds.0 - MARGIN,
+        bounds.1 - MARGIN,
+        bounds.2 + MARGIN,
+        bounds.3 + MARGIN,
+    )
+}
+
+fn escape(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
+
+pub fn svg(canvas: &Canvas) -> String {
+    // Monospace glyphs are about 0.6 em wide
+    let (left, top, right, bottom) = bounds(canvas, SVG_FONT_SIZE * 3 / 5, SVG_FONT_SIZE);
+    let (width, height) = (right - left, bottom - top);
+    let mut svg = format!(
+        "<svg xmlns=\"http://www.w3.org/2000/svg\" width=\"{w}\" height=\"{h}\" viewBox=\"{} {} {w} {h}\">\n",
+        left,
+        top,
+        w = width,
+        h = height
+    );
+    svg.push_str(&format!(
+        "  <rect x=\"{}\" y=\"{}\" width=\"{}\" height=\"{}\" fill=\"white\"/>\n",
+        left, top, width, height
+    ));
+    for segment in &canvas.segments {
+        svg.push_str(&format!(
+            "  <line x1=\"{}\" y1=\"{}\" x2=\"{}\" y2=\"{}\" stroke=\"{}\" stroke-width=\"2\" stroke-linecap=\"round\"/>\n",
+            segment.from.0, segment.from.1, segment.to.0, segment.to.1, segment.color
+        ));
+    }
+    for label in &canvas.labels {
+        svg.push_str(&format!(
+            "  <text x=\"{}\" y=\"{}\" fill=\"{}\" font-family=\"monospace\" font-size=\"{}\">{}</text>\n",
+            label.at.0,
+            label.at.1,
+            label.color,
+            SVG_FONT_SIZE,
+            escape(&label.text)
+        ));
+    }
+    svg.push_str("</svg>\n");
+    svg
+}
+
+// Rows top to bottom, three bits each, the leftmost pixel in the high bit
+const FONT: &[(char, u16)] = &[
+    ('A', 0b010_101_111_101_101),
+    ('B', 0b110_101_110_101_110),
+    ('C', 0b011_100_100_100_011),
+    ('D', 0b110_101_101_101_110),
+    ('E', 0b111_100_110_100_111),
+    ('F', 0b111_100_110_100_100),
+    ('G', 0b011_100_101_101_011),
+    ('H', 0b101_101_111_101_101),
+    ('I', 0b111_010_010_010_111),
+    ('J', 0b001_001_001_101_010),
+    ('K', 0b101_101_110_101_101),
+    ('L', 0b100_100_100_100_111),
+    ('M', 0b101_111_111_101_101),
+    ('N', 0b110_101_101_101_101),
+    ('O', 0b010_101_101_101_010),
+    ('P', 0b110_101_110_100_100),
+    ('Q', 0b010_101_101_110_011),
+    ('R', 0b110_101_110_101_101),
+    ('S', 0b011_100_010_001_110),
+    ('T', 0b111_010_010_010_010),
+    ('U', 0b101_101_101_101_111),
+    ('V', 0b101_101_101_101_010),
+    ('W', 0b101_101_111_111_101),
+    ('X', 0b101_101_010_101_101),
+    ('Y', 0b101_101_010_010_010),
+    ('Z', 0b111_001_010_100_111),
+    ('0', 0b111_101_101_101_111),
+    ('1', 0b010_110_010_010_111),
+    ('2', 0b110_001_010_100_111),
+    ('3', 0b110_001_010_001_110),
+    ('4', 0b101_101_111_001_001),
+    ('5', 0b111_100_110_001_110),
+    ('6', 0b011_100_111_101_111),
+    ('7', 0b111_001_010_010_010),
+    ('8', 0b111_101_111_101_111),
+    ('9', 0b111_101_111_001_110),
+    ('.', 0b000_000_000_000_010),
+    (',', 0b000_000_000_010_100),
+    ('!', 0b010_010_010_000_010),
+    ('?', 0b110_001_010_000_010),
+    ('-', 0b000_000_111_000_000),
+    (':', 0b000_010_000_010_000),
+    ('\'', 0b010_010_000_000_000),
+];
+
+const GLYPH_WIDTH: i64 = 3;
+const GLYPH_HEIGHT: i64 = 5;
+// A glyph plus one column of spacing
+const ADVANCE: i64 = GLYPH_WIDTH + 1;
+
+fn glyph(c: char) -> Option<u16> {
+    let c = c.to_ascii_uppercase();
+    FONT.iter().find(|&&(g, _)| g == c).map(|&(_, bits)| bits)
+}
+
+struct Raster {
+    left: i64,
+    top: i64,
+    width: i64,
+    height: i64,
+    pixels: Vec<Color>,
+}
+
+impl Raster {
+    // Points outside the image are skipped
+    fn plot(&mut self, (x, y): Point, color: Color) {
+        let (column, row) = (x - self.left, y - self.top);
+        if (0..self.width).contains(&column) && (0..self.height).contains(&row) {
+            self.pixels[(row * self.width + column) as usize] = color;
+        }
+    }
+
+    fn line(&mut self, from: Point, to: Point, color: Color) {
+        let (dx, dy) = ((to.0 - from.0).abs(), -(to.1 - from.1).abs());
+        let step = (
+            if from.0 < to.0 { 1 } else { -1 },
+            if from.1 < to.1 { 1 } else { -1 },
+        );
+        let (mut x, mut y) = from;
+        let mut error = dx + dy;
+        loop {
+            self.plot((x, y), color);
+            if (x, y) == to {
+                break;
+            }
+            let doubled = 2 * error;
+            if doubled >= dy {
+                error += dy;
+                x += step.0;
+            }
+            if doubled <= dx {
+                error += dx;
+                y += step.1;
+            }
+        }
+    }
+
+    fn text(&mut self, (x, y): Point, text: &str, color: Color) {
+        for (index, c) in text.chars().enumerate() {
+            let Some(bits) = glyph(c) else { continue };
+            let left = x + index as i64 * ADVANCE;
+            for row in 0..GLYPH_HEIGHT {
+                for column in 0..GLYPH_WIDTH {
+                    let bit = (GLYPH_HEIGHT - 1 - row) * GLYPH_WIDTH + (GLYPH_WIDTH - 1 - column);
+                    if bits >> bit & 1 == 1 {
+                        self.plot((left + column, y - GLYPH_HEIGHT + row), color);
+                    }
+                }
+            }
+        }
+    }
+}
+
+pub fn ppm(canvas: &Canvas) -> Result<Vec<u8>, RenderError> {
+    let (left, top, right, bottom) = bounds(canvas, ADVANCE, GLYPH_HEIGHT);
+    let (width, height) = (right - left + 1, bottom - top + 1);
+    // Both are at most about 2^32, so the product fits in an i64
+    if width * height > MAX_PIXELS {
+        return Err(RenderError::TooLarge { width, height });
+    }
+    let white = Color {
+        r: 255,
+        g: 255,
+        b: 255,
+    };
+    let mut raster = Raster {
+        left,
+        top,
+        width,
+        height,
+        pixels: vec![white; (width * height) as usize],
+    };
+    for segment in &canvas.segments {
+        raster.line(widen(segment.from), widen(segment.to), segment.color);
+    }
+    for label in &canvas.labels {
+        raster.text(widen(label.at), &label.text, label.color);
+    }
+
+    let mut image = format!("P6\n{} {}\n255\n", width, height).into_bytes();
+    for pixel in raster.pixels {
+        image.extend([pixel.r, pixel.g, pixel.b]);
+    }
+    Ok(image)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::{Canvas, parse};
+    use super::*;
+
+    fn draw(script: &str) -> Canvas {
+        let mut canvas = Canvas::new();
+        canvas.run(&parse(script).unwrap());
+        canvas
+    }
+
+    #[test]
+    fn svg_has_a_shape_per_line_and_label() {
+        let canvas = draw(
+            "ChangeColor(255, 0, 0)\nMove { x: 40, y: 0 }\nWrite(\"<east>\")\nMove { x: 0, y: -30 }",
+        );
+        let svg = svg(&canvas);
+        assert!(svg.starts_with(
+            "<svg xmlns=\"http://www.w3.org/2000/svg\" width=\"102\" height=\"50\" viewBox=\"-10 -40 102 50\">"
+        ));
+        assert_eq!(svg.matches("<line ").count(), 2);
+        assert!(
+            svg.contains("<line x1=\"0\" y1=\"0\" x2=\"40\" y2=\"0\" stroke=\"rgb(255, 0, 0)\"")
+        );
+        assert!(svg.contains(">&lt;east&gt;</text>"));
+        assert!(svg.ends_with("</svg>\n"));
+    }
+
+    #[test]
+    fn ppm_rasterizes_lines_and_text() {
+        let canvas = draw("ChangeColor(0, 0, 255)\nMove { x: 4, y: 4 }\nWrite(\"Hi\")");
+        let image = ppm(&canvas).unwrap();
+        // From (-10, -11) to (22, 14): the label's 2 chars reach 8 right, 5 up
+        let header = b"P6\n33 26\n255\n";
+        assert!(image.starts_with(header));
+        assert_eq!(image.len(), header.len() + 33 * 26 * 3);
+        let pixel = |x: i64, y: i64| {
+            let index = header.len() + (((y + 11) * 33 + x + 10) * 3) as usize;
+            [image[index], image[index + 1], image[index + 2]]
+        };
+        // The diagonal
+        for i in 0..=4 {
+            assert_eq!(pixel(i, i), [0, 0, 255]);
+        }
+        assert_eq!(pixel(1, 0), [255, 255, 255]);
+        // "H" sits on the baseline at y = 4: both legs of its top row, then
+        // the gap between them, then its crossbar
+        assert_eq!(pixel(4, -1), [0, 0, 255]);
+        assert_eq!(pixel(5, -1), [255, 255, 255]);
+        assert_eq!(pixel(6, -1), [0, 0, 255]);
+        assert_eq!(pixel(5, 1), [0, 0, 255]);
+        assert_eq!(glyph('h'), glyph('H'));
+        assert_eq!(glyph('~'), None);
+    }
+
+    #[test]
+    fn far_away_pens_do_not_overflow() {
+        let canvas = draw("Move { x: 2147483647, y: 0 }\nWrite(\"edge\")");
+        assert!(svg(&canvas).starts_with(
+            "<svg xmlns=\"http://www.w3.org/2000/svg\" width=\"2147483695\" height=\"32\" viewBox=\"-10 -22 2147483695 32\">"
+        ));
+        assert_eq!(
+            ppm(&canvas),
+            Err(RenderError::TooLarge {
+                width: 2147483684,
+                height: 26
+            })
+        );
+
+        let canvas = draw("Move { x: 100000, y: 100000 }");
+        let err = ppm(&canvas).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "the image would be 100021×100021 pixels, more than the 16000000 a PPM may have"
+        );
+    }
+}
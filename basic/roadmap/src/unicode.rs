@@ -0,0 +1,663 @@
+// UTF-8 Inspector
+// Takes any text apart the way the char and string lessons describe it: one
+// row per code point with its byte offset, UTF-8 and UTF-16 encodings and
+// general category, the byte offsets `&s[a..b]` may use (every other offset
+// panics), and the text split into user-perceived characters.
+//
+// std has no Unicode tables beyond what `char` methods answer, so two parts
+// are approximations: `category` is derived from those methods plus code
+// point ranges for the common scripts, so it always answers with one of the
+// UCD's two-letter categories but can pick the wrong one for rarer code
+// points, and `graphemes` follows the main rules of UAX #29
+// (combining marks, ZWJ sequences, emoji modifiers, flags, CR LF, Indic
+// conjuncts) rather than the full table-driven algorithm.
+
+use std::fmt;
+use std::ops::Range;
+
+// One general category from the Unicode Character Database
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Category {
+    UppercaseLetter,
+    LowercaseLetter,
+    TitlecaseLetter,
+    ModifierLetter,
+    OtherLetter,
+    NonspacingMark,
+    SpacingMark,
+    EnclosingMark,
+    DecimalNumber,
+    LetterNumber,
+    OtherNumber,
+    ConnectorPunctuation,
+    DashPunctuation,
+    OpenPunctuation,
+    ClosePunctuation,
+    InitialPunctuation,
+    FinalPunctuation,
+    OtherPunctuation,
+    MathSymbol,
+    CurrencySymbol,
+    ModifierSymbol,
+    OtherSymbol,
+    SpaceSeparator,
+    LineSeparator,
+    ParagraphSeparator,
+    Control,
+    Format,
+    PrivateUse,
+}
+
+impl Category {
+    // The two-letter code from the Unicode Character Database
+    pub fn code(self) -> &'static str {
+        match self {
+            Category::UppercaseLetter => "Lu",
+            Category::LowercaseLetter => "Ll",
+            Category::TitlecaseLetter => "Lt",
+            Category::ModifierLetter => "Lm",
+            Category::OtherLetter => "Lo",
+            Category::NonspacingMark => "Mn",
+            Category::SpacingMark => "Mc",
+            Category::EnclosingMark => "Me",
+            Category::DecimalNumber => "Nd",
+            Category::LetterNumber => "Nl",
+            Category::OtherNumber => "No",
+            Category::ConnectorPunctuation => "Pc",
+            Category::DashPunctuation => "Pd",
+            Category::OpenPunctuation => "Ps",
+            Category::ClosePunctuation => "Pe",
+            Category::InitialPunctuation => "Pi",
+            Category::FinalPunctuation => "Pf",
+            Category::OtherPunctuation => "Po",
+            Category::MathSymbol => "Sm",
+            Category::CurrencySymbol => "Sc",
+            Category::ModifierSymbol => "Sk",
+            Category::OtherSymbol => "So",
+            Category::SpaceSeparator => "Zs",
+            Category::LineSeparator => "Zl",
+            Category::ParagraphSeparator => "Zp",
+            Category::Control => "Cc",
+            Category::Format => "Cf",
+            Category::PrivateUse => "Co",
+        }
+    }
+
+    pub fn is_mark(self) -> bool {
+        matches!(
+            self,
+            Category::NonspacingMark | Category::SpacingMark | Category::EnclosingMark
+        )
+    }
+}
+
+impl fmt::Display for Category {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let name = match self {
+            Category::UppercaseLetter => "uppercase letter",
+            Category::LowercaseLetter => "lowercase letter",
+            Category::TitlecaseLetter => "titlecase letter",
+            Category::ModifierLetter => "modifier letter",
+            Category::OtherLetter => "other letter",
+            Category::NonspacingMark => "nonspacing mark",
+            Category::SpacingMark => "spacing mark",
+            Category::EnclosingMark => "enclosing mark",
+            Category::DecimalNumber => "decimal number",
+            Category::LetterNumber => "letter number",
+            Category::OtherNumber => "other number",
+            Category::ConnectorPunctuation => "connector punctuation",
+            Category::DashPunctuation => "dash punctuation",
+            Category::OpenPunctuation => "open punctuation",
+            Category::ClosePunctuation => "close punctuation",
+            Category::InitialPunctuation => "initial punctuation",
+            Category::FinalPunctuation => "final punctuation",
+            Category::OtherPunctuation => "other punctuation",
+            Category::MathSymbol => "math symbol",
+            Category::CurrencySymbol => "currency symbol",
+            Category::ModifierSymbol => "modifier symbol",
+            Category::OtherSymbol => "other symbol",
+            Category::SpaceSeparator => "space separator",
+            Category::LineSeparator => "line separator",
+            Category::ParagraphSeparator => "paragraph separator",
+            Category::Control => "control",
+            Category::Format => "format",
+            Category::PrivateUse => "private use",
+        };
+        write!(f, "{} {}", self.code(), name)
+    }
+}
+
+#[derive(Debug, PartialEq)]
+pub struct CharInfo {
+    pub ch: char,
+    pub bytes: Range<usize>,
+    pub utf8: Vec<u8>,
+    pub utf16: Vec<u16>,
+    pub category: Category,
+}
+
+const ZERO_WIDTH_JOINER: char = '\u{200D}';
+
+pub(crate) fn is_combining_mark(c: char) -> bool {
+    matches!(c as u32,
+        0x0300..=0x036F     // combining diacritical marks
+        | 0x0483..=0x0489   // Cyrillic
+        | 0x0591..=0x05BD   // Hebrew points
+        | 0x064B..=0x065F   // Arabic vowel marks
+        | 0x0900..=0x0903   // Devanagari signs
+        | 0x093A..=0x093C | 0x093E..=0x094F   // Devanagari vowel signs and virama
+        | 0x0951..=0x0957
+        | 0x0962..=0x0963
+        | 0x0981..=0x0983   // Bengali
+        | 0x09BC | 0x09BE..=0x09CD | 0x09D7
+        | 0x0E31 | 0x0E34..=0x0E3A | 0x0E47..=0x0E4E  // Thai
+        | 0x1AB0..=0x1AFF
+        | 0x1DC0..=0x1DFF
+        | 0x20D0..=0x20FF   // combining marks for symbols, e.g. keycaps
+        | 0x3099..=0x309A   // Japanese voicing marks
+        | 0xFE20..=0xFE2F
+    )
+}
+
+fn is_variation_selector(c: char) -> bool {
+    matches!(c as u32, 0xFE00..=0xFE0F | 0xE0100..=0xE01EF)
+}
+
+fn is_emoji_modifier(c: char) -> bool {
+    matches!(c as u32, 0x1F3FB..=0x1F3FF)
+}
+
+// Emoji and other pictographs that ZWJ sequences are built from; the
+// Extended_Pictographic property, less its scattered single code points
+fn is_extended_pictographic(c: char) -> bool {
+    matches!(c as u32,
+        0x00A9 | 0x00AE | 0x203C | 0x2049 | 0x2122 | 0x2139
+        | 0x2194..=0x21AA   // arrows
+        | 0x231A..=0x23FF   // watch, hourglass, media controls
+        | 0x25AA..=0x25FE
+        | 0x2600..=0x27BF   // miscellaneous symbols and dingbats
+        | 0x2B05..=0x2B55
+        | 0x1F000..=0x1FFFD
+    ) && !is_regional_indicator(c)
+        && !is_emoji_modifier(c)
+}
+
+fn is_regional_indicator(c: char) -> bool {
+    matches!(c as u32, 0x1F1E6..=0x1F1FF)
+}
+
+// Indic viramas join the consonants around them into one conjunct
+fn is_virama(c: char) -> bool {
+    matches!(
+        c,
+        '\u{094D}' | '\u{09CD}' | '\u{0A4D}' | '\u{0ACD}' | '\u{0B4D}' | '\u{0BCD}' | '\u{0C4D}'
+    )
+}
+
+fn is_format(c: char) -> bool {
+    matches!(c as u32,
+        0x00AD | 0x200B..=0x200F | 0x202A..=0x202E | 0x2060..=0x2064 | 0xFEFF
+    )
+}
+
+// Fullwidth forms of ASCII, e.g. '＋', share the category of the ASCII char
+fn fullwidth_ascii(c: char) -> Option<char> {
+    match c as u32 {
+        0xFF01..=0xFF5E => char::from_u32(c as u32 - 0xFEE0),
+        _ => None,
+    }
+}
+
+pub fn category(c: char) -> Category {
+    if let Some(ascii) = fullwidth_ascii(c) {
+        return category(ascii);
+    }
+    if c.is_control() {
+        Category::Control
+    } else if is_combining_mark(c) || is_variation_selector(c) {
+        mark_category(c)
+    } else if is_format(c) {
+        Category::Format
+    } else if c == '\u{2028}' {
+        Category::LineSeparator
+    } else if c == '\u{2029}' {
+        Category::ParagraphSeparator
+    } else if c.is_whitespace() {
+        Category::SpaceSeparator
+    } else if c.is_alphabetic() || c.is_numeric() {
+        letter_or_number_category(c)
+    } else if is_punctuation(c) {
+        punctuation_category(c)
+    } else if matches!(c as u32, 0xE000..=0xF8FF | 0xF0000..=0xFFFFD | 0x100000..=0x10FFFD) {
+        Category::PrivateUse
+    } else {
+        symbol_category(c)
+    }
+}
+
+fn mark_category(c: char) -> Category {
+    match c as u32 {
+        // Enclosing circles and keycaps drawn around the char before
+        0x0488..=0x0489 | 0x20DD..=0x20E0 | 0x20E2..=0x20E4 => Category::EnclosingMark,
+        // Vowel signs that take up space of their own
+        0x0903
+        | 0x093B
+        | 0x093E..=0x0940
+        | 0x0949..=0x094C
+        | 0x094E..=0x094F
+        | 0x0982..=0x0983
+        | 0x09BE..=0x09C0
+        | 0x09C7..=0x09C8
+        | 0x09CB..=0x09CC
+        | 0x09D7 => Category::SpacingMark,
+        _ => Category::NonspacingMark,
+    }
+}
+
+// `is_alphabetic` also covers letter-like numbers such as 'Ⅻ' and symbols such
+// as 'Ⓐ', and `is_lowercase` covers modifier letters such as 'ʰ', so those
+// ranges are sorted out before case decides between Lu, Ll, Lt and Lo
+fn letter_or_number_category(c: char) -> Category {
+    match c as u32 {
+        0x16EE..=0x16F0 | 0x2160..=0x2182 | 0x2185..=0x2188 | 0x3007 | 0x3021..=0x3029 => {
+            Category::LetterNumber
+        }
+        0x24B6..=0x24E9 | 0x1F130..=0x1F149 | 0x1F150..=0x1F169 | 0x1F170..=0x1F189 => {
+            Category::OtherSymbol
+        }
+        0x02B0..=0x02C1
+        | 0x02C6..=0x02D1
+        | 0x02E0..=0x02E4
+        | 0x02EC
+        | 0x02EE
+        | 0x0374
+        | 0x037A
+        | 0x0559
+        | 0x0640
+        | 0x0E46
+        | 0x1D2C..=0x1D6A
+        | 0x1D78
+        | 0x1D9B..=0x1DBF
+        | 0x2071
+        | 0x207F
+        | 0x2090..=0x209C
+        | 0x3005
+        | 0x3031..=0x3035
+        | 0x309D..=0x309E
+        | 0x30FC..=0x30FE
+        | 0xFF70
+        | 0xFF9E..=0xFF9F => Category::ModifierLetter,
+        // Ordinal indicators are lowercase but not cased letters
+        0x00AA | 0x00BA => Category::OtherLetter,
+        _ if is_decimal_digit(c) => Category::DecimalNumber,
+        _ if c.is_numeric() => Category::OtherNumber,
+        _ if c.is_uppercase() => Category::UppercaseLetter,
+        _ if c.is_lowercase() => Category::LowercaseLetter,
+        // 'ǅ' is neither upper nor lower case but still has a lowercase form
+        _ if c.to_lowercase().ne([c]) => Category::TitlecaseLetter,
+        _ => Category::OtherLetter,
+    }
+}
+
+// The 0-9 runs of the common scripts
+fn is_decimal_digit(c: char) -> bool {
+    c.is_ascii_digit()
+        || matches!(c as u32,
+            0x0660..=0x0669     // Arabic-Indic
+            | 0x06F0..=0x06F9   // Persian
+            | 0x07C0..=0x07C9
+            | 0x0966..=0x096F   // Devanagari
+            | 0x09E6..=0x09EF   // Bengali
+            | 0x0A66..=0x0A6F
+            | 0x0AE6..=0x0AEF
+            | 0x0B66..=0x0B6F
+            | 0x0BE6..=0x0BEF   // Tamil
+            | 0x0C66..=0x0C6F
+            | 0x0CE6..=0x0CEF
+            | 0x0D66..=0x0D6F
+            | 0x0E50..=0x0E59   // Thai
+            | 0x0ED0..=0x0ED9
+            | 0x0F20..=0x0F29
+            | 0x1040..=0x1049
+            | 0x17E0..=0x17E9
+            | 0x1810..=0x1819
+            | 0x1D7CE..=0x1D7FF // mathematical digits
+        )
+}
+
+fn is_punctuation(c: char) -> bool {
+    c.is_ascii_punctuation() && !"$+<=>^`|~".contains(c)
+        || matches!(c as u32,
+            0x00A1 | 0x00A7 | 0x00AB | 0x00B6 | 0x00B7 | 0x00BB | 0x00BF
+            | 0x055A..=0x055F | 0x0589..=0x058A  // Armenian
+            | 0x060C | 0x061B | 0x061F | 0x066A..=0x066D  // Arabic
+            | 0x0964..=0x0965   // Devanagari danda
+            | 0x2010..=0x2027   // dashes, quotes, bullets, ellipsis
+            | 0x2030..=0x205E
+            | 0x207D..=0x207E | 0x208D..=0x208E
+            | 0x3001..=0x3003   // CJK comma and full stops
+            | 0x3008..=0x3011   // CJK brackets
+            | 0x3014..=0x301F
+        ) && !matches!(c as u32, 0x2044 | 0x2052)
+}
+
+fn punctuation_category(c: char) -> Category {
+    match c as u32 {
+        0x005F | 0x203F..=0x2040 | 0x2054 => Category::ConnectorPunctuation,
+        0x002D | 0x058A | 0x2010..=0x2015 | 0x301C => Category::DashPunctuation,
+        // Opening quotes; '‚' and '„' open a quote but count as Ps
+        0x00AB | 0x2018 | 0x201B..=0x201C | 0x201F | 0x2039 => Category::InitialPunctuation,
+        0x00BB | 0x2019 | 0x201D | 0x203A => Category::FinalPunctuation,
+        0x0028 | 0x005B | 0x007B | 0x201A | 0x201E | 0x2045 | 0x207D | 0x208D => {
+            Category::OpenPunctuation
+        }
+        0x0029 | 0x005D | 0x007D | 0x2046 | 0x207E | 0x208E => Category::ClosePunctuation,
+        // CJK brackets come in open/close pairs starting on an even code point
+        0x3008..=0x3011 | 0x3014..=0x301B if (c as u32).is_multiple_of(2) => {
+            Category::OpenPunctuation
+        }
+        0x3008..=0x3011 | 0x3014..=0x301B => Category::ClosePunctuation,
+        0x301D => Category::OpenPunctuation,
+        0x301E..=0x301F => Category::ClosePunctuation,
+        _ => Category::OtherPunctuation,
+    }
+}
+
+fn symbol_category(c: char) -> Category {
+    match c as u32 {
+        0x002B | 0x003C..=0x003E | 0x007C | 0x007E
+        | 0x00AC | 0x00B1 | 0x00D7 | 0x00F7
+        | 0x2044 | 0x2052
+        | 0x207A..=0x207C | 0x208A..=0x208C
+        | 0x2190..=0x2194 | 0x21D2 | 0x21D4
+        | 0x2200..=0x22FF   // mathematical operators
+        | 0x2A00..=0x2AFF => Category::MathSymbol,
+        0x0024 | 0x00A2..=0x00A5 | 0x058F | 0x060B | 0x09F2..=0x09F3 | 0x0E3F
+        | 0x20A0..=0x20C0   // currency symbols, e.g. '€' and '₹'
+        | 0xFFE0..=0xFFE1 | 0xFFE5..=0xFFE6 => Category::CurrencySymbol,
+        // Accents written on their own and emoji skin tones
+        0x005E | 0x0060 | 0x00A8 | 0x00AF | 0x00B4 | 0x00B8
+        | 0x02C2..=0x02C5 | 0x02D2..=0x02DF | 0x02E5..=0x02EB | 0x02ED | 0x02EF..=0x02FF
+        | 0x0375 | 0x0384..=0x0385
+        | 0x309B..=0x309C
+        | 0xA700..=0xA716 | 0xA720..=0xA721
+        | 0xFFE3 => Category::ModifierSymbol,
+        _ if is_emoji_modifier(c) => Category::ModifierSymbol,
+        _ => Category::OtherSymbol,
+    }
+}
+
+// Every code point with its position and encodings
+pub fn inspect(text: &str) -> Vec<CharInfo> {
+    text.char_indices()
+        .map(|(start, ch)| {
+            let mut utf16 = [0u16; 2];
+            CharInfo {
+                ch,
+                bytes: start..start + ch.len_utf8(),
+                utf8: ch.to_string().into_bytes(),
+                utf16: ch.encode_utf16(&mut utf16).to_vec(),
+                category: category(ch),
+            }
+        })
+        .collect()
+}
+
+// The offsets a slice may start or end at, including text.len()
+pub fn char_boundaries(text: &str) -> Vec<usize> {
+    (0..=text.len())
+        .filter(|&index| text.is_char_boundary(index))
+        .collect()
+}
+
+// Offsets inside a multi-byte char: `&text[..i]` or `&text[i..]` panics
+pub fn bad_offsets(text: &str) -> Vec<usize> {
+    (0..=text.len())
+        .filter(|&index| !text.is_char_boundary(index))
+        .collect()
+}
+
+// Chars that attach to whatever comes before them
+fn is_extend(c: char) -> bool {
+    is_combining_mark(c)
+        || is_variation_selector(c)
+        || is_emoji_modifier(c)
+        || c == ZERO_WIDTH_JOINER
+}
+
+// Does a char continue the cluster that `previous` is in? A ZWJ only glues
+// two pictographs together, so that case is decided in `graphemes`
+fn extends(previous: char, c: char) -> bool {
+    (previous == '\r' && c == '\n') || is_extend(c) || (is_virama(previous) && c.is_alphabetic())
+}
+
+// User-perceived characters, e.g. "e\u{301}" (e + combining acute) is one
+pub fn graphemes(text: &str) -> Vec<&str> {
+    let mut clusters = Vec::new();
+    let mut start = 0;
+    let mut previous: Option<char> = None;
+    // Regional indicators pair up into flags, so count them in the cluster
+    let mut indicators = 0;
+    // Whether the last char other than marks and joiners was a pictograph
+    let mut after_pictograph = false;
+    for (index, c) in text.char_indices() {
+        let joins = match previous {
+            None => false,
+            Some(_) if is_regional_indicator(c) && indicators % 2 == 1 => true,
+            Some(ZERO_WIDTH_JOINER) if after_pictograph && is_extended_pictographic(c) => true,
+            Some(previous) => extends(previous, c),
+        };
+        if !joins && index > 0 {
+            clusters.push(&text[start..index]);
+            start = index;
+            indicators = 0;
+        }
+        if is_regional_indicator(c) {
+            indicators += 1;
+        }
+        if !is_extend(c) {
+            after_pictograph = is_extended_pictographic(c);
+        }
+        previous = Some(c);
+    }
+    if start < text.len() {
+        clusters.push(&text[start..]);
+    }
+    clusters
+}
+
+// How a char is shown in the table: invisible ones are escaped and combining
+// marks are drawn on a dotted circle
+fn visible(c: char) -> String {
+    match category(c) {
+        _ if is_variation_selector(c) => c.escape_debug().to_string(),
+        Category::Control
+        | Category::Format
+        | Category::SpaceSeparator
+        | Category::LineSeparator
+        | Category::ParagraphSeparator => c.escape_debug().to_string(),
+        category if category.is_mark() => format!("\u{25CC}{}", c),
+        _ => c.to_string(),
+    }
+}
+
+// Terminal columns a string takes: wide for emoji and CJK, none for marks
+fn columns(text: &str) -> usize {
+    text.chars()
+        .map(|c| match c as u32 {
+            _ if is_combining_mark(c) || is_variation_selector(c) => 0,
+            0x1100..=0x115F
+            | 0x2E80..=0xA4CF
+            | 0xAC00..=0xD7A3
+            | 0xF900..=0xFAFF
+            | 0xFF00..=0xFF60
+            | 0x1F300..=0x1FAFF
+            | 0x20000..=0x3FFFD => 2,
+            _ => 1,
+        })
+        .sum()
+}
+
+fn hex<T: fmt::LowerHex>(units: &[T], width: usize) -> String {
+    units
+        .iter()
+        .map(|unit| format!("{:0width$x}", unit, width = width))
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+// The full report printed by `roadmap utf8 "<text>"`
+pub fn report(text: &str) -> Vec<String> {
+    let chars = inspect(text);
+    let clusters = graphemes(text);
+    let utf16_units: usize = chars.iter().map(|info| info.utf16.len()).sum();
+    let mut lines = vec![
+        format!("{:?}", text),
+        format!(
+            "{} bytes, {} chars, {} graphemes, {} UTF-16 units",
+            text.len(),
+            chars.len(),
+            clusters.len(),
+            utf16_units
+        ),
+        String::new(),
+        format!(
+            "{:>5}  {:<10}{:<12}{:<14}{:<11}{}",
+            "byte", "char", "code point", "UTF-8", "UTF-16", "category"
+        ),
+    ];
+    for info in &chars {
+        let shown = visible(info.ch);
+        let line = format!(
+            "{:>5}  {}{}{:<12}{:<14}{:<11}{}",
+            info.bytes.start,
+            shown,
+            " ".repeat(10usize.saturating_sub(columns(&shown))),
+            format!("U+{:04X}", info.ch as u32),
+            hex(&info.utf8, 2),
+            hex(&info.utf16, 4),
+            info.category
+        );
+        lines.push(line);
+    }
+
+    lines.push(String::new());
+    let join = |offsets: Vec<usize>| {
+        offsets
+            .iter()
+            .map(usize::to_string)
+            .collect::<Vec<_>>()
+            .join(" ")
+    };
+    lines.push(format!("char boundaries:  {}", join(char_boundaries(text))));
+    let bad = bad_offsets(text);
+    if bad.is_empty() {
+        lines.push("every offset is a boundary: the text is ASCII".to_string());
+    } else {
+        lines.push(format!("slicing panics at: {}", join(bad)));
+    }
+    let shown: Vec<String> = clusters
+        .iter()
+        .map(|cluster| format!("[{}]", cluster.escape_debug()))
+        .collect();
+    lines.push(format!("graphemes:        {}", shown.join(" ")));
+    lines
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn inspects_encodings_and_offsets() {
+        let chars = inspect("aé🦀");
+        assert_eq!(chars[1].bytes, 1..3);
+        assert_eq!(chars[1].utf8, vec![0xc3, 0xa9]);
+        assert_eq!(chars[2].utf8, vec![0xf0, 0x9f, 0xa6, 0x80]);
+        assert_eq!(chars[2].utf16, vec![0xd83e, 0xdd80]);
+        assert_eq!(char_boundaries("aé🦀"), vec![0, 1, 3, 7]);
+        assert_eq!(bad_offsets("aé🦀"), vec![2, 4, 5, 6]);
+    }
+
+    #[test]
+    fn categorises_common_characters() {
+        let codes: Vec<&str> = "Aa中1!+ \u{301}\u{200B}\n"
+            .chars()
+            .map(|c| category(c).code())
+            .collect();
+        assert_eq!(
+            codes,
+            vec!["Lu", "Ll", "Lo", "Nd", "Po", "Sm", "Zs", "Mn", "Cf", "Cc"]
+        );
+        assert_eq!(category('€'), Category::CurrencySymbol);
+        assert_eq!(category('…'), Category::OtherPunctuation);
+        // Skin tones are modifier symbols, not combining marks
+        assert_eq!(category('\u{1F3FD}').to_string(), "Sk modifier symbol");
+    }
+
+    #[test]
+    fn every_category_has_its_ucd_code() {
+        let cases = [
+            ('ǅ', "Lt"),
+            ('ʰ', "Lm"),
+            ('ª', "Lo"),
+            ('\u{93F}', "Mc"),  // Devanagari vowel sign i
+            ('\u{20DD}', "Me"), // combining enclosing circle
+            ('\u{FE0F}', "Mn"), // variation selector
+            ('٣', "Nd"),
+            ('Ⅻ', "Nl"),
+            ('½', "No"),
+            ('_', "Pc"),
+            ('-', "Pd"),
+            ('(', "Ps"),
+            (')', "Pe"),
+            ('「', "Ps"),
+            ('」', "Pe"),
+            ('«', "Pi"),
+            ('»', "Pf"),
+            ('„', "Ps"),
+            ('∑', "Sm"),
+            ('＋', "Sm"),
+            ('＄', "Sc"),
+            ('^', "Sk"),
+            ('©', "So"),
+            ('Ⓐ', "So"),
+            ('🦀', "So"),
+            ('\u{2028}', "Zl"),
+            ('\u{2029}', "Zp"),
+            ('\u{E000}', "Co"),
+        ];
+        for (c, code) in cases {
+            assert_eq!(category(c).code(), code, "{:?}", c);
+        }
+    }
+
+    #[test]
+    fn groups_user_perceived_characters() {
+        assert_eq!(graphemes("e\u{301}x"), vec!["e\u{301}", "x"]);
+        assert_eq!(graphemes("a\r\nb"), vec!["a", "\r\n", "b"]);
+        // Woman + ZWJ + laptop, thumbs up + skin tone, two flags
+        assert_eq!(graphemes("👩\u{200D}💻👍🏽"), vec!["👩\u{200D}💻", "👍🏽"]);
+        assert_eq!(graphemes("👍🏽"), vec!["👍🏽"]);
+        assert_eq!(graphemes("🇯🇵🇫🇷"), vec!["🇯🇵", "🇫🇷"]);
+        // A ZWJ stays with the char before it but only joins pictographs
+        assert_eq!(graphemes("a\u{200D}b"), vec!["a\u{200D}", "b"]);
+        assert_eq!(graphemes("a\u{200D}💻"), vec!["a\u{200D}", "💻"]);
+        // Family: man ZWJ woman ZWJ girl, with a skin tone on the man
+        assert_eq!(
+            graphemes("👨🏽\u{200D}👩\u{200D}👧"),
+            vec!["👨🏽\u{200D}👩\u{200D}👧"]
+        );
+        // स + virama + त + vowel sign e is one conjunct
+        assert_eq!(graphemes("नमस्ते"), vec!["न", "म", "स्ते"]);
+        assert!(graphemes("").is_empty());
+    }
+
+    #[test]
+    fn report_lists_panicking_offsets() {
+        let lines = report("Зд");
+        assert_eq!(lines[1], "4 bytes, 2 chars, 2 graphemes, 2 UTF-16 units");
+        assert!(lines.contains(&"slicing panics at: 1 3".to_string()));
+        assert!(lines[4].contains("U+0417      d0 97         0417       Lu"));
+    }
+}
@@ -0,0 +1,549 @@
+// Validation
+// The boolean lesson checks input with functions like `is_valid_username`
+// that answer a bare true or false. Here the checks are values instead: a
+// `Rule<T>` knows what it requires ("have 3 to 20 characters"), rules combine
+// with `and`, `or` and `not`, and checking a value lists every rule it broke.
+//
+//   let username = length(3..=20).and(only("[A-Za-z0-9_]"));
+//   username.failures("a!")   // ["have 3 to 20 characters", "only contain [A-Za-z0-9_]"]
+//
+// `validator!` implements `Validate` for a struct from one rule per field,
+// and failures carry the path to the field, e.g. `account.age`:
+//
+//   validator!(Signup { username: length(3..=20), age: range(0..=150) });
+//   signup.validate()   // Err([Failure { path: "age", message: "must be between 0 and 150" }])
+
+use std::fmt;
+use std::ops::{Bound, RangeBounds};
+use std::rc::Rc;
+
+// The descriptions of the parts that failed, empty when the value passes
+type Check<T> = Rc<dyn Fn(&T) -> Vec<String>>;
+
+pub struct Rule<T: ?Sized> {
+    description: String,
+    check: Check<T>,
+}
+
+impl<T: ?Sized> Clone for Rule<T> {
+    fn clone(&self) -> Self {
+        Rule {
+            description: self.description.clone(),
+            check: Rc::clone(&self.check),
+        }
+    }
+}
+
+impl<T: ?Sized + 'static> Rule<T> {
+    // A rule from a predicate; `description` completes "must ...", e.g.
+    // `Rule::new("be even", |n: &i32| n % 2 == 0)`
+    pub fn new(description: &str, predicate: impl Fn(&T) -> bool + 'static) -> Rule<T> {
+        let failed = description.to_string();
+        Rule {
+            description: description.to_string(),
+            check: Rc::new(move |value| {
+                if predicate(value) {
+                    Vec::new()
+                } else {
+                    vec![failed.clone()]
+                }
+            }),
+        }
+    }
+
+    pub fn description(&self) -> &str {
+        &self.description
+    }
+
+    // Replaces the description, e.g. "contain [A-Z]" with "contain an uppercase letter"
+    pub fn describe(self, description: &str) -> Rule<T> {
+        let inner = self.check;
+        let failed = description.to_string();
+        Rule {
+            description: description.to_string(),
+            check: Rc::new(move |value| {
+                if inner(value).is_empty() {
+                    Vec::new()
+                } else {
+                    vec![failed.clone()]
+                }
+            }),
+        }
+    }
+
+    // Both must pass; each one that does not is reported
+    pub fn and(self, other: Rule<T>) -> Rule<T> {
+        let (first, second) = (self.check, other.check);
+        Rule {
+            description: format!("{} and {}", self.description, other.description),
+            check: Rc::new(move |value| {
+                let mut failed = first(value);
+                failed.extend(second(value));
+                failed
+            }),
+        }
+    }
+
+    // Either may pass; if neither does, the combined rule is reported
+    pub fn or(self, other: Rule<T>) -> Rule<T> {
+        let (first, second) = (self.check, other.check);
+        let description = format!("{} or {}", self.description, other.description);
+        let failed = description.clone();
+        Rule {
+            description,
+            check: Rc::new(move |value| {
+                if first(value).is_empty() || second(value).is_empty() {
+                    Vec::new()
+                } else {
+                    vec![failed.clone()]
+                }
+            }),
+        }
+    }
+
+    // Passes when `self` fails: `contains("\\s").not()` is "must not contain \s"
+    #[allow(clippy::should_implement_trait)]
+    pub fn not(self) -> Rule<T> {
+        let inner = self.check;
+        let description = format!("not {}", self.description);
+        let failed = description.clone();
+        Rule {
+            description,
+            check: Rc::new(move |value| {
+                if inner(value).is_empty() {
+                    vec![failed.clone()]
+                } else {
+                    Vec::new()
+                }
+            }),
+        }
+    }
+
+    pub fn is_valid(&self, value: &T) -> bool {
+        (self.check)(value).is_empty()
+    }
+
+    // The parts of the rule `value` breaks
+    pub fn failures(&self, value: &T) -> Vec<String> {
+        (self.check)(value)
+    }
+
+    // Failures for a field, used by `validator!`
+    pub fn check_at(&self, value: &T, path: &str) -> Vec<Failure> {
+        (self.check)(value)
+            .into_iter()
+            .map(|failed| Failure {
+                path: path.to_string(),
+                message: format!("must {}", failed),
+            })
+            .collect()
+    }
+}
+
+// "be between 0 and 150", "be at least 18"
+pub fn range<T, R>(bounds: R) -> Rule<T>
+where
+    T: PartialOrd + fmt::Display + Clone + 'static,
+    R: RangeBounds<T>,
+{
+    let start = bounds.start_bound().cloned();
+    let end = bounds.end_bound().cloned();
+    let description = match (&start, &end) {
+        (Bound::Included(low), Bound::Included(high)) => format!("be between {} and {}", low, high),
+        _ => {
+            let mut parts = Vec::new();
+            match &start {
+                Bound::Included(low) => parts.push(format!("at least {}", low)),
+                Bound::Excluded(low) => parts.push(format!("greater than {}", low)),
+                Bound::Unbounded => {}
+            }
+            match &end {
+                Bound::Included(high) => parts.push(format!("at most {}", high)),
+                Bound::Excluded(high) => parts.push(format!("less than {}", high)),
+                Bound::Unbounded => {}
+            }
+            format!("be {}", parts.join(" and "))
+        }
+    };
+    Rule::new(&description, move |value: &T| {
+        (start.as_ref(), end.as_ref()).contains(value)
+    })
+}
+
+// Counts chars, not bytes: "héllo" has 5. Panics on a range no length fits,
+// like `0..0` or `5..3`, which is a bug in the rule rather than in the input
+pub fn length<R: RangeBounds<usize> + fmt::Debug>(bounds: R) -> Rule<str> {
+    let empty = || -> ! { panic!("length({:?}) allows no length at all", bounds) };
+    let low = match bounds.start_bound() {
+        Bound::Included(&low) => low,
+        Bound::Excluded(&low) => low.checked_add(1).unwrap_or_else(|| empty()),
+        Bound::Unbounded => 0,
+    };
+    let high = match bounds.end_bound() {
+        Bound::Included(&high) => Some(high),
+        Bound::Excluded(&high) => Some(high.checked_sub(1).unwrap_or_else(|| empty())),
+        Bound::Unbounded => None,
+    };
+    if high.is_some_and(|high| low > high) {
+        empty();
+    }
+    let description = match high {
+        Some(high) if low == high => format!("have exactly {} characters", low),
+        Some(high) if low > 0 => format!("have {} to {} characters", low, high),
+        Some(high) => format!("have at most {} characters", high),
+        None => format!("have at least {} characters", low),
+    };
+    Rule::new(&description, move |text: &str| {
+        let count = text.chars().count();
+        count >= low && high.is_none_or(|high| count <= high)
+    })
+}
+
+pub fn not_empty() -> Rule<str> {
+    Rule::new("not be empty", |text: &str| !text.is_empty())
+}
+
+// Every char is in the class, e.g. `only("[a-z0-9_]")`. Panics on a malformed
+// pattern, which is a bug in the rule rather than in the input
+pub fn only(pattern: &str) -> Rule<str> {
+    let class = CharClass::parse(pattern).unwrap_or_else(|err| panic!("{}", err));
+    Rule::new(&format!("only contain {}", pattern), move |text: &str| {
+        text.chars().all(|c| class.matches(c))
+    })
+}
+
+// At least one char is in the class, e.g. `contains("[A-Z]")`
+pub fn contains(pattern: &str) -> Rule<str> {
+    let class = CharClass::parse(pattern).unwrap_or_else(|err| panic!("{}", err));
+    Rule::new(&format!("contain {}", pattern), move |text: &str| {
+        text.chars().any(|c| class.matches(c))
+    })
+}
+
+// A regex-style character class: `[a-zA-Z_]`, `[^0-9]`, `\d`, `\w`, `\s`
+#[derive(Debug, Clone, PartialEq)]
+pub struct CharClass {
+    negated: bool,
+    items: Vec<ClassItem>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+enum ClassItem {
+    Range(char, char),
+    Digit,
+    Word,
+    Space,
+}
+
+#[derive(Debug, PartialEq)]
+pub enum CharClassError {
+    MissingBrackets(String),
+    Unclosed(String),
+    Empty(String),
+    BackwardsRange(char, char),
+    UnknownEscape(char),
+}
+
+impl fmt::Display for CharClassError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            CharClassError::MissingBrackets(pattern) => write!(
+                f,
+                "character class '{}' must be written as [...] or \\d, \\w, \\s",
+                pattern
+            ),
+            CharClassError::Unclosed(pattern) => {
+                write!(f, "character class '{}' is missing its closing ]", pattern)
+            }
+            CharClassError::Empty(pattern) => write!(f, "character class '{}' is empty", pattern),
+            CharClassError::BackwardsRange(from, to) => {
+                write!(f, "range {}-{} is out of order", from, to)
+            }
+            CharClassError::UnknownEscape(c) => write!(f, "unknown escape \\{}", c),
+        }
+    }
+}
+
+impl CharClass {
+    pub fn parse(pattern: &str) -> Result<CharClass, CharClassError> {
+        if let Some(item) = pattern.strip_prefix('\\').and_then(shorthand) {
+            return Ok(CharClass {
+                negated: false,
+                items: vec![item],
+            });
+        }
+        let body = pattern
+            .strip_prefix('[')
+            .ok_or_else(|| CharClassError::MissingBrackets(pattern.to_string()))?
+            .strip_suffix(']')
+            .ok_or_else(|| CharClassError::Unclosed(pattern.to_string()))?;
+        let (negated, body) = match body.strip_prefix('^') {
+            Some(rest) => (true, rest),
+            None => (false, body),
+        };
+
+        let mut chars = body.chars().peekable();
+        let mut items = Vec::new();
+        while let Some(c) = chars.next() {
+            let from = if c == '\\' {
+                let escaped = chars.next().ok_or(CharClassError::UnknownEscape(' '))?;
+                if let Some(item) = shorthand(&escaped.to_string()) {
+                    items.push(item);
+                    continue;
+                }
+                match escaped {
+                    '\\' | '-' | ']' | '[' | '^' => escaped,
+                    other => return Err(CharClassError::UnknownEscape(other)),
+                }
+            } else {
+                c
+            };
+            // `a-z`, but a `-` at the end is a literal dash
+            let mut lookahead = chars.clone();
+            if lookahead.next() == Some('-')
+                && let Some(to) = lookahead.next()
+            {
+                if to < from {
+                    return Err(CharClassError::BackwardsRange(from, to));
+                }
+                chars = lookahead;
+                items.push(ClassItem::Range(from, to));
+            } else {
+                items.push(ClassItem::Range(from, from));
+            }
+        }
+        if items.is_empty() {
+            return Err(CharClassError::Empty(pattern.to_string()));
+        }
+        Ok(CharClass { negated, items })
+    }
+
+    pub fn matches(&self, c: char) -> bool {
+        let found = self.items.iter().any(|item| match *item {
+            ClassItem::Range(from, to) => (from..=to).contains(&c),
+            ClassItem::Digit => c.is_ascii_digit(),
+            ClassItem::Word => c.is_ascii_alphanumeric() || c == '_',
+            ClassItem::Space => c.is_whitespace(),
+        });
+        found != self.negated
+    }
+}
+
+fn shorthand(escape: &str) -> Option<ClassItem> {
+    match escape {
+        "d" => Some(ClassItem::Digit),
+        "w" => Some(ClassItem::Word),
+        "s" => Some(ClassItem::Space),
+        _ => None,
+    }
+}
+
+// One broken rule: where, and what the value must do instead
+#[derive(Debug, Clone, PartialEq)]
+pub struct Failure {
+    pub path: String,
+    pub message: String,
+}
+
+impl fmt::Display for Failure {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}: {}", self.path, self.message)
+    }
+}
+
+pub trait Validate {
+    // Every failure, with paths below `path` ("" for the top level)
+    fn failures_at(&self, path: &str) -> Vec<Failure>;
+
+    fn validate(&self) -> Result<(), Vec<Failure>> {
+        let failures = self.failures_at("");
+        if failures.is_empty() {
+            Ok(())
+        } else {
+            Err(failures)
+        }
+    }
+}
+
+// The rule for a field whose type implements `Validate` itself
+pub struct Nested;
+
+impl Nested {
+    pub fn check_at<T: Validate>(&self, value: &T, path: &str) -> Vec<Failure> {
+        value.failures_at(path)
+    }
+}
+
+// `server` inside `config` is `config.server`
+pub fn join(path: &str, field: &str) -> String {
+    if path.is_empty() {
+        field.to_string()
+    } else {
+        format!("{}.{}", path, field)
+    }
+}
+
+// Implements `Validate` from one rule per field; fields without a rule are not
+// checked, and `Nested` validates a field with its own `Validate` impl:
+//
+//   validator!(Config { name: not_empty(), server: Nested });
+#[macro_export]
+macro_rules! validator {
+    ($ty:ident { $($field:ident: $rule:expr),* $(,)? }) => {
+        impl $crate::validation::Validate for $ty {
+            fn failures_at(&self, path: &str) -> Vec<$crate::validation::Failure> {
+                let mut failures = Vec::new();
+                $(
+                    failures.extend(($rule).check_at(
+                        &self.$field,
+                        &$crate::validation::join(path, stringify!($field)),
+                    ));
+                )*
+                failures
+            }
+        }
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn rules_describe_what_they_require() {
+        assert_eq!(range(0..=150).description(), "be between 0 and 150");
+        assert_eq!(range(18..).description(), "be at least 18");
+        assert_eq!(
+            range(0.0..1.0).description(),
+            "be at least 0 and less than 1"
+        );
+        assert_eq!(length(3..=20).description(), "have 3 to 20 characters");
+        assert_eq!(length(8..).description(), "have at least 8 characters");
+        assert_eq!(length(..=5).description(), "have at most 5 characters");
+        assert_eq!(
+            contains("[A-Z]")
+                .describe("contain an uppercase letter")
+                .description(),
+            "contain an uppercase letter"
+        );
+        assert!(range(0..=150).is_valid(&150));
+        assert!(!range(0..=150).is_valid(&-5));
+        assert!(length(..=5).is_valid("héllo"));
+    }
+
+    #[test]
+    #[allow(clippy::reversed_empty_ranges)]
+    fn length_rejects_ranges_no_length_fits() {
+        assert_eq!(length(0..=0).description(), "have exactly 0 characters");
+        assert_eq!(length(3..4).description(), "have exactly 3 characters");
+        for bounds in [0..0, 5..3, 4..4] {
+            let built = std::panic::catch_unwind(|| length(bounds.clone()));
+            assert!(built.is_err(), "length({:?}) was accepted", bounds);
+        }
+        assert!(std::panic::catch_unwind(|| length(..0)).is_err());
+        assert!(std::panic::catch_unwind(|| length(5..=3)).is_err());
+    }
+
+    #[test]
+    fn combinators_report_each_broken_part() {
+        let username = length(3..=20).and(only("[A-Za-z0-9_]"));
+        assert!(username.is_valid("john_doe"));
+        assert_eq!(
+            username.failures("a!"),
+            vec!["have 3 to 20 characters", "only contain [A-Za-z0-9_]"]
+        );
+
+        let id = only("\\d").or(only("[a-f]"));
+        assert!(id.is_valid("123") && id.is_valid("cafe"));
+        assert_eq!(
+            id.failures("12ab"),
+            vec!["only contain \\d or only contain [a-f]"]
+        );
+
+        let no_spaces = contains("\\s").not();
+        assert!(no_spaces.is_valid("one"));
+        assert_eq!(no_spaces.failures("one two"), vec!["not contain \\s"]);
+    }
+
+    #[test]
+    fn parses_character_classes() {
+        let class = CharClass::parse("[a-cX\\-_]").unwrap();
+        assert!("abcX-_".chars().all(|c| class.matches(c)));
+        assert!(!class.matches('d'));
+        let not_digit = CharClass::parse("[^0-9]").unwrap();
+        assert!(not_digit.matches('a') && !not_digit.matches('5'));
+        assert!(CharClass::parse("[a-]").unwrap().matches('-'));
+        assert!(CharClass::parse("\\w").unwrap().matches('_'));
+        assert_eq!(
+            CharClass::parse("[z-a]"),
+            Err(CharClassError::BackwardsRange('z', 'a'))
+        );
+        assert_eq!(
+            CharClass::parse("[abc"),
+            Err(CharClassError::Unclosed("[abc".to_string()))
+        );
+        assert_eq!(
+            CharClass::parse("abc"),
+            Err(CharClassError::MissingBrackets("abc".to_string()))
+        );
+        assert_eq!(
+            CharClass::parse("[\\q]"),
+            Err(CharClassError::UnknownEscape('q'))
+        );
+    }
+
+    struct Server {
+        host: String,
+        port: u16,
+    }
+
+    struct Config {
+        name: String,
+        retries: u32,
+        server: Server,
+    }
+
+    validator!(Server {
+        host: not_empty(),
+        port: range(1024..),
+    });
+
+    validator!(Config {
+        name: length(1..=32).and(only("[a-z-]")),
+        retries: range(..=5),
+        server: Nested,
+    });
+
+    #[test]
+    fn validator_collects_failures_with_field_paths() {
+        let mut config = Config {
+            name: "api".to_string(),
+            retries: 3,
+            server: Server {
+                host: "localhost".to_string(),
+                port: 8080,
+            },
+        };
+        assert_eq!(config.validate(), Ok(()));
+
+        config.name = "API".to_string();
+        config.retries = 10;
+        config.server.host.clear();
+        config.server.port = 80;
+        let failures: Vec<String> = config
+            .validate()
+            .unwrap_err()
+            .iter()
+            .map(Failure::to_string)
+            .collect();
+        assert_eq!(
+            failures,
+            vec![
+                "name: must only contain [a-z-]",
+                "retries: must be at most 5",
+                "server.host: must not be empty",
+                "server.port: must be at least 1024",
+            ]
+        );
+    }
+}